@@ -44,7 +44,7 @@ if [ "$IS_NIGHTLY" = "1" ]; then
 fi
 
 NIGHTLY_EXAMPLES=(hasher)
-STABLE_EXAMPLES=(canvas echo minimal todomvc)
+STABLE_EXAMPLES=(benchmarks canvas echo fullscreen gamepad media-player minimal permissions recorder todomvc web-audio webcam)
 if [ "$IS_NIGHTLY" = "1" ]; then
     EXAMPLES=( "${NIGHTLY_EXAMPLES[@]}" "${STABLE_EXAMPLES[@]}" )
 else
@@ -0,0 +1,46 @@
+use stdweb::Value;
+use stdweb::web::TypedArray;
+use stdweb::base64;
+
+use bench;
+
+const SIZE: usize = 4 * 1024 * 1024;
+
+// Encodes and decodes a few megabytes of binary data, once in Rust
+// and once with `btoa`/`atob` over a binary string built in chunks,
+// since a single `String.fromCharCode.apply` overflows the stack.
+pub fn run() {
+    let bytes: Vec< u8 > = (0..SIZE).map( |index| (index * 7919 % 256) as u8 ).collect();
+    let array: TypedArray< u8 > = bytes.as_slice().into();
+
+    let encoded = base64::encode( &bytes );
+    bench( "base64::encode (4 MiB)", 10, || {
+        base64::encode( &bytes );
+    });
+
+    bench( "chunked btoa (4 MiB)", 10, || {
+        js! { @(no_return)
+            var array = @{&array};
+            var binary = "";
+            for( var offset = 0; offset < array.length; offset += 0x8000 ) {
+                binary += String.fromCharCode.apply( null, array.subarray( offset, offset + 0x8000 ) );
+            }
+            btoa( binary );
+        }
+    });
+
+    bench( "base64::decode (4 MiB)", 10, || {
+        base64::decode( &encoded ).unwrap();
+    });
+
+    let encoded: Value = encoded.into();
+    bench( "atob (4 MiB)", 10, || {
+        js! { @(no_return)
+            var binary = atob( @{&encoded} );
+            var array = new Uint8Array( binary.length );
+            for( var index = 0; index < binary.length; ++index ) {
+                array[ index ] = binary.charCodeAt( index );
+            }
+        }
+    });
+}
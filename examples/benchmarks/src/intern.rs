@@ -0,0 +1,26 @@
+use stdweb::{Value, intern};
+
+use bench;
+
+const KEYS: &'static [&'static str] = &[ "loaded", "total", "lengthComputable", "timestamp" ];
+
+// Builds the same per-progress-event stats object over and over,
+// which is exactly the case `intern` is meant for.
+pub fn run() {
+    let plain = bench( "Value::set with &str keys", 10000, || {
+        let stats = js!( return {}; );
+        for (index, &key) in KEYS.iter().enumerate() {
+            stats.set( key, index as f64 );
+        }
+    });
+
+    let interned_keys: Vec< _ > = KEYS.iter().map( |&key| intern( key ) ).collect();
+    let interned = bench( "Value::set with interned keys", 10000, || {
+        let stats: Value = js!( return {}; );
+        for (index, key) in interned_keys.iter().enumerate() {
+            stats.set( key, index as f64 );
+        }
+    });
+
+    ::report( &format!( "Interning saves {:.1}% per object", (1.0 - interned / plain) * 100.0 ) );
+}
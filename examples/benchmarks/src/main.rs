@@ -0,0 +1,49 @@
+#[macro_use]
+extern crate stdweb;
+
+use stdweb::unstable::TryInto;
+
+mod base64;
+mod intern;
+mod structured_clone;
+
+fn now() -> f64 {
+    js!( return performance.now(); ).try_into().unwrap()
+}
+
+/// Runs `body` `iterations` times and reports the average time of a single run.
+fn bench< F: FnMut() >( name: &str, iterations: u32, mut body: F ) -> f64 {
+    // Warm up the JIT first so that it doesn't skew the results.
+    for _ in 0..iterations / 10 {
+        body();
+    }
+
+    let start = now();
+    for _ in 0..iterations {
+        body();
+    }
+
+    let average_ms = (now() - start) / iterations as f64;
+    report( &format!( "{}: {:.4}ms per iteration", name, average_ms ) );
+    average_ms
+}
+
+fn report( line: &str ) {
+    console!( log, line );
+    js! { @(no_return)
+        var output = typeof document !== "undefined" && document.getElementById( "output" );
+        if( output ) {
+            output.textContent += @{line} + "\n";
+        }
+    }
+}
+
+fn main() {
+    stdweb::initialize();
+
+    base64::run();
+    intern::run();
+    structured_clone::run();
+
+    stdweb::event_loop();
+}
@@ -0,0 +1,39 @@
+use stdweb::Value;
+
+use bench;
+
+// A payload like the ones typically sent through `postMessage`:
+// a few nested objects with a typed array at the bottom.
+fn payload() -> Value {
+    js!(
+        var items = [];
+        for( var i = 0; i < 100; ++i ) {
+            items.push( { id: i, tags: new Set( [ "a", "b" ] ), data: new Uint8Array( 256 ) } );
+        }
+
+        return { meta: { created: new Date( 0 ), lookup: new Map( [ [ "key", 1 ] ] ) }, items: items };
+    )
+}
+
+pub fn run() {
+    let value = payload();
+    bench( "structured_clone (native)", 200, || {
+        value.structured_clone().unwrap();
+    });
+
+    // Forces the `MessageChannel` validation and manual copy used when
+    // `structuredClone` isn't available.
+    js! { @(no_return)
+        self.__benchmarks_structured_clone = self.structuredClone;
+        self.structuredClone = undefined;
+    }
+
+    bench( "structured_clone (fallback)", 200, || {
+        value.structured_clone().unwrap();
+    });
+
+    js! { @(no_return)
+        self.structuredClone = self.__benchmarks_structured_clone;
+        delete self.__benchmarks_structured_clone;
+    }
+}
@@ -1,3 +1,4 @@
+#[macro_use]
 extern crate stdweb;
 
 use stdweb::traits::*;
@@ -5,7 +6,9 @@ use stdweb::unstable::TryInto;
 use stdweb::web::{
     document,
     window,
-    CanvasRenderingContext2d
+    CanvasRenderingContext2d,
+    FontFace,
+    FontSource
 };
 
 use stdweb::web::event::{
@@ -15,6 +18,11 @@ use stdweb::web::event::{
 
 use stdweb::web::html_element::CanvasElement;
 
+// Source Code Pro, licensed under the SIL Open Font License; see `static/SOURCE-CODE-PRO-LICENSE.txt`.
+const FONT_URL: &'static str = "url(source-code-pro-500.woff2) format(\"woff2\")";
+const FONT_STYLE: &'static str = "32px SourceCodePro, sans-serif";
+const TEXT: &'static str = "Hello from stdweb!";
+
 // Shamelessly stolen from webplatform's TodoMVC example.
 macro_rules! enclose {
     ( ($( $x:ident ),*) $y:expr ) => {
@@ -34,6 +42,30 @@ fn main() {
     canvas.set_width(canvas.offset_width() as u32);
     canvas.set_height(canvas.offset_height() as u32);
 
+    // Text drawn before a webfont is loaded is rendered with the fallback font,
+    // so we draw it only once the font has been loaded.
+    context.set_font( FONT_STYLE );
+    let fallback_width = context.measure_text( TEXT ).unwrap().get_width();
+
+    let font = FontFace::new( "SourceCodePro", FontSource::Url( FONT_URL ) ).unwrap();
+    font.load( enclose!( (context) move |result| {
+        let font = match result {
+            Ok( font ) => font,
+            Err( error ) => {
+                console!( error, format!( "Failed to load the font: {}", error ) );
+                return;
+            }
+        };
+
+        document().fonts().add( &font );
+        context.set_font( FONT_STYLE );
+        context.fill_text( TEXT, 20.0, 50.0, None );
+
+        let width = context.measure_text( TEXT ).unwrap().get_width();
+        console!( log, format!( "Text width: {} with the fallback font, {} with the webfont", fallback_width, width ) );
+        assert_ne!( width, fallback_width );
+    }));
+
     window().add_event_listener( enclose!( (canvas) move |_: ResizeEvent| {
         canvas.set_width(canvas.offset_width() as u32);
         canvas.set_height(canvas.offset_height() as u32);
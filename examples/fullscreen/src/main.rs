@@ -0,0 +1,55 @@
+extern crate stdweb;
+
+use stdweb::traits::*;
+use stdweb::web::{
+    document,
+    Element,
+    FullscreenError
+};
+
+use stdweb::web::event::{
+    ClickEvent,
+    FullscreenChangeEvent
+};
+
+fn element( selector: &str ) -> Element {
+    document().query_selector( selector ).unwrap().unwrap()
+}
+
+fn set_status( message: &str ) {
+    element( "#status" ).set_text_content( message );
+}
+
+fn main() {
+    stdweb::initialize();
+
+    if !document().fullscreen_enabled() {
+        set_status( "The fullscreen mode isn't available." );
+    }
+
+    let target = element( "#box" );
+    target.add_event_listener( move |_: ClickEvent| {
+        let callback = |result: Result< (), FullscreenError >| {
+            if let Err( error ) = result {
+                set_status( &format!( "Toggling the fullscreen mode failed: {}", error ) );
+            }
+        };
+
+        if document().fullscreen_element().is_some() {
+            document().exit_fullscreen( callback );
+        } else {
+            element( "#box" ).request_fullscreen( callback );
+        }
+    });
+
+    document().add_event_listener( |_: FullscreenChangeEvent| {
+        let message = match document().fullscreen_element() {
+            Some( element ) => format!( "<{}> is in fullscreen mode.", element.node_name().to_lowercase() ),
+            None => "Not in fullscreen mode.".to_owned()
+        };
+
+        set_status( &message );
+    });
+
+    stdweb::event_loop();
+}
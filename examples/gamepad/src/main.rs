@@ -0,0 +1,66 @@
+extern crate stdweb;
+
+use stdweb::traits::*;
+use stdweb::web::{
+    document,
+    window,
+    Element,
+    Gamepad
+};
+
+use stdweb::web::event::{
+    GamepadConnectedEvent,
+    GamepadDisconnectedEvent
+};
+
+fn element( selector: &str ) -> Element {
+    document().query_selector( selector ).unwrap().unwrap()
+}
+
+fn describe( gamepad: &Gamepad ) -> String {
+    let axes: Vec< String > = gamepad.axes().iter()
+        .map( |value| format!( "{:+.2}", value ) )
+        .collect();
+
+    let pressed: Vec< String > = gamepad.buttons().iter()
+        .enumerate()
+        .filter( |&(_, button)| button.pressed )
+        .map( |(index, _)| index.to_string() )
+        .collect();
+
+    format!( "#{} {}\n    axes: [{}]\n    pressed buttons: [{}]\n",
+        gamepad.index(),
+        gamepad.id(),
+        axes.join( ", " ),
+        pressed.join( ", " )
+    )
+}
+
+// The gamepads are only snapshots, so they have to be polled again every frame.
+fn poll_gamepads( _: f64 ) {
+    let text: String = window().navigator().get_gamepads().into_iter()
+        .filter_map( |gamepad| gamepad )
+        .map( |gamepad| describe( &gamepad ) )
+        .collect();
+
+    element( "#gamepads" ).set_text_content( &text );
+    window().request_animation_frame( poll_gamepads );
+}
+
+fn main() {
+    stdweb::initialize();
+
+    window().add_event_listener( |event: GamepadConnectedEvent| {
+        let message = format!( "Gamepad #{} was connected.", event.gamepad().index() );
+        element( "#status" ).set_text_content( &message );
+    });
+
+    window().add_event_listener( |event: GamepadDisconnectedEvent| {
+        let message = format!( "Gamepad #{} was disconnected.", event.gamepad().index() );
+        element( "#status" ).set_text_content( &message );
+    });
+
+    window().request_animation_frame( poll_gamepads );
+
+    stdweb::event_loop();
+}
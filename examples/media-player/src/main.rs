@@ -0,0 +1,167 @@
+#[macro_use]
+extern crate stdweb;
+
+use std::rc::Rc;
+use std::cell::{Cell, RefCell};
+
+use stdweb::traits::*;
+use stdweb::unstable::TryInto;
+use stdweb::web::{
+    document,
+    create_object_url,
+    revoke_object_url,
+    Element,
+    Blob,
+    MediaErrorCode,
+    PlaybackError
+};
+
+use stdweb::web::event::{
+    ChangeEvent,
+    ClickEvent,
+    InputEvent,
+    LoadedMetadataEvent,
+    MediaEndedEvent,
+    MediaErrorEvent,
+    MediaPauseEvent,
+    MediaPlayEvent,
+    TimeUpdateEvent
+};
+
+use stdweb::web::html_element::{InputElement, VideoElement};
+
+// Shamelessly stolen from webplatform's TodoMVC example.
+macro_rules! enclose {
+    ( ($( $x:ident ),*) $y:expr ) => {
+        {
+            $(let $x = $x.clone();)*
+            $y
+        }
+    };
+}
+
+fn element( selector: &str ) -> Element {
+    document().query_selector( selector ).unwrap().unwrap()
+}
+
+fn show_status( message: &str ) {
+    element( "#status" ).set_text_content( message );
+}
+
+fn format_time( seconds: f64 ) -> String {
+    let seconds = seconds as u64;
+    format!( "{}:{:02}", seconds / 60, seconds % 60 )
+}
+
+fn main() {
+    stdweb::initialize();
+
+    let video: VideoElement = element( "#video" ).try_into().unwrap();
+    let file_input: InputElement = element( "#file" ).try_into().unwrap();
+    let scrubber: InputElement = element( "#scrubber" ).try_into().unwrap();
+    let play_button = element( "#play" );
+    let time = element( "#time" );
+
+    // The object URL of the currently loaded file, so that it can be released.
+    let current_url: Rc< RefCell< Option< String > > > = Rc::new( RefCell::new( None ) );
+
+    // Whenever the user is dragging the scrubber; we don't move it
+    // on `timeupdate` in the meantime, otherwise it'd jump around.
+    let is_scrubbing = Rc::new( Cell::new( false ) );
+
+    file_input.add_event_listener( enclose!( (video, file_input, current_url) move |_: ChangeEvent| {
+        let file: Option< Blob > = js!( return @{&file_input}.files[ 0 ] || null; ).try_into().unwrap();
+        let file = match file {
+            Some( file ) => file,
+            None => return
+        };
+
+        if let Some( url ) = current_url.borrow_mut().take() {
+            revoke_object_url( &url );
+        }
+
+        let url = create_object_url( &file );
+        video.set_src( &url );
+        *current_url.borrow_mut() = Some( url );
+        show_status( "Loading..." );
+    }));
+
+    video.add_event_listener( enclose!( (video, scrubber, play_button, time) move |_: LoadedMetadataEvent| {
+        // Live streams don't have a duration, so they can't be scrubbed.
+        match video.duration() {
+            Some( duration ) if duration.is_finite() => {
+                js!( @(no_return) @{&scrubber}.max = @{duration}; );
+                scrubber.remove_attribute( "disabled" );
+                time.set_text_content( &format!( "0:00 / {}", format_time( duration ) ) );
+            },
+            _ => scrubber.set_attribute( "disabled", "" ).unwrap()
+        }
+
+        play_button.remove_attribute( "disabled" );
+        show_status( &format!( "{}x{}", video.video_width(), video.video_height() ) );
+    }));
+
+    video.add_event_listener( enclose!( (video, scrubber, time, is_scrubbing) move |_: TimeUpdateEvent| {
+        let current_time = video.current_time();
+        if !is_scrubbing.get() {
+            scrubber.set_raw_value( &current_time.to_string() );
+        }
+
+        let duration = video.duration().map( format_time ).unwrap_or_default();
+        time.set_text_content( &format!( "{} / {}", format_time( current_time ), duration ) );
+    }));
+
+    scrubber.add_event_listener( enclose!( (video, scrubber, is_scrubbing) move |_: InputEvent| {
+        is_scrubbing.set( true );
+        if let Ok( position ) = scrubber.raw_value().parse() {
+            video.set_current_time( position );
+        }
+    }));
+
+    // This is only fired once the user lets go of the scrubber.
+    scrubber.add_event_listener( enclose!( (is_scrubbing) move |_: ChangeEvent| {
+        is_scrubbing.set( false );
+    }));
+
+    play_button.add_event_listener( enclose!( (video) move |_: ClickEvent| {
+        if !video.paused() {
+            video.pause();
+            return;
+        }
+
+        video.play( |result| {
+            match result {
+                Ok(()) => {},
+                Err( PlaybackError::NotAllowedError( _ ) ) => show_status( "The browser didn't allow the playback." ),
+                Err( PlaybackError::NotSupportedError( _ ) ) => show_status( "This file can't be played." ),
+                // This happens when the playback is paused before it has started.
+                Err( PlaybackError::Error( _ ) ) => {}
+            }
+        });
+    }));
+
+    video.add_event_listener( enclose!( (play_button) move |_: MediaPlayEvent| {
+        play_button.set_text_content( "Pause" );
+    }));
+
+    video.add_event_listener( enclose!( (play_button) move |_: MediaPauseEvent| {
+        play_button.set_text_content( "Play" );
+    }));
+
+    video.add_event_listener( enclose!( (play_button) move |_: MediaEndedEvent| {
+        play_button.set_text_content( "Play" );
+    }));
+
+    video.add_event_listener( enclose!( (video, play_button) move |_: MediaErrorEvent| {
+        play_button.set_attribute( "disabled", "" ).unwrap();
+        let message = match video.error().map( |error| error.code() ) {
+            Some( MediaErrorCode::SrcNotSupported ) => "This file isn't supported by your browser.",
+            Some( MediaErrorCode::Decode ) => "This file is corrupted.",
+            _ => "This file couldn't be loaded."
+        };
+
+        show_status( message );
+    }));
+
+    stdweb::event_loop();
+}
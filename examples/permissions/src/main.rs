@@ -0,0 +1,95 @@
+#[macro_use]
+extern crate stdweb;
+
+use stdweb::traits::*;
+use stdweb::unstable::TryInto;
+use stdweb::web::{
+    document,
+    window,
+    Element,
+    PermissionDescriptor,
+    PermissionState,
+    PermissionStatus
+};
+
+use stdweb::web::event::{
+    ChangeEvent,
+    ClickEvent
+};
+
+fn element( selector: &str ) -> Element {
+    document().query_selector( selector ).unwrap().unwrap()
+}
+
+fn show_status( message: &str ) {
+    element( "#status" ).set_text_content( message );
+}
+
+// This will trigger the browser's prompt if the user wasn't asked yet.
+fn locate() {
+    let show_status = |message: String| show_status( &message );
+    js! { @(no_return)
+        var show_status = @{show_status};
+        navigator.geolocation.getCurrentPosition( function( position ) {
+            show_status( "Looking for shops around " + position.coords.latitude + ", " + position.coords.longitude + "..." );
+            show_status.drop();
+        }, function( error ) {
+            show_status( "Couldn't get your location: " + error.message );
+            show_status.drop();
+        });
+    }
+}
+
+// Only explain why we need the location if the browser is going to ask for it.
+fn update( state: Result< PermissionState, String > ) {
+    let explanation = element( "#explanation" );
+    match state {
+        Ok( PermissionState::Prompt ) => {
+            explanation.remove_attribute( "hidden" );
+            show_status( "" );
+        },
+        Ok( PermissionState::Granted ) => {
+            explanation.set_attribute( "hidden", "" ).unwrap();
+            show_status( "" );
+        },
+        Ok( PermissionState::Denied ) => {
+            explanation.set_attribute( "hidden", "" ).unwrap();
+            show_status( "You've blocked access to your location; please allow it in your browser's settings." );
+        },
+        Err( error ) => {
+            // We can't tell whether there will be a prompt, so explain it just in case.
+            explanation.remove_attribute( "hidden" );
+            show_status( &format!( "Couldn't query the permission: {}", error ) );
+        }
+    }
+}
+
+fn watch( status: PermissionStatus ) {
+    update( status.state().map_err( |error| error.to_string() ) );
+
+    // The user can change the permission at any time in the browser's settings.
+    status.add_event_listener( move |event: ChangeEvent| {
+        let status: PermissionStatus = event.target().unwrap().try_into().unwrap();
+        update( status.state().map_err( |error| error.to_string() ) );
+    });
+}
+
+fn main() {
+    stdweb::initialize();
+
+    match window().navigator().permissions() {
+        Some( permissions ) => {
+            permissions.query( PermissionDescriptor::Geolocation, |result| {
+                match result {
+                    Ok( status ) => watch( status ),
+                    Err( error ) => update( Err( error.to_string() ) )
+                }
+            });
+        },
+        None => update( Err( "the Permissions API isn't supported".to_owned() ) )
+    }
+
+    element( "#locate" ).add_event_listener( |_: ClickEvent| locate() );
+
+    stdweb::event_loop();
+}
@@ -0,0 +1,163 @@
+#[macro_use]
+extern crate stdweb;
+
+use std::mem;
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use stdweb::Value;
+use stdweb::traits::*;
+use stdweb::unstable::TryInto;
+use stdweb::web::{
+    document,
+    window,
+    set_timeout,
+    create_object_url,
+    revoke_object_url,
+    Blob,
+    Element,
+    GetUserMediaError,
+    MediaConstraints,
+    MediaRecorder,
+    MediaStream,
+    RecorderOptions
+};
+
+use stdweb::web::event::{
+    ClickEvent,
+    DataAvailableEvent,
+    MediaRecorderErrorEvent,
+    MediaRecorderStopEvent
+};
+
+use stdweb::web::html_element::VideoElement;
+
+// Shamelessly stolen from webplatform's TodoMVC example.
+macro_rules! enclose {
+    ( ($( $x:ident ),*) $y:expr ) => {
+        {
+            $(let $x = $x.clone();)*
+            $y
+        }
+    };
+}
+
+// How long a single chunk of the recording is, in milliseconds.
+const TIMESLICE: u32 = 1000;
+
+// How long the whole recording is, in milliseconds.
+const DURATION: u32 = 3000;
+
+fn element( selector: &str ) -> Element {
+    document().query_selector( selector ).unwrap().unwrap()
+}
+
+fn show_status( message: &str ) {
+    element( "#status" ).set_text_content( message );
+}
+
+// Turns off the camera and the microphone, and allows another recording to be started.
+fn finish( stream: &MediaStream ) {
+    for track in stream.get_tracks() {
+        track.stop();
+    }
+
+    element( "#record" ).remove_attribute( "disabled" );
+}
+
+fn record( stream: MediaStream, playback_url: Rc< RefCell< Option< String > > > ) {
+    let recorder = match MediaRecorder::new( &stream, &RecorderOptions::default() ) {
+        Ok( recorder ) => recorder,
+        Err( error ) => {
+            show_status( &format!( "Your browser can't record: {}", error ) );
+            finish( &stream );
+            return;
+        }
+    };
+
+    let chunks = Rc::new( RefCell::new( Vec::new() ) );
+    recorder.add_event_listener( enclose!( (chunks) move |event: DataAvailableEvent| {
+        let chunk = event.data();
+
+        // The last event fired after `stop()` can be empty.
+        if chunk.len() == 0 {
+            return;
+        }
+
+        chunks.borrow_mut().push( chunk );
+        show_status( &format!( "Recording... ({} chunks so far)", chunks.borrow().len() ) );
+    }));
+
+    recorder.add_event_listener( |event: MediaRecorderErrorEvent| {
+        let message = event.error().map( |error| error.to_string() ).unwrap_or_default();
+        show_status( &format!( "The recording failed: {}", message ) );
+    });
+
+    recorder.add_event_listener( enclose!( (recorder, stream) move |_: MediaRecorderStopEvent| {
+        finish( &stream );
+
+        let chunks = chunks.borrow();
+        let recording = Blob::from_parts( &chunks, &recorder.mime_type() );
+        show_status( &format!( "Recorded {} bytes in {} chunks.", recording.len(), chunks.len() ) );
+
+        // Release the previous recording, otherwise it'd stay in memory until the page is closed.
+        let url = create_object_url( &recording );
+        if let Some( previous_url ) = mem::replace( &mut *playback_url.borrow_mut(), Some( url.clone() ) ) {
+            revoke_object_url( &previous_url );
+        }
+
+        let video: VideoElement = element( "#playback" ).try_into().unwrap();
+        video.set_src( &url );
+    }));
+
+    if let Err( error ) = recorder.start( Some( TIMESLICE ) ) {
+        show_status( &format!( "Couldn't start the recording: {}", error ) );
+        finish( &stream );
+        return;
+    }
+
+    show_status( "Recording..." );
+    set_timeout( move || recorder.stop(), DURATION );
+}
+
+fn main() {
+    stdweb::initialize();
+
+    let playback_url = Rc::new( RefCell::new( None ) );
+    let button = element( "#record" );
+    button.add_event_listener( enclose!( (button) move |_: ClickEvent| {
+        let media_devices = match window().navigator().media_devices() {
+            Some( media_devices ) => media_devices,
+            None => {
+                show_status( "Your browser doesn't support accessing the camera." );
+                return;
+            }
+        };
+
+        let constraints = MediaConstraints {
+            audio: Value::Bool( true ),
+            video: Value::Bool( true )
+        };
+
+        button.set_attribute( "disabled", "" ).unwrap();
+        media_devices.get_user_media( &constraints, enclose!( (button, playback_url) move |result| {
+            let error = match result {
+                Ok( stream ) => {
+                    record( stream, playback_url );
+                    return;
+                },
+                Err( error ) => error
+            };
+
+            match error {
+                GetUserMediaError::NotAllowedError( _ ) => show_status( "Please allow the access to the camera and the microphone." ),
+                GetUserMediaError::NotFoundError( _ ) => show_status( "No camera or microphone was found." ),
+                error => show_status( &format!( "Couldn't access the camera: {}", error ) )
+            }
+
+            button.remove_attribute( "disabled" );
+        }));
+    }));
+
+    stdweb::event_loop();
+}
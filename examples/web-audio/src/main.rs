@@ -0,0 +1,103 @@
+#[macro_use]
+extern crate stdweb;
+
+use std::f32::consts::PI;
+
+use stdweb::traits::*;
+use stdweb::unstable::TryInto;
+use stdweb::web::{
+    document,
+    AudioContext,
+    AudioContextState,
+    Element
+};
+
+use stdweb::web::event::{ClickEvent, InputEvent};
+use stdweb::web::html_element::InputElement;
+
+// How long every tone lasts, in seconds.
+const DURATION: f32 = 1.0;
+
+fn element( selector: &str ) -> Element {
+    document().query_selector( selector ).unwrap().unwrap()
+}
+
+fn show_status( message: &str ) {
+    element( "#status" ).set_text_content( message );
+}
+
+fn sine_wave( frequency: f32, sample_rate: f32 ) -> Vec< f32 > {
+    let length = (DURATION * sample_rate) as usize;
+    (0..length).map( |index| {
+        let time = index as f32 / sample_rate;
+
+        // Fade the tone in and out over a few milliseconds, otherwise it would start and end with a click.
+        let envelope = (time / 0.01).min( (DURATION - time) / 0.01 ).min( 1.0 );
+        (time * frequency * 2.0 * PI).sin() * envelope
+    }).collect()
+}
+
+fn play( context: &AudioContext, frequency: f32 ) {
+    let samples = sine_wave( frequency, context.sample_rate() );
+    let buffer = match context.create_buffer_from_samples( &samples, context.sample_rate() ) {
+        Ok( buffer ) => buffer,
+        Err( error ) => {
+            show_status( &format!( "Failed to create the buffer: {}", error ) );
+            return;
+        }
+    };
+
+    let gain = context.create_gain();
+    gain.gain().set_value( 0.25 );
+    gain.connect( &context.destination() );
+
+    // A source node can only be started once, so a new one is needed for every click.
+    let source = context.create_buffer_source();
+    source.set_buffer( Some( &buffer ) );
+    source.connect( &gain );
+    source.start();
+
+    show_status( &format!( "Playing {} Hz.", frequency ) );
+}
+
+fn main() {
+    stdweb::initialize();
+
+    let context = match AudioContext::new() {
+        Ok( context ) => context,
+        Err( error ) => {
+            show_status( &format!( "Failed to create the audio context: {}", error ) );
+            stdweb::event_loop();
+            return;
+        }
+    };
+
+    let frequency: InputElement = element( "#frequency" ).try_into().unwrap();
+    frequency.add_event_listener( {
+        let frequency = frequency.clone();
+        move |_: InputEvent| {
+            element( "#frequency-value" ).set_text_content( &format!( "{} Hz", frequency.raw_value() ) );
+        }
+    });
+
+    element( "#play" ).add_event_listener( move |_: ClickEvent| {
+        let value: f32 = frequency.raw_value().parse().unwrap_or( 440.0 );
+
+        // The context starts out suspended until the user interacts with the page,
+        // and it can only be resumed from within a user gesture handler like this one.
+        if context.state() != AudioContextState::Suspended {
+            play( &context, value );
+            return;
+        }
+
+        let resumed_context = context.clone();
+        context.resume( move |result| {
+            match result {
+                Ok(()) => play( &resumed_context, value ),
+                Err( error ) => show_status( &format!( "Failed to resume the audio context: {}", error ) )
+            }
+        });
+    });
+
+    stdweb::event_loop();
+}
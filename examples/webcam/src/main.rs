@@ -0,0 +1,133 @@
+#[macro_use]
+extern crate stdweb;
+
+use std::rc::Rc;
+use std::cell::Cell;
+
+use stdweb::traits::*;
+use stdweb::unstable::TryInto;
+use stdweb::web::{
+    document,
+    window,
+    CanvasRenderingContext2d,
+    GetUserMediaError,
+    MediaConstraints,
+    MediaDeviceKind,
+    MediaReadyState,
+    MediaStream
+};
+
+use stdweb::web::event::ClickEvent;
+use stdweb::web::html_element::{CanvasElement, VideoElement};
+
+fn show_status( message: &str ) {
+    document().query_selector( "#status" ).unwrap().unwrap().set_text_content( message );
+}
+
+fn list_cameras() {
+    let media_devices = window().navigator().media_devices().unwrap();
+    media_devices.enumerate_devices( |result| {
+        let list = document().query_selector( "#cameras" ).unwrap().unwrap();
+        for device in result.unwrap_or_default() {
+            // Browsers can report kinds of devices we don't know about; we just skip those.
+            if device.kind() != MediaDeviceKind::VideoInput {
+                continue;
+            }
+
+            // The labels are only available once the user has allowed the access.
+            let item = document().create_element( "li" ).unwrap();
+            item.set_text_content( &device.label() );
+            list.append_child( &item );
+        }
+    });
+}
+
+// Copies the current frame of the video onto the canvas every animation frame,
+// until `running` is cleared.
+fn scan_frames( video: VideoElement, canvas: CanvasElement, running: Rc< Cell< bool > > ) {
+    if !running.get() {
+        return;
+    }
+
+    if video.ready_state() >= MediaReadyState::HaveCurrentData {
+        canvas.set_width( video.video_width() );
+        canvas.set_height( video.video_height() );
+
+        let context: CanvasRenderingContext2d = canvas.get_context().unwrap();
+        js!( @(no_return) @{&context}.drawImage( @{&video}, 0, 0 ); );
+
+        // This is where a QR code scanner would decode the frame.
+        let frame = context.get_image_data( 0.0, 0.0, canvas.width() as f64, canvas.height() as f64 ).unwrap();
+        show_status( &format!( "Scanning a {}x{} frame", frame.get_width(), frame.get_height() ) );
+    }
+
+    window().request_animation_frame( move |_| scan_frames( video, canvas, running ) );
+}
+
+fn stop( video: &VideoElement, stream: &MediaStream ) {
+    video.set_src_object( None );
+    for track in stream.get_tracks() {
+        track.stop();
+    }
+
+    show_status( "The camera was turned off." );
+}
+
+fn main() {
+    stdweb::initialize();
+
+    let video: VideoElement = document().query_selector( "#video" ).unwrap().unwrap().try_into().unwrap();
+    let canvas: CanvasElement = document().query_selector( "#canvas" ).unwrap().unwrap().try_into().unwrap();
+
+    let media_devices = match window().navigator().media_devices() {
+        Some( media_devices ) => media_devices,
+        None => {
+            show_status( "Your browser doesn't support accessing the camera." );
+            return;
+        }
+    };
+
+    let constraints = MediaConstraints {
+        video: js!( return { facingMode: "environment" }; ),
+        ..MediaConstraints::default()
+    };
+
+    media_devices.get_user_media( &constraints, move |result| {
+        let stream = match result {
+            Ok( stream ) => stream,
+            Err( GetUserMediaError::NotAllowedError( _ ) ) => {
+                show_status( "Please allow the access to the camera to scan QR codes." );
+                return;
+            },
+            Err( GetUserMediaError::NotFoundError( _ ) ) => {
+                show_status( "No camera was found." );
+                return;
+            },
+            Err( error ) => {
+                show_status( &format!( "Couldn't access the camera: {}", error ) );
+                return;
+            }
+        };
+
+        list_cameras();
+        video.set_src_object( Some( &stream ) );
+        video.play( |result| {
+            if let Err( error ) = result {
+                show_status( &format!( "Couldn't play the video: {}", error ) );
+            }
+        });
+
+        let running = Rc::new( Cell::new( true ) );
+        scan_frames( video.clone(), canvas, running.clone() );
+
+        let button = document().query_selector( "#stop" ).unwrap().unwrap();
+        button.add_event_listener( move |_: ClickEvent| {
+            if running.get() {
+                running.set( false );
+                stop( &video, &stream );
+            }
+        });
+    });
+
+    stdweb::event_loop();
+}
@@ -28,6 +28,7 @@ use webcore::try_from::{TryInto, TryFrom};
 use webcore::instance_of::InstanceOf;
 use webcore::array::Array;
 use webcore::object::Object;
+use webapi::typed_array::TypedArray;
 
 impl Serialize for Undefined {
     #[inline]
@@ -159,6 +160,16 @@ impl Serialize for Value {
             Value::Bool( value ) => serializer.serialize_bool( value ),
             Value::Number( ref value ) => value.serialize( serializer ),
             Value::Symbol( _ ) => unimplemented!( "Serialization of symbols is unimplemented!" ),
+            Value::BigInt( ref value ) => {
+                let digits = value.to_decimal_string();
+                if let Ok( value ) = digits.parse::< i64 >() {
+                    serializer.serialize_i64( value )
+                } else if let Ok( value ) = digits.parse::< u64 >() {
+                    serializer.serialize_u64( value )
+                } else {
+                    serializer.serialize_str( &digits )
+                }
+            },
             Value::String( ref value ) => serializer.serialize_str( value ),
             Value::Reference( ref reference ) => {
                 if Array::instance_of( reference ) {
@@ -212,7 +223,7 @@ impl< 'de > Deserialize< 'de > for Value {
             }
 
             fn visit_i64< E: de::Error >( self, value: i64 ) -> Result< Self::Value, E > {
-                value.try_into().map_err( E::custom )
+                Ok( value.into() )
             }
 
             fn visit_u8< E: de::Error >( self, value: u8 ) -> Result< Self::Value, E > {
@@ -228,7 +239,7 @@ impl< 'de > Deserialize< 'de > for Value {
             }
 
             fn visit_u64< E: de::Error >( self, value: u64 ) -> Result< Self::Value, E > {
-                value.try_into().map_err( E::custom )
+                Ok( value.into() )
             }
 
             fn visit_f32< E: de::Error >( self, value: f32 ) -> Result< Self::Value, E > {
@@ -415,7 +426,7 @@ impl< 'a > ser::Serializer for &'a mut Serializer {
     }
 
     fn serialize_i64( self, value: i64 ) -> Result< Self::Ok, Self::Error > {
-        Ok( value.try_into()? )
+        Ok( value.into() )
     }
 
     fn serialize_u8( self, value: u8 ) -> Result< Self::Ok, Self::Error > {
@@ -431,7 +442,7 @@ impl< 'a > ser::Serializer for &'a mut Serializer {
     }
 
     fn serialize_u64( self, value: u64 ) -> Result< Self::Ok, Self::Error > {
-        Ok( value.try_into()? )
+        Ok( value.into() )
     }
 
     fn serialize_f32( self, value: f32 ) -> Result< Self::Ok, Self::Error > {
@@ -451,7 +462,8 @@ impl< 'a > ser::Serializer for &'a mut Serializer {
     }
 
     fn serialize_bytes( self, value: &[u8] ) -> Result< Self::Ok, Self::Error > {
-        Ok( value.into() )
+        let array: TypedArray< u8 > = value.into();
+        Ok( Value::Reference( array.into() ) )
     }
 
     fn serialize_none( self ) -> Result< Self::Ok, Self::Error > {
@@ -525,7 +537,29 @@ impl< 'a > ser::Serializer for &'a mut Serializer {
     }
 }
 
-#[doc(hidden)]
+/// Converts any type implementing Serde's `Serialize` into a `Value`.
+///
+/// Structures and maps become plain objects, sequences become arrays,
+/// `None` becomes `null`, and enums are externally tagged, e.g.
+/// `{ "Variant": value }`. Byte arrays serialized with `serialize_bytes`
+/// (e.g. through `serde_bytes`) become an `Uint8Array`.
+///
+/// Map keys have to be either strings or integers, since JavaScript
+/// objects only have string keys.
+///
+/// # Examples
+///
+/// ```rust
+/// #[derive(Serialize)]
+/// struct Item {
+///     name: String,
+///     tags: Vec< String >
+/// }
+///
+/// let item = Item { name: "Bob".to_owned(), tags: vec![] };
+/// let value = serde::to_value( &item ).unwrap();
+/// js! { send( @{value} ); }
+/// ```
 #[cfg_attr(feature = "cargo-clippy", allow(needless_pass_by_value))]
 #[inline]
 pub fn to_value< T: Serialize >( value: T ) -> Result< Value, ConversionError > {
@@ -533,7 +567,23 @@ pub fn to_value< T: Serialize >( value: T ) -> Result< Value, ConversionError >
     value.serialize( &mut serializer )
 }
 
-#[doc(hidden)]
+/// Converts a `Value` into any type implementing Serde's `Deserialize`.
+///
+/// This is the inverse of [to_value](fn.to_value.html); an error describing
+/// the mismatch is returned if the value doesn't have the expected shape.
+///
+/// # Examples
+///
+/// ```rust
+/// #[derive(Deserialize)]
+/// struct Item {
+///     name: String,
+///     tags: Vec< String >
+/// }
+///
+/// let value = js!( return JSON.parse( @{body} ); );
+/// let item: Item = serde::from_value( value )?;
+/// ```
 #[inline]
 pub fn from_value< 'de, T: Deserialize< 'de > >( value: Value ) -> Result< T, ConversionError > {
     Deserialize::deserialize( value )
@@ -644,6 +694,7 @@ impl ser::SerializeMap for SerializeMap {
                     return Err( ConversionError::invalid_key() )
                 }
             },
+            Value::BigInt( value ) => self.next_key = Some( value.to_decimal_string() ),
             _ => return Err( ConversionError::invalid_key() )
         }
 
@@ -728,6 +779,7 @@ impl Value {
                 }
             },
             Value::Symbol( _ ) => de::Unexpected::Other( "Symbol" ),
+            Value::BigInt( _ ) => de::Unexpected::Other( "BigInt" ),
             Value::String( ref value ) => de::Unexpected::Str( value ),
             Value::Reference( _ ) => de::Unexpected::Other( "reference to a JavaScript value" )
         }
@@ -744,10 +796,23 @@ impl< 'de > de::Deserializer< 'de > for Value {
             Value::Null => visitor.visit_unit(),
             Value::Bool( value ) => visitor.visit_bool( value ),
             Value::Number( value ) => de::Deserializer::deserialize_any( value, visitor ),
-            Value::Symbol( _ ) => unimplemented!( "Deserialization of symbols is unimplemented!" ),
+            Value::Symbol( _ ) => Err( de::Error::invalid_type( de::Unexpected::Other( "Symbol" ), &visitor ) ),
+            Value::BigInt( value ) => {
+                let digits = value.to_decimal_string();
+                if let Ok( value ) = digits.parse::< i64 >() {
+                    visitor.visit_i64( value )
+                } else if let Ok( value ) = digits.parse::< u64 >() {
+                    visitor.visit_u64( value )
+                } else {
+                    visitor.visit_string( digits )
+                }
+            },
             Value::String( value ) => visitor.visit_string( value ),
             Value::Reference( reference ) => {
-                if Array::instance_of( &reference ) {
+                if TypedArray::< u8 >::instance_of( &reference ) {
+                    let value: TypedArray< u8 > = reference.try_into().unwrap();
+                    visitor.visit_byte_buf( value.to_vec() )
+                } else if Array::instance_of( &reference ) {
                     let value: Array = reference.try_into().unwrap();
                     let value: Vec< _ > = value.into();
                     let length = value.len();
@@ -772,12 +837,25 @@ impl< 'de > de::Deserializer< 'de > for Value {
                         Err( de::Error::invalid_length( length, &"fewer elements in the object" ) )
                     }
                 } else {
-                    unimplemented!(); // TODO: ?
+                    Err( de::Error::invalid_type( de::Unexpected::Other( "reference to a JavaScript value" ), &visitor ) )
                 }
             }
         }
     }
 
+    #[inline]
+    fn deserialize_seq< V: Visitor< 'de > >( self, visitor: V ) -> Result< V::Value, Self::Error > {
+        // An `Uint8Array` is also accepted where a sequence of numbers is expected, e.g. for a `Vec< u8 >`.
+        match self {
+            Value::Reference( ref reference ) if TypedArray::< u8 >::instance_of( reference ) => {
+                let array: TypedArray< u8 > = reference.try_into().unwrap();
+                let elements = array.to_vec().into_iter().map( Value::from ).collect();
+                visitor.visit_seq( &mut SeqDeserializer::new( elements ) )
+            },
+            value => de::Deserializer::deserialize_any( value, visitor )
+        }
+    }
+
     #[inline]
     fn deserialize_option< V: Visitor< 'de > >( self, visitor: V ) -> Result< V::Value, Self::Error > {
         match self {
@@ -830,12 +908,68 @@ impl< 'de > de::Deserializer< 'de > for Value {
     }
 
     forward_to_deserialize_any! {
-        bool u8 u16 u32 u64 i8 i16 i32 i64 f32 f64 char str string unit seq
+        bool u8 u16 u32 u64 i8 i16 i32 i64 f32 f64 char str string unit
         bytes byte_buf map unit_struct tuple_struct struct
         identifier tuple ignored_any
     }
 }
 
+/// Deserializes the keys of objects; since those are always strings in
+/// JavaScript integer keys, e.g. of a `BTreeMap< u32, _ >`, are parsed back.
+struct MapKeyDeserializer {
+    key: String
+}
+
+macro_rules! deserialize_integer_key {
+    ($($method:ident => $visit:ident),*) => {
+        $(
+            #[inline]
+            fn $method< V: Visitor< 'de > >( self, visitor: V ) -> Result< V::Value, Self::Error > {
+                match self.key.parse() {
+                    Ok( value ) => visitor.$visit( value ),
+                    Err( _ ) => Err( de::Error::invalid_value( de::Unexpected::Str( &self.key ), &visitor ) )
+                }
+            }
+        )*
+    }
+}
+
+impl< 'de > de::Deserializer< 'de > for MapKeyDeserializer {
+    type Error = ConversionError;
+
+    #[inline]
+    fn deserialize_any< V: Visitor< 'de > >( self, visitor: V ) -> Result< V::Value, Self::Error > {
+        visitor.visit_string( self.key )
+    }
+
+    deserialize_integer_key! {
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64
+    }
+
+    #[inline]
+    fn deserialize_newtype_struct< V: Visitor< 'de > >( self, _name: &'static str, visitor: V ) -> Result< V::Value, Self::Error > {
+        visitor.visit_newtype_struct( self )
+    }
+
+    #[inline]
+    fn deserialize_enum< V: Visitor< 'de > >( self, _name: &str, _variants: &'static [&'static str], visitor: V ) -> Result< V::Value, Self::Error > {
+        visitor.visit_enum( self.key.into_deserializer() )
+    }
+
+    forward_to_deserialize_any! {
+        bool f32 f64 char str string unit option
+        seq bytes byte_buf map unit_struct tuple_struct struct
+        identifier tuple ignored_any
+    }
+}
+
 struct EnumDeserializer {
     variant: String,
     value: Option< Value >,
@@ -979,7 +1113,7 @@ impl< 'de > de::MapAccess< 'de > for MapDeserializer {
         match self.iter.next() {
             Some( (key, value) ) => {
                 self.value = Some( value );
-                seed.deserialize( key.into_deserializer() ).map( Some )
+                seed.deserialize( MapKeyDeserializer { key: key } ).map( Some )
             }
             None => Ok( None )
         }
@@ -1537,4 +1671,118 @@ mod tests {
         assert_eq!( original.len(), deserialized.len() );
         assert_eq!( original[ 0 ], deserialized[ 0 ] );
     }
+
+    #[derive(PartialEq, Serialize, Deserialize, Debug)]
+    enum Shape {
+        Empty,
+        Circle( f64 ),
+        Point( i32, i32 ),
+        Rectangle { width: u32, height: u32 }
+    }
+
+    #[derive(PartialEq, Serialize, Deserialize, Debug)]
+    struct Drawing {
+        title: Option< String >,
+        layers: BTreeMap< u32, Vec< Shape > >
+    }
+
+    #[test]
+    fn round_trip_of_nested_structures() {
+        let mut layers = BTreeMap::new();
+        layers.insert( 1, vec![ Shape::Empty, Shape::Circle( 0.5 ) ] );
+        layers.insert( 20, vec![ Shape::Point( -1, 2 ), Shape::Rectangle { width: 3, height: 4 } ] );
+        let original = Drawing {
+            title: None,
+            layers: layers
+        };
+
+        let value = to_value( &original ).unwrap();
+        let is_valid: bool = js!(
+            var value = @{&value};
+            return value.title === null &&
+                value.layers[ "1" ][ 0 ] === "Empty" &&
+                value.layers[ "1" ][ 1 ].Circle === 0.5 &&
+                value.layers[ "20" ][ 0 ].Point[ 1 ] === 2 &&
+                value.layers[ "20" ][ 1 ].Rectangle.height === 4;
+        ).try_into().unwrap();
+        assert!( is_valid );
+
+        let deserialized: Drawing = from_value( value ).unwrap();
+        assert_eq!( deserialized, original );
+    }
+
+    #[test]
+    fn serialization_of_invalid_map_keys() {
+        let mut map = BTreeMap::new();
+        map.insert( true, 1 );
+        assert!( to_value( &map ).is_err() );
+
+        let value = js!( return { "one": 1 }; );
+        let result: Result< BTreeMap< u32, i32 >, _ > = from_value( value );
+        assert!( result.is_err() );
+    }
+
+    struct Bytes( Vec< u8 > );
+
+    impl Serialize for Bytes {
+        fn serialize< S: ser::Serializer >( &self, serializer: S ) -> Result< S::Ok, S::Error > {
+            serializer.serialize_bytes( &self.0 )
+        }
+    }
+
+    impl< 'de > Deserialize< 'de > for Bytes {
+        fn deserialize< D: de::Deserializer< 'de > >( deserializer: D ) -> Result< Self, D::Error > {
+            struct BytesVisitor;
+            impl< 'de > Visitor< 'de > for BytesVisitor {
+                type Value = Bytes;
+
+                fn expecting( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+                    write!( formatter, "bytes" )
+                }
+
+                fn visit_byte_buf< E: de::Error >( self, value: Vec< u8 > ) -> Result< Bytes, E > {
+                    Ok( Bytes( value ) )
+                }
+            }
+
+            deserializer.deserialize_byte_buf( BytesVisitor )
+        }
+    }
+
+    #[test]
+    fn serialization_of_bytes() {
+        let value = to_value( &Bytes( vec![ 1, 2, 255 ] ) ).unwrap();
+        let is_typed_array: bool = js!( return @{&value} instanceof Uint8Array; ).try_into().unwrap();
+        assert!( is_typed_array );
+
+        let bytes: Bytes = from_value( value.clone() ).unwrap();
+        assert_eq!( bytes.0, vec![ 1, 2, 255 ] );
+
+        let bytes: Vec< u8 > = from_value( value ).unwrap();
+        assert_eq!( bytes, vec![ 1, 2, 255 ] );
+
+        let empty = to_value( &Bytes( Vec::new() ) ).unwrap();
+        let bytes: Bytes = from_value( empty ).unwrap();
+        assert!( bytes.0.is_empty() );
+    }
+
+    #[test]
+    fn deserialization_errors() {
+        let value = js!( return { number: "123", string: "Hello!" }; );
+        let error = from_value::< Structure >( value ).unwrap_err();
+        assert_eq!( format!( "{}", error ), "invalid type: string \"123\", expected i32" );
+
+        let value = js!( return { string: "Hello!" }; );
+        let error = from_value::< Structure >( value ).unwrap_err();
+        assert_eq!( format!( "{}", error ), "missing field `number`" );
+
+        let value = js!( return { Triangle: 1 }; );
+        assert!( from_value::< Shape >( value ).is_err() );
+
+        let value = js!( return Symbol(); );
+        assert!( from_value::< String >( value ).is_err() );
+
+        let value = js!( return new Date(); );
+        assert!( from_value::< Structure >( value ).is_err() );
+    }
 }
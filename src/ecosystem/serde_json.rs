@@ -1,47 +1,185 @@
 use std::collections::BTreeMap;
-use serde_json::value::Value as JsonValue;
-use webcore::value::Value;
+use serde_json::value::{Value as JsonValue, Number as JsonNumber, Map as JsonMap};
+use webcore::value::{Value, ConversionError, is_plain_object};
 use webcore::try_from::{TryFrom, TryInto};
-use webcore::number::ConversionError;
+use webcore::number::{self, get_storage};
+use webcore::instance_of::InstanceOf;
+use webcore::array::Array;
+use webcore::serialization::deserialize_object;
 
-impl TryFrom< JsonValue > for Value {
-    type Error = ConversionError;
+// 2^53 - 1
+const MAX_SAFE_INTEGER_F64: f64 = 9007199254740991.0;
 
-    #[inline]
-    fn try_from( value: JsonValue ) -> Result< Self, Self::Error > {
-        let result = match value {
+/// Integers which can't be exactly represented by a JavaScript
+/// number are converted into a `BigInt`.
+impl From< JsonValue > for Value {
+    fn from( value: JsonValue ) -> Self {
+        match value {
             JsonValue::Null => Value::Null,
             JsonValue::Bool( value ) => Value::Bool( value ),
             JsonValue::Number( value ) => {
                 if let Some( value ) = value.as_u64() {
-                    Value::Number( value.try_into()? )
+                    value.into()
                 } else if let Some( value ) = value.as_i64() {
-                    Value::Number( value.try_into()? )
+                    value.into()
                 } else {
                     Value::Number( value.as_f64().unwrap().into() )
                 }
             },
             JsonValue::String( value ) => Value::String( value ),
             JsonValue::Array( value ) => {
-                let mut vector: Vec< Value > = Vec::new();
+                let vector: Vec< Value > = value.into_iter().map( Value::from ).collect();
+                vector.into()
+            },
+            JsonValue::Object( value ) => {
+                let map: BTreeMap< String, Value > = value.into_iter().map( |(key, value)| (key, value.into()) ).collect();
+                map.into()
+            }
+        }
+    }
+}
+
+impl TryFrom< JsonValue > for Value {
+    type Error = number::ConversionError;
 
-                vector.reserve( value.len() );
-                for element in value.into_iter() {
-                    vector.push( element.try_into()? );
+    #[inline]
+    fn try_from( value: JsonValue ) -> Result< Self, Self::Error > {
+        Ok( value.into() )
+    }
+}
+
+fn number_into_json( value: f64 ) -> Result< JsonValue, ConversionError > {
+    if value.floor() == value && value.abs() <= MAX_SAFE_INTEGER_F64 {
+        Ok( JsonValue::Number( (value as i64).into() ) )
+    } else {
+        JsonNumber::from_f64( value )
+            .map( JsonValue::Number )
+            .ok_or_else( || ConversionError::Custom( format!( "{} can't be represented in JSON", value ) ) )
+    }
+}
+
+/// `undefined` is converted into `null`, numbers which are integers are converted
+/// into JSON integers, and `BigInt`s are converted into JSON integers if they fit
+/// into 64 bits.
+///
+/// Fails for symbols, non-finite numbers, and for references to objects
+/// which are neither arrays nor plain objects, e.g. a `Date`, a `Map` or a function;
+/// objects created with `Object.create( null )` are treated as plain objects.
+impl TryFrom< Value > for JsonValue {
+    type Error = ConversionError;
+
+    fn try_from( value: Value ) -> Result< Self, Self::Error > {
+        let result = match value {
+            Value::Undefined | Value::Null => JsonValue::Null,
+            Value::Bool( value ) => JsonValue::Bool( value ),
+            Value::Number( ref value ) => {
+                match *get_storage( value ) {
+                    number::Storage::I32( value ) => JsonValue::Number( value.into() ),
+                    number::Storage::F64( value ) => number_into_json( value )?
+                }
+            },
+            Value::BigInt( ref value ) => {
+                let digits = value.to_decimal_string();
+                if let Ok( value ) = digits.parse::< i64 >() {
+                    JsonValue::Number( value.into() )
+                } else if let Ok( value ) = digits.parse::< u64 >() {
+                    JsonValue::Number( value.into() )
+                } else {
+                    return Err( ConversionError::OutOfRange );
+                }
+            },
+            Value::String( value ) => JsonValue::String( value ),
+            Value::Reference( ref reference ) if Array::instance_of( reference ) => {
+                let array: Array = reference.try_into()?;
+                let vector: Vec< Value > = array.into();
+                let mut output = Vec::with_capacity( vector.len() );
+                for (index, element) in vector.into_iter().enumerate() {
+                    output.push( element.try_into().map_err( |error| ConversionError::at_index( index, error ) )? );
                 }
 
-                vector.into()
+                JsonValue::Array( output )
             },
-            JsonValue::Object( value ) => {
-                let mut map: BTreeMap< String, Value > = BTreeMap::new();
-                for (key, value) in value.into_iter() {
-                    map.insert( key.into(), value.try_into()? );
+            Value::Reference( ref reference ) if is_plain_object( reference ) => {
+                let map: BTreeMap< String, Value > = deserialize_object( reference, |iter| iter.collect() );
+                let mut output = JsonMap::new();
+                for (key, value) in map {
+                    let value = value.try_into().map_err( |error| ConversionError::at_key( &key, error ) )?;
+                    output.insert( key, value );
                 }
 
-                map.into()
-            }
+                JsonValue::Object( output )
+            },
+            ref value => return Err( ConversionError::wrong_type( "JSON-compatible value", value ) )
         };
 
         Ok( result )
     }
 }
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_json() {
+        let json: JsonValue = ::serde_json::from_str( r#"{ "a": [ 1, -2, 3.5, null ], "b": "c", "d": true }"# ).unwrap();
+        let value: Value = json.into();
+        assert_eq!( js!( return JSON.stringify( @{value} ); ), r#"{"a":[1,-2,3.5,null],"b":"c","d":true}"# );
+    }
+
+    #[test]
+    fn from_json_big_integer() {
+        let json: JsonValue = ::serde_json::from_str( "18446744073709551615" ).unwrap();
+        let value: Value = json.into();
+        assert!( value.is_big_int() );
+        assert_eq!( value, ::std::u64::MAX );
+    }
+
+    #[test]
+    fn into_json() {
+        let value = js!( return { a: [ 1, -2, 3.5, 3e9, null, undefined ], b: "c", d: false }; );
+        let json: JsonValue = value.try_into().unwrap();
+        assert_eq!( ::serde_json::to_string( &json ).unwrap(), r#"{"a":[1,-2,3.5,3000000000,null,null],"b":"c","d":false}"# );
+    }
+
+    #[test]
+    fn into_json_round_trip() {
+        let json: JsonValue = ::serde_json::from_str( r#"[ 9007199254740993, -9223372036854775808, 0.25, "x" ]"# ).unwrap();
+        let value: Value = json.clone().into();
+        let output: JsonValue = value.try_into().unwrap();
+        assert_eq!( output, json );
+    }
+
+    #[test]
+    fn into_json_failure() {
+        let value = js!( return { a: [ 1, Symbol() ] }; );
+        let result: Result< JsonValue, _ > = value.try_into();
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "while converting field `a`: at index 1: expected JSON-compatible value, got symbol"
+        );
+
+        let value = js!( return NaN; );
+        let result: Result< JsonValue, _ > = value.try_into();
+        assert!( result.is_err() );
+
+        let value = js!( return { a: new Date( 0 ) }; );
+        let result: Result< JsonValue, _ > = value.try_into();
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "while converting field `a`: expected JSON-compatible value, got object"
+        );
+    }
+
+    #[test]
+    fn into_json_null_prototype() {
+        let value = js!(
+            var object = Object.create( null );
+            object.a = 1;
+            return object;
+        );
+
+        let json: JsonValue = value.try_into().unwrap();
+        assert_eq!( ::serde_json::to_string( &json ).unwrap(), r#"{"a":1}"# );
+    }
+}
@@ -165,13 +165,26 @@ pub use webcore::value::{
 pub use webcore::number::Number;
 pub use webcore::object::Object;
 pub use webcore::array::Array;
+pub use webcore::map::JsMap;
+pub use webcore::set::JsSet;
 pub use webcore::symbol::Symbol;
+pub use webcore::big_int::BigInt;
 
-pub use webcore::unsafe_typed_array::UnsafeTypedArray;
+pub use webcore::unsafe_typed_array::{UnsafeTypedArray, UnsafeTypedArrayMut};
 pub use webcore::once::Once;
+pub use webcore::callback_handle::CallbackHandle;
 pub use webcore::instance_of::InstanceOf;
-pub use webcore::reference_type::ReferenceType;
+pub use webcore::reference_type::{ReferenceType, is_instance_of};
+
+/// A derive macro for implementing [ReferenceType](trait.ReferenceType.html)
+/// on custom wrappers around a [Reference](struct.Reference.html); this is
+/// the same macro which is used for all of the reference types in this crate.
+///
+/// See [ReferenceType](trait.ReferenceType.html) for the details.
+pub use stdweb_derive::ReferenceType;
 pub use webcore::serialization::JsSerialize;
+pub use webcore::intern::{intern, InternedString, PropertyKey};
+pub use webcore::binary_patch::{apply_binary_patch, PatchError};
 
 #[cfg(feature = "experimental_features_which_may_break_on_minor_version_bumps")]
 pub use webcore::promise::Promise;
@@ -182,12 +195,27 @@ pub use webcore::promise::Promise;
 ))]
 pub use webcore::promise_future::PromiseFuture;
 
+/// A module for encoding and decoding binary data as base64.
+pub mod base64 {
+    pub use webcore::base64::{
+        encode,
+        decode,
+        encode_url_safe,
+        decode_url_safe,
+        bytes_to_base64_value,
+        Base64Alphabet,
+        Base64Error
+    };
+}
+
 #[cfg(feature = "serde")]
 /// A module with serde-related APIs.
 pub mod serde {
     pub use ecosystem::serde::{
         ConversionError,
-        Serde
+        Serde,
+        to_value,
+        from_value
     };
 }
 
@@ -207,12 +235,16 @@ pub mod web {
     };
     pub use webapi::cross_origin_setting::CrossOriginSetting;
     pub use webapi::date::Date;
-    pub use webapi::event_target::{IEventTarget, EventTarget, EventListenerHandle};
+    pub use webapi::event_target::{IEventTarget, EventTarget, EventListenerHandle, EventForwardingHandle};
+    pub use webapi::abort_controller::{AbortController, AbortSignal};
     pub use webapi::window::RequestAnimationFrameHandle;
     pub use webapi::node::{INode, Node, CloneKind};
-    pub use webapi::element::{IElement, Element};
+    pub use webapi::element::{IElement, Element, QualifiedNameError};
     pub use webapi::text_node::TextNode;
     pub use webapi::html_element::{IHtmlElement, HtmlElement};
+    pub use webapi::svg_element::{ISvgElement, SvgElement, ISvgGraphicsElement, SvgGraphicsElement, ISvgGeometryElement, SvgGeometryElement};
+    pub use webapi::svg_element::{Svg, SvgPoint, SvgMatrix, SVG_NAMESPACE};
+    pub use webapi::html_elements::{IHtmlMediaElement, HtmlMediaElement, MediaError, MediaErrorCode, MediaReadyState, PlaybackError};
     pub use webapi::window_or_worker::IWindowOrWorker;
     pub use webapi::parent_node::IParentNode;
     pub use webapi::non_element_parent_node::INonElementParentNode;
@@ -222,30 +254,118 @@ pub mod web {
     pub use webapi::storage::Storage;
     pub use webapi::location::Location;
     pub use webapi::array_buffer::ArrayBuffer;
-    pub use webapi::typed_array::TypedArray;
+    pub use webapi::typed_array::{TypedArray, Uint8ClampedArray};
     pub use webapi::file_reader::{FileReader, FileReaderResult};
     pub use webapi::history::History;
+    pub use webapi::router::{Router, Route};
     pub use webapi::web_socket::{WebSocket, SocketCloseCode, SocketBinaryType};
+    pub use webapi::reconnecting_web_socket::{
+        ReconnectingWebSocket,
+        BackoffConfig,
+        ConnectionState,
+        SendError
+    };
+    pub use webapi::font_face::{FontFace, FontSource, FontFaceLoadStatus, FontFaceSet, FontFaceSetLoadStatus, LoadError};
     pub use webapi::rendering_context::{RenderingContext, CanvasRenderingContext2d, CanvasGradient, CanvasPattern, CanvasStyle, FillRule, ImageData, TextMetrics};
-    pub use webapi::mutation_observer::{MutationObserver, MutationObserverHandle, MutationObserverInit, MutationRecord};
-    pub use webapi::xml_http_request::{XmlHttpRequest, XhrReadyState};
+    pub use webapi::mutation_observer::{MutationObserver, MutationObserverHandle, MutationObserverInit, MutationRecord, MutationRecordType};
+    pub use webapi::lifecycle::{observe_lifecycle, observe_attached, LifecycleHandle};
+    pub use webapi::xml_http_request::{XmlHttpRequest, XhrReadyState, XhrResponseType, XhrRequestId, XhrTiming, XhrMetrics, XhrObserver, set_global_xhr_observer, SignableRequest};
+    pub use webapi::xml_http_request::{XhrResponse, ResourceTiming, HeadResult, XhrError, XhrStreamError, InvalidRangeError, is_mixed_content};
+    pub use webapi::xml_http_request::{ConditionalRequest, ConditionalResponse};
+    #[cfg(feature = "futures")]
+    pub use webapi::xml_http_request::{XhrFuture, XhrStreamFuture, JsonpFuture, jsonp, jsonp_with_timeout, DEFAULT_JSONP_TIMEOUT, HeadFuture, head};
+    #[cfg(feature = "futures")]
+    pub use webapi::xml_http_request::ConditionalFuture;
+    #[cfg(feature = "futures")]
+    pub use webapi::xhr_single_flight::{SingleFlightClient, SingleFlightFuture};
+    #[cfg(feature = "futures")]
+    pub use webapi::xhr_request_queue::{RequestQueue, RequestQueueFuture};
+    pub use webapi::dom_parser::{DomParser, XmlSerializer, ParseMimeType, ParseError};
+    pub use webapi::xpath::{XPathResultType, XPathResult, XPathNodeIter, XPathError};
+    pub use webapi::fetch::{fetch, Response, ReadableStreamReader, StreamError};
     pub use webapi::blob::{IBlob, Blob};
+    pub use webapi::notification::{Notification, NotificationOptions, NotificationPermission};
+    pub use webapi::navigator::Navigator;
+    pub use webapi::gamepad::{Gamepad, GamepadButton, GamepadMappingType};
+    pub use webapi::service_worker::{ServiceWorkerContainer, ServiceWorkerRegistration, ServiceWorker, ServiceWorkerState, ServiceWorkerRegistrationError};
+    pub use webapi::animation::{Animation, AnimationOptions, AnimationPlayState, PlaybackDirection, FillMode, Keyframe};
+    pub use webapi::permissions::{Permissions, PermissionStatus, PermissionState, PermissionDescriptor, PermissionQueryError};
+    pub use webapi::events::device::request_device_orientation_permission;
+    pub use webapi::clipboard::{Clipboard, ClipboardError};
+    pub use webapi::fullscreen::FullscreenError;
+    pub use webapi::page_visibility::{VisibilityState, VisibilityWatcher};
+    pub use webapi::network_state::NetworkState;
+    pub use webapi::task_queue::TaskQueue;
+    pub use webapi::rect::Rect;
+    pub use webapi::intersection_observer::{IntersectionObserver, IntersectionObserverHandle, IntersectionObserverInit, IntersectionObserverEntry};
+    pub use webapi::resize_observer::{ResizeObserver, ResizeObserverHandle, ResizeObserverBoxOptions, ResizeObserverEntry};
+    pub use webapi::performance::{Performance, IPerformanceEntry, PerformanceEntry, PerformanceResourceTiming};
+    pub use webapi::performance_observer::{PerformanceObserver, PerformanceObserverHandle, ObserveOptions, PerformanceObserverEntryList};
+    pub use webapi::worker::{Worker, DedicatedWorkerGlobalScope, worker_global_scope};
+    pub use webapi::message_channel::{MessageChannel, MessagePort};
+    pub use webapi::broadcast_channel::{BroadcastChannel, PostMessageError};
+    pub use webapi::media_stream::{MediaStream, MediaStreamTrack, MediaStreamTrackKind};
+    pub use webapi::media_devices::{MediaDevices, MediaConstraints, MediaDeviceInfo, MediaDeviceKind, GetUserMediaError};
+    pub use webapi::media_recorder::{MediaRecorder, RecorderOptions, RecordingState};
+    pub use webapi::url::{create_object_url, revoke_object_url, resolve_url, expand_template, expand_template_with_query, UrlTemplateError};
+    pub use webapi::text_encoding::{TextEncoder, TextDecoder, TextDecoderOptions};
+    pub use webapi::web_audio::{AudioContext, AudioContextState, DecodeError, IAudioNode, AudioNode, AudioDestinationNode, AudioBufferSourceNode, GainNode, AudioParam, AudioBuffer};
 
     /// A module containing error types.
     pub mod error {
         pub use webapi::dom_exception::{
             IDomException,
             DomException,
+            DomExceptionKind,
+            AbortError,
+            DataCloneError,
+            EncodingError,
             HierarchyRequestError,
             IndexSizeError,
             InvalidAccessError,
+            InvalidCharacterError,
+            InvalidStateError,
+            NamespaceError,
+            NotAllowedError,
             NotFoundError,
             NotSupportedError,
             SecurityError,
-            SyntaxError,
-            TypeError
+            SyntaxError
+        };
+        pub use webapi::error::{IError, Error, RangeError, TypeError, JsError};
+        pub use webapi::media_devices::OverconstrainedError;
+    }
+
+    /// A module containing functions for printing to the browser's console.
+    pub mod console {
+        pub use webapi::console::{
+            ConsoleArgs,
+            log,
+            info,
+            warn,
+            error,
+            debug,
+            trace,
+            group,
+            group_collapsed,
+            group_end,
+            time,
+            time_end,
+            count,
+            table,
+            assert,
+            set_panic_hook
         };
-        pub use webapi::error::{IError, Error};
+    }
+
+    /// A module containing cryptographic primitives.
+    pub mod crypto {
+        pub use webapi::crypto::{get_random_values, random_bytes, random_u64};
+
+        /// A module containing the hashing and other primitives of the `SubtleCrypto` API.
+        pub mod subtle {
+            pub use webapi::subtle_crypto::{digest, DigestAlgorithm, CryptoError};
+        }
     }
 
     /// A module containing HTML DOM elements.
@@ -254,6 +374,37 @@ pub mod web {
         pub use webapi::html_elements::InputElement;
         pub use webapi::html_elements::TextAreaElement;
         pub use webapi::html_elements::CanvasElement;
+        pub use webapi::html_elements::CanvasFitHandle;
+        pub use webapi::html_elements::AudioElement;
+        pub use webapi::html_elements::VideoElement;
+    }
+
+    /// A module containing the internationalization API.
+    pub mod intl {
+        pub use webapi::intl::{
+            NumberFormat,
+            NumberFormatError,
+            NumberFormatOptions,
+            NumberFormatStyle,
+            ResolvedNumberFormatOptions,
+            DateTimeFormat,
+            DateTimeFormatOptions,
+            DateTimeStyle,
+            ResolvedDateTimeFormatOptions,
+            DateTimePart,
+            DateTimePartKind
+        };
+    }
+
+    /// A module containing SVG DOM elements.
+    pub mod svg_element {
+        pub use webapi::svg_elements::SvgSvgElement;
+        pub use webapi::svg_elements::SvgPathElement;
+        pub use webapi::svg_elements::SvgCircleElement;
+        pub use webapi::svg_elements::SvgRectElement;
+        pub use webapi::svg_elements::SvgLineElement;
+        pub use webapi::svg_elements::SvgTextElement;
+        pub use webapi::svg_elements::SvgGElement;
     }
 
     /// A module containing JavaScript DOM events.
@@ -262,6 +413,7 @@ pub mod web {
             IEvent,
             IUiEvent,
             ConcreteEvent,
+            CustomEvent,
 
             EventPhase
         };
@@ -305,11 +457,28 @@ pub mod web {
             SocketMessageEvent
         };
 
+        pub use webapi::events::message::{
+            MessageEvent,
+            MessageErrorEvent
+        };
+
+        pub use webapi::events::worker::{
+            WorkerErrorEvent,
+            UpdateFoundEvent,
+            StateChangeEvent,
+            ControllerChangeEvent
+        };
+
         pub use webapi::events::history::{
             HashChangeEvent,
             PopStateEvent
         };
 
+        pub use webapi::events::animation::{
+            AnimationFinishEvent,
+            AnimationCancelEvent
+        };
+
         pub use webapi::events::dom::{
             ChangeEvent,
             ResourceLoadEvent,
@@ -317,7 +486,27 @@ pub mod web {
             ResourceErrorEvent,
             ResizeEvent,
             InputEvent,
-            ReadyStateChangeEvent
+            ReadyStateChangeEvent,
+            FullscreenChangeEvent,
+            FullscreenErrorEvent,
+            VisibilityChangeEvent,
+            OnlineEvent,
+            OfflineEvent
+        };
+
+        pub use webapi::events::media::{
+            MediaPlayEvent,
+            MediaPauseEvent,
+            MediaEndedEvent,
+            TimeUpdateEvent,
+            LoadedMetadataEvent,
+            MediaErrorEvent
+        };
+
+        pub use webapi::events::media_recorder::{
+            DataAvailableEvent,
+            MediaRecorderStopEvent,
+            MediaRecorderErrorEvent
         };
 
         pub use webapi::events::focus::{
@@ -325,6 +514,33 @@ pub mod web {
             FocusEvent,
             BlurEvent
         };
+
+        pub use webapi::events::font::{
+            IFontFaceSetLoadEvent,
+            FontFaceSetLoadingEvent,
+            FontFaceSetLoadingDoneEvent,
+            FontFaceSetLoadingErrorEvent
+        };
+
+        pub use webapi::events::device::{
+            DeviceOrientationEvent,
+            DeviceMotionEvent,
+            DeviceAcceleration,
+            DeviceRotationRate
+        };
+
+        pub use webapi::events::gamepad::{
+            IGamepadEvent,
+            GamepadConnectedEvent,
+            GamepadDisconnectedEvent
+        };
+
+        pub use webapi::events::notification::{
+            NotificationShowEvent,
+            NotificationClickEvent,
+            NotificationCloseEvent,
+            NotificationErrorEvent
+        };
     }
 }
 
@@ -337,6 +553,7 @@ pub mod unstable {
     };
 
     pub use webcore::void::Void;
+    pub use webcore::value::ConversionError;
 }
 
 /// A module containing reexports of all of our interface traits.
@@ -349,7 +566,13 @@ pub mod traits {
         INode,
         IElement,
         IHtmlElement,
+        IHtmlMediaElement,
+        ISvgElement,
+        ISvgGraphicsElement,
+        ISvgGeometryElement,
         IBlob,
+        IPerformanceEntry,
+        IAudioNode,
 
         // Mixins.
         IWindowOrWorker,
@@ -369,7 +592,9 @@ pub mod traits {
         IKeyboardEvent,
         IProgressEvent,
         IMessageEvent,
-        IFocusEvent
+        IFocusEvent,
+        IFontFaceSetLoadEvent,
+        IGamepadEvent
     };
 }
 
@@ -415,5 +640,5 @@ pub mod private {
         }
     }
 
-    pub use webcore::value::ConversionError;
+    pub use webcore::value::{ConversionError, deep_difference};
 }
@@ -0,0 +1,80 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventTarget};
+
+/// The `AbortController` interface represents a controller object which
+/// allows you to abort one or more operations through its [AbortSignal](struct.AbortSignal.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AbortController)
+// https://dom.spec.whatwg.org/#interface-abortcontroller
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "AbortController")]
+pub struct AbortController( Reference );
+
+impl AbortController {
+    /// Creates a new `AbortController`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AbortController/AbortController)
+    // https://dom.spec.whatwg.org/#dom-abortcontroller-abortcontroller
+    pub fn new() -> AbortController {
+        js!( return new AbortController(); ).try_into().unwrap()
+    }
+
+    /// Returns the [AbortSignal](struct.AbortSignal.html) of this controller.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AbortController/signal)
+    // https://dom.spec.whatwg.org/#dom-abortcontroller-signal
+    pub fn signal( &self ) -> AbortSignal {
+        js!( return @{self}.signal; ).try_into().unwrap()
+    }
+
+    /// Aborts every operation associated with the signal of this controller.
+    ///
+    /// Calling this more than once has no effect.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AbortController/abort)
+    // https://dom.spec.whatwg.org/#dom-abortcontroller-abort
+    pub fn abort( &self ) {
+        js! { @(no_return)
+            @{self}.abort();
+        }
+    }
+}
+
+/// The `AbortSignal` interface represents a signal object which is used
+/// to communicate with an operation and abort it when necessary.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AbortSignal)
+// https://dom.spec.whatwg.org/#interface-AbortSignal
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "AbortSignal")]
+#[reference(subclass_of(EventTarget))]
+pub struct AbortSignal( Reference );
+
+impl IEventTarget for AbortSignal {}
+
+impl AbortSignal {
+    /// Returns whether the signal was already aborted.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AbortSignal/aborted)
+    // https://dom.spec.whatwg.org/#dom-abortsignal-aborted
+    pub fn aborted( &self ) -> bool {
+        js!( return @{self}.aborted; ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_abort() {
+        let controller = AbortController::new();
+        let signal = controller.signal();
+        assert!( !signal.aborted() );
+
+        controller.abort();
+        assert!( signal.aborted() );
+        assert_eq!( controller.signal(), signal );
+    }
+}
@@ -0,0 +1,453 @@
+use webcore::value::{Reference, Value};
+use webcore::try_from::TryInto;
+use webcore::promise::Promise;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::error::Error;
+use webapi::dom_exception::AbortError;
+
+/// The direction in which an animation plays its iterations.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/EffectTiming/direction)
+// https://drafts.csswg.org/web-animations-1/#enumdef-playbackdirection
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PlaybackDirection {
+    /// Every iteration plays forwards.
+    Normal,
+    /// Every iteration plays backwards.
+    Reverse,
+    /// The iterations alternate between playing forwards and backwards,
+    /// starting with forwards.
+    Alternate,
+    /// The iterations alternate between playing backwards and forwards,
+    /// starting with backwards.
+    AlternateReverse
+}
+
+impl PlaybackDirection {
+    fn as_str( &self ) -> &'static str {
+        match *self {
+            PlaybackDirection::Normal => "normal",
+            PlaybackDirection::Reverse => "reverse",
+            PlaybackDirection::Alternate => "alternate",
+            PlaybackDirection::AlternateReverse => "alternate-reverse"
+        }
+    }
+}
+
+/// Whether the effect of an animation is applied before it starts and after it ends.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/EffectTiming/fill)
+// https://drafts.csswg.org/web-animations-1/#enumdef-fillmode
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FillMode {
+    /// The effect is only applied while the animation is running.
+    None,
+    /// The last keyframe stays applied after the animation ended.
+    Forwards,
+    /// The first keyframe is already applied during the delay.
+    Backwards,
+    /// Both `Forwards` and `Backwards`.
+    Both,
+    /// Same as `None` for animations created with
+    /// [animate](trait.IElement.html#method.animate).
+    Auto
+}
+
+impl FillMode {
+    fn as_str( &self ) -> &'static str {
+        match *self {
+            FillMode::None => "none",
+            FillMode::Forwards => "forwards",
+            FillMode::Backwards => "backwards",
+            FillMode::Both => "both",
+            FillMode::Auto => "auto"
+        }
+    }
+}
+
+/// The play state of an [Animation](struct.Animation.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/playState)
+// https://drafts.csswg.org/web-animations-1/#enumdef-animationplaystate
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AnimationPlayState {
+    /// The animation isn't playing, e.g. because it was canceled.
+    Idle,
+    /// The animation is playing.
+    Running,
+    /// The animation is paused.
+    Paused,
+    /// The animation reached its end.
+    Finished
+}
+
+impl AnimationPlayState {
+    pub(crate) fn from_str( s: &str ) -> Self {
+        match s {
+            "idle" => AnimationPlayState::Idle,
+            // Older browsers report animations which are about to play as pending.
+            "running" | "pending" => AnimationPlayState::Running,
+            "paused" => AnimationPlayState::Paused,
+            "finished" => AnimationPlayState::Finished,
+            other => panic!( "Invalid animation play state: {:?}", other )
+        }
+    }
+}
+
+/// Options used when starting an animation with
+/// [IElement::animate](trait.IElement.html#method.animate).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/animate#Parameters)
+// https://drafts.csswg.org/web-animations-1/#dictdef-effecttiming
+#[derive(Clone, Debug)]
+pub struct AnimationOptions< 'a > {
+    /// The duration of a single iteration, in milliseconds.
+    pub duration_ms: f64,
+
+    /// The timing function, e.g. `"ease-in-out"` or `"cubic-bezier(0.1, 0.7, 1.0, 0.1)"`.
+    pub easing: &'a str,
+
+    /// The delay before the animation starts, in milliseconds.
+    pub delay_ms: f64,
+
+    /// The number of iterations; `f64::INFINITY` repeats the animation forever.
+    pub iterations: f64,
+
+    /// The direction in which the iterations are played.
+    pub direction: PlaybackDirection,
+
+    /// Whether the effect is applied before the animation starts and after it ends.
+    pub fill: FillMode
+}
+
+impl< 'a > Default for AnimationOptions< 'a > {
+    fn default() -> Self {
+        AnimationOptions {
+            duration_ms: 0.0,
+            easing: "linear",
+            delay_ms: 0.0,
+            iterations: 1.0,
+            direction: PlaybackDirection::Normal,
+            fill: FillMode::Auto
+        }
+    }
+}
+
+impl< 'a > AnimationOptions< 'a > {
+    pub(crate) fn to_js( &self ) -> Value {
+        js!(
+            return {
+                duration: @{self.duration_ms},
+                easing: @{self.easing},
+                delay: @{self.delay_ms},
+                iterations: @{self.iterations},
+                direction: @{self.direction.as_str()},
+                fill: @{self.fill.as_str()}
+            };
+        )
+    }
+}
+
+/// A typed keyframe for the most commonly animated properties,
+/// which can be passed to [IElement::animate](trait.IElement.html#method.animate)
+/// after converting it into a `Value`.
+///
+/// Keyframes which need other properties can be given as plain objects instead,
+/// e.g. `js!( return { backgroundColor: "red" }; )`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Web_Animations_API/Keyframe_Formats)
+// https://drafts.csswg.org/web-animations-1/#processing-a-keyframes-argument
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Keyframe {
+    offset: Option< f64 >,
+    easing: Option< String >,
+    opacity: Option< f64 >,
+    transform: Option< String >
+}
+
+impl Keyframe {
+    /// Creates an empty keyframe.
+    pub fn new() -> Self {
+        Keyframe::default()
+    }
+
+    /// Sets the position of the keyframe within the animation, between `0.0` and `1.0`;
+    /// keyframes without an offset are spaced evenly.
+    pub fn offset( mut self, offset: f64 ) -> Self {
+        self.offset = Some( offset );
+        self
+    }
+
+    /// Sets the timing function used from this keyframe until the next one.
+    pub fn easing( mut self, easing: &str ) -> Self {
+        self.easing = Some( easing.to_owned() );
+        self
+    }
+
+    /// Sets the `opacity` CSS property.
+    pub fn opacity( mut self, opacity: f64 ) -> Self {
+        self.opacity = Some( opacity );
+        self
+    }
+
+    /// Sets the `transform` CSS property, e.g. `"translateX(100px) rotate(45deg)"`.
+    pub fn transform( mut self, transform: &str ) -> Self {
+        self.transform = Some( transform.to_owned() );
+        self
+    }
+}
+
+impl From< Keyframe > for Value {
+    fn from( keyframe: Keyframe ) -> Self {
+        js!(
+            var keyframe = {};
+            var offset = @{keyframe.offset};
+            var easing = @{keyframe.easing};
+            var opacity = @{keyframe.opacity};
+            var transform = @{keyframe.transform};
+            if( offset !== null ) { keyframe.offset = offset; }
+            if( easing !== null ) { keyframe.easing = easing; }
+            if( opacity !== null ) { keyframe.opacity = opacity; }
+            if( transform !== null ) { keyframe.transform = transform; }
+            return keyframe;
+        )
+    }
+}
+
+/// The `Animation` interface represents an animation started with
+/// [IElement::animate](trait.IElement.html#method.animate).
+///
+/// It fires an [AnimationFinishEvent](struct.AnimationFinishEvent.html) when
+/// it finishes and an [AnimationCancelEvent](struct.AnimationCancelEvent.html)
+/// when it's canceled.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation)
+// https://drafts.csswg.org/web-animations-1/#the-animation-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Animation")]
+#[reference(subclass_of(EventTarget))]
+pub struct Animation( Reference );
+
+impl IEventTarget for Animation {}
+
+impl Animation {
+    /// Starts or resumes playing the animation; restarts it if it already finished.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/play)
+    // https://drafts.csswg.org/web-animations-1/#dom-animation-play
+    pub fn play( &self ) {
+        js! { @(no_return)
+            @{self}.play();
+        }
+    }
+
+    /// Pauses the animation.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/pause)
+    // https://drafts.csswg.org/web-animations-1/#dom-animation-pause
+    pub fn pause( &self ) {
+        js! { @(no_return)
+            @{self}.pause();
+        }
+    }
+
+    /// Stops the animation and removes its effect.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/cancel)
+    // https://drafts.csswg.org/web-animations-1/#dom-animation-cancel
+    pub fn cancel( &self ) {
+        js! { @(no_return)
+            @{self}.cancel();
+        }
+    }
+
+    /// Jumps to the end of the animation, or to its start if it's playing backwards.
+    ///
+    /// This does nothing for animations which repeat forever.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/finish)
+    // https://drafts.csswg.org/web-animations-1/#dom-animation-finish
+    pub fn finish( &self ) {
+        js! { @(no_return)
+            var animation = @{self};
+            try {
+                animation.finish();
+            } catch( error ) {
+                // Thrown for animations which repeat forever.
+            }
+        }
+    }
+
+    /// Reverses the playback direction and plays the animation.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/reverse)
+    // https://drafts.csswg.org/web-animations-1/#dom-animation-reverse
+    pub fn reverse( &self ) {
+        js! { @(no_return)
+            @{self}.reverse();
+        }
+    }
+
+    /// Returns the playback rate; `1.0` is the normal speed and negative
+    /// rates play the animation backwards.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/playbackRate)
+    // https://drafts.csswg.org/web-animations-1/#dom-animation-playbackrate
+    pub fn playback_rate( &self ) -> f64 {
+        js!(
+            return @{self}.playbackRate;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the playback rate.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/playbackRate)
+    // https://drafts.csswg.org/web-animations-1/#dom-animation-playbackrate
+    pub fn set_playback_rate( &self, rate: f64 ) {
+        js! { @(no_return)
+            @{self}.playbackRate = @{rate};
+        }
+    }
+
+    /// Returns the position within the animation in milliseconds, including
+    /// the delay, or `None` if the animation is idle.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/currentTime)
+    // https://drafts.csswg.org/web-animations-1/#dom-animation-currenttime
+    pub fn current_time( &self ) -> Option< f64 > {
+        js!(
+            return @{self}.currentTime;
+        ).try_into().unwrap()
+    }
+
+    /// Seeks the animation to the given position in milliseconds.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/currentTime)
+    // https://drafts.csswg.org/web-animations-1/#dom-animation-currenttime
+    pub fn set_current_time( &self, time_ms: f64 ) {
+        js! { @(no_return)
+            @{self}.currentTime = @{time_ms};
+        }
+    }
+
+    /// Returns the play state of the animation.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/playState)
+    // https://drafts.csswg.org/web-animations-1/#dom-animation-playstate
+    pub fn play_state( &self ) -> AnimationPlayState {
+        let state: String = js!(
+            return @{self}.playState;
+        ).try_into().unwrap();
+
+        AnimationPlayState::from_str( &state )
+    }
+
+    /// Calls the `callback` once the animation finishes, or with an
+    /// `AbortError` if it's canceled before it finishes.
+    ///
+    /// If the animation is already finished the `callback` is called
+    /// asynchronously right away.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/finished)
+    // https://drafts.csswg.org/web-animations-1/#dom-animation-finished
+    pub fn on_finished< F >( &self, callback: F )
+        where F: FnOnce( Result< (), AbortError > ) + 'static
+    {
+        let promise: Promise = js!(
+            return @{self}.finished.then( function() {} );
+        ).try_into().unwrap();
+
+        promise.done( move |result: Result< (), Error >| {
+            callback( result.map_err( |error| error.try_into().unwrap() ) );
+        });
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+    use webapi::element::{Element, IElement};
+    use webapi::error::IError;
+
+    fn animated_element() -> Element {
+        let element = document().create_element( "div" ).unwrap();
+        js!( @(no_return) document.body.appendChild( @{&element} ); );
+        element
+    }
+
+    fn opacity( element: &Element ) -> String {
+        js!( return getComputedStyle( @{element} ).opacity; ).try_into().unwrap()
+    }
+
+    #[test]
+    fn test_keyframe() {
+        let value: Value = Keyframe::new().offset( 0.5 ).opacity( 0.25 ).into();
+        assert_eq!( js!( return JSON.stringify( @{value} ); ), r#"{"offset":0.5,"opacity":0.25}"# );
+    }
+
+    #[test]
+    fn test_animate_opacity() {
+        let element = animated_element();
+        let animation = element.animate(
+            &[ Keyframe::new().opacity( 1.0 ).into(), Keyframe::new().opacity( 0.0 ).into() ],
+            &AnimationOptions { duration_ms: 50.0, fill: FillMode::Forwards, ..AnimationOptions::default() }
+        );
+
+        assert_eq!( animation.play_state(), AnimationPlayState::Running );
+
+        // Seek through the paused animation, since the computed style reflects it right away.
+        animation.pause();
+        animation.set_current_time( 0.0 );
+        assert_eq!( opacity( &element ), "1" );
+
+        animation.set_current_time( 25.0 );
+        assert_eq!( opacity( &element ), "0.5" );
+
+        animation.finish();
+        assert_eq!( opacity( &element ), "0" );
+        js!( @(no_return) document.body.removeChild( @{&element} ); );
+    }
+
+    #[test]
+    fn test_controls() {
+        let element = animated_element();
+        let animation = element.animate(
+            &[ js!( return { opacity: 0 }; ), js!( return { opacity: 1 }; ) ],
+            &AnimationOptions { duration_ms: 1000.0, ..AnimationOptions::default() }
+        );
+
+        animation.pause();
+        assert_eq!( animation.play_state(), AnimationPlayState::Paused );
+
+        animation.set_current_time( 250.0 );
+        assert_eq!( animation.current_time(), Some( 250.0 ) );
+
+        animation.set_playback_rate( 2.0 );
+        assert_eq!( animation.playback_rate(), 2.0 );
+
+        animation.finish();
+        assert_eq!( animation.play_state(), AnimationPlayState::Finished );
+
+        animation.on_finished( |result| assert!( result.is_ok() ) );
+        js!( @(no_return) document.body.removeChild( @{&element} ); );
+    }
+
+    #[test]
+    fn test_cancel() {
+        let element = animated_element();
+        let animation = element.animate(
+            &[ Keyframe::new().transform( "translateX(0px)" ).into(), Keyframe::new().transform( "translateX(10px)" ).into() ],
+            &AnimationOptions { duration_ms: 1000.0, ..AnimationOptions::default() }
+        );
+
+        animation.on_finished( |result| {
+            assert_eq!( result.unwrap_err().name(), "AbortError" );
+        });
+
+        animation.cancel();
+        assert_eq!( animation.play_state(), AnimationPlayState::Idle );
+        assert_eq!( animation.current_time(), None );
+        js!( @(no_return) document.body.removeChild( @{&element} ); );
+    }
+}
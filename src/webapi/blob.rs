@@ -43,3 +43,28 @@ pub trait IBlob: ReferenceType {
 pub struct Blob( Reference );
 
 impl IBlob for Blob {}
+
+impl Blob {
+    /// Creates a new `Blob` by concatenating the given `parts`, e.g. chunks
+    /// of recorded media, with the given MIME type.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Blob/Blob)
+    // https://w3c.github.io/FileAPI/#constructorBlob
+    pub fn from_parts( parts: &[Blob], mime_type: &str ) -> Blob {
+        js!( return new Blob( @{parts}, { type: @{mime_type} } ); ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_parts() {
+        let first: Blob = js!( return new Blob( [ "Hello " ] ); ).try_into().unwrap();
+        let second: Blob = js!( return new Blob( [ "world!" ] ); ).try_into().unwrap();
+        let blob = Blob::from_parts( &[ first, second ], "text/plain" );
+        assert_eq!( blob.len(), 12 );
+        assert_eq!( blob.mime(), Some( "text/plain".to_owned() ) );
+    }
+}
@@ -0,0 +1,145 @@
+use webcore::value::{Reference, Value};
+use webcore::try_from::{TryFrom, TryInto};
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::dom_exception::{DataCloneError, InvalidStateError};
+
+/// The `BroadcastChannel` interface allows simple communication between
+/// browsing contexts (windows, tabs, iframes) and workers of the same origin.
+///
+/// Messages posted to a channel are delivered as [MessageEvent](struct.MessageEvent.html)s
+/// to all of the other `BroadcastChannel` objects with the same name; the
+/// sender doesn't receive its own messages.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BroadcastChannel)
+// https://html.spec.whatwg.org/#broadcastchannel
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "BroadcastChannel")]
+#[reference(subclass_of(EventTarget))]
+pub struct BroadcastChannel( Reference );
+
+impl IEventTarget for BroadcastChannel {}
+
+error_enum_boilerplate! {
+    /// Errors which can occur when posting a message through a [BroadcastChannel](struct.BroadcastChannel.html).
+    PostMessageError,
+    /// The message couldn't be cloned, e.g. because it contains a function.
+    DataCloneError,
+    /// The channel was already closed.
+    InvalidStateError
+}
+
+impl BroadcastChannel {
+    /// Creates a new channel, or joins an existing one, with the given `name`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BroadcastChannel/BroadcastChannel)
+    // https://html.spec.whatwg.org/#dom-broadcastchannel
+    pub fn new( name: &str ) -> BroadcastChannel {
+        js!( return new BroadcastChannel( @{name} ); ).try_into().unwrap()
+    }
+
+    /// Returns the name of the channel.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BroadcastChannel/name)
+    // https://html.spec.whatwg.org/#dom-broadcastchannel-name
+    pub fn name( &self ) -> String {
+        js!( return @{self}.name; ).try_into().unwrap()
+    }
+
+    /// Sends a message to all of the other `BroadcastChannel`s with the same name;
+    /// the `data` is structurally cloned.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BroadcastChannel/postMessage)
+    // https://html.spec.whatwg.org/#dom-broadcastchannel-postmessage
+    pub fn post_message( &self, data: Value ) -> Result< (), PostMessageError > {
+        js_try!( @(no_return)
+            @{self}.postMessage( @{data} );
+        ).unwrap()
+    }
+
+    /// Closes the channel; it won't receive any more messages,
+    /// and posting messages to it will fail.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BroadcastChannel/close)
+    // https://html.spec.whatwg.org/#dom-broadcastchannel-close
+    pub fn close( &self ) {
+        js! { @(no_return)
+            @{self}.close();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use std::cell::RefCell;
+    use webcore::async_test::async_test;
+    use webapi::global::set_timeout;
+    use webapi::events::message::MessageEvent;
+    use webapi::events::socket::IMessageEvent;
+
+    fn record_messages( channel: &BroadcastChannel ) -> Rc< RefCell< Vec< String > > > {
+        let received = Rc::new( RefCell::new( Vec::new() ) );
+        channel.add_event_listener({
+            let received = received.clone();
+            move |event: MessageEvent| {
+                let data: String = event.data().try_into().unwrap();
+                received.borrow_mut().push( data );
+            }
+        });
+
+        received
+    }
+
+    #[test]
+    fn test_cross_delivery() {
+        let sender = BroadcastChannel::new( "stdweb-test-delivery" );
+        let receiver = BroadcastChannel::new( "stdweb-test-delivery" );
+        let unrelated = BroadcastChannel::new( "stdweb-test-delivery-other" );
+        let sent = record_messages( &sender );
+        let received = record_messages( &receiver );
+        let received_elsewhere = record_messages( &unrelated );
+
+        sender.post_message( "ping".into() ).unwrap();
+        sender.post_message( "pong".into() ).unwrap();
+
+        async_test( "test_cross_delivery", 1000, move |done| {
+            set_timeout( move || {
+                assert_eq!( *received.borrow(), vec![ "ping".to_owned(), "pong".to_owned() ] );
+
+                // The sender doesn't receive its own messages.
+                assert!( sent.borrow().is_empty() );
+                assert!( received_elsewhere.borrow().is_empty() );
+
+                sender.close();
+                receiver.close();
+                unrelated.close();
+                done.done();
+            }, 200 );
+        });
+    }
+
+    #[test]
+    fn test_broadcast_channel() {
+        let channel = BroadcastChannel::new( "stdweb-test" );
+        assert_eq!( channel.name(), "stdweb-test" );
+        assert!( channel.post_message( "Hello".into() ).is_ok() );
+        channel.close();
+    }
+
+    #[test]
+    fn test_post_message_errors() {
+        let channel = BroadcastChannel::new( "stdweb-test-errors" );
+        let function = js!( return function() {}; );
+        match channel.post_message( function ) {
+            Err( PostMessageError::DataCloneError( _ ) ) => {},
+            other => panic!( "Unexpected result: {:?}", other )
+        }
+
+        channel.close();
+        match channel.post_message( Value::Null ) {
+            Err( PostMessageError::InvalidStateError( _ ) ) => {},
+            other => panic!( "Unexpected result: {:?}", other )
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use webcore::value::Reference;
+use webcore::try_from::{TryFrom, TryInto};
+use webcore::promise::Promise;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::blob::Blob;
+use webapi::dom_exception::NotAllowedError;
+use webapi::error::Error;
+
+/// The `Clipboard` interface provides read and write access to the contents of the system clipboard.
+///
+/// Most browsers only allow the clipboard to be accessed from within a short-lived
+/// event handler triggered by the user (e.g. a click handler); calling these methods
+/// outside of such a handler will usually fail with a
+/// [NotAllowedError](enum.ClipboardError.html#variant.NotAllowedError).
+///
+/// # Examples
+///
+/// ```rust
+/// let button = document().query_selector( "#copy" ).unwrap().unwrap();
+/// button.add_event_listener( |_: ClickEvent| {
+///     let clipboard = window().navigator().clipboard().unwrap();
+///     clipboard.write_text( "Hello world!", |result| {
+///         if let Err( error ) = result {
+///             console!( error, format!( "Copy failed: {}", error ) );
+///         }
+///     });
+/// });
+/// ```
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard)
+// https://w3c.github.io/clipboard-apis/#clipboard-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Clipboard")]
+#[reference(subclass_of(EventTarget))]
+pub struct Clipboard( Reference );
+
+impl IEventTarget for Clipboard {}
+
+error_enum_boilerplate! {
+    /// Errors which can occur when accessing the [Clipboard](struct.Clipboard.html).
+    ClipboardError,
+    /// The access was denied, e.g. because it didn't happen from within
+    /// a user gesture handler or because the user refused the permission.
+    NotAllowedError,
+    /// Any other error.
+    Error
+}
+
+impl Clipboard {
+    /// Writes the given `text` into the system clipboard.
+    ///
+    /// The `callback` is called once the operation has finished.
+    ///
+    /// This will only succeed when called from within a user gesture handler.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard/writeText)
+    // https://w3c.github.io/clipboard-apis/#dom-clipboard-writetext
+    pub fn write_text< F: FnOnce( Result< (), ClipboardError > ) + 'static >( &self, text: &str, callback: F ) {
+        let promise: Promise = js!( return @{self}.writeText( @{text} ); ).try_into().unwrap();
+        promise.done( callback );
+    }
+
+    /// Reads the text contents of the system clipboard.
+    ///
+    /// The `callback` is called once the operation has finished.
+    ///
+    /// This will only succeed when called from within a user gesture handler
+    /// and when the user has granted the permission to read the clipboard.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard/readText)
+    // https://w3c.github.io/clipboard-apis/#dom-clipboard-readtext
+    pub fn read_text< F: FnOnce( Result< String, ClipboardError > ) + 'static >( &self, callback: F ) {
+        let promise: Promise = js!( return @{self}.readText(); ).try_into().unwrap();
+        promise.done( callback );
+    }
+
+    /// Writes arbitrary data into the system clipboard; each item is a pair
+    /// of a MIME type and a [Blob](struct.Blob.html) with the data, e.g. `("image/png", blob)`.
+    ///
+    /// The `callback` is called once the operation has finished. If the browser
+    /// doesn't support writing arbitrary data into the clipboard the `callback` will
+    /// be called with an error.
+    ///
+    /// This will only succeed when called from within a user gesture handler.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard/write)
+    // https://w3c.github.io/clipboard-apis/#dom-clipboard-write
+    pub fn write< F: FnOnce( Result< (), ClipboardError > ) + 'static >( &self, items: &[(&str, Blob)], callback: F ) {
+        let mime_types: Vec< &str > = items.iter().map( |&(mime_type, _)| mime_type ).collect();
+        let blobs: Vec< Reference > = items.iter().map( |&(_, ref blob)| blob.as_ref().clone() ).collect();
+
+        let promise: Promise = js!(
+            var clipboard = @{self};
+            if( typeof clipboard.write !== "function" || typeof ClipboardItem === "undefined" ) {
+                return Promise.reject( new Error( "Writing arbitrary data into the clipboard is not supported" ) );
+            }
+
+            var mime_types = @{mime_types};
+            var blobs = @{blobs};
+            var items = [];
+            for( var i = 0; i < mime_types.length; ++i ) {
+                var data = {};
+                data[ mime_types[ i ] ] = blobs[ i ];
+                items.push( new ClipboardItem( data ) );
+            }
+
+            return clipboard.write( items );
+        ).try_into().unwrap();
+
+        promise.done( callback );
+    }
+}
+
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::window::window;
+    use webapi::error::IError;
+    use webcore::async_test::async_test;
+
+    // Replaces `navigator.clipboard` with a stub whose methods reject with the given `error`.
+    fn stub_clipboard( error: Reference ) -> Clipboard {
+        js! { @(no_return)
+            var error = @{error};
+            var reject = function() {
+                return Promise.reject( error );
+            };
+
+            Object.defineProperty( navigator, "clipboard", {
+                value: { writeText: reject, readText: reject },
+                configurable: true
+            });
+        }
+
+        window().navigator().clipboard().unwrap()
+    }
+
+    fn restore_clipboard() {
+        js! { @(no_return)
+            delete navigator.clipboard;
+        }
+    }
+
+    #[test]
+    fn test_not_allowed() {
+        let error = js!( return new DOMException( "denied", "NotAllowedError" ); ).try_into().unwrap();
+        let clipboard = stub_clipboard( error );
+        restore_clipboard();
+
+        async_test( "test_not_allowed", 1000, move |done| {
+            clipboard.write_text( "text", move |result| {
+                match result {
+                    Err( ClipboardError::NotAllowedError( _ ) ) => {},
+                    result => panic!( "unexpected result: {:?}", result )
+                }
+                done.done();
+            });
+        });
+    }
+
+    #[test]
+    fn test_other_error() {
+        let error = js!( return new TypeError( "broken" ); ).try_into().unwrap();
+        let clipboard = stub_clipboard( error );
+        restore_clipboard();
+
+        async_test( "test_other_error", 1000, move |done| {
+            clipboard.read_text( move |result| {
+                match result {
+                    Err( ClipboardError::Error( error ) ) => assert_eq!( error.message(), "broken" ),
+                    result => panic!( "unexpected result: {:?}", result )
+                }
+                done.done();
+            });
+        });
+    }
+}
@@ -1,3 +1,6 @@
+use std::panic;
+use webcore::value::{Value, Reference};
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __internal_console_unsafe {
@@ -73,3 +76,252 @@ macro_rules! console {
     ( log, $( $args:expr ),+ ) => { __internal_console_unsafe!( log, $( $args ),+ ) };
     ( error, $( $args:expr ),+ ) => { __internal_console_unsafe!( error, $( $args ),+ ) };
 }
+
+/// Arguments which can be passed to the functions in the
+/// [console](index.html) module.
+///
+/// This is implemented for strings, which are printed as they are, and for
+/// slices of [Value](../../struct.Value.html)s, which are passed as separate
+/// arguments; any references among them are passed as the live objects,
+/// so they can be inspected interactively in the browser's console.
+pub trait ConsoleArgs {
+    #[doc(hidden)]
+    fn into_console_args( self ) -> Vec< Value >;
+}
+
+impl< 'a > ConsoleArgs for &'a str {
+    fn into_console_args( self ) -> Vec< Value > {
+        vec![ self.into() ]
+    }
+}
+
+impl< 'a > ConsoleArgs for &'a String {
+    fn into_console_args( self ) -> Vec< Value > {
+        vec![ self.into() ]
+    }
+}
+
+impl< 'a > ConsoleArgs for &'a Value {
+    fn into_console_args( self ) -> Vec< Value > {
+        vec![ self.clone() ]
+    }
+}
+
+impl< 'a > ConsoleArgs for &'a Reference {
+    fn into_console_args( self ) -> Vec< Value > {
+        vec![ self.into() ]
+    }
+}
+
+impl< 'a > ConsoleArgs for &'a [Value] {
+    fn into_console_args( self ) -> Vec< Value > {
+        self.to_vec()
+    }
+}
+
+impl< 'a > ConsoleArgs for &'a Vec< Value > {
+    fn into_console_args( self ) -> Vec< Value > {
+        self.clone()
+    }
+}
+
+macro_rules! console_functions {
+    ($($(#[$attr:meta])* fn $name:ident => $method:expr;)+) => {
+        $(
+            $(#[$attr])*
+            pub fn $name< T: ConsoleArgs >( args: T ) {
+                let args = args.into_console_args();
+                js! { @(no_return)
+                    console[ @{$method} ].apply( console, @{args} );
+                }
+            }
+        )+
+    };
+}
+
+console_functions! {
+    /// Prints a message to the console.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Console/log)
+    // https://console.spec.whatwg.org/#log
+    fn log => "log";
+
+    /// Prints an informational message to the console.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Console/info)
+    // https://console.spec.whatwg.org/#info
+    fn info => "info";
+
+    /// Prints a warning to the console.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Console/warn)
+    // https://console.spec.whatwg.org/#warn
+    fn warn => "warn";
+
+    /// Prints an error to the console.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Console/error)
+    // https://console.spec.whatwg.org/#error
+    fn error => "error";
+
+    /// Prints a debug message to the console; most browsers hide these by default.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Console/debug)
+    // https://console.spec.whatwg.org/#debug
+    fn debug => "debug";
+
+    /// Prints a message to the console along with a stack trace.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Console/trace)
+    // https://console.spec.whatwg.org/#trace
+    fn trace => "trace";
+}
+
+/// Starts a new group with the given `label`; all of the following messages
+/// will be indented until [group_end](fn.group_end.html) is called.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Console/group)
+// https://console.spec.whatwg.org/#group
+pub fn group( label: &str ) {
+    js! { @(no_return)
+        console.group( @{label} );
+    }
+}
+
+/// Same as [group](fn.group.html), except the group starts out collapsed.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Console/groupCollapsed)
+// https://console.spec.whatwg.org/#groupcollapsed
+pub fn group_collapsed( label: &str ) {
+    js! { @(no_return)
+        console.groupCollapsed( @{label} );
+    }
+}
+
+/// Ends the most recently started group.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Console/groupEnd)
+// https://console.spec.whatwg.org/#groupend
+pub fn group_end() {
+    js! { @(no_return)
+        console.groupEnd();
+    }
+}
+
+/// Starts a timer with the given `label`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Console/time)
+// https://console.spec.whatwg.org/#time
+pub fn time( label: &str ) {
+    js! { @(no_return)
+        console.time( @{label} );
+    }
+}
+
+/// Stops the timer with the given `label` and prints the elapsed time.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Console/timeEnd)
+// https://console.spec.whatwg.org/#timeend
+pub fn time_end( label: &str ) {
+    js! { @(no_return)
+        console.timeEnd( @{label} );
+    }
+}
+
+/// Prints how many times this was called with the given `label`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Console/count)
+// https://console.spec.whatwg.org/#count
+pub fn count( label: &str ) {
+    js! { @(no_return)
+        console.count( @{label} );
+    }
+}
+
+/// Prints the given array or object as a table.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Console/table)
+// https://console.spec.whatwg.org/#table
+pub fn table( data: &Value ) {
+    js! { @(no_return)
+        console.table( @{data} );
+    }
+}
+
+/// Prints the `message` as an error if the `condition` is `false`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Console/assert)
+// https://console.spec.whatwg.org/#assert
+pub fn assert( condition: bool, message: &str ) {
+    js! { @(no_return)
+        console.assert( @{condition}, @{message} );
+    }
+}
+
+/// Installs a panic hook which prints the messages of Rust panics,
+/// along with their location, through `console.error`.
+///
+/// Unlike the default hook installed by [initialize](../../fn.initialize.html)
+/// this also preserves the messages of panics with a `&'static str` payload,
+/// e.g. `panic!( "oops" )`, and prints everything as a single message.
+pub fn set_panic_hook() {
+    panic::set_hook( Box::new( |info| {
+        let payload = info.payload();
+        let message = payload.downcast_ref::< String >().map( |message| message.as_str() )
+            .or_else( || payload.downcast_ref::< &'static str >().cloned() )
+            .unwrap_or( "Box<Any>" );
+
+        let text = match info.location() {
+            Some( location ) => format!( "panicked at '{}', {}:{}:{}", message, location.file(), location.line(), location.column() ),
+            None => format!( "panicked at '{}'", message )
+        };
+
+        error( &text );
+    }));
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webcore::try_from::TryInto;
+
+    fn capture_console< F: FnOnce() >( method: &str, callback: F ) -> Vec< Value > {
+        js! { @(no_return)
+            var method = @{method};
+            var original = console[ method ];
+            console.__stdweb_original = original;
+            console.__stdweb_captured = [];
+            console[ method ] = function() {
+                console.__stdweb_captured.push( Array.prototype.slice.call( arguments ) );
+            };
+        }
+
+        callback();
+
+        js!(
+            var method = @{method};
+            console[ method ] = console.__stdweb_original;
+            var captured = console.__stdweb_captured;
+            delete console.__stdweb_original;
+            delete console.__stdweb_captured;
+            return captured[ 0 ];
+        ).try_into().unwrap()
+    }
+
+    #[test]
+    fn test_log_str() {
+        let args = capture_console( "log", || log( "Hello world!" ) );
+        assert_eq!( args, vec![ Value::from( "Hello world!" ) ] );
+    }
+
+    #[test]
+    fn test_warn_values() {
+        let object = js!( return { answer: 42 }; );
+        let args = capture_console( "warn", || warn( &[ Value::from( 1 ), object.clone() ][..] ) );
+        assert_eq!( args.len(), 2 );
+        assert_eq!( args[ 0 ], 1 );
+
+        // The very same object should be passed, not a copy.
+        assert_eq!( args[ 1 ].as_reference(), object.as_reference() );
+    }
+}
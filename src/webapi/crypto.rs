@@ -0,0 +1,74 @@
+use webcore::unsafe_typed_array::UnsafeTypedArrayMut;
+
+// The maximum number of bytes `crypto.getRandomValues` accepts in a single call.
+const MAX_RANDOM_VALUES_LENGTH: usize = 65536;
+
+/// Fills the given `buffer` with cryptographically secure random bytes.
+///
+/// Unlike `Math.random` this is suitable for generating e.g. tokens or nonces.
+///
+/// The browser only accepts up to 65536 bytes per call, so longer
+/// buffers are filled in multiple chunks.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Crypto/getRandomValues)
+// https://w3c.github.io/webcrypto/#Crypto-method-getRandomValues
+pub fn get_random_values( buffer: &mut [u8] ) {
+    for chunk in buffer.chunks_mut( MAX_RANDOM_VALUES_LENGTH ) {
+        // This is safe since `getRandomValues` doesn't allocate
+        // anything on the Rust heap while it fills the array.
+        let array = unsafe { UnsafeTypedArrayMut::new( chunk ) };
+        js! { @(no_return)
+            var crypto = self.crypto || self.msCrypto;
+            crypto.getRandomValues( @{array} );
+        }
+    }
+}
+
+/// Returns a new vector with `length` cryptographically secure random bytes.
+///
+/// See [get_random_values](fn.get_random_values.html).
+pub fn random_bytes( length: usize ) -> Vec< u8 > {
+    let mut buffer = vec![ 0; length ];
+    get_random_values( &mut buffer );
+    buffer
+}
+
+/// Returns a cryptographically secure random `u64`.
+///
+/// See [get_random_values](fn.get_random_values.html).
+pub fn random_u64() -> u64 {
+    let mut buffer = [0; 8];
+    get_random_values( &mut buffer );
+    buffer.iter().rev().fold( 0, |value, &byte| value << 8 | byte as u64 )
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_random_bytes() {
+        let first = random_bytes( 64 );
+        let second = random_bytes( 64 );
+        assert_eq!( first.len(), 64 );
+        assert!( first.iter().any( |&byte| byte != 0 ) );
+        assert!( first.iter().any( |&byte| byte != first[ 0 ] ) );
+        assert_ne!( first, second );
+    }
+
+    #[test]
+    fn test_get_random_values_in_chunks() {
+        let mut buffer = vec![ 0; MAX_RANDOM_VALUES_LENGTH * 2 + 16 ];
+        get_random_values( &mut buffer );
+
+        // The odds of any of these chunks staying zeroed are negligible.
+        assert!( buffer[ ..MAX_RANDOM_VALUES_LENGTH ].iter().any( |&byte| byte != 0 ) );
+        assert!( buffer[ MAX_RANDOM_VALUES_LENGTH..MAX_RANDOM_VALUES_LENGTH * 2 ].iter().any( |&byte| byte != 0 ) );
+        assert!( buffer[ MAX_RANDOM_VALUES_LENGTH * 2.. ].iter().any( |&byte| byte != 0 ) );
+    }
+
+    #[test]
+    fn test_random_u64() {
+        assert_ne!( random_u64(), random_u64() );
+    }
+}
@@ -1,11 +1,16 @@
-use webcore::value::Reference;
+use webcore::value::{Reference, Value};
 use webapi::event_target::{IEventTarget, EventTarget};
 use webapi::node::{INode, Node};
-use webapi::element::Element;
+use webapi::element::{Element, QualifiedNameError};
 use webapi::text_node::TextNode;
 use webapi::location::Location;
 use webapi::parent_node::IParentNode;
 use webapi::non_element_parent_node::INonElementParentNode;
+use webapi::fullscreen::{self, FullscreenError};
+use webapi::page_visibility::VisibilityState;
+use webapi::xpath::{self, XPathResultType, XPathResult, XPathError};
+use webapi::font_face::FontFaceSet;
+use webcore::try_from::TryInto;
 use private::TODO;
 
 /// The `Document` interface represents any web page loaded in the browser and
@@ -44,6 +49,19 @@ impl Document {
         }
     }
 
+    /// Creates an element with the given `namespace` and qualified `name`.
+    ///
+    /// This is how non-HTML elements, e.g. the SVG ones in the
+    /// `http://www.w3.org/2000/svg` namespace, are created; see also [Svg](struct.Svg.html).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/createElementNS)
+    // https://dom.spec.whatwg.org/#ref-for-dom-document-createelementns
+    pub fn create_element_ns( &self, namespace: &str, name: &str ) -> Result< Element, QualifiedNameError > {
+        js_try!(
+            return @{self}.createElementNS( @{namespace}, @{name} );
+        ).unwrap()
+    }
+
     /// Creates a new text node.
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/createTextNode)
@@ -67,4 +85,121 @@ impl Document {
             ).into_reference_unchecked()
         }
     }
+
+    /// Returns the [Element](struct.Element.html) which is currently being
+    /// presented in fullscreen mode, or `None` if the fullscreen mode is not in use.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/fullscreenElement)
+    // https://fullscreen.spec.whatwg.org/#ref-for-dom-document-fullscreenelement
+    pub fn fullscreen_element( &self ) -> Option< Element > {
+        unsafe {
+            js!(
+                var document = @{self};
+                return document.fullscreenElement ||
+                       document.webkitFullscreenElement ||
+                       document.mozFullScreenElement ||
+                       document.msFullscreenElement ||
+                       null;
+            ).into_reference_unchecked()
+        }
+    }
+
+    /// Returns whenever the fullscreen mode is available.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/fullscreenEnabled)
+    // https://fullscreen.spec.whatwg.org/#ref-for-dom-document-fullscreenenabled
+    pub fn fullscreen_enabled( &self ) -> bool {
+        js!(
+            var document = @{self};
+            return !!(document.fullscreenEnabled ||
+                      document.webkitFullscreenEnabled ||
+                      document.mozFullScreenEnabled ||
+                      document.msFullscreenEnabled);
+        ).try_into().unwrap()
+    }
+
+    /// Asynchronously takes the document out of fullscreen mode.
+    ///
+    /// The `callback` is called once the fullscreen mode was exited or when that failed.
+    ///
+    /// In browsers which only support the prefixed version of this API the prefixed
+    /// method will be used instead.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/exitFullscreen)
+    // https://fullscreen.spec.whatwg.org/#ref-for-dom-document-exitfullscreen
+    pub fn exit_fullscreen< F: FnOnce( Result< (), FullscreenError > ) + 'static >( &self, callback: F ) {
+        fullscreen::call_fullscreen_method( self.as_ref(), fullscreen::EXIT_FULLSCREEN, callback );
+    }
+
+    /// Returns the visibility state of the document, i.e. whenever
+    /// it's currently visible to the user.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/visibilityState)
+    // https://w3c.github.io/page-visibility/#dom-document-visibilitystate
+    pub fn visibility_state( &self ) -> VisibilityState {
+        let state: String = js!(
+            var state = @{self}.visibilityState;
+            return state === undefined ? "visible" : state;
+        ).try_into().unwrap();
+
+        VisibilityState::from_str( &state )
+    }
+
+    /// Returns whenever the document is hidden from the user.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/hidden)
+    // https://w3c.github.io/page-visibility/#dom-document-hidden
+    pub fn hidden( &self ) -> bool {
+        js!( return !!@{self}.hidden; ).try_into().unwrap()
+    }
+
+    /// Returns the set of fonts available to the document.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/fonts)
+    // https://drafts.csswg.org/css-font-loading/#dom-fontfacesource-fonts
+    pub fn fonts( &self ) -> FontFaceSet {
+        js!( return @{self}.fonts; ).try_into().unwrap()
+    }
+
+    /// Evaluates the XPath `expression` with the `context` node as the starting point.
+    ///
+    /// The `result_type` determines which kind of [XPathResult](struct.XPathResult.html)
+    /// is returned. Namespace prefixes can't be used in the `expression`; for namespaced
+    /// XML use [evaluate_xpath_with_resolver](#method.evaluate_xpath_with_resolver).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let result = document.evaluate_xpath( "count(//item)", &document, XPathResultType::Number ).unwrap();
+    /// let count = result.number_value().unwrap();
+    /// ```
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/evaluate)
+    // https://www.w3.org/TR/DOM-Level-3-XPath/xpath.html#XPathEvaluator-evaluate
+    pub fn evaluate_xpath< T: INode >( &self, expression: &str, context: &T, result_type: XPathResultType ) -> Result< XPathResult, XPathError > {
+        xpath::evaluate( self.as_ref(), expression, context, result_type, Value::Null )
+    }
+
+    /// Same as [evaluate_xpath](#method.evaluate_xpath), but with a `resolver`
+    /// which maps the namespace prefixes used in the `expression` to namespace URIs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let result = document.evaluate_xpath_with_resolver( "//atom:entry", &document, XPathResultType::OrderedNodeSnapshot, |prefix| {
+    ///     match prefix {
+    ///         "atom" => Some( "http://www.w3.org/2005/Atom".to_owned() ),
+    ///         _ => None
+    ///     }
+    /// }).unwrap();
+    /// ```
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/evaluate)
+    // https://www.w3.org/TR/DOM-Level-3-XPath/xpath.html#XPathEvaluator-evaluate
+    pub fn evaluate_xpath_with_resolver< T, F >( &self, expression: &str, context: &T, result_type: XPathResultType, resolver: F ) -> Result< XPathResult, XPathError >
+        where T: INode,
+              F: Fn( &str ) -> Option< String > + 'static
+    {
+        xpath::evaluate_with_resolver( self.as_ref(), expression, context, result_type, resolver )
+    }
 }
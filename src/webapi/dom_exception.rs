@@ -22,6 +22,96 @@ impl IDomException for DomException {}
 
 error_boilerplate! { DomException }
 
+impl DomException {
+    /// Returns the kind of this exception, as determined by its `name`.
+    ///
+    /// This makes it possible to handle a `DomException` thrown by any fallible API
+    /// without having to downcast it into every specific error type in turn.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMException/name)
+    // https://heycam.github.io/webidl/#idl-DOMException-error-names
+    pub fn kind( &self ) -> DomExceptionKind {
+        DomExceptionKind::from_name( &self.name() )
+    }
+}
+
+/// The kind of a [DomException](struct.DomException.html), corresponding to one of
+/// the standard error names.
+// https://heycam.github.io/webidl/#idl-DOMException-error-names
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DomExceptionKind {
+    /// The index is not in the allowed range.
+    IndexSizeError,
+    /// The operation would yield an incorrect node tree.
+    HierarchyRequestError,
+    /// The object is in the wrong document.
+    WrongDocumentError,
+    /// The string contains invalid characters.
+    InvalidCharacterError,
+    /// The object can not be modified.
+    NoModificationAllowedError,
+    /// The object can not be found here.
+    NotFoundError,
+    /// The operation is not supported.
+    NotSupportedError,
+    /// The object is in an invalid state.
+    InvalidStateError,
+    /// The string did not match the expected pattern.
+    SyntaxError,
+    /// The object can not be modified in this way.
+    InvalidModificationError,
+    /// The operation is not allowed by Namespaces in XML.
+    NamespaceError,
+    /// The object does not support the operation or argument.
+    InvalidAccessError,
+    /// The operation is insecure.
+    SecurityError,
+    /// A network error occurred.
+    NetworkError,
+    /// The operation was aborted.
+    AbortError,
+    /// The quota has been exceeded.
+    QuotaExceededError,
+    /// The operation timed out.
+    TimeoutError,
+    /// The object can not be cloned.
+    DataCloneError,
+    /// The encoding operation (either encoded or decoding) failed.
+    EncodingError,
+    /// The request is not allowed by the user agent or the platform in the current context.
+    NotAllowedError,
+    /// An exception with a name which isn't one of the above.
+    Other( String )
+}
+
+impl DomExceptionKind {
+    fn from_name( name: &str ) -> Self {
+        match name {
+            "IndexSizeError" => DomExceptionKind::IndexSizeError,
+            "HierarchyRequestError" => DomExceptionKind::HierarchyRequestError,
+            "WrongDocumentError" => DomExceptionKind::WrongDocumentError,
+            "InvalidCharacterError" => DomExceptionKind::InvalidCharacterError,
+            "NoModificationAllowedError" => DomExceptionKind::NoModificationAllowedError,
+            "NotFoundError" => DomExceptionKind::NotFoundError,
+            "NotSupportedError" => DomExceptionKind::NotSupportedError,
+            "InvalidStateError" => DomExceptionKind::InvalidStateError,
+            "SyntaxError" => DomExceptionKind::SyntaxError,
+            "InvalidModificationError" => DomExceptionKind::InvalidModificationError,
+            "NamespaceError" => DomExceptionKind::NamespaceError,
+            "InvalidAccessError" => DomExceptionKind::InvalidAccessError,
+            "SecurityError" => DomExceptionKind::SecurityError,
+            "NetworkError" => DomExceptionKind::NetworkError,
+            "AbortError" => DomExceptionKind::AbortError,
+            "QuotaExceededError" => DomExceptionKind::QuotaExceededError,
+            "TimeoutError" => DomExceptionKind::TimeoutError,
+            "DataCloneError" => DomExceptionKind::DataCloneError,
+            "EncodingError" => DomExceptionKind::EncodingError,
+            "NotAllowedError" => DomExceptionKind::NotAllowedError,
+            name => DomExceptionKind::Other( name.to_owned() )
+        }
+    }
+}
+
 /// Occurs when an operation would result in an incorrect node tree.
 // https://heycam.github.io/webidl/#hierarchyrequesterror
 #[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
@@ -33,6 +123,41 @@ impl IDomException for HierarchyRequestError {}
 
 error_boilerplate! { HierarchyRequestError, name = "HierarchyRequestError" }
 
+/// Occurs when the request is not allowed by the user agent or the platform
+/// in the current context, e.g. because the user denied permission.
+// https://heycam.github.io/webidl/#notallowederror
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(subclass_of(Error, DomException))]
+pub struct NotAllowedError( Reference );
+
+impl IError for NotAllowedError {}
+impl IDomException for NotAllowedError {}
+
+error_boilerplate! { NotAllowedError, name = "NotAllowedError" }
+
+/// Occurs when an object can't be cloned with the structured clone algorithm,
+/// e.g. when trying to send a function through `postMessage`.
+// https://heycam.github.io/webidl/#datacloneerror
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(subclass_of(Error, DomException))]
+pub struct DataCloneError( Reference );
+
+impl IError for DataCloneError {}
+impl IDomException for DataCloneError {}
+
+error_boilerplate! { DataCloneError, name = "DataCloneError" }
+
+/// Occurs when an encoding or decoding operation failed, e.g. because the data is corrupt.
+// https://heycam.github.io/webidl/#encodingerror
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(subclass_of(Error, DomException))]
+pub struct EncodingError( Reference );
+
+impl IError for EncodingError {}
+impl IDomException for EncodingError {}
+
+error_boilerplate! { EncodingError, name = "EncodingError" }
+
 /// Occurs when an object does not support an operation or argument.
 // https://heycam.github.io/webidl/#invalidaccesserror
 #[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
@@ -99,16 +224,28 @@ impl IDomException for InvalidStateError {}
 
 error_boilerplate! { InvalidStateError, name = "InvalidStateError" }
 
-/// Used to indicate an unsuccessful operation when none of the other NativeError objects are an appropriate indication of the failure cause.
-// https://tc39.github.io/ecma262/#sec-native-error-types-used-in-this-standard-typeerror
-#[derive(Clone, Debug, ReferenceType)]
+/// Occurs when a string contains characters which aren't allowed, e.g. in an element or attribute name.
+// https://heycam.github.io/webidl/#invalidcharactererror
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
 #[reference(subclass_of(Error, DomException))]
-pub struct TypeError( Reference );
+pub struct InvalidCharacterError( Reference );
 
-impl IError for TypeError {}
-impl IDomException for TypeError {}
+impl IError for InvalidCharacterError {}
+impl IDomException for InvalidCharacterError {}
 
-error_boilerplate! { TypeError, name = "TypeError" }
+error_boilerplate! { InvalidCharacterError, name = "InvalidCharacterError" }
+
+/// Occurs when an operation is not allowed by Namespaces in XML, e.g. when
+/// a qualified name has a prefix but no namespace.
+// https://heycam.github.io/webidl/#namespaceerror
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(subclass_of(Error, DomException))]
+pub struct NamespaceError( Reference );
+
+impl IError for NamespaceError {}
+impl IDomException for NamespaceError {}
+
+error_boilerplate! { NamespaceError, name = "NamespaceError" }
 
 /// Used to indicate an unsuccessful operation when none of the other NativeError objects are an appropriate indication of the failure cause.
 // https://heycam.github.io/webidl/#notsupportederror
@@ -121,10 +258,22 @@ impl IDomException for NotSupportedError {}
 
 error_boilerplate! { NotSupportedError, name = "NotSupportedError" }
 
+/// Occurs when an operation was aborted, e.g. when an animation was canceled.
+// https://heycam.github.io/webidl/#aborterror
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(subclass_of(Error, DomException))]
+pub struct AbortError( Reference );
+
+impl IError for AbortError {}
+impl IDomException for AbortError {}
+
+error_boilerplate! { AbortError, name = "AbortError" }
+
 #[cfg(all(test, feature = "web_test"))]
 mod test {
     use super::*;
     use webcore::try_from::TryInto;
+    use webapi::error::TypeError;
 
     fn new_dom_exception(message: &str, name: &str) -> DomException {
         js!(
@@ -145,4 +294,42 @@ mod test {
         let err: Result<SyntaxError, _> = err.try_into();
         assert!(err.is_err());
     }
+
+    #[test]
+    fn test_kind() {
+        assert_eq!(new_dom_exception("foo", "NotFoundError").kind(), DomExceptionKind::NotFoundError);
+        assert_eq!(new_dom_exception("foo", "QuotaExceededError").kind(), DomExceptionKind::QuotaExceededError);
+        assert_eq!(new_dom_exception("foo", "CustomError").kind(), DomExceptionKind::Other("CustomError".to_owned()));
+    }
+
+    #[test]
+    fn test_thrown_values() {
+        use webcore::value::Value;
+
+        let result: Result<Value, Value> = js_try!( throw new DOMException("gone", "NotFoundError"); ).unwrap();
+        let err: DomException = result.unwrap_err().try_into().unwrap();
+        assert_eq!(err.kind(), DomExceptionKind::NotFoundError);
+        assert_eq!(err.message(), "gone");
+
+        let result: Result<Value, Value> = js_try!( throw new TypeError("native"); ).unwrap();
+        let err: TypeError = result.unwrap_err().try_into().unwrap();
+        assert_eq!(err.name(), "TypeError");
+        assert_eq!(err.message(), "native");
+
+        let result: Result<Value, Value> = js_try!( throw new Error("plain"); ).unwrap();
+        let err: Error = result.unwrap_err().try_into().unwrap();
+        assert_eq!(err.name(), "Error");
+        assert_eq!(err.message(), "plain");
+        let stack: Value = js!( return @{&err}.stack; );
+        assert!(stack.as_str().is_some());
+
+        let result: Result<Value, Value> = js_try!( throw "text"; ).unwrap();
+        assert_eq!(result, Err(Value::String("text".to_owned())));
+
+        let result: Result<Value, Value> = js_try!( throw { code: 42 }; ).unwrap();
+        let thrown = result.unwrap_err();
+        assert_eq!(js!( return @{&thrown}.code; ), 42);
+        let err: Result<Error, _> = thrown.try_into();
+        assert!(err.is_err());
+    }
 }
@@ -0,0 +1,167 @@
+use std::fmt;
+use std::error;
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::document::Document;
+use webapi::node::INode;
+
+/// The type of the source parsed by a [DomParser](struct.DomParser.html).
+// https://w3c.github.io/DOM-Parsing/#the-domparser-interface
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParseMimeType {
+    /// `text/html`; parsing HTML never fails.
+    TextHtml,
+    /// `text/xml`
+    TextXml,
+    /// `application/xml`
+    ApplicationXml,
+    /// `image/svg+xml`
+    ImageSvgXml
+}
+
+impl ParseMimeType {
+    /// Returns the MIME type as a string, e.g. `"text/xml"`.
+    pub fn as_str( &self ) -> &'static str {
+        match *self {
+            ParseMimeType::TextHtml => "text/html",
+            ParseMimeType::TextXml => "text/xml",
+            ParseMimeType::ApplicationXml => "application/xml",
+            ParseMimeType::ImageSvgXml => "image/svg+xml"
+        }
+    }
+}
+
+/// An error returned by [DomParser::parse_from_string](struct.DomParser.html#method.parse_from_string)
+/// when the source isn't well-formed XML.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    message: String
+}
+
+impl ParseError {
+    /// Returns the description of the error as reported by the browser.
+    ///
+    /// Its format differs between browsers.
+    pub fn message( &self ) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        write!( formatter, "failed to parse the document: {}", self.message )
+    }
+}
+
+impl error::Error for ParseError {
+    fn description( &self ) -> &str {
+        "failed to parse the document"
+    }
+}
+
+/// Parses HTML or XML source code into a new [Document](struct.Document.html)
+/// without touching the current one.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMParser)
+// https://w3c.github.io/DOM-Parsing/#the-domparser-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "DOMParser")]
+pub struct DomParser( Reference );
+
+impl DomParser {
+    /// Creates a new `DomParser`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMParser/DOMParser)
+    pub fn new() -> Self {
+        js!( return new DOMParser(); ).try_into().unwrap()
+    }
+
+    /// Parses the `source` as a document of the given `mime` type.
+    ///
+    /// # Errors
+    ///
+    /// Instead of failing browsers return a document describing the error
+    /// when the source isn't well-formed XML; that is detected and turned
+    /// into a [ParseError](struct.ParseError.html) with the browser's description.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMParser/parseFromString)
+    // https://w3c.github.io/DOM-Parsing/#dom-domparser-parsefromstring
+    pub fn parse_from_string( &self, source: &str, mime: ParseMimeType ) -> Result< Document, ParseError > {
+        let document: Document = js!(
+            return @{self}.parseFromString( @{source}, @{mime.as_str()} );
+        ).try_into().unwrap();
+
+        if mime == ParseMimeType::TextHtml {
+            return Ok( document );
+        }
+
+        // The namespace of the error element differs between browsers,
+        // so it's taken from the result of parsing something which is always malformed.
+        let message: Option< String > = js!(
+            var parser = @{self};
+            var document = @{&document};
+            var namespace = parser.parseFromString( "<", "text/xml" ).getElementsByTagName( "parsererror" )[ 0 ].namespaceURI;
+            var errors = document.getElementsByTagNameNS( namespace, "parsererror" );
+            if( errors.length === 0 ) {
+                return null;
+            }
+
+            return errors[ 0 ].textContent.trim();
+        ).try_into().unwrap();
+
+        match message {
+            None => Ok( document ),
+            Some( message ) => Err( ParseError { message: message } )
+        }
+    }
+}
+
+/// Serializes DOM trees into XML strings.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLSerializer)
+// https://w3c.github.io/DOM-Parsing/#the-xmlserializer-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "XMLSerializer")]
+pub struct XmlSerializer( Reference );
+
+impl XmlSerializer {
+    /// Creates a new `XmlSerializer`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLSerializer)
+    pub fn new() -> Self {
+        js!( return new XMLSerializer(); ).try_into().unwrap()
+    }
+
+    /// Serializes the `node` and all of its descendants into a string.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLSerializer/serializeToString)
+    // https://w3c.github.io/DOM-Parsing/#dom-xmlserializer-serializetostring
+    pub fn serialize_to_string< T: INode >( &self, node: &T ) -> String {
+        js!( return @{self}.serializeToString( @{node.as_ref()} ); ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_svg_round_trip() {
+        let source = r#"<svg xmlns="http://www.w3.org/2000/svg"><circle r="5"/></svg>"#;
+        let document = DomParser::new().parse_from_string( source, ParseMimeType::ImageSvgXml ).unwrap();
+        assert_eq!( XmlSerializer::new().serialize_to_string( &document ), source );
+    }
+
+    #[test]
+    fn test_malformed_xml() {
+        let error = DomParser::new().parse_from_string( "<root><unclosed></root>", ParseMimeType::ApplicationXml ).unwrap_err();
+        assert!( !error.message().is_empty() );
+    }
+
+    #[test]
+    fn test_html_never_fails() {
+        let document = DomParser::new().parse_from_string( "<p>unclosed", ParseMimeType::TextHtml ).unwrap();
+        let text: String = js!( return @{&document}.body.textContent; ).try_into().unwrap();
+        assert_eq!( text, "unclosed" );
+    }
+}
@@ -1,8 +1,12 @@
-use webcore::value::Reference;
+use webcore::value::{Reference, Value};
+use webcore::try_from::{TryFrom, TryInto};
 use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::dom_exception::{InvalidCharacterError, NamespaceError};
 use webapi::node::{INode, Node};
 use webapi::token_list::TokenList;
 use webapi::parent_node::IParentNode;
+use webapi::fullscreen::{self, FullscreenError};
+use webapi::animation::{Animation, AnimationOptions};
 
 /// The `IElement` interface represents an object of a [Document](struct.Document.html).
 /// This interface describes methods and properties common to all
@@ -22,6 +26,114 @@ pub trait IElement: INode + IParentNode {
             js!( return @{self.as_ref()}.classList; ).into_reference_unchecked().unwrap()
         }
     }
+
+    /// Asynchronously asks the browser to display this element in fullscreen mode.
+    ///
+    /// The `callback` is called once the element is in fullscreen mode, or when
+    /// the request was denied, e.g. because it wasn't made from within a user gesture
+    /// handler or because the element is inside of an `<iframe>` without the
+    /// `allowfullscreen` attribute.
+    ///
+    /// In browsers which only support the prefixed version of this API the prefixed
+    /// method will be used instead.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/requestFullScreen)
+    // https://fullscreen.spec.whatwg.org/#ref-for-dom-element-requestfullscreen
+    fn request_fullscreen< F: FnOnce( Result< (), FullscreenError > ) + 'static >( &self, callback: F ) {
+        fullscreen::call_fullscreen_method( self.as_ref(), fullscreen::REQUEST_FULLSCREEN, callback );
+    }
+
+    /// Returns the value of the attribute with the given name,
+    /// or `None` if the element doesn't have such an attribute.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/getAttribute)
+    // https://dom.spec.whatwg.org/#ref-for-dom-element-getattribute
+    fn get_attribute( &self, name: &str ) -> Option< String > {
+        js!(
+            return @{self.as_ref()}.getAttribute( @{name} );
+        ).try_into().unwrap()
+    }
+
+    /// Sets the value of the attribute with the given name. The attribute
+    /// is always set in the null namespace, which is also where the attributes
+    /// of SVG elements, e.g. `cx` or `viewBox`, belong.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/setAttribute)
+    // https://dom.spec.whatwg.org/#ref-for-dom-element-setattribute
+    fn set_attribute( &self, name: &str, value: &str ) -> Result< (), InvalidCharacterError > {
+        js_try!( @(no_return)
+            @{self.as_ref()}.setAttribute( @{name}, @{value} );
+        ).unwrap()
+    }
+
+    /// Removes the attribute with the given name, if it exists.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/removeAttribute)
+    // https://dom.spec.whatwg.org/#ref-for-dom-element-removeattribute
+    fn remove_attribute( &self, name: &str ) {
+        js! { @(no_return)
+            @{self.as_ref()}.removeAttribute( @{name} );
+        }
+    }
+
+    /// Returns the value of the attribute with the given `namespace` and local `name`,
+    /// or `None` if the element doesn't have such an attribute.
+    ///
+    /// A `namespace` of `None` is the null namespace, which is **not** the same as
+    /// the namespace of the element itself.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/getAttributeNS)
+    // https://dom.spec.whatwg.org/#ref-for-dom-element-getattributens
+    fn get_attribute_ns( &self, namespace: Option< &str >, name: &str ) -> Option< String > {
+        js!(
+            return @{self.as_ref()}.getAttributeNS( @{namespace}, @{name} );
+        ).try_into().unwrap()
+    }
+
+    /// Sets the value of the attribute with the given `namespace` and qualified `name`,
+    /// e.g. `xlink:href` in the `http://www.w3.org/1999/xlink` namespace.
+    ///
+    /// A `namespace` of `None` is the null namespace, which is **not** the same as
+    /// the namespace of the element itself.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/setAttributeNS)
+    // https://dom.spec.whatwg.org/#ref-for-dom-element-setattributens
+    fn set_attribute_ns( &self, namespace: Option< &str >, name: &str, value: &str ) -> Result< (), QualifiedNameError > {
+        js_try!( @(no_return)
+            @{self.as_ref()}.setAttributeNS( @{namespace}, @{name}, @{value} );
+        ).unwrap()
+    }
+
+    /// Removes the attribute with the given `namespace` and local `name`, if it exists.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/removeAttributeNS)
+    // https://dom.spec.whatwg.org/#ref-for-dom-element-removeattributens
+    fn remove_attribute_ns( &self, namespace: Option< &str >, name: &str ) {
+        js! { @(no_return)
+            @{self.as_ref()}.removeAttributeNS( @{namespace}, @{name} );
+        }
+    }
+
+    /// Starts animating the element through the given `keyframes`, which are either
+    /// plain objects mapping CSS properties to values or typed [Keyframe](struct.Keyframe.html)s.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/animate)
+    // https://drafts.csswg.org/web-animations-1/#dom-animatable-animate
+    fn animate( &self, keyframes: &[Value], options: &AnimationOptions ) -> Animation {
+        js!(
+            return @{self.as_ref()}.animate( @{keyframes}, @{options.to_js()} );
+        ).try_into().unwrap()
+    }
+}
+
+error_enum_boilerplate! {
+    /// Errors thrown by methods which take a qualified name, e.g.
+    /// [Document::create_element_ns](struct.Document.html#method.create_element_ns).
+    QualifiedNameError,
+    /// The name contains characters which aren't allowed.
+    InvalidCharacterError,
+    /// The name has a prefix which is inconsistent with the namespace.
+    NamespaceError
 }
 
 /// A reference to a JavaScript object which implements the [IElement](trait.IElement.html)
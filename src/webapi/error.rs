@@ -1,6 +1,9 @@
-use webcore::value::Reference;
-use webcore::try_from::TryInto;
+use std::fmt;
+use std::error;
+use webcore::value::{Reference, Value, ConversionError};
+use webcore::try_from::{TryFrom, TryInto};
 use webcore::reference_type::ReferenceType;
+use webcore::serialization::{JsSerialize, PreallocatedArena, SerializedValue};
 
 /// Represents the JavaScript `Error` interface. An `Error` is thrown whenever a run-time error
 /// occurs.
@@ -54,6 +57,173 @@ impl IError for Error {}
 
 error_boilerplate! { Error }
 
+/// A reference to a JavaScript `RangeError` object. A `RangeError` is thrown when
+/// a value is not in the set or range of allowed values.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RangeError)
+// https://www.ecma-international.org/ecma-262/6.0/#sec-native-error-types-used-in-this-standard-rangeerror
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "RangeError")]
+#[reference(subclass_of(Error))]
+pub struct RangeError( Reference );
+
+impl IError for RangeError {}
+
+error_boilerplate! { RangeError }
+
+/// A reference to a JavaScript `TypeError` object. A `TypeError` is thrown when
+/// a value is not of the expected type, and is used by the web APIs when none of
+/// the other native errors are an appropriate indication of the failure cause.
+///
+/// This is a native JavaScript error and not a `DOMException`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypeError)
+// https://www.ecma-international.org/ecma-262/6.0/#sec-native-error-types-used-in-this-standard-typeerror
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "TypeError")]
+#[reference(subclass_of(Error))]
+pub struct TypeError( Reference );
+
+impl IError for TypeError {}
+
+error_boilerplate! { TypeError }
+
+/// Anything thrown by JavaScript code, as caught by [js_try!](macro.js_try.html).
+///
+/// JavaScript allows throwing any value, not only `Error` objects, so unlike
+/// [Error](struct.Error.html) the conversion into a `JsError` never fails;
+/// when something other than an object is thrown, e.g. `throw "oops"`, it's
+/// wrapped in a new `Error` with the thrown value converted to a string as
+/// its message.
+///
+/// # Examples
+///
+/// ```rust
+/// let result: Result< String, JsError > = js_try!(
+///     return JSON.parse( "{" );
+/// ).unwrap();
+///
+/// let error = result.unwrap_err();
+/// assert_eq!( error.name(), "SyntaxError" );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsError( Reference );
+
+impl JsError {
+    /// Returns the name of the error, e.g. `"TypeError"`, or `"NetworkError"`
+    /// for a `DOMException`; `"Error"` if the thrown object doesn't have a name.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error/name)
+    pub fn name( &self ) -> String {
+        js!(
+            var name = @{&self.0}.name;
+            return typeof name === "string" ? name : "Error";
+        ).try_into().unwrap()
+    }
+
+    /// Returns a human-readable description of the error; if the thrown object
+    /// doesn't have a message this is the object converted to a string.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error/message)
+    pub fn message( &self ) -> String {
+        js!(
+            var error = @{&self.0};
+            if( typeof error.message === "string" ) {
+                return error.message;
+            }
+
+            try {
+                return String( error );
+            } catch( _ ) {
+                return Object.prototype.toString.call( error );
+            }
+        ).try_into().unwrap()
+    }
+
+    /// Returns the stack trace captured when the error was created, if any.
+    ///
+    /// The format of the stack trace is not standardized and differs between browsers.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error/Stack)
+    pub fn stack( &self ) -> Option< String > {
+        js!(
+            var stack = @{&self.0}.stack;
+            return typeof stack === "string" ? stack : null;
+        ).try_into().unwrap()
+    }
+
+    /// Converts the thrown object into a more specific type, e.g.
+    /// a [TypeError](struct.TypeError.html); returns `None` if it isn't of that type.
+    pub fn downcast< T: ReferenceType >( &self ) -> Option< T > {
+        self.0.clone().downcast()
+    }
+}
+
+impl TryFrom< Value > for JsError {
+    type Error = ConversionError;
+
+    fn try_from( value: Value ) -> Result< Self, Self::Error > {
+        let reference = match value {
+            Value::Reference( reference ) => reference,
+            value => js!(
+                var value = @{value};
+                var message;
+                try {
+                    message = String( value );
+                } catch( _ ) {
+                    message = typeof value;
+                }
+
+                return new Error( message );
+            ).try_into()?
+        };
+
+        Ok( JsError( reference ) )
+    }
+}
+
+impl AsRef< Reference > for JsError {
+    #[inline]
+    fn as_ref( &self ) -> &Reference {
+        &self.0
+    }
+}
+
+impl From< JsError > for Reference {
+    #[inline]
+    fn from( error: JsError ) -> Self {
+        error.0
+    }
+}
+
+impl JsSerialize for JsError {
+    #[doc(hidden)]
+    #[inline]
+    fn _into_js< 'a >( &'a self, arena: &'a PreallocatedArena ) -> SerializedValue< 'a > {
+        self.0._into_js( arena )
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn _memory_required( &self ) -> usize {
+        self.0._memory_required()
+    }
+}
+
+__js_serializable_boilerplate!( JsError );
+
+impl fmt::Display for JsError {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        write!( formatter, "{}: {}", self.name(), self.message() )
+    }
+}
+
+impl error::Error for JsError {
+    fn description( &self ) -> &str {
+        "JsError"
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -74,4 +244,69 @@ mod test {
         assert_eq!(&text, "Error: foo");
         assert_eq!(::std::error::Error::description(&error), "Error");
     }
+
+    #[test]
+    fn test_range_error() {
+        let error: RangeError = js!(
+           return new RangeError("out of range");
+        ).try_into().unwrap();
+
+        assert_eq!(error.name(), "RangeError");
+        assert_eq!(format!("{}", error), "RangeError: out of range");
+
+        let error: Result<RangeError, _> = js!( return new Error("foo"); ).try_into();
+        assert!(error.is_err());
+    }
+
+    #[test]
+    fn test_js_error() {
+        let result: Result< String, JsError > = js_try!(
+            throw new TypeError( "bad type" );
+        ).unwrap();
+
+        let error = result.unwrap_err();
+        assert_eq!( error.name(), "TypeError" );
+        assert_eq!( error.message(), "bad type" );
+        assert_eq!( format!( "{}", error ), "TypeError: bad type" );
+        assert!( error.downcast::< Error >().is_some() );
+        assert!( error.downcast::< RangeError >().is_none() );
+
+        let has_stack: bool = js!( return typeof new Error().stack === "string"; ).try_into().unwrap();
+        assert_eq!( error.stack().is_some(), has_stack );
+    }
+
+    #[test]
+    fn test_js_error_non_error_throws() {
+        let result: Result< (), JsError > = js_try!( @(no_return) throw "oops"; ).unwrap();
+        let error = result.unwrap_err();
+        assert_eq!( error.name(), "Error" );
+        assert_eq!( error.message(), "oops" );
+
+        let result: Result< (), JsError > = js_try!( @(no_return) throw 42; ).unwrap();
+        assert_eq!( result.unwrap_err().message(), "42" );
+
+        let result: Result< (), JsError > = js_try!( @(no_return) throw { code: 7 }; ).unwrap();
+        let error = result.unwrap_err();
+        assert_eq!( error.name(), "Error" );
+        assert_eq!( error.message(), "[object Object]" );
+
+        let result: Result< (), JsError > = js_try!( @(no_return) throw Object.create( null ); ).unwrap();
+        assert_eq!( result.unwrap_err().message(), "[object Object]" );
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod web_tests {
+    use super::*;
+
+    #[test]
+    fn test_js_error_dom_exception() {
+        let result: Result< (), JsError > = js_try!( @(no_return)
+            throw new DOMException( "the request failed", "NetworkError" );
+        ).unwrap();
+
+        let error = result.unwrap_err();
+        assert_eq!( error.name(), "NetworkError" );
+        assert_eq!( error.message(), "the request failed" );
+    }
 }
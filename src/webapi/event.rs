@@ -1,4 +1,4 @@
-use webcore::value::Reference;
+use webcore::value::{Reference, Value};
 use webcore::try_from::TryInto;
 use webcore::reference_type::ReferenceType;
 use webapi::event_target::EventTarget;
@@ -60,7 +60,11 @@ pub trait IEvent: ReferenceType {
         ).try_into().unwrap()
     }
 
-    /// A reference to the currently registered target of this event.
+    /// A reference to the currently registered target of this event, i.e. the object
+    /// on which the listener that's currently being invoked was registered.
+    ///
+    /// This differs from [target](#method.target) when the event is being handled
+    /// by a listener registered on one of the ancestors of the original target.
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Event/currentTarget)
     // https://dom.spec.whatwg.org/#ref-for-dom-event-currenttarget%E2%91%A0
@@ -120,7 +124,19 @@ pub trait IEvent: ReferenceType {
     }
 
 
-    /// Returns a reference to the target to which this event was originally registered.
+    /// Returns a reference to the target to which this event was originally dispatched.
+    ///
+    /// The target is returned as a generic [EventTarget](struct.EventTarget.html); it can be
+    /// converted into a concrete type with `try_into`, which allows a single listener
+    /// to be shared between multiple targets:
+    ///
+    /// ```rust
+    /// let listener = |event: ProgressEvent| {
+    ///     let target = event.target().unwrap();
+    ///     let request: XmlHttpRequest = target.as_ref().try_into().unwrap();
+    ///     println!( "Request status: {}", request.status() );
+    /// };
+    /// ```
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Event/target)
     // https://dom.spec.whatwg.org/#ref-for-dom-event-target%E2%91%A1
@@ -210,6 +226,45 @@ pub struct Event( Reference );
 
 impl IEvent for Event {}
 
+/// The `CustomEvent` interface represents an event created by the application,
+/// which can carry arbitrary data in its [detail](#method.detail).
+///
+/// Events forwarded with [forward_events](trait.IEventTarget.html#method.forward_events)
+/// are `CustomEvent`s whose detail is the original event.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CustomEvent)
+// https://dom.spec.whatwg.org/#interface-customevent
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "CustomEvent")]
+#[reference(subclass_of(Event))]
+pub struct CustomEvent( Reference );
+
+impl IEvent for CustomEvent {}
+
+impl CustomEvent {
+    /// Creates a new `CustomEvent` of the given type which doesn't bubble
+    /// and isn't cancelable; it can be fired with
+    /// [dispatch_event](trait.IEventTarget.html#method.dispatch_event).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CustomEvent/CustomEvent)
+    // https://dom.spec.whatwg.org/#dom-customevent-customevent
+    pub fn new( event_type: &str, detail: Value ) -> Self {
+        js!(
+            return new CustomEvent( @{event_type}, { detail: @{detail} } );
+        ).try_into().unwrap()
+    }
+
+    /// Returns the data the event was created with.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CustomEvent/detail)
+    // https://dom.spec.whatwg.org/#dom-customevent-detail
+    pub fn detail( &self ) -> Value {
+        js!(
+            return @{self}.detail;
+        )
+    }
+}
+
 /// The `IUiEvent` interface represents simple user interface events.
 ///
 /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/UIEvent)
@@ -276,6 +331,35 @@ mod tests {
         event.stop_propagation();
     }
 
+    #[test]
+    fn test_event_target() {
+        use std::rc::Rc;
+        use std::cell::RefCell;
+        use webapi::event_target::IEventTarget;
+        use webapi::xml_http_request::XmlHttpRequest;
+        use webapi::events::progress::LoadStartEvent;
+
+        let request = XmlHttpRequest::new();
+        let targets = Rc::new( RefCell::new( None ) );
+        request.add_event_listener({
+            let targets = targets.clone();
+            move |event: LoadStartEvent| {
+                *targets.borrow_mut() = Some( (event.target(), event.current_target()) );
+            }
+        });
+
+        let event: Event = js!(
+            return new ProgressEvent( @{LoadStartEvent::EVENT_TYPE} );
+        ).try_into().unwrap();
+        request.dispatch_event( &event ).unwrap();
+
+        let (target, current_target) = targets.borrow_mut().take().unwrap();
+        let target: XmlHttpRequest = target.unwrap().as_ref().try_into().unwrap();
+        let current_target: XmlHttpRequest = current_target.unwrap().as_ref().try_into().unwrap();
+        assert_eq!( target, request );
+        assert_eq!( current_target, request );
+    }
+
     #[test]
     fn test_ui_event() {
         use webapi::events::mouse::ClickEvent;
@@ -4,6 +4,7 @@ use webcore::value::Reference;
 use webcore::try_from::TryInto;
 use webcore::reference_type::ReferenceType;
 use webapi::event::{ConcreteEvent, IEvent};
+use webapi::abort_controller::AbortSignal;
 use private::TODO;
 
 /// A handle to a particular event listener.
@@ -31,11 +32,165 @@ impl EventListenerHandle {
             var event_type = @{self.event_type};
             var listener = @{self.listener_reference};
             listener.drop();
-            self.removeEventListener( event_type, listener );
+            self.removeEventListener( event_type, listener, listener.options );
         }
     }
 }
 
+/// A handle to events being forwarded from one [IEventTarget](trait.IEventTarget.html)
+/// to another, as returned by [forward_events](trait.IEventTarget.html#method.forward_events).
+///
+/// The events stop being forwarded once the handle is dropped.
+pub struct EventForwardingHandle {
+    event_type: String,
+    reference: Reference,
+    listener_reference: Reference
+}
+
+impl fmt::Debug for EventForwardingHandle {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        write!( formatter, "EventForwardingHandle {{ event_type: {}, reference: {:?} }}", self.event_type, self.reference )
+    }
+}
+
+impl Drop for EventForwardingHandle {
+    fn drop( &mut self ) {
+        js! { @(no_return)
+            @{&self.reference}.removeEventListener( @{&self.event_type}, @{&self.listener_reference} );
+        }
+    }
+}
+
+// This is separate from `IEventTarget::add_event_listener` so that types
+// which override it can still use the default implementation.
+pub(crate) fn add_event_listener< T, F >( reference: &Reference, signal: Option< &AbortSignal >, debounce_ms: Option< u32 >, listener: F ) -> EventListenerHandle
+    where T: ConcreteEvent, F: FnMut( T ) + 'static
+{
+    add_event_listener_impl( reference, signal, debounce_ms, None, listener )
+}
+
+fn add_event_listener_impl< T, F >( reference: &Reference, signal: Option< &AbortSignal >, debounce_ms: Option< u32 >, name: Option< &str >, listener: F ) -> EventListenerHandle
+    where T: ConcreteEvent, F: FnMut( T ) + 'static
+{
+    let listener_reference = js! {
+        var callback = @{listener};
+        var target = @{reference};
+        var event_type = @{T::EVENT_TYPE};
+        var signal = @{signal};
+        var debounce_ms = @{debounce_ms};
+        var name = @{name};
+
+        // When debouncing only the last event is delivered, once
+        // no other event was fired for `debounce_ms` milliseconds.
+        var timer = null;
+        var deliver = callback;
+        if( debounce_ms !== null ) {
+            deliver = function( event ) {
+                if( timer !== null ) {
+                    clearTimeout( timer );
+                }
+
+                timer = setTimeout( function() {
+                    timer = null;
+                    if( !listener.removed ) {
+                        callback( event );
+                    }
+                }, debounce_ms );
+            };
+        }
+
+        // Events which are fired while the target is suspended
+        // are queued up; see `suspend_events`.
+        var listener = function( event ) {
+            if( target.__stdweb_suspended_events ) {
+                target.__stdweb_suspended_events.push( [ listener, event ] );
+            } else {
+                deliver( event );
+            }
+        };
+
+        listener.drop = function() {
+            if( listener.removed ) {
+                return;
+            }
+
+            listener.removed = true;
+            if( timer !== null ) {
+                clearTimeout( timer );
+                timer = null;
+            }
+            callback.drop();
+            if( signal ) {
+                signal.removeEventListener( "abort", on_abort );
+            }
+            if( name !== null ) {
+                var named = Module.STDWEB_PRIVATE.named_listeners.get( target );
+                if( named && named[ key ] === listener ) {
+                    delete named[ key ];
+                }
+            }
+        };
+
+        // Listeners registered under a name are kept in a registry which
+        // is weakly keyed by their target, so that registering another one
+        // under the same name replaces the previous one.
+        if( name !== null ) {
+            if( !Module.STDWEB_PRIVATE.named_listeners ) {
+                Module.STDWEB_PRIVATE.named_listeners = new WeakMap();
+            }
+
+            var named = Module.STDWEB_PRIVATE.named_listeners.get( target );
+            if( !named ) {
+                named = Object.create( null );
+                Module.STDWEB_PRIVATE.named_listeners.set( target, named );
+            }
+
+            var key = event_type + "\0" + name;
+            var previous = named[ key ];
+            if( previous ) {
+                target.removeEventListener( event_type, previous, previous.options );
+                previous.drop();
+            }
+        }
+
+        // Browsers which don't support the `signal` option won't remove
+        // the listener by themselves, so we always do it manually.
+        var on_abort = function() {
+            target.removeEventListener( event_type, listener, listener.options );
+            listener.drop();
+        };
+
+        if( signal ) {
+            if( signal.aborted ) {
+                listener.drop();
+                return listener;
+            }
+
+            listener.options = { signal: signal };
+            signal.addEventListener( "abort", on_abort );
+        }
+
+        target.addEventListener( event_type, listener, listener.options );
+        if( name !== null ) {
+            named[ key ] = listener;
+        }
+
+        // A `MessagePort` doesn't deliver any messages until it's started,
+        // which `onmessage` does implicitly but `addEventListener` doesn't.
+        if( event_type === "message" && typeof MessagePort !== "undefined" && target instanceof MessagePort ) {
+            target.start();
+        }
+
+        return listener;
+    }.try_into().unwrap();
+
+    EventListenerHandle {
+        event_type: T::EVENT_TYPE,
+        reference: reference.clone(),
+        listener_reference: listener_reference
+    }
+}
+
 /// `IEventTarget` is an interface implemented by objects that
 /// can receive events and may have listeners for them.
 ///
@@ -50,16 +205,201 @@ pub trait IEventTarget: ReferenceType {
     fn add_event_listener< T, F >( &self, listener: F ) -> EventListenerHandle
         where T: ConcreteEvent, F: FnMut( T ) + 'static
     {
-        let reference = self.as_ref();
-        let listener_reference = js! {
-            var listener = @{listener};
-            @{reference}.addEventListener( @{T::EVENT_TYPE}, listener );
+        add_event_listener( self.as_ref(), None, None, listener )
+    }
+
+    /// Adds given event handler to the list of event listeners for the specified
+    /// `EventTarget` on which it's called, and removes it once the given `signal` is aborted.
+    ///
+    /// This makes it possible to remove many listeners at once with a single call to
+    /// [AbortController::abort](struct.AbortController.html#method.abort). The closure
+    /// is freed as soon as the signal is aborted; if the `signal` is already aborted
+    /// the listener is not added at all.
+    ///
+    /// The listener can still be removed earlier through the returned handle.
+    ///
+    /// # Examples
+    ///
+    /// Tearing down all of the listeners of a request together:
+    ///
+    /// ```rust
+    /// let xhr = XmlHttpRequest::new();
+    /// let controller = AbortController::new();
+    /// let signal = controller.signal();
+    ///
+    /// xhr.add_event_listener_with_signal( &signal, |event: ProgressEvent| {
+    ///     console!( log, format!( "Received {} bytes", event.loaded() ) );
+    /// });
+    /// xhr.add_event_listener_with_signal( &signal, |_: ProgressLoadEvent| {
+    ///     console!( log, "Done" );
+    /// });
+    /// xhr.add_event_listener_with_signal( &signal, |_: ProgressErrorEvent| {
+    ///     console!( error, "Failed" );
+    /// });
+    ///
+    /// xhr.open( "GET", "/api/items" ).unwrap();
+    /// xhr.send().unwrap();
+    ///
+    /// // Later, e.g. when the component which made the request is unmounted;
+    /// // this removes and frees all three of the listeners.
+    /// controller.abort();
+    /// xhr.abort();
+    /// ```
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/addEventListener)
+    // https://dom.spec.whatwg.org/#dom-addeventlisteneroptions-signal
+    fn add_event_listener_with_signal< T, F >( &self, signal: &AbortSignal, listener: F ) -> EventListenerHandle
+        where T: ConcreteEvent, F: FnMut( T ) + 'static
+    {
+        add_event_listener( self.as_ref(), Some( signal ), None, listener )
+    }
+
+    /// Adds given event handler to the list of event listeners for the specified
+    /// `EventTarget` on which it's called, but only calls it once no event of
+    /// the given type was fired for `delay_ms` milliseconds, with the last
+    /// event which was fired.
+    ///
+    /// This is useful for reacting to the final state of a burst of events,
+    /// e.g. to the last `input` event once the user stops typing.
+    ///
+    /// Removing the listener through the returned handle also cancels
+    /// the delivery of any event which is still pending.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// input.add_debounced_event_listener( 300, |_: InputEvent| {
+    ///     console!( log, "The user has stopped typing" );
+    /// });
+    /// ```
+    fn add_debounced_event_listener< T, F >( &self, delay_ms: u32, listener: F ) -> EventListenerHandle
+        where T: ConcreteEvent, F: FnMut( T ) + 'static
+    {
+        add_event_listener( self.as_ref(), None, Some( delay_ms ), listener )
+    }
+
+    /// Adds given event handler to the list of event listeners for the specified
+    /// `EventTarget` on which it's called under the given `name`, replacing and
+    /// freeing the listener which was previously added for the same event type
+    /// under the same name, if any.
+    ///
+    /// This makes the registration idempotent, e.g. when a component
+    /// registers its listeners again every time it's rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// fn render( button: &Element, count: u32 ) {
+    ///     // Only the listener from the last render will be called.
+    ///     button.add_event_listener_named( "counter", move |_: ClickEvent| {
+    ///         console!( log, count );
+    ///     });
+    /// }
+    /// ```
+    fn add_event_listener_named< T, F >( &self, name: &str, listener: F ) -> EventListenerHandle
+        where T: ConcreteEvent, F: FnMut( T ) + 'static
+    {
+        add_event_listener_impl( self.as_ref(), None, None, Some( name ), listener )
+    }
+
+    /// Suspends the delivery of events to the listeners registered on this
+    /// `EventTarget` through [add_event_listener](#method.add_event_listener).
+    ///
+    /// Any events fired while the target is suspended are queued up, and
+    /// are delivered in order once [resume_events](#method.resume_events) is called.
+    /// Since queued events are delivered after the fact calling
+    /// [prevent_default](trait.IEvent.html#method.prevent_default) or
+    /// [stop_propagation](trait.IEvent.html#method.stop_propagation) on them has no effect.
+    ///
+    /// Every queued event is kept alive until the target is resumed, so if
+    /// it's never resumed the queue will grow without bound.
+    ///
+    /// Listeners registered through other means are not affected.
+    fn suspend_events( &self ) {
+        js! { @(no_return)
+            var target = @{self.as_ref()};
+            if( !target.__stdweb_suspended_events ) {
+                target.__stdweb_suspended_events = [];
+            }
+        }
+    }
+
+    /// Resumes the delivery of events suspended by [suspend_events](#method.suspend_events),
+    /// first delivering all of the events which were queued up in the meantime,
+    /// in the order in which they were fired.
+    ///
+    /// Events queued for listeners which were removed in the meantime are discarded.
+    fn resume_events( &self ) {
+        js! { @(no_return)
+            var target = @{self.as_ref()};
+            var queue = target.__stdweb_suspended_events;
+            delete target.__stdweb_suspended_events;
+
+            if( queue ) {
+                for( var i = 0; i < queue.length; ++i ) {
+                    var listener = queue[ i ][ 0 ];
+                    if( !listener.removed ) {
+                        listener( queue[ i ][ 1 ] );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Re-dispatches every event of the given type which is fired at this `EventTarget`
+    /// at the `destination`, until the returned handle is dropped.
+    ///
+    /// Since an event can't be dispatched twice, the `destination` receives a
+    /// [CustomEvent](struct.CustomEvent.html) of the same type whose
+    /// [detail](struct.CustomEvent.html#method.detail) is the original event,
+    /// and which bubbles and is cancelable whenever the original one is. If a listener
+    /// of the `destination` cancels it the original event is canceled too.
+    ///
+    /// An event is never forwarded back to a target it was already forwarded from,
+    /// so forwarding in a cycle (e.g. from `a` to `b` and from `b` to `a`) is fine.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `destination` is this `EventTarget` itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Lets the listeners of the component's event bus observe the upload.
+    /// let handle = request.forward_events( "progress", &component.event_bus() );
+    /// ```
+    fn forward_events< T: IEventTarget >( &self, event_type: &str, destination: &T ) -> EventForwardingHandle {
+        assert!( self.as_ref() != destination.as_ref(), "An event target can't forward events to itself" );
+
+        let listener_reference = js!(
+            var source = @{self.as_ref()};
+            var destination = @{destination.as_ref()};
+            var listener = function( event ) {
+                // Every forwarded event remembers the targets it was forwarded from, to break cycles.
+                var forwarded_from = event.__stdweb_forwarded_from || [];
+                if( forwarded_from.indexOf( destination ) !== -1 ) {
+                    return;
+                }
+
+                var forwarded = new CustomEvent( event.type, {
+                    detail: event,
+                    bubbles: event.bubbles,
+                    cancelable: event.cancelable
+                });
+
+                forwarded.__stdweb_forwarded_from = forwarded_from.concat( [ source ] );
+                if( !destination.dispatchEvent( forwarded ) ) {
+                    event.preventDefault();
+                }
+            };
+
+            source.addEventListener( @{event_type}, listener );
             return listener;
-        }.try_into().unwrap();
+        ).try_into().unwrap();
 
-        EventListenerHandle {
-            event_type: T::EVENT_TYPE,
-            reference: reference.clone(),
+        EventForwardingHandle {
+            event_type: event_type.to_owned(),
+            reference: self.as_ref().clone(),
             listener_reference: listener_reference
         }
     }
@@ -85,3 +425,282 @@ pub trait IEventTarget: ReferenceType {
 pub struct EventTarget( Reference );
 
 impl IEventTarget for EventTarget {}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use std::cell::RefCell;
+    use webapi::xml_http_request::XmlHttpRequest;
+    use webapi::events::progress::{IProgressEvent, ProgressEvent};
+    use webapi::abort_controller::AbortController;
+    use webapi::event::CustomEvent;
+
+    fn progress_event( loaded: u32 ) -> ProgressEvent {
+        js!(
+            return new ProgressEvent( @{ProgressEvent::EVENT_TYPE}, { loaded: @{loaded} } );
+        ).try_into().unwrap()
+    }
+
+    #[test]
+    fn test_suspend_events() {
+        let target = XmlHttpRequest::new();
+        let received = Rc::new( RefCell::new( Vec::new() ) );
+        target.add_event_listener({
+            let received = received.clone();
+            move |event: ProgressEvent| received.borrow_mut().push( event.loaded() )
+        });
+
+        target.dispatch_event( &progress_event( 1 ) ).unwrap();
+        target.suspend_events();
+        target.dispatch_event( &progress_event( 2 ) ).unwrap();
+        target.dispatch_event( &progress_event( 3 ) ).unwrap();
+        assert_eq!( *received.borrow(), vec![ 1 ] );
+
+        target.resume_events();
+        assert_eq!( *received.borrow(), vec![ 1, 2, 3 ] );
+
+        target.dispatch_event( &progress_event( 4 ) ).unwrap();
+        assert_eq!( *received.borrow(), vec![ 1, 2, 3, 4 ] );
+    }
+
+    #[test]
+    fn test_suspended_events_of_removed_listener() {
+        let target = XmlHttpRequest::new();
+        let received = Rc::new( RefCell::new( Vec::new() ) );
+        let handle = target.add_event_listener({
+            let received = received.clone();
+            move |event: ProgressEvent| received.borrow_mut().push( event.loaded() )
+        });
+
+        target.suspend_events();
+        target.dispatch_event( &progress_event( 1 ) ).unwrap();
+        handle.remove();
+        target.resume_events();
+        assert!( received.borrow().is_empty() );
+    }
+
+    #[test]
+    fn test_debounced_event_listener() {
+        let target = XmlHttpRequest::new();
+        let received = Rc::new( RefCell::new( Vec::new() ) );
+        let handle = target.add_debounced_event_listener( 10, {
+            let received = received.clone();
+            move |event: ProgressEvent| received.borrow_mut().push( event.loaded() )
+        });
+
+        target.dispatch_event( &progress_event( 1 ) ).unwrap();
+        target.dispatch_event( &progress_event( 2 ) ).unwrap();
+        assert!( received.borrow().is_empty() );
+
+        // This also cancels the pending delivery of the second event.
+        handle.remove();
+        assert!( received.borrow().is_empty() );
+        assert_eq!( Rc::strong_count( &received ), 1 );
+    }
+
+    #[test]
+    fn test_debounced_event_delivery() {
+        use webapi::global::set_timeout;
+        use webcore::async_test::async_test;
+
+        async_test( "test_debounced_event_delivery", 1000, |done| {
+            let target = XmlHttpRequest::new();
+            let received = Rc::new( RefCell::new( Vec::new() ) );
+            target.add_debounced_event_listener( 50, {
+                let received = received.clone();
+                move |event: ProgressEvent| received.borrow_mut().push( event.loaded() )
+            });
+
+            for loaded in 1..6 {
+                target.dispatch_event( &progress_event( loaded ) ).unwrap();
+            }
+
+            set_timeout( move || {
+                // Only the last event of the burst is delivered, exactly once.
+                assert_eq!( *received.borrow(), vec![ 5 ] );
+                drop( target );
+                done.done();
+            }, 300 );
+        });
+    }
+
+    #[test]
+    fn test_debounced_event_listener_removed_while_pending() {
+        use webapi::global::set_timeout;
+        use webcore::async_test::async_test;
+
+        async_test( "test_debounced_event_listener_removed_while_pending", 1000, |done| {
+            let target = XmlHttpRequest::new();
+            let received = Rc::new( RefCell::new( Vec::new() ) );
+            let handle = target.add_debounced_event_listener( 10, {
+                let received = received.clone();
+                move |event: ProgressEvent| received.borrow_mut().push( event.loaded() )
+            });
+
+            target.dispatch_event( &progress_event( 1 ) ).unwrap();
+            handle.remove();
+
+            set_timeout( move || {
+                assert!( received.borrow().is_empty() );
+                done.done();
+            }, 100 );
+        });
+    }
+
+    #[test]
+    fn test_add_event_listener_with_signal() {
+        let target = XmlHttpRequest::new();
+        let controller = AbortController::new();
+        let received = Rc::new( RefCell::new( Vec::new() ) );
+        for _ in 0..2 {
+            let received = received.clone();
+            target.add_event_listener_with_signal( &controller.signal(), move |event: ProgressEvent| {
+                received.borrow_mut().push( event.loaded() )
+            });
+        }
+
+        target.dispatch_event( &progress_event( 1 ) ).unwrap();
+        assert_eq!( *received.borrow(), vec![ 1, 1 ] );
+        assert_eq!( Rc::strong_count( &received ), 3 );
+
+        controller.abort();
+        target.dispatch_event( &progress_event( 2 ) ).unwrap();
+        assert_eq!( *received.borrow(), vec![ 1, 1 ] );
+        assert_eq!( Rc::strong_count( &received ), 1 );
+    }
+
+    #[test]
+    fn test_add_event_listener_with_aborted_signal() {
+        let target = XmlHttpRequest::new();
+        let controller = AbortController::new();
+        controller.abort();
+
+        let received = Rc::new( RefCell::new( Vec::new() ) );
+        target.add_event_listener_with_signal( &controller.signal(), {
+            let received = received.clone();
+            move |event: ProgressEvent| received.borrow_mut().push( event.loaded() )
+        });
+
+        target.dispatch_event( &progress_event( 1 ) ).unwrap();
+        assert!( received.borrow().is_empty() );
+        assert_eq!( Rc::strong_count( &received ), 1 );
+    }
+
+    #[test]
+    fn test_add_event_listener_named() {
+        let target = XmlHttpRequest::new();
+        let received = Rc::new( RefCell::new( Vec::new() ) );
+        for index in 0..3 {
+            let received = received.clone();
+            target.add_event_listener_named( "progress", move |event: ProgressEvent| {
+                received.borrow_mut().push( (index, event.loaded()) )
+            });
+        }
+
+        // The replaced closures are freed.
+        assert_eq!( Rc::strong_count( &received ), 2 );
+        target.dispatch_event( &progress_event( 1 ) ).unwrap();
+        assert_eq!( *received.borrow(), vec![ (2, 1) ] );
+
+        let handle = target.add_event_listener_named( "other", {
+            let received = received.clone();
+            move |event: ProgressEvent| received.borrow_mut().push( (3, event.loaded()) )
+        });
+
+        target.dispatch_event( &progress_event( 2 ) ).unwrap();
+        assert_eq!( *received.borrow(), vec![ (2, 1), (2, 2), (3, 2) ] );
+
+        handle.remove();
+        target.add_event_listener_named( "other", {
+            let received = received.clone();
+            move |event: ProgressEvent| received.borrow_mut().push( (4, event.loaded()) )
+        });
+
+        target.dispatch_event( &progress_event( 3 ) ).unwrap();
+        assert_eq!( *received.borrow(), vec![ (2, 1), (2, 2), (3, 2), (2, 3), (4, 3) ] );
+    }
+
+    #[test]
+    fn test_forward_events() {
+        let source = XmlHttpRequest::new();
+        let destination: EventTarget = js!( return new EventTarget(); ).try_into().unwrap();
+        js!( @(no_return)
+            var destination = @{&destination};
+            destination.received = [];
+            destination.addEventListener( "progress", function( event ) {
+                destination.received.push( event instanceof CustomEvent ? event.detail.loaded : null );
+            });
+        );
+
+        let handle = source.forward_events( "progress", &destination );
+        source.dispatch_event( &progress_event( 1 ) ).unwrap();
+        source.dispatch_event( &progress_event( 2 ) ).unwrap();
+        drop( handle );
+        source.dispatch_event( &progress_event( 3 ) ).unwrap();
+
+        let received: Vec< u32 > = js!( return @{&destination}.received; ).try_into().unwrap();
+        assert_eq!( received, vec![ 1, 2 ] );
+    }
+
+    #[test]
+    fn test_forwarded_event_cancelation() {
+        let source = XmlHttpRequest::new();
+        let destination: EventTarget = js!( return new EventTarget(); ).try_into().unwrap();
+        js!( @(no_return)
+            @{&destination}.addEventListener( "custom", function( event ) {
+                event.preventDefault();
+            });
+        );
+
+        let _handle = source.forward_events( "custom", &destination );
+        let event: CustomEvent = js!( return new CustomEvent( "custom", { cancelable: true } ); ).try_into().unwrap();
+        assert_eq!( source.dispatch_event( &event ).unwrap(), false );
+        assert!( event.default_prevented() );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_forward_events_to_itself() {
+        let target = XmlHttpRequest::new();
+        target.forward_events( "progress", &target );
+    }
+
+    #[test]
+    fn test_forward_events_in_a_cycle() {
+        let a: EventTarget = js!( return new EventTarget(); ).try_into().unwrap();
+        let b: EventTarget = js!( return new EventTarget(); ).try_into().unwrap();
+        js!( @(no_return)
+            var a = @{&a};
+            var b = @{&b};
+            a.received = 0;
+            b.received = 0;
+            a.addEventListener( "custom", function() { a.received += 1; } );
+            b.addEventListener( "custom", function() { b.received += 1; } );
+        );
+
+        let _a_to_b = a.forward_events( "custom", &b );
+        let _b_to_a = b.forward_events( "custom", &a );
+        let event: CustomEvent = js!( return new CustomEvent( "custom" ); ).try_into().unwrap();
+        a.dispatch_event( &event ).unwrap();
+
+        let received: Vec< u32 > = js!( return [ @{&a}.received, @{&b}.received ]; ).try_into().unwrap();
+        assert_eq!( received, vec![ 1, 1 ] );
+    }
+
+    #[test]
+    fn test_remove_listener_with_signal() {
+        let target = XmlHttpRequest::new();
+        let controller = AbortController::new();
+        let received = Rc::new( RefCell::new( Vec::new() ) );
+        let handle = target.add_event_listener_with_signal( &controller.signal(), {
+            let received = received.clone();
+            move |event: ProgressEvent| received.borrow_mut().push( event.loaded() )
+        });
+
+        handle.remove();
+        controller.abort();
+        target.dispatch_event( &progress_event( 1 ) ).unwrap();
+        assert!( received.borrow().is_empty() );
+    }
+}
@@ -0,0 +1,33 @@
+use webcore::value::Reference;
+use webapi::event::{IEvent, Event, ConcreteEvent};
+
+/// The `AnimationFinishEvent` is fired on an [Animation](struct.Animation.html)
+/// when it finishes playing, or when it's finished with
+/// [finish](struct.Animation.html#method.finish).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/onfinish)
+// https://drafts.csswg.org/web-animations-1/#finish-event
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct AnimationFinishEvent( Reference );
+
+impl IEvent for AnimationFinishEvent {}
+impl ConcreteEvent for AnimationFinishEvent {
+    const EVENT_TYPE: &'static str = "finish";
+}
+
+/// The `AnimationCancelEvent` is fired on an [Animation](struct.Animation.html)
+/// when it's canceled, e.g. with [cancel](struct.Animation.html#method.cancel).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Animation/oncancel)
+// https://drafts.csswg.org/web-animations-1/#cancel-event
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct AnimationCancelEvent( Reference );
+
+impl IEvent for AnimationCancelEvent {}
+impl ConcreteEvent for AnimationCancelEvent {
+    const EVENT_TYPE: &'static str = "cancel";
+}
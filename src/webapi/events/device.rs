@@ -0,0 +1,242 @@
+use webcore::value::{Reference, Value};
+use webcore::try_from::TryInto;
+use webcore::promise::Promise;
+use webapi::event::{IEvent, Event, ConcreteEvent};
+use webapi::error::Error;
+use webapi::permissions::PermissionState;
+
+/// The acceleration of a device along its three axes, in m/s².
+///
+/// Each of the values is `None` if the device can't measure it.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceMotionEventAcceleration)
+// https://w3c.github.io/deviceorientation/#devicemotioneventacceleration
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DeviceAcceleration {
+    /// The acceleration along the X axis, which runs from the left to the right of the screen.
+    pub x: Option< f64 >,
+    /// The acceleration along the Y axis, which runs from the bottom to the top of the screen.
+    pub y: Option< f64 >,
+    /// The acceleration along the Z axis, which runs out of the screen towards the user.
+    pub z: Option< f64 >
+}
+
+/// The rate of rotation of a device around its three axes, in degrees per second.
+///
+/// Each of the values is `None` if the device can't measure it.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceMotionEventRotationRate)
+// https://w3c.github.io/deviceorientation/#devicemotioneventrotationrate
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DeviceRotationRate {
+    /// The rate of rotation around the Z axis.
+    pub alpha: Option< f64 >,
+    /// The rate of rotation around the X axis.
+    pub beta: Option< f64 >,
+    /// The rate of rotation around the Y axis.
+    pub gamma: Option< f64 >
+}
+
+/// The `DeviceOrientationEvent` is fired on the window when the physical
+/// orientation of the device changes.
+///
+/// On iOS the event is only fired after the permission was granted through
+/// [request_device_orientation_permission](fn.request_device_orientation_permission.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceOrientationEvent)
+// https://w3c.github.io/deviceorientation/#deviceorientation
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "DeviceOrientationEvent")]
+#[reference(subclass_of(Event))]
+pub struct DeviceOrientationEvent( Reference );
+
+impl IEvent for DeviceOrientationEvent {}
+impl ConcreteEvent for DeviceOrientationEvent {
+    const EVENT_TYPE: &'static str = "deviceorientation";
+}
+
+impl DeviceOrientationEvent {
+    /// Returns the rotation of the device around the Z axis, from `0` to `360` degrees,
+    /// or `None` if the device doesn't have an orientation sensor.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceOrientationEvent/alpha)
+    // https://w3c.github.io/deviceorientation/#dom-deviceorientationevent-alpha
+    pub fn alpha( &self ) -> Option< f64 > {
+        js!( return @{self}.alpha; ).try_into().ok()
+    }
+
+    /// Returns the rotation of the device around the X axis, from `-180` to `180` degrees,
+    /// or `None` if the device doesn't have an orientation sensor.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceOrientationEvent/beta)
+    // https://w3c.github.io/deviceorientation/#dom-deviceorientationevent-beta
+    pub fn beta( &self ) -> Option< f64 > {
+        js!( return @{self}.beta; ).try_into().ok()
+    }
+
+    /// Returns the rotation of the device around the Y axis, from `-90` to `90` degrees,
+    /// or `None` if the device doesn't have an orientation sensor.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceOrientationEvent/gamma)
+    // https://w3c.github.io/deviceorientation/#dom-deviceorientationevent-gamma
+    pub fn gamma( &self ) -> Option< f64 > {
+        js!( return @{self}.gamma; ).try_into().ok()
+    }
+
+    /// Returns whether the orientation is relative to the Earth's coordinate
+    /// frame, rather than to some arbitrary frame chosen by the device.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceOrientationEvent/absolute)
+    // https://w3c.github.io/deviceorientation/#dom-deviceorientationevent-absolute
+    pub fn absolute( &self ) -> bool {
+        js!( return !!@{self}.absolute; ).try_into().unwrap()
+    }
+}
+
+/// The `DeviceMotionEvent` is fired on the window at regular intervals with
+/// the acceleration and the rate of rotation of the device.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceMotionEvent)
+// https://w3c.github.io/deviceorientation/#devicemotion
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "DeviceMotionEvent")]
+#[reference(subclass_of(Event))]
+pub struct DeviceMotionEvent( Reference );
+
+impl IEvent for DeviceMotionEvent {}
+impl ConcreteEvent for DeviceMotionEvent {
+    const EVENT_TYPE: &'static str = "devicemotion";
+}
+
+impl DeviceMotionEvent {
+    fn acceleration_of( &self, property: &str ) -> DeviceAcceleration {
+        let values: Vec< Value > = js!(
+            var acceleration = @{self}[ @{property} ] || {};
+            return [ acceleration.x, acceleration.y, acceleration.z ];
+        ).try_into().unwrap();
+
+        let mut values = values.into_iter().map( |value| value.try_into().ok() );
+        DeviceAcceleration {
+            x: values.next().unwrap(),
+            y: values.next().unwrap(),
+            z: values.next().unwrap()
+        }
+    }
+
+    /// Returns the acceleration of the device, excluding the effect of gravity.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceMotionEvent/acceleration)
+    // https://w3c.github.io/deviceorientation/#dom-devicemotionevent-acceleration
+    pub fn acceleration( &self ) -> DeviceAcceleration {
+        self.acceleration_of( "acceleration" )
+    }
+
+    /// Returns the acceleration of the device, including the effect of gravity.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceMotionEvent/accelerationIncludingGravity)
+    // https://w3c.github.io/deviceorientation/#dom-devicemotionevent-accelerationincludinggravity
+    pub fn acceleration_including_gravity( &self ) -> DeviceAcceleration {
+        self.acceleration_of( "accelerationIncludingGravity" )
+    }
+
+    /// Returns the rate of rotation of the device.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceMotionEvent/rotationRate)
+    // https://w3c.github.io/deviceorientation/#dom-devicemotionevent-rotationrate
+    pub fn rotation_rate( &self ) -> DeviceRotationRate {
+        let values: Vec< Value > = js!(
+            var rate = @{self}.rotationRate || {};
+            return [ rate.alpha, rate.beta, rate.gamma ];
+        ).try_into().unwrap();
+
+        let mut values = values.into_iter().map( |value| value.try_into().ok() );
+        DeviceRotationRate {
+            alpha: values.next().unwrap(),
+            beta: values.next().unwrap(),
+            gamma: values.next().unwrap()
+        }
+    }
+
+    /// Returns the interval at which the data is obtained from the device, in milliseconds.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceMotionEvent/interval)
+    // https://w3c.github.io/deviceorientation/#dom-devicemotionevent-interval
+    pub fn interval( &self ) -> f64 {
+        js!( return @{self}.interval || 0; ).try_into().unwrap()
+    }
+}
+
+/// Requests the permission to receive [DeviceOrientationEvent](struct.DeviceOrientationEvent.html)s
+/// and [DeviceMotionEvent](struct.DeviceMotionEvent.html)s.
+///
+/// This is only necessary on iOS, where it has to be called from within a user
+/// gesture handler (e.g. a click handler); in other browsers the `callback` is
+/// called right away with [Granted](enum.PermissionState.html#variant.Granted).
+///
+/// # Examples
+///
+/// ```rust
+/// button.add_event_listener( |_: ClickEvent| {
+///     request_device_orientation_permission( |result| {
+///         if result == Ok( PermissionState::Granted ) {
+///             window().add_event_listener( |event: DeviceOrientationEvent| {
+///                 console!( log, format!( "{:?} {:?}", event.beta(), event.gamma() ) );
+///             });
+///         }
+///     });
+/// });
+/// ```
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DeviceOrientationEvent#Browser_compatibility)
+// https://w3c.github.io/deviceorientation/#dom-deviceorientationevent-requestpermission
+pub fn request_device_orientation_permission< F >( callback: F )
+    where F: FnOnce( Result< PermissionState, Error > ) + 'static
+{
+    let promise: Promise = js!(
+        if( typeof DeviceOrientationEvent === "undefined" || typeof DeviceOrientationEvent.requestPermission !== "function" ) {
+            return Promise.resolve( "granted" );
+        }
+
+        return DeviceOrientationEvent.requestPermission();
+    ).try_into().unwrap();
+
+    promise.done( move |result: Result< String, Error >| {
+        callback( result.and_then( |state| {
+            PermissionState::from_str( &state ).map_err( |error| {
+                js!( return new TypeError( @{error.to_string()} ); ).try_into().unwrap()
+            })
+        }));
+    });
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_device_orientation_event() {
+        let event: DeviceOrientationEvent = js!(
+            return new DeviceOrientationEvent( @{DeviceOrientationEvent::EVENT_TYPE}, { beta: 45, gamma: -10 } );
+        ).try_into().unwrap();
+
+        assert_eq!( event.alpha(), None );
+        assert_eq!( event.beta(), Some( 45.0 ) );
+        assert_eq!( event.gamma(), Some( -10.0 ) );
+        assert!( !event.absolute() );
+    }
+
+    #[test]
+    fn test_device_motion_event() {
+        let event: DeviceMotionEvent = js!(
+            return new DeviceMotionEvent( @{DeviceMotionEvent::EVENT_TYPE}, {
+                accelerationIncludingGravity: { x: 0.5, y: 9.8, z: null },
+                interval: 16
+            });
+        ).try_into().unwrap();
+
+        assert_eq!( event.acceleration(), DeviceAcceleration::default() );
+        assert_eq!( event.acceleration_including_gravity(), DeviceAcceleration { x: Some( 0.5 ), y: Some( 9.8 ), z: None } );
+        assert_eq!( event.rotation_rate(), DeviceRotationRate::default() );
+        assert_eq!( event.interval(), 16.0 );
+    }
+}
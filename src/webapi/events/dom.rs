@@ -115,6 +115,92 @@ impl ConcreteEvent for ReadyStateChangeEvent {
     const EVENT_TYPE: &'static str = "readystatechange";
 }
 
+/// The `FullscreenChangeEvent` is fired on the [Document](struct.Document.html) immediately
+/// after the browser switches into or out of fullscreen mode.
+///
+/// Older browsers only fire the prefixed version of this event
+/// (e.g. `webkitfullscreenchange`) which this type doesn't cover.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/fullscreenchange)
+// https://fullscreen.spec.whatwg.org/#handler-document-onfullscreenchange
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct FullscreenChangeEvent( Reference );
+
+impl IEvent for FullscreenChangeEvent {}
+
+impl ConcreteEvent for FullscreenChangeEvent {
+    const EVENT_TYPE: &'static str = "fullscreenchange";
+}
+
+/// The `FullscreenErrorEvent` is fired on the [Document](struct.Document.html) when
+/// the browser cannot switch into fullscreen mode.
+///
+/// Older browsers only fire the prefixed version of this event
+/// (e.g. `webkitfullscreenerror`) which this type doesn't cover.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/fullscreenerror)
+// https://fullscreen.spec.whatwg.org/#handler-document-onfullscreenerror
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct FullscreenErrorEvent( Reference );
+
+impl IEvent for FullscreenErrorEvent {}
+
+impl ConcreteEvent for FullscreenErrorEvent {
+    const EVENT_TYPE: &'static str = "fullscreenerror";
+}
+
+/// The `VisibilityChangeEvent` is fired on the [Document](struct.Document.html) when
+/// the content of its tab has become visible or has been hidden.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/visibilitychange)
+// https://w3c.github.io/page-visibility/#sec-reacting-to-visibilitychange-changes
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct VisibilityChangeEvent( Reference );
+
+impl IEvent for VisibilityChangeEvent {}
+
+impl ConcreteEvent for VisibilityChangeEvent {
+    const EVENT_TYPE: &'static str = "visibilitychange";
+}
+
+/// The `OnlineEvent` is fired on the [Window](struct.Window.html) when
+/// the browser has gained access to the network.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/online)
+// https://html.spec.whatwg.org/#event-online
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct OnlineEvent( Reference );
+
+impl IEvent for OnlineEvent {}
+
+impl ConcreteEvent for OnlineEvent {
+    const EVENT_TYPE: &'static str = "online";
+}
+
+/// The `OfflineEvent` is fired on the [Window](struct.Window.html) when
+/// the browser has lost access to the network.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/offline)
+// https://html.spec.whatwg.org/#event-offline
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct OfflineEvent( Reference );
+
+impl IEvent for OfflineEvent {}
+
+impl ConcreteEvent for OfflineEvent {
+    const EVENT_TYPE: &'static str = "offline";
+}
+
 #[cfg(all(test, feature = "web_test"))]
 mod tests {
     use super::*;
@@ -159,4 +245,12 @@ mod tests {
         ).try_into().unwrap();
         assert_eq!( event.event_type(), ReadyStateChangeEvent::EVENT_TYPE);
     }
+
+    #[test]
+    fn test_fullscreen_change_event() {
+        let event: FullscreenChangeEvent = js!(
+            return new Event( @{FullscreenChangeEvent::EVENT_TYPE} );
+        ).try_into().unwrap();
+        assert_eq!( event.event_type(), FullscreenChangeEvent::EVENT_TYPE );
+    }
 }
@@ -0,0 +1,90 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event::{IEvent, Event, ConcreteEvent};
+use webapi::font_face::FontFace;
+
+/// The `IFontFaceSetLoadEvent` interface represents events fired
+/// by a [FontFaceSet](struct.FontFaceSet.html) while fonts are loaded.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/FontFaceSetLoadEvent)
+// https://drafts.csswg.org/css-font-loading/#fontfacesetloadevent
+pub trait IFontFaceSetLoadEvent: IEvent {
+    /// Returns the fonts which this event is about.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/FontFaceSetLoadEvent/fontfaces)
+    // https://drafts.csswg.org/css-font-loading/#dom-fontfacesetloadevent-fontfaces
+    #[inline]
+    fn font_faces( &self ) -> Vec< FontFace > {
+        js!(
+            return @{self.as_ref()}.fontfaces;
+        ).try_into().unwrap()
+    }
+}
+
+/// The `FontFaceSetLoadingEvent` is fired when the document
+/// starts loading fonts.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/FontFaceSet/loading_event)
+// https://drafts.csswg.org/css-font-loading/#dom-fontfaceset-onloading
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "FontFaceSetLoadEvent")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct FontFaceSetLoadingEvent( Reference );
+
+impl IEvent for FontFaceSetLoadingEvent {}
+impl IFontFaceSetLoadEvent for FontFaceSetLoadingEvent {}
+impl ConcreteEvent for FontFaceSetLoadingEvent {
+    const EVENT_TYPE: &'static str = "loading";
+}
+
+/// The `FontFaceSetLoadingDoneEvent` is fired when the document has
+/// finished loading fonts; its [font_faces](trait.IFontFaceSetLoadEvent.html#method.font_faces)
+/// are the fonts which have loaded successfully.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/FontFaceSet/loadingdone_event)
+// https://drafts.csswg.org/css-font-loading/#dom-fontfaceset-onloadingdone
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "FontFaceSetLoadEvent")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct FontFaceSetLoadingDoneEvent( Reference );
+
+impl IEvent for FontFaceSetLoadingDoneEvent {}
+impl IFontFaceSetLoadEvent for FontFaceSetLoadingDoneEvent {}
+impl ConcreteEvent for FontFaceSetLoadingDoneEvent {
+    const EVENT_TYPE: &'static str = "loadingdone";
+}
+
+/// The `FontFaceSetLoadingErrorEvent` is fired when the document has
+/// finished loading fonts and some of them have failed to load; its
+/// [font_faces](trait.IFontFaceSetLoadEvent.html#method.font_faces) are the fonts which have failed.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/FontFaceSet/loadingerror_event)
+// https://drafts.csswg.org/css-font-loading/#dom-fontfaceset-onloadingerror
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "FontFaceSetLoadEvent")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct FontFaceSetLoadingErrorEvent( Reference );
+
+impl IEvent for FontFaceSetLoadingErrorEvent {}
+impl IFontFaceSetLoadEvent for FontFaceSetLoadingErrorEvent {}
+impl ConcreteEvent for FontFaceSetLoadingErrorEvent {
+    const EVENT_TYPE: &'static str = "loadingerror";
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_loading_done_event() {
+        let event: FontFaceSetLoadingDoneEvent = js!(
+            return new FontFaceSetLoadEvent( @{FontFaceSetLoadingDoneEvent::EVENT_TYPE}, {
+                fontfaces: [ new FontFace( "Test", "url(test.woff2)" ) ]
+            });
+        ).try_into().unwrap();
+
+        assert_eq!( event.event_type(), FontFaceSetLoadingDoneEvent::EVENT_TYPE );
+        assert_eq!( event.font_faces().len(), 1 );
+        assert_eq!( event.font_faces()[ 0 ].family(), "Test" );
+    }
+}
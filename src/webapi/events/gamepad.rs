@@ -0,0 +1,51 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event::{IEvent, Event, ConcreteEvent};
+use webapi::gamepad::Gamepad;
+
+/// A trait representing events which carry a [Gamepad](struct.Gamepad.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/GamepadEvent)
+// https://w3c.github.io/gamepad/#gamepadevent-interface
+pub trait IGamepadEvent: IEvent {
+    /// Returns a snapshot of the gamepad which was connected or disconnected.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/GamepadEvent/gamepad)
+    // https://w3c.github.io/gamepad/#dom-gamepadevent-gamepad
+    #[inline]
+    fn gamepad( &self ) -> Gamepad {
+        let gamepad: Reference = js!( return @{self.as_ref()}.gamepad; ).try_into().unwrap();
+        Gamepad::from_reference( &gamepad )
+    }
+}
+
+/// The `GamepadConnectedEvent` is fired on the window when a gamepad is
+/// connected, or when it's used for the first time.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/gamepadconnected)
+// https://w3c.github.io/gamepad/#event-gamepadconnected
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "GamepadEvent")]
+#[reference(subclass_of(Event))]
+pub struct GamepadConnectedEvent( Reference );
+
+impl IEvent for GamepadConnectedEvent {}
+impl IGamepadEvent for GamepadConnectedEvent {}
+impl ConcreteEvent for GamepadConnectedEvent {
+    const EVENT_TYPE: &'static str = "gamepadconnected";
+}
+
+/// The `GamepadDisconnectedEvent` is fired on the window when a gamepad is disconnected.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/gamepaddisconnected)
+// https://w3c.github.io/gamepad/#event-gamepaddisconnected
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "GamepadEvent")]
+#[reference(subclass_of(Event))]
+pub struct GamepadDisconnectedEvent( Reference );
+
+impl IEvent for GamepadDisconnectedEvent {}
+impl IGamepadEvent for GamepadDisconnectedEvent {}
+impl ConcreteEvent for GamepadDisconnectedEvent {
+    const EVENT_TYPE: &'static str = "gamepaddisconnected";
+}
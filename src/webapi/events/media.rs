@@ -0,0 +1,106 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event::{IEvent, Event, ConcreteEvent};
+use webapi::html_elements::MediaError;
+
+/// The `MediaPlayEvent` is fired when a media element starts playing,
+/// e.g. after a call to `play()`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/play)
+// https://html.spec.whatwg.org/#event-media-play
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct MediaPlayEvent( Reference );
+
+impl IEvent for MediaPlayEvent {}
+impl ConcreteEvent for MediaPlayEvent {
+    const EVENT_TYPE: &'static str = "play";
+}
+
+/// The `MediaPauseEvent` is fired when a media element has been paused.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/pause)
+// https://html.spec.whatwg.org/#event-media-pause
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct MediaPauseEvent( Reference );
+
+impl IEvent for MediaPauseEvent {}
+impl ConcreteEvent for MediaPauseEvent {
+    const EVENT_TYPE: &'static str = "pause";
+}
+
+/// The `MediaEndedEvent` is fired when a media element has reached
+/// the end of the media.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/ended)
+// https://html.spec.whatwg.org/#event-media-ended
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct MediaEndedEvent( Reference );
+
+impl IEvent for MediaEndedEvent {}
+impl ConcreteEvent for MediaEndedEvent {
+    const EVENT_TYPE: &'static str = "ended";
+}
+
+/// The `TimeUpdateEvent` is fired when the current playback position
+/// of a media element has changed.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/timeupdate)
+// https://html.spec.whatwg.org/#event-media-timeupdate
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct TimeUpdateEvent( Reference );
+
+impl IEvent for TimeUpdateEvent {}
+impl ConcreteEvent for TimeUpdateEvent {
+    const EVENT_TYPE: &'static str = "timeupdate";
+}
+
+/// The `LoadedMetadataEvent` is fired when the metadata of a media element,
+/// e.g. its duration and dimensions, has been loaded.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/loadedmetadata)
+// https://html.spec.whatwg.org/#event-media-loadedmetadata
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct LoadedMetadataEvent( Reference );
+
+impl IEvent for LoadedMetadataEvent {}
+impl ConcreteEvent for LoadedMetadataEvent {
+    const EVENT_TYPE: &'static str = "loadedmetadata";
+}
+
+/// The `MediaErrorEvent` is fired when an error occurred while loading
+/// or decoding the media of a media element.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/error_event)
+// https://html.spec.whatwg.org/#event-media-error
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct MediaErrorEvent( Reference );
+
+impl IEvent for MediaErrorEvent {}
+impl ConcreteEvent for MediaErrorEvent {
+    const EVENT_TYPE: &'static str = "error";
+}
+
+impl MediaErrorEvent {
+    /// Returns the error of the media element on which this event was fired.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/error)
+    // https://html.spec.whatwg.org/#dom-media-error
+    pub fn media_error( &self ) -> Option< MediaError > {
+        js!(
+            var target = @{self.as_ref()}.target;
+            return target ? target.error : null;
+        ).try_into().unwrap()
+    }
+}
@@ -0,0 +1,70 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event::{IEvent, Event, ConcreteEvent};
+use webapi::blob::Blob;
+use webapi::error::Error;
+
+/// The `DataAvailableEvent` is fired on a [MediaRecorder](struct.MediaRecorder.html)
+/// to deliver a chunk of the recorded data.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaRecorder/dataavailable_event)
+// https://w3c.github.io/mediacapture-record/#eventdef-mediarecorder-dataavailable
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "BlobEvent")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct DataAvailableEvent( Reference );
+
+impl IEvent for DataAvailableEvent {}
+impl ConcreteEvent for DataAvailableEvent {
+    const EVENT_TYPE: &'static str = "dataavailable";
+}
+
+impl DataAvailableEvent {
+    /// Returns the chunk of recorded data.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BlobEvent/data)
+    // https://w3c.github.io/mediacapture-record/#dom-blobevent-data
+    pub fn data( &self ) -> Blob {
+        js!( return @{self}.data; ).try_into().unwrap()
+    }
+}
+
+/// The `MediaRecorderStopEvent` is fired on a [MediaRecorder](struct.MediaRecorder.html)
+/// once the recording has stopped and all of the recorded data was delivered.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaRecorder/stop_event)
+// https://w3c.github.io/mediacapture-record/#eventdef-mediarecorder-stop
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct MediaRecorderStopEvent( Reference );
+
+impl IEvent for MediaRecorderStopEvent {}
+impl ConcreteEvent for MediaRecorderStopEvent {
+    const EVENT_TYPE: &'static str = "stop";
+}
+
+/// The `MediaRecorderErrorEvent` is fired on a [MediaRecorder](struct.MediaRecorder.html)
+/// when an error occurred during the recording; the recording is stopped afterwards.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaRecorder/error_event)
+// https://w3c.github.io/mediacapture-record/#eventdef-mediarecorder-error
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct MediaRecorderErrorEvent( Reference );
+
+impl IEvent for MediaRecorderErrorEvent {}
+impl ConcreteEvent for MediaRecorderErrorEvent {
+    const EVENT_TYPE: &'static str = "error";
+}
+
+impl MediaRecorderErrorEvent {
+    /// Returns the error which occurred, if the browser provides it.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaRecorderErrorEvent/error)
+    // https://w3c.github.io/mediacapture-record/#dom-mediarecordererrorevent-error
+    pub fn error( &self ) -> Option< Error > {
+        js!( return @{self}.error || null; ).try_into().unwrap()
+    }
+}
@@ -0,0 +1,56 @@
+use webcore::value::{Reference, Value};
+use webapi::event::{IEvent, Event, ConcreteEvent};
+use webapi::events::socket::IMessageEvent;
+
+/// The `MessageEvent` is fired when a message is received through a
+/// [Worker](struct.Worker.html), a worker's global scope, or a similar channel.
+///
+/// The data of the message can be of any type which can be structurally cloned.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/message)
+// https://html.spec.whatwg.org/#event-message
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "MessageEvent")]
+#[reference(subclass_of(Event))]
+pub struct MessageEvent( Reference );
+
+impl IMessageEvent for MessageEvent {
+    type Data = Value;
+}
+
+impl IEvent for MessageEvent {}
+impl ConcreteEvent for MessageEvent {
+    const EVENT_TYPE: &'static str = "message";
+}
+
+/// The `MessageErrorEvent` is fired when a message was received
+/// but it couldn't be deserialized.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/messageerror)
+// https://html.spec.whatwg.org/#event-messageerror
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "MessageEvent")]
+#[reference(subclass_of(Event))]
+pub struct MessageErrorEvent( Reference );
+
+impl IEvent for MessageErrorEvent {}
+impl ConcreteEvent for MessageErrorEvent {
+    const EVENT_TYPE: &'static str = "messageerror";
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webcore::try_from::TryInto;
+
+    #[test]
+    fn test_message_event() {
+        let event: MessageEvent = js!(
+            return new MessageEvent( @{MessageEvent::EVENT_TYPE}, { data: [ 1, 2 ] } );
+        ).try_into().unwrap();
+        assert_eq!( event.event_type(), MessageEvent::EVENT_TYPE );
+
+        let data: Vec< i32 > = event.data().try_into().unwrap();
+        assert_eq!( data, vec![ 1, 2 ] );
+    }
+}
@@ -1,7 +1,16 @@
+pub mod animation;
 pub mod dom;
+pub mod device;
 pub mod focus;
+pub mod font;
+pub mod gamepad;
 pub mod history;
 pub mod keyboard;
+pub mod media;
+pub mod media_recorder;
+pub mod message;
 pub mod mouse;
+pub mod notification;
 pub mod progress;
 pub mod socket;
+pub mod worker;
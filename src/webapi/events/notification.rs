@@ -0,0 +1,59 @@
+use webcore::value::Reference;
+use webapi::event::{IEvent, Event, ConcreteEvent};
+
+/// The `NotificationShowEvent` is fired when a [Notification](struct.Notification.html) is displayed.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/onshow)
+// https://notifications.spec.whatwg.org/#dom-notification-onshow
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct NotificationShowEvent( Reference );
+
+impl IEvent for NotificationShowEvent {}
+impl ConcreteEvent for NotificationShowEvent {
+    const EVENT_TYPE: &'static str = "show";
+}
+
+/// The `NotificationClickEvent` is fired when the user clicks on a [Notification](struct.Notification.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/onclick)
+// https://notifications.spec.whatwg.org/#dom-notification-onclick
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct NotificationClickEvent( Reference );
+
+impl IEvent for NotificationClickEvent {}
+impl ConcreteEvent for NotificationClickEvent {
+    const EVENT_TYPE: &'static str = "click";
+}
+
+/// The `NotificationCloseEvent` is fired when a [Notification](struct.Notification.html) is closed.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/onclose)
+// https://notifications.spec.whatwg.org/#dom-notification-onclose
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct NotificationCloseEvent( Reference );
+
+impl IEvent for NotificationCloseEvent {}
+impl ConcreteEvent for NotificationCloseEvent {
+    const EVENT_TYPE: &'static str = "close";
+}
+
+/// The `NotificationErrorEvent` is fired when a [Notification](struct.Notification.html)
+/// could not be displayed.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/onerror)
+// https://notifications.spec.whatwg.org/#dom-notification-onerror
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct NotificationErrorEvent( Reference );
+
+impl IEvent for NotificationErrorEvent {}
+impl ConcreteEvent for NotificationErrorEvent {
+    const EVENT_TYPE: &'static str = "error";
+}
@@ -0,0 +1,89 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event::{IEvent, Event, ConcreteEvent};
+
+/// The `WorkerErrorEvent` is fired when an error occurs in a [Worker](struct.Worker.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AbstractWorker/onerror)
+// https://html.spec.whatwg.org/#handler-abstractworker-onerror
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct WorkerErrorEvent( Reference );
+
+impl WorkerErrorEvent {
+    /// Returns a human-readable message describing the error, if any.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ErrorEvent/message)
+    // https://html.spec.whatwg.org/#dom-errorevent-message
+    pub fn message( &self ) -> Option< String > {
+        js!( return @{self}.message; ).try_into().unwrap()
+    }
+
+    /// Returns the name of the script file in which the error occurred, if any.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ErrorEvent/filename)
+    // https://html.spec.whatwg.org/#dom-errorevent-filename
+    pub fn filename( &self ) -> Option< String > {
+        js!( return @{self}.filename; ).try_into().unwrap()
+    }
+
+    /// Returns the line number of the script file on which the error occurred, if any.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ErrorEvent/lineno)
+    // https://html.spec.whatwg.org/#dom-errorevent-lineno
+    pub fn lineno( &self ) -> Option< u32 > {
+        js!( return @{self}.lineno; ).try_into().unwrap()
+    }
+}
+
+impl IEvent for WorkerErrorEvent {}
+impl ConcreteEvent for WorkerErrorEvent {
+    const EVENT_TYPE: &'static str = "error";
+}
+
+/// The `UpdateFoundEvent` is fired on a [ServiceWorkerRegistration](struct.ServiceWorkerRegistration.html)
+/// when a new service worker starts installing, i.e. when its
+/// [installing](struct.ServiceWorkerRegistration.html#method.installing) worker changes.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerRegistration/onupdatefound)
+// https://w3c.github.io/ServiceWorker/#service-worker-registration-updatefound-event
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct UpdateFoundEvent( Reference );
+
+impl IEvent for UpdateFoundEvent {}
+impl ConcreteEvent for UpdateFoundEvent {
+    const EVENT_TYPE: &'static str = "updatefound";
+}
+
+/// The `StateChangeEvent` is fired on a [ServiceWorker](struct.ServiceWorker.html)
+/// whenever its [state](struct.ServiceWorker.html#method.state) changes.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorker/onstatechange)
+// https://w3c.github.io/ServiceWorker/#service-worker-statechange-event
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct StateChangeEvent( Reference );
+
+impl IEvent for StateChangeEvent {}
+impl ConcreteEvent for StateChangeEvent {
+    const EVENT_TYPE: &'static str = "statechange";
+}
+
+/// The `ControllerChangeEvent` is fired on a [ServiceWorkerContainer](struct.ServiceWorkerContainer.html)
+/// when the service worker which controls the page changes.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerContainer/oncontrollerchange)
+// https://w3c.github.io/ServiceWorker/#service-worker-container-controllerchange-event
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")] // TODO: Better type check.
+#[reference(subclass_of(Event))]
+pub struct ControllerChangeEvent( Reference );
+
+impl IEvent for ControllerChangeEvent {}
+impl ConcreteEvent for ControllerChangeEvent {
+    const EVENT_TYPE: &'static str = "controllerchange";
+}
@@ -0,0 +1,257 @@
+use webcore::value::{Reference, Value};
+use webcore::try_from::{TryFrom, TryInto};
+use webcore::promise::Promise;
+use webcore::once::Once;
+use webapi::typed_array::TypedArray;
+use webapi::error::{Error, TypeError};
+
+/// The response to a request made with [fetch](fn.fetch.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Response)
+// https://fetch.spec.whatwg.org/#response-class
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Response")]
+pub struct Response( Reference );
+
+error_enum_boilerplate! {
+    /// Errors which can occur when reading a [ReadableStreamReader](struct.ReadableStreamReader.html).
+    StreamError,
+    /// The stream has failed, e.g. because of a network error in the middle of the response.
+    TypeError,
+    /// Any other error the stream has failed with.
+    Error
+}
+
+/// Fetches the resource at the given `url`.
+///
+/// The `callback` is called as soon as the headers of the response have arrived;
+/// the body can then be read with [Response::body_reader](struct.Response.html#method.body_reader).
+/// Any HTTP status code is considered a successful response, while a network
+/// error results in a [TypeError](struct.TypeError.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WindowOrWorkerGlobalScope/fetch)
+// https://fetch.spec.whatwg.org/#fetch-method
+pub fn fetch< F: FnOnce( Result< Response, TypeError > ) + 'static >( url: &str, callback: F ) {
+    let promise: Promise = js!( return fetch( @{url} ); ).try_into().unwrap();
+    promise.done( callback );
+}
+
+impl Response {
+    /// Returns the HTTP status code of the response.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Response/status)
+    // https://fetch.spec.whatwg.org/#dom-response-status
+    pub fn status( &self ) -> u16 {
+        js!( return @{self}.status; ).try_into().unwrap()
+    }
+
+    /// Returns whenever the status code of the response is in the `200-299` range.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Response/ok)
+    // https://fetch.spec.whatwg.org/#dom-response-ok
+    pub fn ok( &self ) -> bool {
+        js!( return @{self}.ok; ).try_into().unwrap()
+    }
+
+    /// Returns the final URL of the response, after any redirects.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Response/url)
+    // https://fetch.spec.whatwg.org/#dom-response-url
+    pub fn url( &self ) -> String {
+        js!( return @{self}.url; ).try_into().unwrap()
+    }
+
+    /// Returns the value of the response header with the given `name`,
+    /// or `None` if it's missing or not exposed by the browser.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Headers/get)
+    // https://fetch.spec.whatwg.org/#dom-headers-get
+    pub fn header( &self, name: &str ) -> Option< String > {
+        js!( return @{self}.headers.get( @{name} ); ).try_into().unwrap()
+    }
+
+    /// Returns the size of the body in bytes from the `Content-Length` header, if present.
+    pub fn content_length( &self ) -> Option< u64 > {
+        self.header( "Content-Length" ).and_then( |length| length.trim().parse().ok() )
+    }
+
+    /// Locks the body of the response and returns a reader which can be used
+    /// to read it chunk by chunk as it arrives, or `None` if the response
+    /// doesn't have a body, or if it was already read or locked.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStream/getReader)
+    // https://streams.spec.whatwg.org/#rs-get-reader
+    pub fn body_reader( &self ) -> Option< ReadableStreamReader > {
+        js!(
+            var body = @{self}.body;
+            if( !body || body.locked ) {
+                return null;
+            }
+
+            return body.getReader();
+        ).try_into().unwrap()
+    }
+}
+
+/// A reader of a byte stream, like the body of a [Response](struct.Response.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStreamDefaultReader)
+// https://streams.spec.whatwg.org/#default-reader-class
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ReadableStreamDefaultReader")]
+pub struct ReadableStreamReader( Reference );
+
+impl ReadableStreamReader {
+    /// Reads the next chunk from the stream.
+    ///
+    /// The `callback` is called with the chunk once it's available,
+    /// or with `None` if the stream has ended.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStreamDefaultReader/read)
+    // https://streams.spec.whatwg.org/#default-reader-read
+    pub fn read< F: FnOnce( Result< Option< Vec< u8 > >, StreamError > ) + 'static >( &self, callback: F ) {
+        let promise: Promise = js!(
+            return @{self}.read().then( function( result ) {
+                return result.done ? null : result.value;
+            });
+        ).try_into().unwrap();
+
+        promise.done( move |result: Result< Option< TypedArray< u8 > >, StreamError >| {
+            callback( result.map( |chunk| chunk.map( |chunk| chunk.to_vec() ) ) );
+        });
+    }
+
+    /// Reads the whole stream, calling `on_chunk` with every chunk as it arrives,
+    /// and then `on_done` once the stream has ended or failed.
+    ///
+    /// # Examples
+    ///
+    /// Download a file while reporting the progress:
+    ///
+    /// ```rust
+    /// fetch( "/downloads/video.mp4", |result| {
+    ///     let response = result.unwrap();
+    ///     let total = response.content_length();
+    ///     let mut received = 0;
+    ///     response.body_reader().unwrap().for_each_chunk(
+    ///         move |chunk| {
+    ///             received += chunk.len() as u64;
+    ///             if let Some( total ) = total {
+    ///                 console!( log, format!( "{}%", received * 100 / total ) );
+    ///             }
+    ///         },
+    ///         |result| console!( log, format!( "Done: {:?}", result ) )
+    ///     );
+    /// });
+    /// ```
+    pub fn for_each_chunk< F, D >( &self, on_chunk: F, on_done: D )
+        where F: FnMut( Vec< u8 > ) + 'static,
+              D: FnOnce( Result< (), StreamError > ) + 'static
+    {
+        let mut on_chunk = on_chunk;
+        let on_chunk = move |chunk: TypedArray< u8 >| on_chunk( chunk.to_vec() );
+        let on_done = move |error: Value| {
+            let result = match error {
+                Value::Null => Ok( () ),
+                error => Err( StreamError::try_from( error ).unwrap() )
+            };
+
+            on_done( result );
+        };
+
+        js! { @(no_return)
+            var reader = @{self};
+            var on_chunk = @{on_chunk};
+            var on_done = @{Once( on_done )};
+
+            var finish = function( error ) {
+                on_chunk.drop();
+                on_done( error );
+            };
+
+            var pump = function() {
+                reader.read().then( function( result ) {
+                    if( result.done ) {
+                        finish( null );
+                        return;
+                    }
+
+                    on_chunk( result.value );
+                    pump();
+                }, finish );
+            };
+
+            pump();
+        }
+    }
+
+    /// Cancels the stream, discarding any data which wasn't read yet,
+    /// and releases the lock on it.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStreamDefaultReader/cancel)
+    // https://streams.spec.whatwg.org/#default-reader-cancel
+    pub fn cancel( &self, reason: &str ) {
+        js! { @(no_return)
+            var reader = @{self};
+            reader.cancel( @{reason} ).catch( function() {} );
+            try {
+                reader.releaseLock();
+            } catch( error ) {}
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::error::IError;
+    use webcore::async_test::async_test;
+
+    fn response( body: &str, length: &str ) -> Response {
+        js!(
+            return new Response( @{body}, { headers: { "Content-Length": @{length} } } );
+        ).try_into().unwrap()
+    }
+
+    #[test]
+    fn test_response() {
+        let response = response( "abc", "3" );
+        assert_eq!( response.status(), 200 );
+        assert!( response.ok() );
+        assert_eq!( response.content_length(), Some( 3 ) );
+        assert_eq!( response.header( "X-Missing" ), None );
+    }
+
+    #[test]
+    fn test_body_reader() {
+        let response = response( "abc", "3" );
+        let reader = response.body_reader().unwrap();
+        assert!( response.body_reader().is_none() );
+
+        reader.cancel( "no longer needed" );
+        let locked: bool = js!( return @{&response}.body.locked; ).try_into().unwrap();
+        assert!( !locked );
+
+        let empty: Response = js!( return new Response( null ); ).try_into().unwrap();
+        assert!( empty.body_reader().is_none() );
+    }
+
+    #[test]
+    fn test_stream_error_from_native_type_error() {
+        let error: StreamError = js!( return new TypeError( "network error" ); ).try_into().unwrap();
+        match error {
+            StreamError::TypeError( error ) => assert_eq!( error.message(), "network error" ),
+            error => panic!( "Unexpected error: {:?}", error )
+        }
+    }
+
+    #[test]
+    fn test_fetch_unreachable() {
+        async_test( "test_fetch_unreachable", 10000, |done| {
+            fetch( "http://__stdweb_unreachable.invalid/", move |result| {
+                assert_eq!( result.unwrap_err().name(), "TypeError" );
+                done.done();
+            });
+        });
+    }
+}
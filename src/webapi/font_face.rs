@@ -0,0 +1,240 @@
+use webcore::value::Reference;
+use webcore::try_from::{TryFrom, TryInto};
+use webcore::promise::Promise;
+use webcore::unsafe_typed_array::UnsafeTypedArray;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::dom_exception::{DomException, SyntaxError};
+
+/// The source of the data of a [FontFace](struct.FontFace.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FontSource< 'a > {
+    /// A list of URLs in the same format as the `src` descriptor
+    /// of `@font-face`, e.g. `url(font.woff2) format("woff2")`.
+    Url( &'a str ),
+    /// The raw data of a font file; it's copied, so it doesn't need
+    /// to stay around after the `FontFace` is created.
+    Bytes( &'a [u8] )
+}
+
+/// The status of a [FontFace](struct.FontFace.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/FontFace/status)
+// https://drafts.csswg.org/css-font-loading/#enumdef-fontfaceloadstatus
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FontFaceLoadStatus {
+    /// The font wasn't requested yet.
+    Unloaded,
+    /// The font is being loaded.
+    Loading,
+    /// The font was loaded and can be used.
+    Loaded,
+    /// The font has failed to load.
+    Error
+}
+
+impl FontFaceLoadStatus {
+    fn from_str( s: &str ) -> Self {
+        match s {
+            "unloaded" => FontFaceLoadStatus::Unloaded,
+            "loading" => FontFaceLoadStatus::Loading,
+            "loaded" => FontFaceLoadStatus::Loaded,
+            "error" => FontFaceLoadStatus::Error,
+            other => panic!( "Invalid font face status: {:?}", other )
+        }
+    }
+}
+
+error_enum_boilerplate! {
+    /// Errors which can occur when loading a [FontFace](struct.FontFace.html).
+    LoadError,
+    /// The source or the data of the font is invalid.
+    SyntaxError,
+    /// Any other error, e.g. a `NetworkError` when the font couldn't be downloaded.
+    DomException
+}
+
+/// A font which can be loaded and then added to a [FontFaceSet](struct.FontFaceSet.html)
+/// to be used by the document, e.g. when drawing text on a canvas.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/FontFace)
+// https://drafts.csswg.org/css-font-loading/#fontface-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "FontFace")]
+pub struct FontFace( Reference );
+
+impl FontFace {
+    /// Creates a new `FontFace` with the given `family` name.
+    ///
+    /// A font created from `Bytes` starts loading right away, while a font
+    /// created from a `Url` is only downloaded once it's [loaded](#method.load)
+    /// or used by the document.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/FontFace/FontFace)
+    // https://drafts.csswg.org/css-font-loading/#dom-fontface-fontface
+    pub fn new( family: &str, source: FontSource ) -> Result< FontFace, SyntaxError > {
+        match source {
+            FontSource::Url( url ) => js_try!(
+                return new FontFace( @{family}, @{url} );
+            ).unwrap(),
+            FontSource::Bytes( bytes ) => js_try!(
+                return new FontFace( @{family}, new Uint8Array( @{UnsafeTypedArray( bytes )} ) );
+            ).unwrap()
+        }
+    }
+
+    /// Returns the family name of the font.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/FontFace/family)
+    // https://drafts.csswg.org/css-font-loading/#dom-fontface-family
+    pub fn family( &self ) -> String {
+        js!( return @{self}.family; ).try_into().unwrap()
+    }
+
+    /// Returns the status of the font.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/FontFace/status)
+    // https://drafts.csswg.org/css-font-loading/#dom-fontface-status
+    pub fn status( &self ) -> FontFaceLoadStatus {
+        let status: String = js!( return @{self}.status; ).try_into().unwrap();
+        FontFaceLoadStatus::from_str( &status )
+    }
+
+    /// Loads the font, if it isn't loaded already, and then calls the `callback`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/FontFace/load)
+    // https://drafts.csswg.org/css-font-loading/#dom-fontface-load
+    pub fn load< F: FnOnce( Result< FontFace, LoadError > ) + 'static >( &self, callback: F ) {
+        let promise: Promise = js!( return @{self}.load(); ).try_into().unwrap();
+        promise.done( callback );
+    }
+}
+
+/// The loading status of a [FontFaceSet](struct.FontFaceSet.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/FontFaceSet/status)
+// https://drafts.csswg.org/css-font-loading/#enumdef-fontfacesetloadstatus
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FontFaceSetLoadStatus {
+    /// Some of the fonts are being loaded.
+    Loading,
+    /// None of the fonts are being loaded.
+    Loaded
+}
+
+/// The set of fonts available to a document.
+///
+/// Fonts declared with `@font-face` in the stylesheets are part of it,
+/// and other fonts can be [added](#method.add) to it.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/FontFaceSet)
+// https://drafts.csswg.org/css-font-loading/#fontfaceset
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "FontFaceSet")]
+#[reference(subclass_of(EventTarget))]
+pub struct FontFaceSet( Reference );
+
+impl IEventTarget for FontFaceSet {}
+
+impl FontFaceSet {
+    /// Adds the `font` to the set, so that it can be used by the document.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/FontFaceSet/add)
+    // https://drafts.csswg.org/css-font-loading/#dom-fontfaceset-add
+    pub fn add( &self, font: &FontFace ) {
+        js! { @(no_return)
+            @{self}.add( @{font} );
+        }
+    }
+
+    /// Removes the `font` from the set; returns whenever it was in it.
+    ///
+    /// Fonts declared with `@font-face` can't be removed.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/FontFaceSet/delete)
+    // https://drafts.csswg.org/css-font-loading/#dom-fontfaceset-delete
+    pub fn delete( &self, font: &FontFace ) -> bool {
+        js!( return @{self}.delete( @{font} ); ).try_into().unwrap()
+    }
+
+    /// Returns whenever all of the fonts needed to render the given `font`, specified
+    /// in the same format as the CSS `font` property (e.g. `"16px Roboto"`), are loaded.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/FontFaceSet/check)
+    // https://drafts.csswg.org/css-font-loading/#dom-fontfaceset-check
+    pub fn check( &self, font: &str ) -> Result< bool, SyntaxError > {
+        js_try!(
+            return @{self}.check( @{font} );
+        ).unwrap()
+    }
+
+    /// Loads all of the fonts needed to render the given `font`, specified
+    /// in the same format as the CSS `font` property, and then calls the `callback`
+    /// with the fonts which were loaded.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/FontFaceSet/load)
+    // https://drafts.csswg.org/css-font-loading/#dom-fontfaceset-load
+    pub fn load< F: FnOnce( Result< Vec< FontFace >, LoadError > ) + 'static >( &self, font: &str, callback: F ) {
+        let promise: Promise = js!(
+            var set = @{self};
+            var font = @{font};
+            return new Promise( function( resolve ) {
+                resolve( set.load( font ) );
+            });
+        ).try_into().unwrap();
+
+        promise.done( callback );
+    }
+
+    /// Calls the `callback` once the document has finished loading fonts
+    /// and laying itself out.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/FontFaceSet/ready)
+    // https://drafts.csswg.org/css-font-loading/#dom-fontfaceset-ready
+    pub fn ready< F: FnOnce() + 'static >( &self, callback: F ) {
+        let promise: Promise = js!( return @{self}.ready; ).try_into().unwrap();
+        promise.done( move |_: Result< FontFaceSet, LoadError >| callback() );
+    }
+
+    /// Returns whenever any fonts are being loaded.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/FontFaceSet/status)
+    // https://drafts.csswg.org/css-font-loading/#dom-fontfaceset-status
+    pub fn status( &self ) -> FontFaceSetLoadStatus {
+        let status: String = js!( return @{self}.status; ).try_into().unwrap();
+        match status.as_str() {
+            "loading" => FontFaceSetLoadStatus::Loading,
+            "loaded" => FontFaceSetLoadStatus::Loaded,
+            other => panic!( "Invalid font face set status: {:?}", other )
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+
+    #[test]
+    fn test_font_face() {
+        let font = FontFace::new( "Test", FontSource::Url( "url(__stdweb_no_such_font.woff2)" ) ).unwrap();
+        assert_eq!( font.family(), "Test" );
+        assert_eq!( font.status(), FontFaceLoadStatus::Unloaded );
+
+        let fonts = document().fonts();
+        fonts.add( &font );
+        assert!( fonts.delete( &font ) );
+        assert!( !fonts.delete( &font ) );
+    }
+
+    #[test]
+    fn test_invalid_bytes() {
+        let font = FontFace::new( "Test", FontSource::Bytes( &[ 1, 2, 3 ] ) ).unwrap();
+        assert_ne!( font.status(), FontFaceLoadStatus::Loaded );
+    }
+
+    #[test]
+    fn test_check() {
+        assert!( document().fonts().check( "16px sans-serif" ).unwrap() );
+        assert!( document().fonts().check( "sans-serif" ).is_err() );
+    }
+}
@@ -0,0 +1,158 @@
+use webcore::value::{Reference, Value};
+use webcore::try_from::{TryFrom, TryInto};
+use webcore::once::Once;
+use webapi::dom_exception::NotAllowedError;
+use webapi::error::Error;
+
+error_enum_boilerplate! {
+    /// Errors which can occur when entering or exiting fullscreen mode.
+    ///
+    /// A request is usually rejected when it wasn't made from within a user
+    /// gesture handler, or when the element is inside of an `<iframe>`
+    /// which lacks the `allowfullscreen` attribute.
+    FullscreenError,
+    /// The request was not allowed by the user agent.
+    NotAllowedError,
+    /// Any other error.
+    Error
+}
+
+pub(crate) const REQUEST_FULLSCREEN: &'static [&'static str] = &[
+    "requestFullscreen",
+    "webkitRequestFullscreen",
+    "mozRequestFullScreen",
+    "msRequestFullscreen"
+];
+
+pub(crate) const EXIT_FULLSCREEN: &'static [&'static str] = &[
+    "exitFullscreen",
+    "webkitExitFullscreen",
+    "mozCancelFullScreen",
+    "msExitFullscreen"
+];
+
+// Calls the first of the `methods` which is supported by the `target`; the entries in
+// `methods` have to be ordered the same way as the prefixes of the events below.
+//
+// The prefixed versions of the API don't return a promise, so in that case
+// we wait for the corresponding change or error event instead.
+pub(crate) fn call_fullscreen_method< F >( target: &Reference, methods: &[&str], callback: F )
+    where F: FnOnce( Result< (), FullscreenError > ) + 'static
+{
+    let callback = move |success: bool, error: Value| {
+        if success {
+            callback( Ok( () ) );
+        } else {
+            callback( Err( error.try_into().unwrap() ) );
+        }
+    };
+
+    js! { @(no_return)
+        var target = @{target};
+        var methods = @{methods};
+        var callback = @{Once( callback )};
+        var change_events = [ "fullscreenchange", "webkitfullscreenchange", "mozfullscreenchange", "MSFullscreenChange" ];
+        var error_events = [ "fullscreenerror", "webkitfullscreenerror", "mozfullscreenerror", "MSFullscreenError" ];
+
+        var index = -1;
+        for( var i = 0; i < methods.length; ++i ) {
+            if( typeof target[ methods[ i ] ] === "function" ) {
+                index = i;
+                break;
+            }
+        }
+
+        var on_change = function() {
+            cleanup();
+            callback( true, null );
+        };
+
+        var on_error = function() {
+            cleanup();
+            callback( false, new Error( "The fullscreen request was denied" ) );
+        };
+
+        var cleanup = function() {
+            document.removeEventListener( change_events[ index ], on_change );
+            document.removeEventListener( error_events[ index ], on_error );
+        };
+
+        if( index === -1 ) {
+            callback( false, new Error( "The Fullscreen API is not supported" ) );
+        } else {
+            var result = target[ methods[ index ] ]();
+            if( result && typeof result.then === "function" ) {
+                result.then( function() {
+                    callback( true, null );
+                }, function( error ) {
+                    callback( false, error );
+                });
+            } else {
+                document.addEventListener( change_events[ index ], on_change );
+                document.addEventListener( error_events[ index ], on_error );
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use std::cell::Cell;
+    use webcore::async_test::async_test;
+
+    // A target which only supports the prefixed, callback based version of the API
+    // and which asynchronously fires the given event on the document when called.
+    fn prefixed_target( event: &str ) -> Reference {
+        js!(
+            var event = @{event};
+            return {
+                webkitRequestFullscreen: function() {
+                    setTimeout( function() {
+                        document.dispatchEvent( new Event( event ) );
+                    }, 0 );
+                }
+            };
+        ).try_into().unwrap()
+    }
+
+    #[test]
+    fn test_prefixed_change() {
+        let target = prefixed_target( "webkitfullscreenchange" );
+        async_test( "test_prefixed_change", 1000, move |done| {
+            call_fullscreen_method( &target, REQUEST_FULLSCREEN, move |result| {
+                assert!( result.is_ok() );
+                done.done();
+            });
+        });
+    }
+
+    #[test]
+    fn test_prefixed_error() {
+        let target = prefixed_target( "webkitfullscreenerror" );
+        async_test( "test_prefixed_error", 1000, move |done| {
+            call_fullscreen_method( &target, REQUEST_FULLSCREEN, move |result| {
+                match result {
+                    Err( FullscreenError::Error( _ ) ) => {},
+                    result => panic!( "unexpected result: {:?}", result )
+                }
+                done.done();
+            });
+        });
+    }
+
+    #[test]
+    fn test_unsupported() {
+        let target: Reference = js!( return {}; ).try_into().unwrap();
+        let called = Rc::new( Cell::new( false ) );
+        call_fullscreen_method( &target, REQUEST_FULLSCREEN, {
+            let called = called.clone();
+            move |result| {
+                assert!( result.is_err() );
+                called.set( true );
+            }
+        });
+        assert!( called.get() );
+    }
+}
@@ -0,0 +1,254 @@
+use webcore::value::{Reference, Value};
+use webcore::try_from::TryInto;
+
+/// The layout of the buttons and axes of a [Gamepad](struct.Gamepad.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Gamepad/mapping)
+// https://w3c.github.io/gamepad/#dom-gamepadmappingtype
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GamepadMappingType {
+    /// The browser hasn't mapped the gamepad to any known layout.
+    Unknown,
+    /// The gamepad is mapped to the [standard gamepad layout](https://w3c.github.io/gamepad/#remapping).
+    Standard,
+    /// The gamepad is mapped to the standard layout of XR controllers.
+    XrStandard
+}
+
+/// The state of a single button of a [Gamepad](struct.Gamepad.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/GamepadButton)
+// https://w3c.github.io/gamepad/#gamepadbutton-interface
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GamepadButton {
+    /// Whether the button is pressed.
+    pub pressed: bool,
+    /// Whether the button is touched; for buttons without touch sensors this is the same as `pressed`.
+    pub touched: bool,
+    /// How far the button is pressed, from `0.0` to `1.0`; for digital buttons this is either of the two.
+    pub value: f64
+}
+
+/// A snapshot of the state of a gamepad.
+///
+/// This is a **snapshot**, i.e. it doesn't change when the state of the
+/// gamepad does. To read the current state the gamepads have to be polled
+/// again with [Navigator::get_gamepads](struct.Navigator.html#method.get_gamepads),
+/// usually once per frame.
+///
+/// # Examples
+///
+/// Printing the axes of every connected gamepad on every frame:
+///
+/// ```rust
+/// fn poll_gamepads( _: f64 ) {
+///     for gamepad in window().navigator().get_gamepads().into_iter().filter_map( |gamepad| gamepad ) {
+///         console!( log, format!( "{}: {:?}", gamepad.id(), gamepad.axes() ) );
+///     }
+///
+///     window().request_animation_frame( poll_gamepads );
+/// }
+///
+/// window().request_animation_frame( poll_gamepads );
+/// ```
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Gamepad)
+// https://w3c.github.io/gamepad/#gamepad-interface
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gamepad {
+    id: String,
+    index: u32,
+    connected: bool,
+    mapping: GamepadMappingType,
+    timestamp: f64,
+    buttons: Vec< GamepadButton >,
+    axes: Vec< f64 >
+}
+
+impl Gamepad {
+    pub(crate) fn from_reference( gamepad: &Reference ) -> Self {
+        // Everything is read at once since this is usually called every frame.
+        let value: Vec< Value > = js!(
+            var gamepad = @{gamepad};
+            var pressed = [];
+            var touched = [];
+            var values = [];
+            for( var i = 0; i < gamepad.buttons.length; ++i ) {
+                var button = gamepad.buttons[ i ];
+                pressed.push( button.pressed );
+                touched.push( button.touched === undefined ? button.pressed : button.touched );
+                values.push( button.value );
+            }
+
+            return [
+                gamepad.id,
+                gamepad.index,
+                gamepad.connected,
+                gamepad.mapping,
+                gamepad.timestamp,
+                pressed,
+                touched,
+                values,
+                Array.prototype.slice.call( gamepad.axes )
+            ];
+        ).try_into().unwrap();
+
+        let mut value = value.into_iter();
+        let mut next = move || value.next().unwrap();
+
+        let id: String = next().try_into().unwrap();
+        let index: u32 = next().try_into().unwrap();
+        let connected: bool = next().try_into().unwrap();
+        let mapping: String = next().try_into().unwrap();
+        let timestamp: f64 = next().try_into().unwrap();
+        let pressed: Vec< bool > = next().try_into().unwrap();
+        let touched: Vec< bool > = next().try_into().unwrap();
+        let values: Vec< f64 > = next().try_into().unwrap();
+        let axes: Vec< f64 > = next().try_into().unwrap();
+
+        let mapping = match mapping.as_str() {
+            "standard" => GamepadMappingType::Standard,
+            "xr-standard" => GamepadMappingType::XrStandard,
+            _ => GamepadMappingType::Unknown
+        };
+
+        let buttons = pressed.into_iter().zip( touched ).zip( values )
+            .map( |((pressed, touched), value)| GamepadButton {
+                pressed: pressed,
+                touched: touched,
+                value: value
+            })
+            .collect();
+
+        Gamepad {
+            id: id,
+            index: index,
+            connected: connected,
+            mapping: mapping,
+            timestamp: timestamp,
+            buttons: buttons,
+            axes: axes
+        }
+    }
+
+    /// Returns a string identifying the gamepad, e.g. its name and its USB vendor and product IDs.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Gamepad/id)
+    // https://w3c.github.io/gamepad/#dom-gamepad-id
+    pub fn id( &self ) -> &str {
+        &self.id
+    }
+
+    /// Returns the index of the gamepad in the array returned by
+    /// [Navigator::get_gamepads](struct.Navigator.html#method.get_gamepads).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Gamepad/index)
+    // https://w3c.github.io/gamepad/#dom-gamepad-index
+    pub fn index( &self ) -> u32 {
+        self.index
+    }
+
+    /// Returns whether the gamepad was still connected when the snapshot was taken.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Gamepad/connected)
+    // https://w3c.github.io/gamepad/#dom-gamepad-connected
+    pub fn connected( &self ) -> bool {
+        self.connected
+    }
+
+    /// Returns the layout of the buttons and axes of the gamepad.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Gamepad/mapping)
+    // https://w3c.github.io/gamepad/#dom-gamepad-mapping
+    pub fn mapping( &self ) -> GamepadMappingType {
+        self.mapping
+    }
+
+    /// Returns the time at which the state of the gamepad was last updated,
+    /// in milliseconds relative to the navigation start.
+    ///
+    /// Comparing this between two snapshots tells whether the state has changed.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Gamepad/timestamp)
+    // https://w3c.github.io/gamepad/#dom-gamepad-timestamp
+    pub fn timestamp( &self ) -> f64 {
+        self.timestamp
+    }
+
+    /// Returns the state of the buttons of the gamepad.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Gamepad/buttons)
+    // https://w3c.github.io/gamepad/#dom-gamepad-buttons
+    pub fn buttons( &self ) -> &[GamepadButton] {
+        &self.buttons
+    }
+
+    /// Returns the positions of the axes of the gamepad, from `-1.0` to `1.0`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Gamepad/axes)
+    // https://w3c.github.io/gamepad/#dom-gamepad-axes
+    pub fn axes( &self ) -> &[f64] {
+        &self.axes
+    }
+}
+
+pub(crate) fn get_gamepads( navigator: &Reference ) -> Vec< Option< Gamepad > > {
+    let gamepads: Vec< Option< Reference > > = js!(
+        var navigator = @{navigator};
+        if( typeof navigator.getGamepads !== "function" ) {
+            return [];
+        }
+
+        // Some browsers return a `GamepadList` instead of an array.
+        return Array.prototype.slice.call( navigator.getGamepads() ).map( function( gamepad ) {
+            return gamepad || null;
+        });
+    ).try_into().unwrap();
+
+    gamepads.iter()
+        .map( |gamepad| gamepad.as_ref().map( Gamepad::from_reference ) )
+        .collect()
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_reference() {
+        let gamepad: Reference = js!(
+            return {
+                id: "Test pad",
+                index: 1,
+                connected: true,
+                mapping: "standard",
+                timestamp: 12.5,
+                buttons: [
+                    { pressed: true, touched: true, value: 1 },
+                    { pressed: false, value: 0.25 }
+                ],
+                axes: [ -0.5, 0.75 ]
+            };
+        ).try_into().unwrap();
+
+        let gamepad = Gamepad::from_reference( &gamepad );
+        assert_eq!( gamepad.id(), "Test pad" );
+        assert_eq!( gamepad.index(), 1 );
+        assert!( gamepad.connected() );
+        assert_eq!( gamepad.mapping(), GamepadMappingType::Standard );
+        assert_eq!( gamepad.timestamp(), 12.5 );
+        assert_eq!( gamepad.buttons(), &[
+            GamepadButton { pressed: true, touched: true, value: 1.0 },
+            GamepadButton { pressed: false, touched: false, value: 0.25 }
+        ]);
+        assert_eq!( gamepad.axes(), &[ -0.5, 0.75 ] );
+    }
+
+    #[test]
+    fn test_get_gamepads() {
+        let navigator: Reference = js!( return navigator; ).try_into().unwrap();
+        for gamepad in get_gamepads( &navigator ).into_iter().filter_map( |gamepad| gamepad ) {
+            assert!( gamepad.connected() );
+        }
+    }
+}
@@ -76,6 +76,149 @@ pub trait IHtmlElement: IElement {
             return @{self.as_ref()}.innerText;
         ).try_into().unwrap()
     }
+
+    /// Replaces the children of the element with the given text; line breaks
+    /// are turned into `<br>` elements.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Node/innerText)
+    // https://html.spec.whatwg.org/#elements-in-the-dom:dom-innertext
+    fn set_inner_text( &self, text: &str ) {
+        js! { @(no_return)
+            @{self.as_ref()}.innerText = @{text};
+        }
+    }
+
+    /// Returns the nearest positioned ancestor of the element, which its
+    /// offsets are relative to, or `None` if the element isn't rendered or
+    /// is fixed positioned.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/offsetParent)
+    // https://drafts.csswg.org/cssom-view/#dom-htmlelement-offsetparent
+    fn offset_parent( &self ) -> Option< Element > {
+        js!(
+            return @{self.as_ref()}.offsetParent;
+        ).try_into().unwrap()
+    }
+
+    /// Returns whether the contents of the element can be edited by the user,
+    /// either because of its own `contenteditable` attribute or an inherited one.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/isContentEditable)
+    // https://html.spec.whatwg.org/#dom-iscontenteditable
+    fn is_content_editable( &self ) -> bool {
+        js!(
+            return @{self.as_ref()}.isContentEditable;
+        ).try_into().unwrap()
+    }
+
+    /// Makes the contents of the element editable by the user, or explicitly not editable
+    /// even if an ancestor is.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/contentEditable)
+    // https://html.spec.whatwg.org/#dom-contenteditable
+    fn set_content_editable( &self, editable: bool ) {
+        js! { @(no_return)
+            @{self.as_ref()}.contentEditable = @{editable} ? "true" : "false";
+        }
+    }
+
+    /// Returns whether the element has the `hidden` attribute.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/hidden)
+    // https://html.spec.whatwg.org/#dom-hidden
+    fn hidden( &self ) -> bool {
+        js!(
+            return @{self.as_ref()}.hidden === true;
+        ).try_into().unwrap()
+    }
+
+    /// Adds or removes the `hidden` attribute.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/hidden)
+    // https://html.spec.whatwg.org/#dom-hidden
+    fn set_hidden( &self, hidden: bool ) {
+        js! { @(no_return)
+            @{self.as_ref()}.hidden = @{hidden};
+        }
+    }
+
+    /// Returns the advisory information of the element, usually displayed as a tooltip.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/title)
+    // https://html.spec.whatwg.org/#dom-title
+    fn title( &self ) -> String {
+        js!(
+            return @{self.as_ref()}.title;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the advisory information of the element.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/title)
+    // https://html.spec.whatwg.org/#dom-title
+    fn set_title( &self, title: &str ) {
+        js! { @(no_return)
+            @{self.as_ref()}.title = @{title};
+        }
+    }
+
+    /// Returns the language of the element as set by its own `lang` attribute,
+    /// e.g. `"en-US"`, or an empty string if it doesn't have one.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/lang)
+    // https://html.spec.whatwg.org/#dom-lang
+    fn lang( &self ) -> String {
+        js!(
+            return @{self.as_ref()}.lang;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the language of the element.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/lang)
+    // https://html.spec.whatwg.org/#dom-lang
+    fn set_lang( &self, lang: &str ) {
+        js! { @(no_return)
+            @{self.as_ref()}.lang = @{lang};
+        }
+    }
+
+    /// Returns the text direction of the element as set by its own `dir` attribute,
+    /// that is `"ltr"`, `"rtl"`, `"auto"`, or an empty string if it doesn't have one.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/dir)
+    // https://html.spec.whatwg.org/#dom-dir
+    fn dir( &self ) -> String {
+        js!(
+            return @{self.as_ref()}.dir;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the text direction of the element; values other than
+    /// `"ltr"`, `"rtl"` and `"auto"` are ignored by the browser.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/dir)
+    // https://html.spec.whatwg.org/#dom-dir
+    fn set_dir( &self, dir: &str ) {
+        js! { @(no_return)
+            @{self.as_ref()}.dir = @{dir};
+        }
+    }
+
+    /// Simulates a mouse click on the element, which fires a `click` event and
+    /// triggers the element's activation behavior, e.g. opening the file picker
+    /// of an `<input type="file">` or following a link.
+    ///
+    /// Browsers only open file pickers and popups when this is called while
+    /// handling a user interaction, e.g. inside of a click handler.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/click)
+    // https://html.spec.whatwg.org/#dom-click
+    fn click( &self ) {
+        js! { @(no_return)
+            @{self.as_ref()}.click();
+        }
+    }
 }
 
 /// A reference to a JavaScript object which implements the [IHtmlElement](trait.IHtmlElement.html)
@@ -117,4 +260,71 @@ mod tests {
         element.append_child(&text("foo"));
         assert_eq!(element.inner_text(), "foo foo");
     }
+
+    #[test]
+    fn test_set_inner_text() {
+        // A detached element isn't rendered, so `innerText`
+        // returns the same as `textContent` here.
+        let element: HtmlElement = div().try_into().unwrap();
+        element.set_inner_text("foo\nbar");
+        assert_eq!(js!( return @{&element}.childNodes.length; ), 3);
+        assert_eq!(element.inner_text(), "foobar");
+    }
+
+    #[test]
+    fn test_offset_parent() {
+        let element: HtmlElement = div().try_into().unwrap();
+        assert_eq!(element.offset_parent(), None);
+
+        js!( @(no_return) document.body.appendChild( @{&element} ); );
+        let body: Element = js!( return document.body; ).try_into().unwrap();
+        assert_eq!(element.offset_parent(), Some(body));
+        js!( @(no_return) document.body.removeChild( @{&element} ); );
+    }
+
+    #[test]
+    fn test_content_editable() {
+        let parent: HtmlElement = div().try_into().unwrap();
+        let child: HtmlElement = div().try_into().unwrap();
+        parent.append_child(&child);
+        js!( @(no_return) document.body.appendChild( @{&parent} ); );
+
+        assert!(!child.is_content_editable());
+        parent.set_content_editable(true);
+        assert!(child.is_content_editable());
+        child.set_content_editable(false);
+        assert!(!child.is_content_editable());
+
+        js!( @(no_return) document.body.removeChild( @{&parent} ); );
+    }
+
+    #[test]
+    fn test_attributes() {
+        let element: HtmlElement = div().try_into().unwrap();
+        assert!(!element.hidden());
+        element.set_hidden(true);
+        assert!(element.hidden());
+        assert!(element.get_attribute("hidden").is_some());
+
+        element.set_title("tooltip");
+        element.set_lang("de");
+        element.set_dir("rtl");
+        assert_eq!(element.title(), "tooltip");
+        assert_eq!(element.lang(), "de");
+        assert_eq!(element.dir(), "rtl");
+
+        element.set_dir("sideways");
+        assert_eq!(element.dir(), "");
+    }
+
+    #[test]
+    fn test_click() {
+        let element: HtmlElement = div().try_into().unwrap();
+        js!( @(no_return)
+            @{&element}.addEventListener( "click", function( event ) { event.target.textContent = "clicked"; } );
+        );
+
+        element.click();
+        assert_eq!(element.inner_text(), "clicked");
+    }
 }
@@ -7,6 +7,7 @@ use webapi::element::{IElement, Element};
 use webapi::html_element::{IHtmlElement, HtmlElement};
 use webapi::blob::Blob;
 use webapi::rendering_context::RenderingContext;
+use webapi::resize_observer::{ResizeObserver, ResizeObserverHandle};
 use private::TODO;
 
 /// The HTML `<canvas>` element provides an empty graphic zone on which specific JavaScript APIs
@@ -104,4 +105,169 @@ impl CanvasElement {
 
         Ok(())
     }
+
+    /// Resizes the backing store of the canvas, i.e. its [width](#method.width) and
+    /// [height](#method.height), to the size at which the canvas is displayed.
+    ///
+    /// If `dpr_aware` is `true` the displayed size is multiplied by the device pixel ratio,
+    /// so that the canvas is drawn at the full resolution of high density displays instead
+    /// of being upscaled and blurry; drawing code then has to scale its coordinates by
+    /// `window.devicePixelRatio` as well.
+    ///
+    /// Since resizing the backing store clears the canvas, it's only resized when its size
+    /// actually differs; the return value tells whenever it was resized, and so whenever
+    /// the canvas has to be redrawn.
+    ///
+    /// The displayed size is the size of the content box of the canvas, so it has to be set
+    /// with CSS, e.g. `width: 100%; height: 100%`. Otherwise the canvas is displayed at the
+    /// size of its backing store, and with a device pixel ratio above 1 it would grow every
+    /// time it's fitted.
+    ///
+    /// # Examples
+    ///
+    /// A render loop which only repaints when the size of the canvas changed:
+    ///
+    /// ```rust
+    /// fn render_loop( canvas: CanvasElement ) {
+    ///     window().request_animation_frame( move |_| {
+    ///         if canvas.fit_to_display( true ) {
+    ///             let context: CanvasRenderingContext2d = canvas.get_context().unwrap();
+    ///             draw_scene( &context, canvas.width(), canvas.height() );
+    ///         }
+    ///
+    ///         render_loop( canvas );
+    ///     });
+    /// }
+    /// ```
+    pub fn fit_to_display( &self, dpr_aware: bool ) -> bool {
+        let size: Vec< u32 > = js!(
+            var style = getComputedStyle( @{self} );
+            var ratio = @{dpr_aware} ? (window.devicePixelRatio || 1) : 1;
+            return [ Math.round( parseFloat( style.width ) * ratio ), Math.round( parseFloat( style.height ) * ratio ) ];
+        ).try_into().unwrap();
+
+        let (width, height) = (size[ 0 ], size[ 1 ]);
+        if self.width() == width && self.height() == height {
+            return false;
+        }
+
+        self.set_width( width );
+        self.set_height( height );
+        true
+    }
+
+    /// Keeps the backing store of the canvas fitted to its displayed size,
+    /// taking the device pixel ratio into account, as [fit_to_display](#method.fit_to_display)
+    /// does; the `callback` is called with the new width and height every time the
+    /// canvas was resized and has to be redrawn, including once right after this is called.
+    ///
+    /// The canvas stops being fitted once the returned handle is dropped.
+    ///
+    /// Just as with `fit_to_display` the size of the canvas has to be set with CSS.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserver)
+    pub fn auto_fit< F >( &self, mut callback: F ) -> CanvasFitHandle
+        where F: FnMut( u32, u32 ) + 'static
+    {
+        let canvas = self.clone();
+        let observer = ResizeObserver::new( move |_, _| {
+            if canvas.fit_to_display( true ) {
+                callback( canvas.width(), canvas.height() );
+            }
+        });
+
+        let element: Element = self.clone().into();
+        observer.observe( &element );
+        CanvasFitHandle {
+            observer: observer
+        }
+    }
+}
+
+/// A handle to a canvas which is kept fitted to its displayed size, as
+/// returned by [CanvasElement::auto_fit](struct.CanvasElement.html#method.auto_fit).
+///
+/// The canvas stops being fitted once the handle is dropped.
+#[derive(Debug)]
+pub struct CanvasFitHandle {
+    observer: ResizeObserverHandle
+}
+
+impl CanvasFitHandle {
+    /// Stops fitting the canvas; this is the same as dropping the handle.
+    #[inline]
+    pub fn stop( self ) {
+        self.observer.disconnect();
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use std::cell::RefCell;
+    use webcore::async_test::async_test;
+    use webapi::global::set_timeout;
+
+    fn displayed_canvas( css: &str ) -> CanvasElement {
+        js!(
+            var canvas = document.createElement( "canvas" );
+            canvas.style.cssText = @{css};
+            document.body.appendChild( canvas );
+            return canvas;
+        ).try_into().unwrap()
+    }
+
+    #[test]
+    fn test_fit_to_display() {
+        let canvas = displayed_canvas( "width: 40px; height: 30px" );
+        assert_eq!( (canvas.width(), canvas.height()), (300, 150) );
+
+        assert!( canvas.fit_to_display( false ) );
+        assert_eq!( (canvas.width(), canvas.height()), (40, 30) );
+        assert!( !canvas.fit_to_display( false ) );
+
+        let ratio: f64 = js!( return window.devicePixelRatio || 1; ).try_into().unwrap();
+        assert_eq!( canvas.fit_to_display( true ), ratio != 1.0 );
+        assert_eq!( canvas.width(), (40.0 * ratio).round() as u32 );
+
+        js!( @(no_return) document.body.removeChild( @{canvas} ); );
+    }
+
+    #[test]
+    fn test_auto_fit() {
+        let canvas = displayed_canvas( "width: 20px; height: 10px; padding: 5px; border: 1px solid" );
+        let ratio: f64 = js!( return window.devicePixelRatio || 1; ).try_into().unwrap();
+        let scaled = move |size: f64| (size * ratio).round() as u32;
+
+        async_test( "test_auto_fit", 5000, move |done| {
+            let sizes = Rc::new( RefCell::new( Vec::new() ) );
+            let handle = Rc::new( RefCell::new( None ) );
+            let done = RefCell::new( Some( done ) );
+            *handle.borrow_mut() = Some( canvas.auto_fit({
+                let canvas = canvas.clone();
+                let handle = handle.clone();
+                move |width, height| {
+                    sizes.borrow_mut().push( (width, height) );
+                    assert_eq!( (canvas.width(), canvas.height()), (width, height) );
+                    if sizes.borrow().len() == 1 {
+                        js!( @(no_return) @{&canvas}.style.width = "30px"; );
+                        return;
+                    }
+
+                    // The padding and the border aren't a part of the displayed size.
+                    assert_eq!( *sizes.borrow(), vec![ (scaled( 20.0 ), scaled( 10.0 )), (scaled( 30.0 ), scaled( 10.0 )) ] );
+                    done.borrow_mut().take().unwrap().done();
+
+                    // The observer can't be disconnected from within its own callback.
+                    let handle = handle.borrow_mut().take().unwrap();
+                    let canvas = canvas.clone();
+                    set_timeout( move || {
+                        handle.stop();
+                        js!( @(no_return) document.body.removeChild( @{canvas} ); );
+                    }, 0 );
+                }
+            }));
+        });
+    }
 }
@@ -0,0 +1,485 @@
+use webcore::value::Reference;
+use webcore::try_from::{TryFrom, TryInto};
+use webcore::promise::Promise;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::node::{INode, Node};
+use webapi::element::{IElement, Element};
+use webapi::html_element::{IHtmlElement, HtmlElement};
+use webapi::dom_exception::{IndexSizeError, NotAllowedError, NotSupportedError};
+use webapi::error::Error;
+use webapi::media_stream::MediaStream;
+
+error_enum_boilerplate! {
+    /// Errors which can occur when starting the playback of a media element.
+    ///
+    /// Most browsers have an autoplay policy which will reject the playback
+    /// with a [NotAllowedError](enum.PlaybackError.html#variant.NotAllowedError)
+    /// unless it was started from within a user gesture handler or the media is muted.
+    PlaybackError,
+    /// The playback was not allowed by the user agent.
+    NotAllowedError,
+    /// The media source is not supported.
+    NotSupportedError,
+    /// Any other error, e.g. the playback was interrupted by a call to `pause()`.
+    Error
+}
+
+/// Describes how much of the media is available for playback.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/readyState)
+// https://html.spec.whatwg.org/#ready-states
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MediaReadyState {
+    /// No information is available about the media.
+    HaveNothing,
+    /// The metadata has been loaded, e.g. the duration and the dimensions.
+    HaveMetadata,
+    /// Data is available for the current playback position, but not for the next frame.
+    HaveCurrentData,
+    /// Data is available for the current playback position and at least a little bit beyond it.
+    HaveFutureData,
+    /// Enough data is available to play the media through to the end without interruption.
+    HaveEnoughData
+}
+
+/// The reason why loading or decoding of a media resource has failed.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaError/code)
+// https://html.spec.whatwg.org/#dom-mediaerror-code
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MediaErrorCode {
+    /// The fetching of the resource was aborted by the user.
+    Aborted,
+    /// A network error prevented the resource from being fetched.
+    Network,
+    /// An error occurred while decoding the resource.
+    Decode,
+    /// The resource or its format is not supported.
+    SrcNotSupported
+}
+
+/// The `MediaError` interface represents an error which occurred while
+/// handling a media element.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaError)
+// https://html.spec.whatwg.org/#mediaerror
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "MediaError")]
+pub struct MediaError( Reference );
+
+impl MediaError {
+    /// Returns the kind of the error.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaError/code)
+    // https://html.spec.whatwg.org/#dom-mediaerror-code
+    pub fn code( &self ) -> MediaErrorCode {
+        let code: u32 = js!( return @{self}.code; ).try_into().unwrap();
+        match code {
+            1 => MediaErrorCode::Aborted,
+            2 => MediaErrorCode::Network,
+            3 => MediaErrorCode::Decode,
+            4 => MediaErrorCode::SrcNotSupported,
+            _ => unreachable!( "Unexpected MediaError code: {}", code )
+        }
+    }
+
+    /// Returns a human readable message with details about the error;
+    /// this is empty if the browser doesn't provide any details.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaError/message)
+    // https://html.spec.whatwg.org/#dom-mediaerror-message
+    pub fn message( &self ) -> String {
+        js!( return @{self}.message || ""; ).try_into().unwrap()
+    }
+}
+
+/// The `IHtmlMediaElement` interface adds the properties and methods
+/// needed to support audio and video playback to [IHtmlElement](trait.IHtmlElement.html).
+///
+/// # Examples
+///
+/// A scrubber which seeks a video according to the position of an `<input type="range">`:
+///
+/// ```rust
+/// let video: VideoElement = document().query_selector( "video" ).unwrap().unwrap().try_into().unwrap();
+/// let scrubber: InputElement = document().query_selector( "#scrubber" ).unwrap().unwrap().try_into().unwrap();
+///
+/// video.add_event_listener( enclose!( (video, scrubber) move |_: TimeUpdateEvent| {
+///     if let Some( duration ) = video.duration() {
+///         scrubber.set_raw_value( &( video.current_time() / duration * 100.0 ).to_string() );
+///     }
+/// }));
+///
+/// scrubber.add_event_listener( enclose!( (video, scrubber) move |_: InputEvent| {
+///     if let Some( duration ) = video.duration() {
+///         let percent: f64 = scrubber.raw_value().parse().unwrap_or( 0.0 );
+///         video.set_current_time( percent / 100.0 * duration );
+///     }
+/// }));
+/// ```
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement)
+// https://html.spec.whatwg.org/#htmlmediaelement
+pub trait IHtmlMediaElement: IHtmlElement {
+    /// Starts the playback of the media.
+    ///
+    /// The `callback` is called once the playback has started, or with an error
+    /// if the playback was rejected, e.g. due to the browser's autoplay policy.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/play)
+    // https://html.spec.whatwg.org/#dom-media-play
+    fn play< F: FnOnce( Result< (), PlaybackError > ) + 'static >( &self, callback: F ) {
+        let promise: Promise = js!(
+            // Older browsers don't return a promise here.
+            var result = @{self.as_ref()}.play();
+            if( result && typeof result.then === "function" ) {
+                return result;
+            }
+            return Promise.resolve();
+        ).try_into().unwrap();
+
+        promise.done( callback );
+    }
+
+    /// Pauses the playback of the media.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/pause)
+    // https://html.spec.whatwg.org/#dom-media-pause
+    fn pause( &self ) {
+        js! { @(no_return)
+            @{self.as_ref()}.pause();
+        }
+    }
+
+    /// Returns the URL of the media resource.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/src)
+    // https://html.spec.whatwg.org/#dom-media-src
+    fn src( &self ) -> String {
+        js!( return @{self.as_ref()}.src; ).try_into().unwrap()
+    }
+
+    /// Sets the URL of the media resource.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/src)
+    // https://html.spec.whatwg.org/#dom-media-src
+    fn set_src( &self, value: &str ) {
+        js! { @(no_return)
+            @{self.as_ref()}.src = @{value};
+        }
+    }
+
+    /// Returns the [MediaStream](struct.MediaStream.html) which is being played back, if any.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/srcObject)
+    // https://html.spec.whatwg.org/#dom-media-srcobject
+    fn src_object( &self ) -> Option< MediaStream > {
+        js!( return @{self.as_ref()}.srcObject; ).try_into().ok()
+    }
+
+    /// Sets the [MediaStream](struct.MediaStream.html) which should be played back,
+    /// e.g. one from a webcam; `None` detaches the current stream.
+    ///
+    /// Detaching a stream doesn't stop it; to release e.g. the webcam
+    /// every track of the stream has to be stopped.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/srcObject)
+    // https://html.spec.whatwg.org/#dom-media-srcobject
+    fn set_src_object( &self, stream: Option< &MediaStream > ) {
+        js! { @(no_return)
+            @{self.as_ref()}.srcObject = @{stream};
+        }
+    }
+
+    /// Returns the current playback position in seconds.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/currentTime)
+    // https://html.spec.whatwg.org/#dom-media-currenttime
+    fn current_time( &self ) -> f64 {
+        js!( return @{self.as_ref()}.currentTime; ).try_into().unwrap()
+    }
+
+    /// Seeks the media to the given playback position in seconds.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/currentTime)
+    // https://html.spec.whatwg.org/#dom-media-currenttime
+    fn set_current_time( &self, value: f64 ) {
+        js! { @(no_return)
+            @{self.as_ref()}.currentTime = @{value};
+        }
+    }
+
+    /// Returns the length of the media in seconds, or `None` if the metadata
+    /// of the media is not available yet.
+    ///
+    /// For media without a known end, e.g. a live stream, this returns infinity.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/duration)
+    // https://html.spec.whatwg.org/#dom-media-duration
+    fn duration( &self ) -> Option< f64 > {
+        let duration: f64 = js!( return @{self.as_ref()}.duration; ).try_into().unwrap();
+        if duration.is_nan() {
+            None
+        } else {
+            Some( duration )
+        }
+    }
+
+    /// Returns the volume of the media, from `0.0` (silent) to `1.0` (loudest).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/volume)
+    // https://html.spec.whatwg.org/#dom-media-volume
+    fn volume( &self ) -> f64 {
+        js!( return @{self.as_ref()}.volume; ).try_into().unwrap()
+    }
+
+    /// Sets the volume of the media, from `0.0` (silent) to `1.0` (loudest).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [IndexSizeError](struct.IndexSizeError.html) if the `value`
+    /// is outside of the `0.0` to `1.0` range.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/volume)
+    // https://html.spec.whatwg.org/#dom-media-volume
+    fn set_volume( &self, value: f64 ) -> Result< (), IndexSizeError > {
+        js_try!( @(no_return)
+            @{self.as_ref()}.volume = @{value};
+        ).unwrap()
+    }
+
+    /// Returns whether the media is muted.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/muted)
+    // https://html.spec.whatwg.org/#dom-media-muted
+    fn muted( &self ) -> bool {
+        js!( return @{self.as_ref()}.muted; ).try_into().unwrap()
+    }
+
+    /// Mutes or unmutes the media.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/muted)
+    // https://html.spec.whatwg.org/#dom-media-muted
+    fn set_muted( &self, value: bool ) {
+        js! { @(no_return)
+            @{self.as_ref()}.muted = @{value};
+        }
+    }
+
+    /// Returns the rate at which the media is played back, where `1.0` is the normal speed.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/playbackRate)
+    // https://html.spec.whatwg.org/#dom-media-playbackrate
+    fn playback_rate( &self ) -> f64 {
+        js!( return @{self.as_ref()}.playbackRate; ).try_into().unwrap()
+    }
+
+    /// Sets the rate at which the media is played back, where `1.0` is the normal speed.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/playbackRate)
+    // https://html.spec.whatwg.org/#dom-media-playbackrate
+    fn set_playback_rate( &self, value: f64 ) {
+        js! { @(no_return)
+            @{self.as_ref()}.playbackRate = @{value};
+        }
+    }
+
+    /// Returns whether the media is paused.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/paused)
+    // https://html.spec.whatwg.org/#dom-media-paused
+    fn paused( &self ) -> bool {
+        js!( return @{self.as_ref()}.paused; ).try_into().unwrap()
+    }
+
+    /// Returns whether the media has finished playing.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/ended)
+    // https://html.spec.whatwg.org/#dom-media-ended
+    fn ended( &self ) -> bool {
+        js!( return @{self.as_ref()}.ended; ).try_into().unwrap()
+    }
+
+    /// Returns how much of the media is available for playback.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/readyState)
+    // https://html.spec.whatwg.org/#dom-media-readystate
+    fn ready_state( &self ) -> MediaReadyState {
+        let ready_state: u32 = js!( return @{self.as_ref()}.readyState; ).try_into().unwrap();
+        match ready_state {
+            0 => MediaReadyState::HaveNothing,
+            1 => MediaReadyState::HaveMetadata,
+            2 => MediaReadyState::HaveCurrentData,
+            3 => MediaReadyState::HaveFutureData,
+            4 => MediaReadyState::HaveEnoughData,
+            _ => unreachable!( "Unexpected readyState: {}", ready_state )
+        }
+    }
+
+    /// Returns the time ranges of the media which have been buffered,
+    /// as a list of `(start, end)` pairs in seconds.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/buffered)
+    // https://html.spec.whatwg.org/#dom-media-buffered
+    fn buffered( &self ) -> Vec< (f64, f64) > {
+        let ranges: Vec< f64 > = js!(
+            var buffered = @{self.as_ref()}.buffered;
+            var ranges = [];
+            for( var i = 0; i < buffered.length; ++i ) {
+                ranges.push( buffered.start( i ), buffered.end( i ) );
+            }
+            return ranges;
+        ).try_into().unwrap();
+
+        ranges.chunks( 2 ).map( |range| (range[ 0 ], range[ 1 ]) ).collect()
+    }
+
+    /// Returns the most recent error which occurred while loading or decoding the media, if any.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/error)
+    // https://html.spec.whatwg.org/#dom-media-error
+    fn error( &self ) -> Option< MediaError > {
+        js!( return @{self.as_ref()}.error; ).try_into().unwrap()
+    }
+}
+
+/// A reference to a JavaScript object which implements the [IHtmlMediaElement](trait.IHtmlMediaElement.html)
+/// interface.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement)
+// https://html.spec.whatwg.org/#htmlmediaelement
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "HTMLMediaElement")]
+#[reference(subclass_of(EventTarget, Node, Element, HtmlElement))]
+pub struct HtmlMediaElement( Reference );
+
+impl IEventTarget for HtmlMediaElement {}
+impl INode for HtmlMediaElement {}
+impl IElement for HtmlMediaElement {}
+impl IHtmlElement for HtmlMediaElement {}
+impl IHtmlMediaElement for HtmlMediaElement {}
+
+/// The HTML `<audio>` element is used to embed sound content in documents.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLAudioElement)
+// https://html.spec.whatwg.org/#htmlaudioelement
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "HTMLAudioElement")]
+#[reference(subclass_of(EventTarget, Node, Element, HtmlElement, HtmlMediaElement))]
+pub struct AudioElement( Reference );
+
+impl IEventTarget for AudioElement {}
+impl INode for AudioElement {}
+impl IElement for AudioElement {}
+impl IHtmlElement for AudioElement {}
+impl IHtmlMediaElement for AudioElement {}
+
+impl AudioElement {
+    /// Constructs a new AudioElement.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLAudioElement/Audio)
+    // https://html.spec.whatwg.org/#dom-audio
+    pub fn new() -> AudioElement {
+        js!(
+            return new Audio();
+        ).try_into().unwrap()
+    }
+
+    /// Constructs a new AudioElement which loads the media from the given `src`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLAudioElement/Audio)
+    // https://html.spec.whatwg.org/#dom-audio
+    pub fn with_src( src: &str ) -> AudioElement {
+        js!(
+            return new Audio( @{src} );
+        ).try_into().unwrap()
+    }
+}
+
+/// The HTML `<video>` element is used to embed video content in documents.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLVideoElement)
+// https://html.spec.whatwg.org/#htmlvideoelement
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "HTMLVideoElement")]
+#[reference(subclass_of(EventTarget, Node, Element, HtmlElement, HtmlMediaElement))]
+pub struct VideoElement( Reference );
+
+impl IEventTarget for VideoElement {}
+impl INode for VideoElement {}
+impl IElement for VideoElement {}
+impl IHtmlElement for VideoElement {}
+impl IHtmlMediaElement for VideoElement {}
+
+impl VideoElement {
+    /// Returns the intrinsic width of the video in pixels, or `0` if the
+    /// metadata of the video is not available yet.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLVideoElement)
+    // https://html.spec.whatwg.org/#dom-video-videowidth
+    pub fn video_width( &self ) -> u32 {
+        js!( return @{self}.videoWidth; ).try_into().unwrap()
+    }
+
+    /// Returns the intrinsic height of the video in pixels, or `0` if the
+    /// metadata of the video is not available yet.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLVideoElement)
+    // https://html.spec.whatwg.org/#dom-video-videoheight
+    pub fn video_height( &self ) -> u32 {
+        js!( return @{self}.videoHeight; ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+
+    fn video() -> VideoElement {
+        document().create_element( "video" ).unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        let audio = AudioElement::new();
+        assert!( audio.paused() );
+        assert!( !audio.ended() );
+        assert_eq!( audio.ready_state(), MediaReadyState::HaveNothing );
+        assert_eq!( audio.duration(), None );
+        assert_eq!( audio.buffered(), vec![] );
+        assert!( audio.error().is_none() );
+    }
+
+    #[test]
+    fn test_volume() {
+        let audio = AudioElement::new();
+        assert_eq!( audio.volume(), 1.0 );
+        audio.set_volume( 0.5 ).unwrap();
+        assert_eq!( audio.volume(), 0.5 );
+        assert!( audio.set_volume( 1.5 ).is_err() );
+        assert_eq!( audio.volume(), 0.5 );
+    }
+
+    #[test]
+    fn test_muted_and_playback_rate() {
+        let audio = AudioElement::new();
+        assert!( !audio.muted() );
+        audio.set_muted( true );
+        assert!( audio.muted() );
+
+        audio.set_playback_rate( 2.0 );
+        assert_eq!( audio.playback_rate(), 2.0 );
+    }
+
+    #[test]
+    fn test_video() {
+        let video = video();
+        assert_eq!( video.video_width(), 0 );
+        assert_eq!( video.video_height(), 0 );
+        assert_eq!( video.current_time(), 0.0 );
+
+        let media: HtmlMediaElement = video.into();
+        assert!( media.paused() );
+    }
+}
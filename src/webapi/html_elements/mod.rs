@@ -2,8 +2,10 @@ mod image;
 mod input;
 mod textarea;
 mod canvas;
+mod media;
 
 pub use self::image::ImageElement;
 pub use self::input::InputElement;
 pub use self::textarea::TextAreaElement;
-pub use self::canvas::CanvasElement;
+pub use self::canvas::{CanvasElement, CanvasFitHandle};
+pub use self::media::{IHtmlMediaElement, HtmlMediaElement, AudioElement, VideoElement, MediaError, MediaErrorCode, MediaReadyState, PlaybackError};
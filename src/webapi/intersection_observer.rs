@@ -0,0 +1,331 @@
+use std;
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::element::Element;
+use webapi::rect::Rect;
+use webapi::dom_exception::SyntaxError;
+
+/// Provides a way to asynchronously observe changes in the intersection of
+/// target elements with an ancestor element or with the viewport.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver)
+// https://w3c.github.io/IntersectionObserver/#intersection-observer-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "IntersectionObserver")]
+pub struct IntersectionObserver( Reference );
+
+/// Specifies the root and the thresholds of an [`IntersectionObserver`](struct.IntersectionObserver.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver/IntersectionObserver#Parameters)
+// https://w3c.github.io/IntersectionObserver/#dictdef-intersectionobserverinit
+#[derive(Clone, Debug)]
+pub struct IntersectionObserverInit< 'a > {
+    /// The element which is used as the viewport for checking the visibility of the targets.
+    ///
+    /// If `None` the browser's viewport will be used.
+    pub root: Option< Element >,
+
+    /// The margin around the root, specified like the CSS `margin` property, e.g. `"10px 20px"`.
+    ///
+    /// Only pixels and percentages are allowed.
+    pub root_margin: &'a str,
+
+    /// The ratios of the target's visibility at which the callback should be called,
+    /// each between `0.0` and `1.0`.
+    pub thresholds: &'a [f64]
+}
+
+impl< 'a > Default for IntersectionObserverInit< 'a > {
+    fn default() -> Self {
+        IntersectionObserverInit {
+            root: None,
+            root_margin: "0px",
+            thresholds: &[ 0.0 ]
+        }
+    }
+}
+
+impl IntersectionObserver {
+    /// Returns a new [`IntersectionObserverHandle`](struct.IntersectionObserverHandle.html) with the given callback.
+    ///
+    /// The callback will be called with the following arguments when the visibility
+    /// of any of the observed elements crosses one of the thresholds:
+    ///
+    /// 1. A vector of entries describing the changes.
+    ///
+    /// 2. The `IntersectionObserver`.
+    ///
+    /// The callback is also called once for each element shortly after it starts
+    /// being [observed](#method.observe), even if it isn't visible.
+    ///
+    /// This will fail if the [`root_margin`](struct.IntersectionObserverInit.html#structfield.root_margin)
+    /// can't be parsed.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver/IntersectionObserver)
+    // https://w3c.github.io/IntersectionObserver/#dom-intersectionobserver-intersectionobserver
+    pub fn new< F >( callback: F, options: &IntersectionObserverInit ) -> Result< IntersectionObserverHandle, SyntaxError >
+        where F: FnMut( Vec< IntersectionObserverEntry >, Self ) + 'static {
+        let callback_reference: Reference = js! ( return @{callback}; ).try_into().unwrap();
+
+        let intersection_observer: Result< IntersectionObserver, SyntaxError > = js_try! (
+            return new IntersectionObserver( @{&callback_reference}, {
+                root: @{&options.root},
+                rootMargin: @{options.root_margin},
+                threshold: @{options.thresholds}
+            });
+        ).unwrap();
+
+        match intersection_observer {
+            Ok( intersection_observer ) => Ok( IntersectionObserverHandle {
+                intersection_observer: intersection_observer,
+                callback_reference: callback_reference
+            }),
+            Err( error ) => {
+                js! { @(no_return)
+                    @{&callback_reference}.drop();
+                }
+
+                Err( error )
+            }
+        }
+    }
+
+    /// Returns the margin around the root, as normalized by the browser.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver/rootMargin)
+    // https://w3c.github.io/IntersectionObserver/#dom-intersectionobserver-rootmargin
+    pub fn root_margin( &self ) -> String {
+        js!( return @{self}.rootMargin; ).try_into().unwrap()
+    }
+
+    /// Returns the thresholds of this observer, sorted in increasing order.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver/thresholds)
+    // https://w3c.github.io/IntersectionObserver/#dom-intersectionobserver-thresholds
+    pub fn thresholds( &self ) -> Vec< f64 > {
+        js!( return @{self}.thresholds; ).try_into().unwrap()
+    }
+
+    /// Starts observing the `target`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver/observe)
+    // https://w3c.github.io/IntersectionObserver/#dom-intersectionobserver-observe
+    pub fn observe( &self, target: &Element ) {
+        js! { @(no_return)
+            @{self}.observe( @{target} );
+        }
+    }
+
+    /// Stops observing the `target`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver/unobserve)
+    // https://w3c.github.io/IntersectionObserver/#dom-intersectionobserver-unobserve
+    pub fn unobserve( &self, target: &Element ) {
+        js! { @(no_return)
+            @{self}.unobserve( @{target} );
+        }
+    }
+
+    /// Stops observing all targets.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver/disconnect)
+    // https://w3c.github.io/IntersectionObserver/#dom-intersectionobserver-disconnect
+    pub fn disconnect( &self ) {
+        js! { @(no_return)
+            @{self}.disconnect();
+        }
+    }
+
+    /// Empties the `IntersectionObserver`'s queue of pending entries and returns what was in there.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver/takeRecords)
+    // https://w3c.github.io/IntersectionObserver/#dom-intersectionobserver-takerecords
+    pub fn take_records( &self ) -> Vec< IntersectionObserverEntry > {
+        js!( return @{self}.takeRecords(); ).try_into().unwrap()
+    }
+}
+
+/// A wrapper which ensures that memory is properly cleaned up when it's no longer needed.
+///
+/// This is created by the [`IntersectionObserver::new`](struct.IntersectionObserver.html#method.new) method, and
+/// it can use the same methods as [`IntersectionObserver`](struct.IntersectionObserver.html).
+///
+/// When the `IntersectionObserverHandle` is dropped, the [`disconnect`](struct.IntersectionObserver.html#method.disconnect)
+/// method will automatically be called.
+#[derive(Debug)]
+pub struct IntersectionObserverHandle {
+    intersection_observer: IntersectionObserver,
+    callback_reference: Reference
+}
+
+impl std::ops::Deref for IntersectionObserverHandle {
+    type Target = IntersectionObserver;
+
+    #[inline]
+    fn deref( &self ) -> &Self::Target {
+        &self.intersection_observer
+    }
+}
+
+impl Drop for IntersectionObserverHandle {
+    #[inline]
+    fn drop( &mut self ) {
+        self.disconnect();
+
+        js! { @(no_return)
+            @{&self.callback_reference}.drop();
+        }
+    }
+}
+
+/// Describes the intersection between a target element and the root
+/// of an [`IntersectionObserver`](struct.IntersectionObserver.html) at a given moment.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserverEntry)
+// https://w3c.github.io/IntersectionObserver/#intersection-observer-entry
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "IntersectionObserverEntry")]
+pub struct IntersectionObserverEntry( Reference );
+
+impl IntersectionObserverEntry {
+    /// Returns the element whose intersection with the root changed.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserverEntry/target)
+    // https://w3c.github.io/IntersectionObserver/#dom-intersectionobserverentry-target
+    pub fn target( &self ) -> Element {
+        js!( return @{self}.target; ).try_into().unwrap()
+    }
+
+    /// Returns whenever the target intersects with the root.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserverEntry/isIntersecting)
+    // https://w3c.github.io/IntersectionObserver/#dom-intersectionobserverentry-isintersecting
+    pub fn is_intersecting( &self ) -> bool {
+        js!( return @{self}.isIntersecting; ).try_into().unwrap()
+    }
+
+    /// Returns how much of the target is visible, between `0.0` and `1.0`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserverEntry/intersectionRatio)
+    // https://w3c.github.io/IntersectionObserver/#dom-intersectionobserverentry-intersectionratio
+    pub fn intersection_ratio( &self ) -> f64 {
+        js!( return @{self}.intersectionRatio; ).try_into().unwrap()
+    }
+
+    /// Returns the bounding rectangle of the target.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserverEntry/boundingClientRect)
+    // https://w3c.github.io/IntersectionObserver/#dom-intersectionobserverentry-boundingclientrect
+    pub fn bounding_client_rect( &self ) -> Rect {
+        js!( return @{self}.boundingClientRect; ).try_into().unwrap()
+    }
+
+    /// Returns the visible area of the target.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserverEntry/intersectionRect)
+    // https://w3c.github.io/IntersectionObserver/#dom-intersectionobserverentry-intersectionrect
+    pub fn intersection_rect( &self ) -> Rect {
+        js!( return @{self}.intersectionRect; ).try_into().unwrap()
+    }
+
+    /// Returns the rectangle of the root, or `None` if the target
+    /// isn't in the same origin as the root.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserverEntry/rootBounds)
+    // https://w3c.github.io/IntersectionObserver/#dom-intersectionobserverentry-rootbounds
+    pub fn root_bounds( &self ) -> Option< Rect > {
+        js!( return @{self}.rootBounds; ).try_into().unwrap()
+    }
+
+    /// Returns the time in milliseconds at which the intersection was recorded,
+    /// relative to the time origin of the document.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserverEntry/time)
+    // https://w3c.github.io/IntersectionObserver/#dom-intersectionobserverentry-time
+    pub fn time( &self ) -> f64 {
+        js!( return @{self}.time; ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use std::cell::RefCell;
+    use webcore::async_test::async_test;
+    use webapi::global::set_timeout;
+
+    fn positioned_element( top: i32 ) -> Element {
+        js!(
+            var element = document.createElement( "div" );
+            element.style.cssText = "position: absolute; left: 0px; width: 10px; height: 10px; top: " + @{top} + "px";
+            document.body.appendChild( element );
+            return element;
+        ).try_into().unwrap()
+    }
+
+    #[test]
+    fn test_initial_callback() {
+        let visible = positioned_element( 0 );
+        let hidden = positioned_element( -1000 );
+
+        async_test( "test_initial_callback", 5000, move |done| {
+            let entries = Rc::new( RefCell::new( Vec::new() ) );
+            let handle = Rc::new( RefCell::new( None ) );
+            let done = RefCell::new( Some( done ) );
+            let observer = IntersectionObserver::new({
+                let handle = handle.clone();
+                let visible = visible.clone();
+                let hidden = hidden.clone();
+                move |new_entries, _| {
+                    entries.borrow_mut().extend( new_entries );
+                    if entries.borrow().len() < 2 {
+                        return;
+                    }
+
+                    // The callback is called right away for every observed element, visible or not.
+                    let states: Vec< (bool, f64) > = [ &visible, &hidden ].iter().map( |&element| {
+                        let entry = entries.borrow().iter().find( |entry| entry.target() == *element ).cloned().unwrap();
+                        (entry.is_intersecting(), entry.intersection_ratio())
+                    }).collect();
+
+                    assert_eq!( states, vec![ (true, 1.0), (false, 0.0) ] );
+                    done.borrow_mut().take().unwrap().done();
+
+                    // The observer can't be disconnected from within its own callback.
+                    let handle = handle.borrow_mut().take();
+                    let (visible, hidden) = (visible.clone(), hidden.clone());
+                    set_timeout( move || {
+                        drop( handle );
+                        js!( @(no_return) document.body.removeChild( @{visible} ); document.body.removeChild( @{hidden} ); );
+                    }, 0 );
+                }
+            }, &IntersectionObserverInit::default() ).unwrap();
+
+            observer.observe( &visible );
+            observer.observe( &hidden );
+            *handle.borrow_mut() = Some( observer );
+        });
+    }
+
+    #[test]
+    fn test_thresholds() {
+        let observer = IntersectionObserver::new( |_, _| {}, &IntersectionObserverInit {
+            thresholds: &[ 1.0, 0.0, 0.5 ],
+            .. IntersectionObserverInit::default()
+        }).unwrap();
+
+        assert_eq!( observer.thresholds(), vec![ 0.0, 0.5, 1.0 ] );
+        assert!( observer.take_records().is_empty() );
+    }
+
+    #[test]
+    fn test_invalid_root_margin() {
+        let result = IntersectionObserver::new( |_, _| {}, &IntersectionObserverInit {
+            root_margin: "10em",
+            .. IntersectionObserverInit::default()
+        });
+
+        assert!( result.is_err() );
+    }
+}
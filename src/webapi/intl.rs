@@ -0,0 +1,521 @@
+use webcore::value::Reference;
+use webcore::try_from::{TryFrom, TryInto};
+use webapi::error::{RangeError, TypeError};
+
+/// The formatting style of a [NumberFormat](struct.NumberFormat.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/NumberFormat/NumberFormat#Parameters)
+// https://tc39.github.io/ecma402/#sec-initializenumberformat
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NumberFormatStyle {
+    /// Plain numbers, e.g. `1,234.5`.
+    Decimal,
+    /// Amounts of money in the given [currency](struct.NumberFormatOptions.html#structfield.currency),
+    /// e.g. `$1,234.50`.
+    Currency,
+    /// Percentages; the formatted numbers are multiplied by 100, e.g. `0.25` becomes `25%`.
+    Percent
+}
+
+impl NumberFormatStyle {
+    fn as_str( &self ) -> &'static str {
+        match *self {
+            NumberFormatStyle::Decimal => "decimal",
+            NumberFormatStyle::Currency => "currency",
+            NumberFormatStyle::Percent => "percent"
+        }
+    }
+
+    fn from_str( s: &str ) -> Self {
+        match s {
+            "currency" => NumberFormatStyle::Currency,
+            "percent" => NumberFormatStyle::Percent,
+            _ => NumberFormatStyle::Decimal
+        }
+    }
+}
+
+/// Options used when constructing a new [NumberFormat](struct.NumberFormat.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/NumberFormat/NumberFormat#Parameters)
+// https://tc39.github.io/ecma402/#sec-initializenumberformat
+#[derive(Clone, Debug)]
+pub struct NumberFormatOptions< 'a > {
+    /// The formatting style.
+    pub style: NumberFormatStyle,
+
+    /// The ISO 4217 code of the currency, e.g. `"EUR"`; required for the
+    /// [Currency](enum.NumberFormatStyle.html#variant.Currency) style.
+    pub currency: Option< &'a str >,
+
+    /// The minimum number of fraction digits; the default depends on the style and the currency.
+    pub minimum_fraction_digits: Option< u32 >,
+
+    /// The maximum number of fraction digits; the default depends on the style and the currency.
+    pub maximum_fraction_digits: Option< u32 >,
+
+    /// Whether to use grouping separators, e.g. thousands separators.
+    pub use_grouping: bool
+}
+
+impl< 'a > Default for NumberFormatOptions< 'a > {
+    fn default() -> Self {
+        NumberFormatOptions {
+            style: NumberFormatStyle::Decimal,
+            currency: None,
+            minimum_fraction_digits: None,
+            maximum_fraction_digits: None,
+            use_grouping: true
+        }
+    }
+}
+
+/// The options actually used by a [NumberFormat](struct.NumberFormat.html),
+/// as returned by [resolved_options](struct.NumberFormat.html#method.resolved_options).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolvedNumberFormatOptions {
+    /// The locale which was picked out of the requested ones, e.g. `"de-DE"`.
+    pub locale: String,
+    /// The numbering system, e.g. `"latn"`.
+    pub numbering_system: String,
+    /// The formatting style.
+    pub style: NumberFormatStyle,
+    /// The currency, if the style is [Currency](enum.NumberFormatStyle.html#variant.Currency).
+    pub currency: Option< String >,
+    /// The minimum number of fraction digits.
+    pub minimum_fraction_digits: u32,
+    /// The maximum number of fraction digits.
+    pub maximum_fraction_digits: u32,
+    /// Whether grouping separators are used.
+    pub use_grouping: bool
+}
+
+error_enum_boilerplate! {
+    /// Errors which can occur when creating a [NumberFormat](struct.NumberFormat.html).
+    NumberFormatError,
+    /// A locale isn't a well-formed language tag, the currency isn't a well-formed
+    /// currency code, or a number of fraction digits is out of range.
+    RangeError,
+    /// The style is [Currency](enum.NumberFormatStyle.html#variant.Currency) but no currency was given.
+    TypeError
+}
+
+/// The `NumberFormat` object formats numbers according to the conventions of a locale.
+///
+/// # Examples
+///
+/// ```rust
+/// let format = intl::NumberFormat::new( &[ "de-DE" ], &intl::NumberFormatOptions {
+///     style: intl::NumberFormatStyle::Currency,
+///     currency: Some( "EUR" ),
+///     ..Default::default()
+/// }).unwrap();
+///
+/// assert_eq!( format.format( 1234567.89 ), "1.234.567,89\u{a0}€" );
+/// ```
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/NumberFormat)
+// https://tc39.github.io/ecma402/#numberformat-objects
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Intl.NumberFormat")]
+pub struct NumberFormat( Reference );
+
+impl NumberFormat {
+    /// Creates a new `NumberFormat` for the first of the given `locales` which is
+    /// supported by the browser, or for the default locale of the browser if none is
+    /// or if `locales` is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `RangeError` if a locale isn't a well-formed language tag, if the
+    /// currency isn't a well-formed currency code, or if a number of fraction digits
+    /// is out of range; returns a `TypeError` if the style is
+    /// [Currency](enum.NumberFormatStyle.html#variant.Currency) but no currency was given.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/NumberFormat/NumberFormat)
+    // https://tc39.github.io/ecma402/#sec-intl-numberformat-constructor
+    pub fn new( locales: &[&str], options: &NumberFormatOptions ) -> Result< Self, NumberFormatError > {
+        js_try!(
+            var options = {
+                style: @{options.style.as_str()},
+                useGrouping: @{options.use_grouping}
+            };
+
+            var currency = @{options.currency};
+            var minimum_fraction_digits = @{options.minimum_fraction_digits};
+            var maximum_fraction_digits = @{options.maximum_fraction_digits};
+            if( currency !== null ) { options.currency = currency; }
+            if( minimum_fraction_digits !== null ) { options.minimumFractionDigits = minimum_fraction_digits; }
+            if( maximum_fraction_digits !== null ) { options.maximumFractionDigits = maximum_fraction_digits; }
+
+            return new Intl.NumberFormat( @{locales}, options );
+        ).unwrap()
+    }
+
+    /// Formats the given number.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/NumberFormat/format)
+    // https://tc39.github.io/ecma402/#sec-intl.numberformat.prototype.format
+    pub fn format( &self, value: f64 ) -> String {
+        js!(
+            return @{self}.format( @{value} );
+        ).try_into().unwrap()
+    }
+
+    /// Returns the options which are actually used, including the picked locale.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/NumberFormat/resolvedOptions)
+    // https://tc39.github.io/ecma402/#sec-intl.numberformat.prototype.resolvedoptions
+    pub fn resolved_options( &self ) -> ResolvedNumberFormatOptions {
+        let options = js!(
+            return @{self}.resolvedOptions();
+        );
+
+        let style: String = js!( return @{&options}.style; ).try_into().unwrap();
+        ResolvedNumberFormatOptions {
+            locale: js!( return @{&options}.locale; ).try_into().unwrap(),
+            numbering_system: js!( return @{&options}.numberingSystem; ).try_into().unwrap(),
+            style: NumberFormatStyle::from_str( &style ),
+            currency: js!( return @{&options}.currency; ).try_into().unwrap(),
+            minimum_fraction_digits: js!( return @{&options}.minimumFractionDigits; ).try_into().unwrap(),
+            maximum_fraction_digits: js!( return @{&options}.maximumFractionDigits; ).try_into().unwrap(),
+            // Newer browsers report the kind of grouping, e.g. `"auto"`, instead of `true`.
+            use_grouping: js!( return !!@{&options}.useGrouping; ).try_into().unwrap()
+        }
+    }
+}
+
+/// The length of the date or the time formatted by a [DateTimeFormat](struct.DateTimeFormat.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat/DateTimeFormat#Parameters)
+// https://tc39.github.io/proposal-intl-datetime-style/
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DateTimeStyle {
+    /// E.g. `Thursday, January 1, 1970`.
+    Full,
+    /// E.g. `January 1, 1970`.
+    Long,
+    /// E.g. `Jan 1, 1970`.
+    Medium,
+    /// E.g. `1/1/70`.
+    Short
+}
+
+impl DateTimeStyle {
+    fn as_str( &self ) -> &'static str {
+        match *self {
+            DateTimeStyle::Full => "full",
+            DateTimeStyle::Long => "long",
+            DateTimeStyle::Medium => "medium",
+            DateTimeStyle::Short => "short"
+        }
+    }
+}
+
+/// Options used when constructing a new [DateTimeFormat](struct.DateTimeFormat.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat/DateTimeFormat#Parameters)
+// https://tc39.github.io/ecma402/#sec-initializedatetimeformat
+#[derive(Clone, Debug, Default)]
+pub struct DateTimeFormatOptions< 'a > {
+    /// How to format the date, or `None` to leave it out; if both the date
+    /// and the time style are `None` only the date is formatted.
+    pub date_style: Option< DateTimeStyle >,
+
+    /// How to format the time, or `None` to leave it out.
+    pub time_style: Option< DateTimeStyle >,
+
+    /// The IANA time zone to use, e.g. `"Europe/Berlin"` or `"UTC"`,
+    /// or `None` for the time zone of the user.
+    pub time_zone: Option< &'a str >,
+
+    /// Whether to use a 12-hour clock, or `None` for the default of the locale.
+    pub hour12: Option< bool >
+}
+
+/// The options actually used by a [DateTimeFormat](struct.DateTimeFormat.html),
+/// as returned by [resolved_options](struct.DateTimeFormat.html#method.resolved_options).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolvedDateTimeFormatOptions {
+    /// The locale which was picked out of the requested ones, e.g. `"en-US"`.
+    pub locale: String,
+    /// The calendar, e.g. `"gregory"`.
+    pub calendar: String,
+    /// The numbering system, e.g. `"latn"`.
+    pub numbering_system: String,
+    /// The time zone, e.g. `"UTC"`.
+    pub time_zone: String,
+    /// Whether a 12-hour clock is used, or `None` if no time is formatted.
+    pub hour12: Option< bool >
+}
+
+/// The kind of a part of a formatted date, as returned by
+/// [DateTimeFormat::format_to_parts](struct.DateTimeFormat.html#method.format_to_parts).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat/formatToParts#Description)
+// https://tc39.github.io/ecma402/#sec-formatdatetimetoparts
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DateTimePartKind {
+    /// The day of the month, e.g. `1`.
+    Day,
+    /// The day period, e.g. `AM`.
+    DayPeriod,
+    /// The era, e.g. `AD`.
+    Era,
+    /// The fractional seconds.
+    FractionalSecond,
+    /// The hour.
+    Hour,
+    /// Separators like `/`, `, ` or `:`.
+    Literal,
+    /// The minute.
+    Minute,
+    /// The month, e.g. `1` or `January`.
+    Month,
+    /// The second.
+    Second,
+    /// The name of the time zone, e.g. `UTC`.
+    TimeZoneName,
+    /// The day of the week, e.g. `Thursday`.
+    Weekday,
+    /// The year.
+    Year,
+    /// Any other kind of part, with its name.
+    Other( String )
+}
+
+impl DateTimePartKind {
+    fn from_str( s: &str ) -> Self {
+        match s {
+            "day" => DateTimePartKind::Day,
+            // Older browsers used `dayperiod`.
+            "dayPeriod" | "dayperiod" => DateTimePartKind::DayPeriod,
+            "era" => DateTimePartKind::Era,
+            "fractionalSecond" => DateTimePartKind::FractionalSecond,
+            "hour" => DateTimePartKind::Hour,
+            "literal" => DateTimePartKind::Literal,
+            "minute" => DateTimePartKind::Minute,
+            "month" => DateTimePartKind::Month,
+            "second" => DateTimePartKind::Second,
+            "timeZoneName" => DateTimePartKind::TimeZoneName,
+            "weekday" => DateTimePartKind::Weekday,
+            "year" => DateTimePartKind::Year,
+            other => DateTimePartKind::Other( other.to_owned() )
+        }
+    }
+}
+
+/// A part of a formatted date, as returned by
+/// [DateTimeFormat::format_to_parts](struct.DateTimeFormat.html#method.format_to_parts).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DateTimePart {
+    /// What this part represents.
+    pub kind: DateTimePartKind,
+    /// The formatted text of this part.
+    pub value: String
+}
+
+/// The `DateTimeFormat` object formats dates and times according to the conventions of a locale.
+///
+/// # Examples
+///
+/// ```rust
+/// let format = intl::DateTimeFormat::new( &[ "en-US" ], &intl::DateTimeFormatOptions {
+///     date_style: Some( intl::DateTimeStyle::Medium ),
+///     time_zone: Some( "UTC" ),
+///     ..Default::default()
+/// }).unwrap();
+///
+/// assert_eq!( format.format( 0.0 ), "Jan 1, 1970" );
+/// ```
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat)
+// https://tc39.github.io/ecma402/#datetimeformat-objects
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Intl.DateTimeFormat")]
+pub struct DateTimeFormat( Reference );
+
+impl DateTimeFormat {
+    /// Creates a new `DateTimeFormat` for the first of the given `locales` which is
+    /// supported by the browser, or for the default locale of the browser if none is
+    /// or if `locales` is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `RangeError` if a locale isn't a well-formed language tag,
+    /// or if the time zone isn't supported.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat/DateTimeFormat)
+    // https://tc39.github.io/ecma402/#sec-intl-datetimeformat-constructor
+    pub fn new( locales: &[&str], options: &DateTimeFormatOptions ) -> Result< Self, RangeError > {
+        js_try!(
+            var options = {};
+            var date_style = @{options.date_style.map( |style| style.as_str() )};
+            var time_style = @{options.time_style.map( |style| style.as_str() )};
+            var time_zone = @{options.time_zone};
+            var hour12 = @{options.hour12};
+            if( date_style !== null ) { options.dateStyle = date_style; }
+            if( time_style !== null ) { options.timeStyle = time_style; }
+            if( time_zone !== null ) { options.timeZone = time_zone; }
+            if( hour12 !== null ) { options.hour12 = hour12; }
+
+            return new Intl.DateTimeFormat( @{locales}, options );
+        ).unwrap()
+    }
+
+    /// Formats the date at the given number of milliseconds since the Unix epoch.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat/format)
+    // https://tc39.github.io/ecma402/#sec-intl.datetimeformat.prototype.format
+    pub fn format( &self, epoch_ms: f64 ) -> String {
+        js!(
+            return @{self}.format( @{epoch_ms} );
+        ).try_into().unwrap()
+    }
+
+    /// Formats the date at the given number of milliseconds since the Unix epoch
+    /// into its parts, which can be used to render them individually.
+    ///
+    /// Concatenating the values of the parts gives the same result as [format](#method.format).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat/formatToParts)
+    // https://tc39.github.io/ecma402/#sec-Intl.DateTimeFormat.prototype.formatToParts
+    pub fn format_to_parts( &self, epoch_ms: f64 ) -> Vec< DateTimePart > {
+        let parts = js!(
+            return @{self}.formatToParts( @{epoch_ms} );
+        );
+
+        let kinds: Vec< String > = js!( return @{&parts}.map( function( part ) { return part.type; } ); ).try_into().unwrap();
+        let values: Vec< String > = js!( return @{&parts}.map( function( part ) { return part.value; } ); ).try_into().unwrap();
+        kinds.into_iter().zip( values ).map( |(kind, value)| DateTimePart {
+            kind: DateTimePartKind::from_str( &kind ),
+            value: value
+        }).collect()
+    }
+
+    /// Returns the options which are actually used, including the picked locale.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat/resolvedOptions)
+    // https://tc39.github.io/ecma402/#sec-intl.datetimeformat.prototype.resolvedoptions
+    pub fn resolved_options( &self ) -> ResolvedDateTimeFormatOptions {
+        let options = js!(
+            return @{self}.resolvedOptions();
+        );
+
+        ResolvedDateTimeFormatOptions {
+            locale: js!( return @{&options}.locale; ).try_into().unwrap(),
+            calendar: js!( return @{&options}.calendar; ).try_into().unwrap(),
+            numbering_system: js!( return @{&options}.numberingSystem; ).try_into().unwrap(),
+            time_zone: js!( return @{&options}.timeZone; ).try_into().unwrap(),
+            hour12: js!( return @{&options}.hour12; ).try_into().unwrap()
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::error::IError;
+
+    #[test]
+    fn test_number_format_currency() {
+        let format = NumberFormat::new( &[ "de-DE" ], &NumberFormatOptions {
+            style: NumberFormatStyle::Currency,
+            currency: Some( "EUR" ),
+            ..NumberFormatOptions::default()
+        }).unwrap();
+
+        assert_eq!( format.format( 1234567.89 ), "1.234.567,89\u{a0}€" );
+
+        let options = format.resolved_options();
+        assert_eq!( options.locale, "de-DE" );
+        assert_eq!( options.style, NumberFormatStyle::Currency );
+        assert_eq!( options.currency, Some( "EUR".to_owned() ) );
+        assert_eq!( options.maximum_fraction_digits, 2 );
+        assert!( options.use_grouping );
+    }
+
+    #[test]
+    fn test_number_format_options() {
+        let format = NumberFormat::new( &[ "en-US" ], &NumberFormatOptions {
+            maximum_fraction_digits: Some( 1 ),
+            use_grouping: false,
+            ..NumberFormatOptions::default()
+        }).unwrap();
+        assert_eq!( format.format( 1234.56 ), "1234.6" );
+
+        let format = NumberFormat::new( &[ "en-US" ], &NumberFormatOptions {
+            style: NumberFormatStyle::Percent,
+            ..NumberFormatOptions::default()
+        }).unwrap();
+        assert_eq!( format.format( 0.25 ), "25%" );
+    }
+
+    #[test]
+    fn test_number_format_errors() {
+        let error = NumberFormat::new( &[ "en-US" ], &NumberFormatOptions {
+            style: NumberFormatStyle::Currency,
+            currency: Some( "EURO" ),
+            ..NumberFormatOptions::default()
+        }).unwrap_err();
+        match error {
+            NumberFormatError::RangeError( _ ) => {},
+            error => panic!( "Unexpected error: {:?}", error )
+        }
+
+        let error = NumberFormat::new( &[ "en-US" ], &NumberFormatOptions {
+            style: NumberFormatStyle::Currency,
+            ..NumberFormatOptions::default()
+        }).unwrap_err();
+        match error {
+            NumberFormatError::TypeError( _ ) => {},
+            error => panic!( "Unexpected error: {:?}", error )
+        }
+
+        assert!( NumberFormat::new( &[ "not a locale!" ], &NumberFormatOptions::default() ).is_err() );
+    }
+
+    #[test]
+    fn test_date_time_format() {
+        let format = DateTimeFormat::new( &[ "en-US" ], &DateTimeFormatOptions {
+            date_style: Some( DateTimeStyle::Short ),
+            time_zone: Some( "UTC" ),
+            ..DateTimeFormatOptions::default()
+        }).unwrap();
+
+        assert_eq!( format.format( 0.0 ), "1/1/70" );
+        assert_eq!( format.format_to_parts( 0.0 ), vec![
+            DateTimePart { kind: DateTimePartKind::Month, value: "1".to_owned() },
+            DateTimePart { kind: DateTimePartKind::Literal, value: "/".to_owned() },
+            DateTimePart { kind: DateTimePartKind::Day, value: "1".to_owned() },
+            DateTimePart { kind: DateTimePartKind::Literal, value: "/".to_owned() },
+            DateTimePart { kind: DateTimePartKind::Year, value: "70".to_owned() }
+        ]);
+
+        let options = format.resolved_options();
+        assert_eq!( options.locale, "en-US" );
+        assert_eq!( options.time_zone, "UTC" );
+        assert_eq!( options.hour12, None );
+    }
+
+    #[test]
+    fn test_date_time_format_time_zone() {
+        let format = DateTimeFormat::new( &[ "en-US" ], &DateTimeFormatOptions {
+            time_style: Some( DateTimeStyle::Short ),
+            time_zone: Some( "Asia/Tokyo" ),
+            hour12: Some( true ),
+            ..DateTimeFormatOptions::default()
+        }).unwrap();
+
+        let parts = format.format_to_parts( 0.0 );
+        assert!( parts.contains( &DateTimePart { kind: DateTimePartKind::Hour, value: "9".to_owned() } ) );
+        assert!( parts.iter().any( |part| part.kind == DateTimePartKind::DayPeriod ) );
+        assert_eq!( format.resolved_options().hour12, Some( true ) );
+
+        let error = DateTimeFormat::new( &[], &DateTimeFormatOptions {
+            time_zone: Some( "Mars/Olympus_Mons" ),
+            ..DateTimeFormatOptions::default()
+        }).unwrap_err();
+        assert_eq!( error.name(), "RangeError" );
+    }
+}
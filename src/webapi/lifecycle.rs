@@ -0,0 +1,253 @@
+use std::fmt;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use webapi::document::document;
+use webapi::element::Element;
+use webapi::node::INode;
+use webapi::mutation_observer::{MutationObserver, MutationObserverHandle, MutationObserverInit, MutationRecord};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Transition {
+    Attached,
+    Detached
+}
+
+struct LifecycleEntry {
+    element: Element,
+    transition: Transition,
+    callback: Box< dyn FnOnce() >
+}
+
+#[derive(Default)]
+struct Registry {
+    observer: Option< MutationObserverHandle >,
+    observing: bool,
+    next_id: u64,
+    entries: HashMap< u64, LifecycleEntry >
+}
+
+thread_local! {
+    static REGISTRY: RefCell< Registry > = RefCell::new( Registry::default() );
+}
+
+fn register( element: &Element, transition: Transition, callback: Box< dyn FnOnce() > ) -> LifecycleHandle {
+    REGISTRY.with( |registry| {
+        let mut registry = registry.borrow_mut();
+        if !registry.observing {
+            if registry.observer.is_none() {
+                registry.observer = Some( MutationObserver::new( |records, _| process_records( records ) ) );
+            }
+
+            registry.observer.as_ref().unwrap().observe( &document(), MutationObserverInit {
+                child_list: true,
+                attributes: false,
+                character_data: false,
+                subtree: true,
+                attribute_old_value: false,
+                character_data_old_value: false,
+                attribute_filter: None
+            }).unwrap();
+            registry.observing = true;
+        }
+
+        let id = registry.next_id;
+        registry.next_id += 1;
+        registry.entries.insert( id, LifecycleEntry {
+            element: element.clone(),
+            transition: transition,
+            callback: callback
+        });
+
+        LifecycleHandle( id )
+    })
+}
+
+fn unregister( id: u64 ) -> Option< LifecycleEntry > {
+    REGISTRY.with( |registry| {
+        let mut registry = registry.borrow_mut();
+        let entry = registry.entries.remove( &id );
+        if registry.entries.is_empty() && registry.observing {
+            // The observer itself is kept around, since this might be running inside of its callback.
+            registry.observer.as_ref().unwrap().disconnect();
+            registry.observing = false;
+        }
+
+        entry
+    })
+}
+
+// Only the state after the whole batch of mutations is looked at,
+// so an element which was moved within a single batch is never
+// reported as detached.
+fn process_records( records: Vec< MutationRecord > ) {
+    let added = records.iter().any( |record| !record.added_nodes().is_empty() );
+    let removed = records.iter().any( |record| !record.removed_nodes().is_empty() );
+    if !added && !removed {
+        return;
+    }
+
+    let document = document();
+    let fired: Vec< u64 > = REGISTRY.with( |registry| {
+        registry.borrow().entries.iter()
+            .filter( |&(_, entry)| match entry.transition {
+                Transition::Attached => added && document.contains( &entry.element ),
+                Transition::Detached => removed && !document.contains( &entry.element )
+            })
+            .map( |(&id, _)| id )
+            .collect()
+    });
+
+    // The callbacks are called outside of the borrow since they
+    // might register or unregister other elements.
+    for id in fired {
+        if let Some( entry ) = unregister( id ) {
+            (entry.callback)();
+        }
+    }
+}
+
+/// Calls `on_detached` once the `element` is removed from the document,
+/// either directly or together with one of its ancestors.
+///
+/// This is meant for tearing down state attached to elements which are
+/// managed by some other code, e.g. event listeners which would otherwise leak.
+///
+/// All of the registered elements share a single `MutationObserver` on the
+/// `document`, so registering many of them is cheap. Since mutations are delivered
+/// in batches an element which is removed and then inserted again within
+/// the same batch (that is, moved) doesn't count as being detached.
+///
+/// The `element` should currently be in the document. Dropping the returned
+/// handle unregisters the callback without calling it.
+///
+/// # Examples
+///
+/// ```rust
+/// let handle = observe_lifecycle( &element, move || drop( listener ) );
+/// ```
+// https://dom.spec.whatwg.org/#interface-mutationobserver
+pub fn observe_lifecycle< F >( element: &Element, on_detached: F ) -> LifecycleHandle
+    where F: FnOnce() + 'static {
+    register( element, Transition::Detached, Box::new( on_detached ) )
+}
+
+/// Calls `on_attached` once the `element`, which currently isn't in the document,
+/// is inserted into it, either directly or together with one of its ancestors.
+///
+/// This is the counterpart of [observe_lifecycle](fn.observe_lifecycle.html)
+/// and shares the same `MutationObserver`. Dropping the returned handle
+/// unregisters the callback without calling it.
+// https://dom.spec.whatwg.org/#interface-mutationobserver
+pub fn observe_attached< F >( element: &Element, on_attached: F ) -> LifecycleHandle
+    where F: FnOnce() + 'static {
+    register( element, Transition::Attached, Box::new( on_attached ) )
+}
+
+/// A handle returned by [observe_lifecycle](fn.observe_lifecycle.html)
+/// and [observe_attached](fn.observe_attached.html).
+///
+/// When it's dropped the callback is unregistered without being called.
+pub struct LifecycleHandle( u64 );
+
+impl fmt::Debug for LifecycleHandle {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        write!( formatter, "LifecycleHandle" )
+    }
+}
+
+impl Drop for LifecycleHandle {
+    fn drop( &mut self ) {
+        unregister( self.0 );
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use std::cell::Cell;
+
+    fn flush() {
+        let records = REGISTRY.with( |registry| registry.borrow().observer.as_ref().unwrap().take_records() );
+        process_records( records );
+    }
+
+    fn nested() -> ( Element, Element ) {
+        let root = document().create_element( "div" ).unwrap();
+        let leaf = document().create_element( "span" ).unwrap();
+        let mut parent = root.clone();
+        for _ in 0..3 {
+            let child = document().create_element( "div" ).unwrap();
+            parent.append_child( &child );
+            parent = child;
+        }
+
+        parent.append_child( &leaf );
+        ( root, leaf )
+    }
+
+    #[test]
+    fn test_detached_with_ancestor() {
+        let ( root, leaf ) = nested();
+        js!( @(no_return) document.body.appendChild( @{&root} ); );
+
+        let calls = Rc::new( Cell::new( 0 ) );
+        let handle = observe_lifecycle( &leaf, {
+            let calls = calls.clone();
+            move || calls.set( calls.get() + 1 )
+        });
+
+        js!( @(no_return) @{&root}.remove(); );
+        flush();
+        assert_eq!( calls.get(), 1 );
+
+        js!( @(no_return) document.body.appendChild( @{&root} ); @{&root}.remove(); );
+        flush();
+        assert_eq!( calls.get(), 1 );
+        drop( handle );
+    }
+
+    #[test]
+    fn test_moved_is_not_detached() {
+        let ( root, leaf ) = nested();
+        js!( @(no_return) document.body.appendChild( @{&root} ); );
+
+        let calls = Rc::new( Cell::new( 0 ) );
+        let _handle = observe_lifecycle( &leaf, {
+            let calls = calls.clone();
+            move || calls.set( calls.get() + 1 )
+        });
+
+        js!( @(no_return) @{&root}.remove(); document.body.appendChild( @{&root} ); );
+        flush();
+        assert_eq!( calls.get(), 0 );
+
+        js!( @(no_return) @{&root}.remove(); );
+    }
+
+    #[test]
+    fn test_attached_and_dropped_handle() {
+        let ( root, leaf ) = nested();
+
+        let attached = Rc::new( Cell::new( false ) );
+        let _handle = observe_attached( &leaf, {
+            let attached = attached.clone();
+            move || attached.set( true )
+        });
+
+        let detached = Rc::new( Cell::new( false ) );
+        let handle = observe_lifecycle( &leaf, {
+            let detached = detached.clone();
+            move || detached.set( true )
+        });
+        drop( handle );
+
+        js!( @(no_return) document.body.appendChild( @{&root} ); );
+        flush();
+        assert!( attached.get() );
+
+        js!( @(no_return) @{&root}.remove(); );
+        flush();
+        assert!( !detached.get() );
+    }
+}
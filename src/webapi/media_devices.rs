@@ -0,0 +1,281 @@
+use webcore::value::{Value, Reference};
+use webcore::try_from::{TryFrom, TryInto};
+use webcore::promise::Promise;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::dom_exception::{NotAllowedError, NotFoundError};
+use webapi::error::{IError, Error};
+use webapi::media_stream::MediaStream;
+
+/// Occurs when none of the available devices can satisfy the constraints
+/// passed to [MediaDevices::get_user_media](struct.MediaDevices.html#method.get_user_media).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/OverconstrainedError)
+// https://w3c.github.io/mediacapture-main/#overconstrainederror-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(subclass_of(Error))]
+pub struct OverconstrainedError( Reference );
+
+impl IError for OverconstrainedError {}
+
+// Older browsers reject with a plain object named `OverconstrainedError`
+// instead of a `DOMException`, so we only check the name here.
+impl ::InstanceOf for OverconstrainedError {
+    #[inline]
+    fn instance_of( reference: &Reference ) -> bool {
+        js!(
+            return @{reference}.name === "OverconstrainedError";
+        ) == true
+    }
+}
+
+error_boilerplate! { OverconstrainedError }
+
+impl OverconstrainedError {
+    /// Returns the name of the constraint which couldn't be satisfied, e.g. `"width"`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/OverconstrainedError/constraint)
+    // https://w3c.github.io/mediacapture-main/#dom-overconstrainederror-constraint
+    pub fn constraint( &self ) -> String {
+        js!( return @{self}.constraint || ""; ).try_into().unwrap()
+    }
+}
+
+error_enum_boilerplate! {
+    /// Errors which can occur when calling
+    /// [MediaDevices::get_user_media](struct.MediaDevices.html#method.get_user_media).
+    GetUserMediaError,
+    /// The user or the browser denied the permission to access the devices.
+    NotAllowedError,
+    /// No device of the requested kind is available.
+    NotFoundError,
+    /// None of the available devices can satisfy the requested constraints.
+    OverconstrainedError,
+    /// Any other error, e.g. the device is already in use by another application.
+    Error
+}
+
+/// The constraints passed to [MediaDevices::get_user_media](struct.MediaDevices.html#method.get_user_media).
+///
+/// Each field can either be a `bool`, which simply requests (or doesn't request)
+/// a track of the given kind, or an object with more detailed constraints,
+/// e.g. `js!( return { facingMode: "environment" }; )`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaStreamConstraints)
+// https://w3c.github.io/mediacapture-main/#dom-mediastreamconstraints
+#[derive(Clone, Debug)]
+pub struct MediaConstraints {
+    /// The constraints of the requested audio track.
+    pub audio: Value,
+    /// The constraints of the requested video track.
+    pub video: Value
+}
+
+impl Default for MediaConstraints {
+    fn default() -> Self {
+        MediaConstraints {
+            audio: Value::Bool( false ),
+            video: Value::Bool( false )
+        }
+    }
+}
+
+/// The kind of a media device.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaDeviceInfo/kind)
+// https://w3c.github.io/mediacapture-main/#dom-mediadevicekind
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MediaDeviceKind {
+    /// An audio input device, e.g. a microphone.
+    AudioInput,
+    /// An audio output device, e.g. headphones.
+    AudioOutput,
+    /// A video input device, e.g. a webcam.
+    VideoInput,
+    /// A kind of device which isn't known to this crate, since browsers
+    /// can add new kinds; contains the kind as reported by the browser.
+    Other( String )
+}
+
+impl MediaDeviceKind {
+    fn from_str( s: &str ) -> Self {
+        match s {
+            "audioinput" => MediaDeviceKind::AudioInput,
+            "audiooutput" => MediaDeviceKind::AudioOutput,
+            "videoinput" => MediaDeviceKind::VideoInput,
+            other => MediaDeviceKind::Other( other.to_owned() )
+        }
+    }
+}
+
+/// The `MediaDeviceInfo` interface describes a single media input or output device.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaDeviceInfo)
+// https://w3c.github.io/mediacapture-main/#device-info
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "MediaDeviceInfo")]
+pub struct MediaDeviceInfo( Reference );
+
+impl MediaDeviceInfo {
+    /// Returns the kind of the device.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaDeviceInfo/kind)
+    // https://w3c.github.io/mediacapture-main/#dom-mediadeviceinfo-kind
+    pub fn kind( &self ) -> MediaDeviceKind {
+        let kind: String = js!( return @{self}.kind; ).try_into().unwrap();
+        MediaDeviceKind::from_str( &kind )
+    }
+
+    /// Returns a human readable label of the device, e.g. `"FaceTime HD Camera"`.
+    ///
+    /// This is empty unless the user has already granted the permission to access the device.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaDeviceInfo/label)
+    // https://w3c.github.io/mediacapture-main/#dom-mediadeviceinfo-label
+    pub fn label( &self ) -> String {
+        js!( return @{self}.label; ).try_into().unwrap()
+    }
+
+    /// Returns an identifier of the device which is persisted across sessions.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaDeviceInfo/deviceId)
+    // https://w3c.github.io/mediacapture-main/#dom-mediadeviceinfo-deviceid
+    pub fn device_id( &self ) -> String {
+        js!( return @{self}.deviceId; ).try_into().unwrap()
+    }
+
+    /// Returns an identifier shared by all of the devices which belong
+    /// to the same physical device, e.g. a headset.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaDeviceInfo/groupId)
+    // https://w3c.github.io/mediacapture-main/#dom-mediadeviceinfo-groupid
+    pub fn group_id( &self ) -> String {
+        js!( return @{self}.groupId; ).try_into().unwrap()
+    }
+}
+
+/// The `MediaDevices` interface provides access to the connected media input
+/// devices like cameras and microphones.
+///
+/// # Examples
+///
+/// Showing the webcam in a `<video>` element, e.g. to scan QR codes from its frames:
+///
+/// ```rust
+/// let video: VideoElement = document().query_selector( "video" ).unwrap().unwrap().try_into().unwrap();
+/// let media_devices = match window().navigator().media_devices() {
+///     Some( media_devices ) => media_devices,
+///     None => return // The browser doesn't support camera access.
+/// };
+///
+/// let constraints = MediaConstraints {
+///     video: js!( return { facingMode: "environment" }; ),
+///     ..MediaConstraints::default()
+/// };
+///
+/// media_devices.get_user_media( &constraints, move |result| {
+///     match result {
+///         Ok( stream ) => {
+///             video.set_src_object( Some( &stream ) );
+///             video.play( |_| {} );
+///
+///             // ...draw the frames of `video` onto a canvas and scan them...
+///
+///             // Once we're done we have to stop all of the tracks,
+///             // otherwise the camera will stay turned on.
+///             video.set_src_object( None );
+///             for track in stream.get_tracks() {
+///                 track.stop();
+///             }
+///         },
+///         Err( GetUserMediaError::NotAllowedError( _ ) ) => {
+///             alert( "Please allow access to the camera to scan QR codes." );
+///         },
+///         Err( error ) => {
+///             console!( error, format!( "Cannot access the camera: {}", error ) );
+///         }
+///     }
+/// });
+/// ```
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaDevices)
+// https://w3c.github.io/mediacapture-main/#mediadevices
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "MediaDevices")]
+#[reference(subclass_of(EventTarget))]
+pub struct MediaDevices( Reference );
+
+impl IEventTarget for MediaDevices {}
+
+impl MediaDevices {
+    /// Asks the user for the permission to use the media input devices matching
+    /// the given `constraints`, and on success returns a [MediaStream](struct.MediaStream.html)
+    /// with the requested tracks.
+    ///
+    /// The `callback` is called once the user makes a decision; if the user denies
+    /// the permission it will be called with a
+    /// [NotAllowedError](enum.GetUserMediaError.html#variant.NotAllowedError).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaDevices/getUserMedia)
+    // https://w3c.github.io/mediacapture-main/#dom-mediadevices-getusermedia
+    pub fn get_user_media< F >( &self, constraints: &MediaConstraints, callback: F )
+        where F: FnOnce( Result< MediaStream, GetUserMediaError > ) + 'static
+    {
+        let promise: Promise = js!(
+            return @{self}.getUserMedia({
+                audio: @{&constraints.audio},
+                video: @{&constraints.video}
+            });
+        ).try_into().unwrap();
+
+        promise.done( callback );
+    }
+
+    /// Returns a list of the available media input and output devices.
+    ///
+    /// The `callback` is called once the list is available.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaDevices/enumerateDevices)
+    // https://w3c.github.io/mediacapture-main/#dom-mediadevices-enumeratedevices
+    pub fn enumerate_devices< F >( &self, callback: F )
+        where F: FnOnce( Result< Vec< MediaDeviceInfo >, Error > ) + 'static
+    {
+        let promise: Promise = js!( return @{self}.enumerateDevices(); ).try_into().unwrap();
+        promise.done( callback );
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_media_device_kind() {
+        assert_eq!( MediaDeviceKind::from_str( "videoinput" ), MediaDeviceKind::VideoInput );
+        assert_eq!( MediaDeviceKind::from_str( "audiooutput" ), MediaDeviceKind::AudioOutput );
+        assert_eq!( MediaDeviceKind::from_str( "depthinput" ), MediaDeviceKind::Other( "depthinput".to_owned() ) );
+    }
+
+    #[test]
+    fn test_overconstrained_error() {
+        let error: Value = js!(
+            var error = new Error( "Overconstrained" );
+            error.name = "OverconstrainedError";
+            error.constraint = "width";
+            return error;
+        );
+
+        match GetUserMediaError::try_from( error ).unwrap() {
+            GetUserMediaError::OverconstrainedError( error ) => assert_eq!( error.constraint(), "width" ),
+            error => panic!( "Unexpected error: {:?}", error )
+        }
+    }
+
+    #[test]
+    fn test_not_allowed_error() {
+        let error: Value = js!( return new DOMException( "Denied", "NotAllowedError" ); );
+        match GetUserMediaError::try_from( error ).unwrap() {
+            GetUserMediaError::NotAllowedError( _ ) => {},
+            error => panic!( "Unexpected error: {:?}", error )
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::dom_exception::{InvalidStateError, NotSupportedError};
+use webapi::media_stream::MediaStream;
+
+/// The state of a [MediaRecorder](struct.MediaRecorder.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaRecorder/state)
+// https://w3c.github.io/mediacapture-record/#enumdef-recordingstate
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RecordingState {
+    /// The recorder isn't recording, either because it wasn't started yet or because it was stopped.
+    Inactive,
+    /// The recorder is recording.
+    Recording,
+    /// The recording was started, but is currently paused.
+    Paused
+}
+
+/// Options used when constructing a new [MediaRecorder](struct.MediaRecorder.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaRecorder/MediaRecorder#Parameters)
+// https://w3c.github.io/mediacapture-record/#mediarecorderoptions-section
+#[derive(Clone, Debug, Default)]
+pub struct RecorderOptions< 'a > {
+    /// The MIME type of the recording, e.g. `"video/webm"`; if it's `None`
+    /// the browser picks one.
+    pub mime_type: Option< &'a str >,
+
+    /// The bit rate of the audio of the recording.
+    pub audio_bits_per_second: Option< u32 >,
+
+    /// The bit rate of the video of the recording.
+    pub video_bits_per_second: Option< u32 >
+}
+
+/// The `MediaRecorder` interface records the contents of a [MediaStream](struct.MediaStream.html).
+///
+/// The recorded data is delivered in chunks through [DataAvailableEvent](struct.DataAvailableEvent.html)s.
+///
+/// # Examples
+///
+/// Recording a short clip in one second chunks, and playing it back once it's done:
+///
+/// ```rust
+/// let recorder = MediaRecorder::new( &stream, &RecorderOptions::default() ).unwrap();
+/// let chunks = Rc::new( RefCell::new( Vec::new() ) );
+///
+/// recorder.add_event_listener( enclose!( (chunks) move |event: DataAvailableEvent| {
+///     chunks.borrow_mut().push( event.data() );
+/// }));
+///
+/// recorder.add_event_listener( enclose!( (recorder, chunks) move |_: MediaRecorderStopEvent| {
+///     let clip = Blob::from_parts( &chunks.borrow(), &recorder.mime_type() );
+///     let video: VideoElement = document().query_selector( "video" ).unwrap().unwrap().try_into().unwrap();
+///     video.set_src( &create_object_url( &clip ) );
+///
+///     for track in stream.get_tracks() {
+///         track.stop();
+///     }
+/// }));
+///
+/// recorder.start( Some( 1000 ) ).unwrap();
+/// set_timeout( move || recorder.stop(), 3000 );
+/// ```
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaRecorder)
+// https://w3c.github.io/mediacapture-record/#mediarecorder-api
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "MediaRecorder")]
+#[reference(subclass_of(EventTarget))]
+pub struct MediaRecorder( Reference );
+
+impl IEventTarget for MediaRecorder {}
+
+impl MediaRecorder {
+    /// Creates a new `MediaRecorder` which records the given `stream`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [NotSupportedError](struct.NotSupportedError.html) if the requested
+    /// MIME type is not supported, or if the browser doesn't support recording at all.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaRecorder/MediaRecorder)
+    // https://w3c.github.io/mediacapture-record/#dom-mediarecorder-constructor
+    pub fn new( stream: &MediaStream, options: &RecorderOptions ) -> Result< MediaRecorder, NotSupportedError > {
+        js_try!(
+            if( typeof MediaRecorder === "undefined" ) {
+                throw new DOMException( "MediaRecorder is not supported", "NotSupportedError" );
+            }
+
+            var options = {};
+            var mime_type = @{options.mime_type};
+            var audio_bits_per_second = @{options.audio_bits_per_second};
+            var video_bits_per_second = @{options.video_bits_per_second};
+            if( mime_type !== null ) { options.mimeType = mime_type; }
+            if( audio_bits_per_second !== null ) { options.audioBitsPerSecond = audio_bits_per_second; }
+            if( video_bits_per_second !== null ) { options.videoBitsPerSecond = video_bits_per_second; }
+
+            return new MediaRecorder( @{stream}, options );
+        ).unwrap()
+    }
+
+    /// Returns whether recording with the given MIME type is supported, e.g. `"video/webm;codecs=vp9"`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaRecorder/isTypeSupported)
+    // https://w3c.github.io/mediacapture-record/#dom-mediarecorder-istypesupported
+    pub fn is_type_supported( mime_type: &str ) -> bool {
+        js!(
+            return typeof MediaRecorder !== "undefined" && MediaRecorder.isTypeSupported( @{mime_type} );
+        ).try_into().unwrap()
+    }
+
+    /// Returns the stream which is being recorded.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaRecorder/stream)
+    // https://w3c.github.io/mediacapture-record/#dom-mediarecorder-stream
+    pub fn stream( &self ) -> MediaStream {
+        js!( return @{self}.stream; ).try_into().unwrap()
+    }
+
+    /// Returns the MIME type of the recording.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaRecorder/mimeType)
+    // https://w3c.github.io/mediacapture-record/#dom-mediarecorder-mimetype
+    pub fn mime_type( &self ) -> String {
+        js!( return @{self}.mimeType; ).try_into().unwrap()
+    }
+
+    /// Returns the current state of the recorder.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaRecorder/state)
+    // https://w3c.github.io/mediacapture-record/#dom-mediarecorder-state
+    pub fn state( &self ) -> RecordingState {
+        let state: String = js!( return @{self}.state; ).try_into().unwrap();
+        match state.as_str() {
+            "inactive" => RecordingState::Inactive,
+            "recording" => RecordingState::Recording,
+            "paused" => RecordingState::Paused,
+            other => panic!( "Invalid MediaRecorder state: {:?}", other )
+        }
+    }
+
+    /// Starts recording.
+    ///
+    /// If a `timeslice` is given the recorded data is delivered in chunks of roughly
+    /// that many milliseconds; otherwise it's delivered in a single chunk once
+    /// the recording is stopped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [InvalidStateError](struct.InvalidStateError.html) if the recorder
+    /// was already started.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaRecorder/start)
+    // https://w3c.github.io/mediacapture-record/#dom-mediarecorder-start
+    pub fn start( &self, timeslice: Option< u32 > ) -> Result< (), InvalidStateError > {
+        js_try!( @(no_return)
+            var recorder = @{self};
+            var timeslice = @{timeslice};
+            if( timeslice === null ) {
+                recorder.start();
+            } else {
+                recorder.start( timeslice );
+            }
+        ).unwrap()
+    }
+
+    /// Pauses the recording.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [InvalidStateError](struct.InvalidStateError.html) if the recorder
+    /// wasn't started.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaRecorder/pause)
+    // https://w3c.github.io/mediacapture-record/#dom-mediarecorder-pause
+    pub fn pause( &self ) -> Result< (), InvalidStateError > {
+        js_try!( @(no_return)
+            @{self}.pause();
+        ).unwrap()
+    }
+
+    /// Resumes a paused recording.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [InvalidStateError](struct.InvalidStateError.html) if the recorder
+    /// wasn't started.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaRecorder/resume)
+    // https://w3c.github.io/mediacapture-record/#dom-mediarecorder-resume
+    pub fn resume( &self ) -> Result< (), InvalidStateError > {
+        js_try!( @(no_return)
+            @{self}.resume();
+        ).unwrap()
+    }
+
+    /// Stops the recording; the remaining data is delivered in a final
+    /// [DataAvailableEvent](struct.DataAvailableEvent.html), followed by
+    /// a [MediaRecorderStopEvent](struct.MediaRecorderStopEvent.html).
+    ///
+    /// Does nothing if the recorder isn't recording.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaRecorder/stop)
+    // https://w3c.github.io/mediacapture-record/#dom-mediarecorder-stop
+    pub fn stop( &self ) {
+        js! { @(no_return)
+            var recorder = @{self};
+            if( recorder.state !== "inactive" ) {
+                recorder.stop();
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    fn canvas_stream() -> MediaStream {
+        js!(
+            return document.createElement( "canvas" ).captureStream();
+        ).try_into().unwrap()
+    }
+
+    #[test]
+    fn test_unsupported_mime_type() {
+        assert!( !MediaRecorder::is_type_supported( "video/x-unsupported" ) );
+
+        let options = RecorderOptions {
+            mime_type: Some( "video/x-unsupported" ),
+            ..RecorderOptions::default()
+        };
+
+        assert!( MediaRecorder::new( &canvas_stream(), &options ).is_err() );
+    }
+
+    #[test]
+    fn test_state() {
+        let stream = canvas_stream();
+        let recorder = MediaRecorder::new( &stream, &RecorderOptions::default() ).unwrap();
+        assert_eq!( recorder.stream(), stream );
+        assert_eq!( recorder.state(), RecordingState::Inactive );
+        assert!( recorder.pause().is_err() );
+
+        recorder.start( Some( 100 ) ).unwrap();
+        assert_eq!( recorder.state(), RecordingState::Recording );
+        assert!( recorder.start( None ).is_err() );
+
+        recorder.pause().unwrap();
+        assert_eq!( recorder.state(), RecordingState::Paused );
+        recorder.resume().unwrap();
+        assert_eq!( recorder.state(), RecordingState::Recording );
+
+        recorder.stop();
+        assert_eq!( recorder.state(), RecordingState::Inactive );
+        recorder.stop();
+    }
+}
@@ -0,0 +1,180 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventTarget};
+
+/// The kind of media carried by a [MediaStreamTrack](struct.MediaStreamTrack.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaStreamTrack/kind)
+// https://w3c.github.io/mediacapture-main/#dom-mediastreamtrack-kind
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MediaStreamTrackKind {
+    /// The track carries audio.
+    Audio,
+    /// The track carries video.
+    Video
+}
+
+/// The `MediaStream` interface represents a stream of media content,
+/// consisting of several audio and video tracks.
+///
+/// A stream can be played back by assigning it to a media element with
+/// [IHtmlMediaElement::set_src_object](trait.IHtmlMediaElement.html#method.set_src_object).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaStream)
+// https://w3c.github.io/mediacapture-main/#mediastream
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "MediaStream")]
+#[reference(subclass_of(EventTarget))]
+pub struct MediaStream( Reference );
+
+impl IEventTarget for MediaStream {}
+
+impl MediaStream {
+    /// Returns a unique identifier of the stream.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaStream/id)
+    // https://w3c.github.io/mediacapture-main/#dom-mediastream-id
+    pub fn id( &self ) -> String {
+        js!( return @{self}.id; ).try_into().unwrap()
+    }
+
+    /// Returns whether the stream has at least one track which hasn't ended yet.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaStream/active)
+    // https://w3c.github.io/mediacapture-main/#dom-mediastream-active
+    pub fn active( &self ) -> bool {
+        js!( return @{self}.active; ).try_into().unwrap()
+    }
+
+    /// Returns all of the tracks of the stream.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaStream/getTracks)
+    // https://w3c.github.io/mediacapture-main/#dom-mediastream-gettracks
+    pub fn get_tracks( &self ) -> Vec< MediaStreamTrack > {
+        js!( return @{self}.getTracks(); ).try_into().unwrap()
+    }
+
+    /// Returns the audio tracks of the stream.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaStream/getAudioTracks)
+    // https://w3c.github.io/mediacapture-main/#dom-mediastream-getaudiotracks
+    pub fn get_audio_tracks( &self ) -> Vec< MediaStreamTrack > {
+        js!( return @{self}.getAudioTracks(); ).try_into().unwrap()
+    }
+
+    /// Returns the video tracks of the stream.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaStream/getVideoTracks)
+    // https://w3c.github.io/mediacapture-main/#dom-mediastream-getvideotracks
+    pub fn get_video_tracks( &self ) -> Vec< MediaStreamTrack > {
+        js!( return @{self}.getVideoTracks(); ).try_into().unwrap()
+    }
+}
+
+/// The `MediaStreamTrack` interface represents a single audio or video track
+/// of a [MediaStream](struct.MediaStream.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaStreamTrack)
+// https://w3c.github.io/mediacapture-main/#mediastreamtrack
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "MediaStreamTrack")]
+#[reference(subclass_of(EventTarget))]
+pub struct MediaStreamTrack( Reference );
+
+impl IEventTarget for MediaStreamTrack {}
+
+impl MediaStreamTrack {
+    /// Returns a unique identifier of the track.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaStreamTrack/id)
+    // https://w3c.github.io/mediacapture-main/#dom-mediastreamtrack-id
+    pub fn id( &self ) -> String {
+        js!( return @{self}.id; ).try_into().unwrap()
+    }
+
+    /// Returns whether this is an audio or a video track.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaStreamTrack/kind)
+    // https://w3c.github.io/mediacapture-main/#dom-mediastreamtrack-kind
+    pub fn kind( &self ) -> MediaStreamTrackKind {
+        let kind: String = js!( return @{self}.kind; ).try_into().unwrap();
+        match kind.as_str() {
+            "audio" => MediaStreamTrackKind::Audio,
+            "video" => MediaStreamTrackKind::Video,
+            other => panic!( "Invalid MediaStreamTrack kind: {:?}", other )
+        }
+    }
+
+    /// Returns a human readable label of the source of the track, e.g. `"Internal microphone"`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaStreamTrack/label)
+    // https://w3c.github.io/mediacapture-main/#dom-mediastreamtrack-label
+    pub fn label( &self ) -> String {
+        js!( return @{self}.label; ).try_into().unwrap()
+    }
+
+    /// Returns whether the track is enabled; a disabled track produces silence or black frames.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaStreamTrack/enabled)
+    // https://w3c.github.io/mediacapture-main/#dom-mediastreamtrack-enabled
+    pub fn enabled( &self ) -> bool {
+        js!( return @{self}.enabled; ).try_into().unwrap()
+    }
+
+    /// Enables or disables the track.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaStreamTrack/enabled)
+    // https://w3c.github.io/mediacapture-main/#dom-mediastreamtrack-enabled
+    pub fn set_enabled( &self, value: bool ) {
+        js! { @(no_return)
+            @{self}.enabled = @{value};
+        }
+    }
+
+    /// Permanently stops the track and releases its source, e.g. turns off the camera.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaStreamTrack/stop)
+    // https://w3c.github.io/mediacapture-main/#dom-mediastreamtrack-stop
+    pub fn stop( &self ) {
+        js! { @(no_return)
+            @{self}.stop();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    fn canvas_stream() -> MediaStream {
+        js!(
+            return document.createElement( "canvas" ).captureStream();
+        ).try_into().unwrap()
+    }
+
+    #[test]
+    fn test_tracks() {
+        let stream = canvas_stream();
+        assert_eq!( stream.get_audio_tracks().len(), 0 );
+
+        let tracks = stream.get_video_tracks();
+        assert_eq!( tracks.len(), 1 );
+        assert_eq!( stream.get_tracks(), tracks );
+
+        let track = &tracks[ 0 ];
+        assert_eq!( track.kind(), MediaStreamTrackKind::Video );
+        assert!( track.enabled() );
+        track.set_enabled( false );
+        assert!( !track.enabled() );
+    }
+
+    #[test]
+    fn test_stop() {
+        let stream = canvas_stream();
+        for track in stream.get_tracks() {
+            track.stop();
+        }
+
+        assert!( !stream.active() );
+    }
+}
@@ -0,0 +1,225 @@
+use webcore::value::{Reference, Value};
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventTarget};
+
+/// The `MessageChannel` interface creates a new channel through which
+/// two [MessagePort](struct.MessagePort.html)s can send messages to each other.
+///
+/// One of the ports is usually transferred to an iframe or a [Worker](struct.Worker.html)
+/// through `post_message_with_transfer`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MessageChannel)
+// https://html.spec.whatwg.org/#message-channels
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "MessageChannel")]
+pub struct MessageChannel( Reference );
+
+impl MessageChannel {
+    /// Creates a new channel with two entangled ports.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MessageChannel/MessageChannel)
+    // https://html.spec.whatwg.org/#dom-messagechannel
+    pub fn new() -> MessageChannel {
+        js!( return new MessageChannel(); ).try_into().unwrap()
+    }
+
+    /// Returns the first port of the channel.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MessageChannel/port1)
+    // https://html.spec.whatwg.org/#dom-messagechannel-port1
+    pub fn port1( &self ) -> MessagePort {
+        js!( return @{self}.port1; ).try_into().unwrap()
+    }
+
+    /// Returns the second port of the channel.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MessageChannel/port2)
+    // https://html.spec.whatwg.org/#dom-messagechannel-port2
+    pub fn port2( &self ) -> MessagePort {
+        js!( return @{self}.port2; ).try_into().unwrap()
+    }
+}
+
+/// The `MessagePort` interface represents one of the two ports of a
+/// [MessageChannel](struct.MessageChannel.html).
+///
+/// Messages sent to a port are queued until the port is [started](#method.start);
+/// registering a [MessageEvent](struct.MessageEvent.html) listener through any of the
+/// methods of [IEventTarget](trait.IEventTarget.html) starts the port automatically.
+///
+/// Messages are received as [MessageEvent](struct.MessageEvent.html)s.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MessagePort)
+// https://html.spec.whatwg.org/#message-ports
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "MessagePort")]
+#[reference(subclass_of(EventTarget))]
+pub struct MessagePort( Reference );
+
+impl IEventTarget for MessagePort {}
+
+impl MessagePort {
+    /// Sends a message through the channel; the `data` is structurally cloned.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MessagePort/postMessage)
+    // https://html.spec.whatwg.org/#dom-messageport-postmessage
+    pub fn post_message( &self, data: Value ) {
+        js! { @(no_return)
+            @{self}.postMessage( @{data} );
+        }
+    }
+
+    /// Sends a message through the channel, transferring the ownership of the
+    /// objects in `transfer` (e.g. `ArrayBuffer`s or other `MessagePort`s) without copying them.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MessagePort/postMessage)
+    // https://html.spec.whatwg.org/#dom-messageport-postmessage
+    pub fn post_message_with_transfer( &self, data: Value, transfer: &[Reference] ) {
+        js! { @(no_return)
+            @{self}.postMessage( @{data}, @{transfer} );
+        }
+    }
+
+    /// Starts the delivery of the messages queued on this port.
+    ///
+    /// This is called automatically when a [MessageEvent](struct.MessageEvent.html) listener
+    /// is registered through any of the methods of [IEventTarget](trait.IEventTarget.html).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MessagePort/start)
+    // https://html.spec.whatwg.org/#dom-messageport-start
+    pub fn start( &self ) {
+        js! { @(no_return)
+            @{self}.start();
+        }
+    }
+
+    /// Disconnects the port, so that it's no longer active.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MessagePort/close)
+    // https://html.spec.whatwg.org/#dom-messageport-close
+    pub fn close( &self ) {
+        js! { @(no_return)
+            @{self}.close();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use std::cell::RefCell;
+    use webapi::events::message::{MessageEvent, MessageErrorEvent};
+    use webapi::events::socket::IMessageEvent;
+    use webapi::global::set_timeout;
+    use webcore::async_test::async_test;
+
+    #[test]
+    fn test_message_channel() {
+        let channel = MessageChannel::new();
+        let port1 = channel.port1();
+        let port2 = channel.port2();
+        assert_ne!( port1, port2 );
+        assert_eq!( port1, channel.port1() );
+
+        let handle = port2.add_event_listener( |_: MessageEvent| {} );
+        port1.post_message( "Hello".into() );
+        handle.remove();
+
+        port1.close();
+        port2.close();
+    }
+
+    #[test]
+    fn test_started_only_by_message_listeners() {
+        let channel = MessageChannel::new();
+        let port = channel.port2();
+        js! { @(no_return)
+            var port = @{&port};
+            port.__stdweb_started = false;
+            port.start = function() {
+                port.__stdweb_started = true;
+            };
+        }
+
+        let started = || -> bool { js!( return @{&port}.__stdweb_started; ).try_into().unwrap() };
+        port.add_event_listener( |_: MessageErrorEvent| {} );
+        assert!( !started() );
+        port.add_event_listener( |_: MessageEvent| {} );
+        assert!( started() );
+    }
+
+    #[test]
+    fn test_transfer_port() {
+        let channel = MessageChannel::new();
+        let other = MessageChannel::new();
+        let port: Reference = other.port2().as_ref().clone();
+
+        channel.port1().post_message_with_transfer( Value::Null, &[ port ] );
+    }
+
+    fn receive_on< F >( name: &str, register: F ) where F: FnOnce( &MessagePort, Box< dyn FnMut( MessageEvent ) > ) {
+        async_test( name, 1000, move |done| {
+            let channel = MessageChannel::new();
+            let received = Rc::new( RefCell::new( Vec::new() ) );
+            register( &channel.port2(), Box::new( {
+                let received = received.clone();
+                move |event: MessageEvent| {
+                    let data: String = event.data().try_into().unwrap();
+                    received.borrow_mut().push( data );
+                }
+            }));
+
+            channel.port1().post_message( "ping".into() );
+            channel.port1().post_message( "pong".into() );
+            set_timeout( move || {
+                assert_eq!( *received.borrow(), vec![ "ping".to_owned(), "pong".to_owned() ] );
+                channel.port1().close();
+                done.done();
+            }, 200 );
+        });
+    }
+
+    #[test]
+    fn test_cross_port_delivery() {
+        receive_on( "test_cross_port_delivery", |port, mut listener| {
+            port.add_event_listener( move |event: MessageEvent| listener( event ) );
+        });
+    }
+
+    #[test]
+    fn test_cross_port_delivery_with_signal() {
+        use webapi::abort_controller::AbortController;
+
+        receive_on( "test_cross_port_delivery_with_signal", |port, mut listener| {
+            let controller = AbortController::new();
+            port.add_event_listener_with_signal( &controller.signal(), move |event: MessageEvent| listener( event ) );
+        });
+    }
+
+    #[test]
+    fn test_cross_port_delivery_with_named_listener() {
+        receive_on( "test_cross_port_delivery_with_named_listener", |port, mut listener| {
+            port.add_event_listener_named( "receiver", move |event: MessageEvent| listener( event ) );
+        });
+    }
+
+    #[test]
+    fn test_cross_port_delivery_with_debounced_listener() {
+        async_test( "test_cross_port_delivery_with_debounced_listener", 1000, |done| {
+            let channel = MessageChannel::new();
+            let received = Rc::new( RefCell::new( 0 ) );
+            channel.port2().add_debounced_event_listener( 10, {
+                let received = received.clone();
+                move |_: MessageEvent| *received.borrow_mut() += 1
+            });
+
+            channel.port1().post_message( "ping".into() );
+            set_timeout( move || {
+                assert_eq!( *received.borrow(), 1 );
+                channel.port1().close();
+                done.done();
+            }, 200 );
+        });
+    }
+}
@@ -2,6 +2,8 @@ pub mod global;
 pub mod cross_origin_setting;
 pub mod date;
 pub mod document;
+pub mod xpath;
+pub mod dom_parser;
 pub mod window;
 pub mod event;
 pub mod event_target;
@@ -9,6 +11,8 @@ pub mod node;
 pub mod element;
 pub mod html_element;
 pub mod html_elements;
+pub mod svg_element;
+pub mod svg_elements;
 pub mod window_or_worker;
 pub mod token_list;
 pub mod text_node;
@@ -24,13 +28,51 @@ pub mod array_buffer;
 pub mod typed_array;
 /// A module containing XMLHttpRequest and its ReadyState
 pub mod xml_http_request;
+pub mod fetch;
+#[cfg(feature = "futures")]
+pub mod xhr_single_flight;
+#[cfg(feature = "futures")]
+pub mod xhr_request_queue;
 pub mod history;
+pub mod router;
 pub mod web_socket;
 pub mod rendering_context;
 pub mod mutation_observer;
+pub mod lifecycle;
 pub mod error;
 pub mod dom_exception;
 pub mod events;
 pub mod parent_node;
 pub mod non_element_parent_node;
 pub mod console;
+pub mod notification;
+pub mod navigator;
+pub mod gamepad;
+pub mod permissions;
+pub mod intl;
+pub mod animation;
+pub mod service_worker;
+pub mod clipboard;
+pub mod fullscreen;
+pub mod page_visibility;
+pub mod network_state;
+pub mod task_queue;
+pub mod reconnecting_web_socket;
+pub mod font_face;
+pub mod rect;
+pub mod intersection_observer;
+pub mod resize_observer;
+pub mod performance;
+pub mod performance_observer;
+pub mod worker;
+pub mod message_channel;
+pub mod broadcast_channel;
+pub mod media_stream;
+pub mod media_devices;
+pub mod abort_controller;
+pub mod web_audio;
+pub mod media_recorder;
+pub mod url;
+pub mod crypto;
+pub mod subtle_crypto;
+pub mod text_encoding;
@@ -3,7 +3,7 @@ use webcore::value::{Reference, Value, ConversionError};
 use webapi::node_list::NodeList;
 use webcore::try_from::{TryFrom, TryInto};
 use webapi::node::{INode, Node};
-use private::TODO;
+use webapi::error::TypeError;
 
 /// Provides a way to receive notifications about changes to the DOM.
 ///
@@ -87,7 +87,9 @@ impl MutationObserver {
     /// with the new `options`. It will **not** notify multiple times for the same change to the same
     /// `target`.
     ///
-    /// # Panics
+    /// # Errors
+    ///
+    /// A [`TypeError`](struct.TypeError.html) is returned when the `options` are invalid, that is:
     ///
     /// * At least one of
     /// [`child_list`](struct.MutationObserverInit.html#structfield.child_list),
@@ -105,13 +107,13 @@ impl MutationObserver {
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MutationObserver#observe())
     // https://dom.spec.whatwg.org/#ref-for-dom-mutationobserver-observe
-    pub fn observe< T: INode >( &self, target: &T, options: MutationObserverInit ) -> Result< (), TODO > {
+    pub fn observe< T: INode >( &self, target: &T, options: MutationObserverInit ) -> Result< (), TypeError > {
         let attribute_filter = options.attribute_filter
             .map( |val| val.into() )
             // This must compile to JavaScript `undefined`, NOT `null`
             .unwrap_or( Value::Undefined );
 
-        js! { @(no_return)
+        js_try! { @(no_return)
             @{self.as_ref()}.observe( @{target.as_ref()}, {
                 childList: @{options.child_list},
                 attributes: @{options.attributes},
@@ -121,9 +123,7 @@ impl MutationObserver {
                 characterDataOldValue: @{options.character_data_old_value},
                 attributeFilter: @{attribute_filter}
             } );
-        }
-
-        Ok(())
+        }.unwrap()
     }
 
     /// Stops observing all targets.
@@ -239,6 +239,120 @@ pub enum MutationRecord {
     },
 }
 
+/// The type of a [`MutationRecord`](enum.MutationRecord.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MutationRecord#Properties)
+// https://dom.spec.whatwg.org/#dom-mutationrecord-type
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MutationRecordType {
+    /// One of the target's attributes was changed.
+    Attributes,
+    /// The target's data was changed.
+    CharacterData,
+    /// The children of the target were changed.
+    ChildList
+}
+
+impl MutationRecord {
+    /// Returns the type of the change.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MutationRecord#Properties)
+    // https://dom.spec.whatwg.org/#dom-mutationrecord-type
+    pub fn record_type( &self ) -> MutationRecordType {
+        match *self {
+            MutationRecord::Attribute { .. } => MutationRecordType::Attributes,
+            MutationRecord::CharacterData { .. } => MutationRecordType::CharacterData,
+            MutationRecord::ChildList { .. } => MutationRecordType::ChildList
+        }
+    }
+
+    /// Returns the [`Node`](struct.Node.html) affected by the change.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MutationRecord#Properties)
+    // https://dom.spec.whatwg.org/#dom-mutationrecord-target
+    pub fn target( &self ) -> &Node {
+        match *self {
+            MutationRecord::Attribute { ref target, .. } |
+            MutationRecord::CharacterData { ref target, .. } |
+            MutationRecord::ChildList { ref target, .. } => target
+        }
+    }
+
+    /// Returns the nodes which were inserted; this is empty if the
+    /// record isn't of the [`ChildList`](#variant.ChildList) type.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MutationRecord#Properties)
+    // https://dom.spec.whatwg.org/#dom-mutationrecord-addednodes
+    pub fn added_nodes( &self ) -> Vec< Node > {
+        match *self {
+            MutationRecord::ChildList { ref inserted_nodes, .. } => inserted_nodes.iter().collect(),
+            _ => Vec::new()
+        }
+    }
+
+    /// Returns the nodes which were removed; this is empty if the
+    /// record isn't of the [`ChildList`](#variant.ChildList) type.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MutationRecord#Properties)
+    // https://dom.spec.whatwg.org/#dom-mutationrecord-removednodes
+    pub fn removed_nodes( &self ) -> Vec< Node > {
+        match *self {
+            MutationRecord::ChildList { ref removed_nodes, .. } => removed_nodes.iter().collect(),
+            _ => Vec::new()
+        }
+    }
+
+    /// Returns the previous sibling of the inserted or removed nodes, if any.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MutationRecord#Properties)
+    // https://dom.spec.whatwg.org/#dom-mutationrecord-previoussibling
+    pub fn previous_sibling( &self ) -> Option< &Node > {
+        match *self {
+            MutationRecord::ChildList { ref previous_sibling, .. } => previous_sibling.as_ref(),
+            _ => None
+        }
+    }
+
+    /// Returns the next sibling of the inserted or removed nodes, if any.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MutationRecord#Properties)
+    // https://dom.spec.whatwg.org/#dom-mutationrecord-nextsibling
+    pub fn next_sibling( &self ) -> Option< &Node > {
+        match *self {
+            MutationRecord::ChildList { ref next_sibling, .. } => next_sibling.as_ref(),
+            _ => None
+        }
+    }
+
+    /// Returns the name of the changed attribute if the record
+    /// is of the [`Attribute`](#variant.Attribute) type.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MutationRecord#Properties)
+    // https://dom.spec.whatwg.org/#dom-mutationrecord-attributename
+    pub fn attribute_name( &self ) -> Option< &str > {
+        match *self {
+            MutationRecord::Attribute { ref name, .. } => Some( name ),
+            _ => None
+        }
+    }
+
+    /// Returns the value of the changed attribute, or the data of the
+    /// changed `CharacterData` node, from before the change.
+    ///
+    /// This is only available if [`attribute_old_value`](struct.MutationObserverInit.html#structfield.attribute_old_value)
+    /// or [`character_data_old_value`](struct.MutationObserverInit.html#structfield.character_data_old_value) were set.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MutationRecord#Properties)
+    // https://dom.spec.whatwg.org/#dom-mutationrecord-oldvalue
+    pub fn old_value( &self ) -> Option< &str > {
+        match *self {
+            MutationRecord::Attribute { ref old_value, .. } => old_value.as_ref().map( |value| value.as_str() ),
+            MutationRecord::CharacterData { ref old_data, .. } => old_data.as_ref().map( |value| value.as_str() ),
+            MutationRecord::ChildList { .. } => None
+        }
+    }
+}
+
 // TODO create a MutationRecord Reference and use instanceof to verify it
 impl TryFrom< Value > for MutationRecord {
     type Error = ConversionError;
@@ -299,4 +413,69 @@ mod tests {
             attribute_filter: Some( &[ "foo", "bar", "qux" ] ),
         }).unwrap();
     }
+
+    fn no_options() -> MutationObserverInit< 'static > {
+        MutationObserverInit {
+            child_list: false,
+            attributes: false,
+            character_data: false,
+            subtree: false,
+            attribute_old_value: false,
+            character_data_old_value: false,
+            attribute_filter: None,
+        }
+    }
+
+    #[ test ]
+    fn test_observe_without_options() {
+        let observer = MutationObserver::new( |_, _| {} );
+        assert!( observer.observe( &document(), no_options() ).is_err() );
+    }
+
+    #[ test ]
+    fn test_records() {
+        use webapi::node::Node;
+
+        let observer = MutationObserver::new( |_, _| {} );
+        let parent: Node = document().create_element( "div" ).unwrap().into();
+        let first: Node = document().create_element( "span" ).unwrap().into();
+        let second: Node = document().create_element( "span" ).unwrap().into();
+        parent.append_child( &first );
+
+        observer.observe( &parent, MutationObserverInit {
+            child_list: true,
+            attributes: true,
+            attribute_old_value: true,
+            .. no_options()
+        }).unwrap();
+
+        js! { @(no_return)
+            var parent = @{&parent};
+            parent.setAttribute( "foo", "1" );
+            parent.setAttribute( "foo", "2" );
+        }
+        parent.append_child( &second );
+        parent.remove_child( &first ).unwrap();
+
+        let records = observer.take_records();
+        assert_eq!( records.len(), 4 );
+
+        assert_eq!( records[ 0 ].record_type(), MutationRecordType::Attributes );
+        assert_eq!( records[ 0 ].target(), &parent );
+        assert_eq!( records[ 0 ].attribute_name(), Some( "foo" ) );
+        assert_eq!( records[ 0 ].old_value(), None );
+        assert_eq!( records[ 1 ].old_value(), Some( "1" ) );
+
+        assert_eq!( records[ 2 ].record_type(), MutationRecordType::ChildList );
+        assert_eq!( records[ 2 ].added_nodes(), vec![ second.clone() ] );
+        assert!( records[ 2 ].removed_nodes().is_empty() );
+        assert_eq!( records[ 2 ].previous_sibling(), Some( &first ) );
+        assert_eq!( records[ 2 ].next_sibling(), None );
+        assert_eq!( records[ 2 ].attribute_name(), None );
+
+        assert_eq!( records[ 3 ].removed_nodes(), vec![ first.clone() ] );
+        assert_eq!( records[ 3 ].next_sibling(), Some( &second ) );
+
+        assert!( observer.take_records().is_empty() );
+    }
 }
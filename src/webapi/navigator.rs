@@ -0,0 +1,102 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::clipboard::Clipboard;
+use webapi::media_devices::MediaDevices;
+use webapi::gamepad::{self, Gamepad};
+use webapi::permissions::Permissions;
+use webapi::service_worker::ServiceWorkerContainer;
+
+/// The `Navigator` interface represents the state and the identity of the user agent.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Navigator)
+// https://html.spec.whatwg.org/#navigator
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Navigator")]
+pub struct Navigator( Reference );
+
+impl Navigator {
+    /// Returns a [Clipboard](struct.Clipboard.html) object which provides access
+    /// to the system clipboard, or `None` if the Clipboard API is not supported.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/clipboard)
+    // https://w3c.github.io/clipboard-apis/#dom-navigator-clipboard
+    pub fn clipboard( &self ) -> Option< Clipboard > {
+        unsafe {
+            js!(
+                return @{self}.clipboard;
+            ).into_reference_unchecked()
+        }
+    }
+
+    /// Returns a [MediaDevices](struct.MediaDevices.html) object which provides access
+    /// to the cameras and microphones, or `None` if it's not supported, e.g. because
+    /// the page wasn't loaded in a secure context.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/mediaDevices)
+    // https://w3c.github.io/mediacapture-main/#dom-navigator-mediadevices
+    pub fn media_devices( &self ) -> Option< MediaDevices > {
+        unsafe {
+            js!(
+                return @{self}.mediaDevices;
+            ).into_reference_unchecked()
+        }
+    }
+
+    /// Returns a [Permissions](struct.Permissions.html) object which can be used to
+    /// query the state of permissions, or `None` if the Permissions API is not supported.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/permissions)
+    // https://w3c.github.io/permissions/#dom-navigator-permissions
+    pub fn permissions( &self ) -> Option< Permissions > {
+        unsafe {
+            js!(
+                return @{self}.permissions;
+            ).into_reference_unchecked()
+        }
+    }
+
+    /// Returns a [ServiceWorkerContainer](struct.ServiceWorkerContainer.html) which can be
+    /// used to register service workers, or `None` if service workers are not supported.
+    ///
+    /// Browsers only expose service workers in secure contexts, so this is
+    /// also `None` on pages which are served over plain HTTP.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/serviceWorker)
+    // https://w3c.github.io/ServiceWorker/#navigator-service-worker-attribute
+    pub fn service_worker( &self ) -> Option< ServiceWorkerContainer > {
+        unsafe {
+            js!(
+                return @{self}.serviceWorker;
+            ).into_reference_unchecked()
+        }
+    }
+
+    /// Returns snapshots of the connected gamepads.
+    ///
+    /// The gamepads are indexed by their [index](struct.Gamepad.html#method.index),
+    /// so the slots of disconnected gamepads are `None`. Since the returned gamepads
+    /// are only snapshots this has to be called again whenever the current state
+    /// is needed, e.g. on every animation frame.
+    ///
+    /// For privacy reasons most browsers only expose the gamepads after the user
+    /// has pressed one of their buttons while the page was visible.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/getGamepads)
+    // https://w3c.github.io/gamepad/#dom-navigator-getgamepads
+    pub fn get_gamepads( &self ) -> Vec< Option< Gamepad > > {
+        gamepad::get_gamepads( self.as_ref() )
+    }
+
+    /// Returns whenever the browser is online.
+    ///
+    /// Only `false` is reliable, since being connected to a network
+    /// doesn't mean that the internet can actually be reached.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/NavigatorOnLine/onLine)
+    // https://html.spec.whatwg.org/#dom-navigator-online
+    pub fn on_line( &self ) -> bool {
+        js!(
+            return @{self}.onLine;
+        ).try_into().unwrap()
+    }
+}
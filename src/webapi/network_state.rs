@@ -0,0 +1,75 @@
+use webapi::window::window;
+use webapi::event_target::{IEventTarget, EventListenerHandle};
+use webapi::events::dom::{OnlineEvent, OfflineEvent};
+
+/// Provides access to the online status of the browser.
+///
+/// Requests made while the browser is offline fail with
+/// [XhrError::Offline](enum.XhrError.html#variant.Offline).
+///
+/// # Examples
+///
+/// ```rust
+/// let handle = NetworkState::on_online( || retry_pending_requests() );
+/// ```
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/NavigatorOnLine/Online_and_offline_events)
+// https://html.spec.whatwg.org/#navigator.online
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NetworkState;
+
+impl NetworkState {
+    /// Returns whenever the browser is online.
+    ///
+    /// Only `false` is reliable, since being connected to a network
+    /// doesn't mean that the internet can actually be reached.
+    ///
+    /// This is the same as [Navigator::on_line](struct.Navigator.html#method.on_line).
+    #[inline]
+    pub fn is_online() -> bool {
+        window().navigator().on_line()
+    }
+
+    /// Calls `callback` every time the browser goes online.
+    ///
+    /// The callback is called until the returned handle is removed.
+    pub fn on_online< F: FnMut() + 'static >( mut callback: F ) -> EventListenerHandle {
+        window().add_event_listener( move |_: OnlineEvent| callback() )
+    }
+
+    /// Calls `callback` every time the browser goes offline.
+    ///
+    /// The callback is called until the returned handle is removed.
+    pub fn on_offline< F: FnMut() + 'static >( mut callback: F ) -> EventListenerHandle {
+        window().add_event_listener( move |_: OfflineEvent| callback() )
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_on_online_and_offline() {
+        let online = Rc::new( Cell::new( 0 ) );
+        let offline = Rc::new( Cell::new( 0 ) );
+        let online_handle = NetworkState::on_online({
+            let online = online.clone();
+            move || online.set( online.get() + 1 )
+        });
+        let offline_handle = NetworkState::on_offline({
+            let offline = offline.clone();
+            move || offline.set( offline.get() + 1 )
+        });
+
+        js!( @(no_return) window.dispatchEvent( new Event( "offline" ) ); );
+        assert_eq!( ( online.get(), offline.get() ), ( 0, 1 ) );
+
+        online_handle.remove();
+        offline_handle.remove();
+        js!( @(no_return) window.dispatchEvent( new Event( "online" ) ); );
+        assert_eq!( ( online.get(), offline.get() ), ( 0, 1 ) );
+    }
+}
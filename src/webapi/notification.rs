@@ -0,0 +1,267 @@
+use webcore::value::{Value, Reference};
+use webcore::try_from::TryInto;
+use webcore::once::Once;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::error::Error;
+
+/// The `Notification` interface of the Notifications API is used to configure
+/// and display desktop notifications to the user.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification)
+// https://notifications.spec.whatwg.org/#notification
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Notification")]
+#[reference(subclass_of(EventTarget))]
+pub struct Notification( Reference );
+
+impl IEventTarget for Notification {}
+
+/// The current permission the user has granted to display notifications.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/permission)
+// https://notifications.spec.whatwg.org/#enumdef-notificationpermission
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NotificationPermission {
+    /// The user hasn't made a decision yet; the browser will treat this as `Denied`.
+    Default,
+    /// The user has explicitly allowed notifications to be shown.
+    Granted,
+    /// The user has explicitly refused notifications to be shown.
+    Denied
+}
+
+impl NotificationPermission {
+    fn from_str( s: &str ) -> Self {
+        match s {
+            "default" => NotificationPermission::Default,
+            "granted" => NotificationPermission::Granted,
+            "denied" => NotificationPermission::Denied,
+            other => panic!( "Invalid notification permission: {:?}", other )
+        }
+    }
+}
+
+/// Options used when constructing a new [Notification](struct.Notification.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/Notification#Parameters)
+// https://notifications.spec.whatwg.org/#dictdef-notificationoptions
+#[derive(Clone, Debug)]
+pub struct NotificationOptions< 'a > {
+    /// The body text of the notification.
+    pub body: Option< &'a str >,
+
+    /// The URL of an icon to be displayed in the notification.
+    pub icon: Option< &'a str >,
+
+    /// An identifying tag; a new notification with the same tag replaces the old one.
+    pub tag: Option< &'a str >,
+
+    /// If `true` the notification will remain active until the user clicks or dismisses it.
+    pub require_interaction: bool,
+
+    /// If `true` the notification will be displayed without any sound or vibration.
+    pub silent: bool,
+
+    /// Arbitrary data associated with the notification; it can be retrieved
+    /// later with [Notification::data](struct.Notification.html#method.data).
+    pub data: Value
+}
+
+impl< 'a > Default for NotificationOptions< 'a > {
+    fn default() -> Self {
+        NotificationOptions {
+            body: None,
+            icon: None,
+            tag: None,
+            require_interaction: false,
+            silent: false,
+            data: Value::Null
+        }
+    }
+}
+
+impl Notification {
+    /// Creates and displays a new notification.
+    ///
+    /// This will fail if the browser refuses to create the notification,
+    /// e.g. when the permission to show notifications was denied.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/Notification)
+    // https://notifications.spec.whatwg.org/#dom-notification-notification
+    pub fn new( title: &str, options: &NotificationOptions ) -> Result< Notification, Error > {
+        js_try!(
+            var options = {
+                requireInteraction: @{options.require_interaction},
+                silent: @{options.silent},
+                data: @{&options.data}
+            };
+
+            var body = @{options.body};
+            var icon = @{options.icon};
+            var tag = @{options.tag};
+            if( body !== null ) { options.body = body; }
+            if( icon !== null ) { options.icon = icon; }
+            if( tag !== null ) { options.tag = tag; }
+
+            return new Notification( @{title}, options );
+        ).unwrap()
+    }
+
+    /// Returns the current permission to display notifications.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/permission)
+    // https://notifications.spec.whatwg.org/#dom-notification-permission
+    pub fn permission() -> NotificationPermission {
+        let permission: String = js!( return Notification.permission; ).try_into().unwrap();
+        NotificationPermission::from_str( &permission )
+    }
+
+    /// Requests permission from the user to display notifications.
+    ///
+    /// The `callback` will be called once the user makes a decision.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/requestPermission)
+    // https://notifications.spec.whatwg.org/#dom-notification-requestpermission
+    pub fn request_permission< F: FnOnce( NotificationPermission ) + 'static >( callback: F ) {
+        let callback = move |permission: String| {
+            callback( NotificationPermission::from_str( &permission ) );
+        };
+
+        js! { @(no_return)
+            var callback = @{Once( callback )};
+            var called = false;
+            var done = function( permission ) {
+                if( !called ) {
+                    called = true;
+                    callback( permission );
+                }
+            };
+
+            // Older browsers only support the callback based version.
+            var promise = Notification.requestPermission( done );
+            if( promise ) {
+                promise.then( done );
+            }
+        }
+    }
+
+    /// Returns the title of the notification.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/title)
+    // https://notifications.spec.whatwg.org/#dom-notification-title
+    pub fn title( &self ) -> String {
+        js!( return @{self}.title; ).try_into().unwrap()
+    }
+
+    /// Returns the body text of the notification.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/body)
+    // https://notifications.spec.whatwg.org/#dom-notification-body
+    pub fn body( &self ) -> String {
+        js!( return @{self}.body; ).try_into().unwrap()
+    }
+
+    /// Returns the identifying tag of the notification.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/tag)
+    // https://notifications.spec.whatwg.org/#dom-notification-tag
+    pub fn tag( &self ) -> String {
+        js!( return @{self}.tag; ).try_into().unwrap()
+    }
+
+    /// Returns the data which was passed in [NotificationOptions::data](struct.NotificationOptions.html#structfield.data)
+    /// when the notification was created.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/data)
+    // https://notifications.spec.whatwg.org/#dom-notification-data
+    pub fn data( &self ) -> Value {
+        js!( return @{self}.data; )
+    }
+
+    /// Programmatically closes the notification.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/close)
+    // https://notifications.spec.whatwg.org/#dom-notification-close
+    pub fn close( &self ) {
+        js! { @(no_return)
+            @{self}.close();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    fn is_supported() -> bool {
+        js!( return typeof Notification !== "undefined"; ).try_into().unwrap()
+    }
+
+    #[test]
+    fn test_permission_from_str() {
+        assert_eq!( NotificationPermission::from_str( "default" ), NotificationPermission::Default );
+        assert_eq!( NotificationPermission::from_str( "granted" ), NotificationPermission::Granted );
+        assert_eq!( NotificationPermission::from_str( "denied" ), NotificationPermission::Denied );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_permission_from_invalid_str() {
+        NotificationPermission::from_str( "revoked" );
+    }
+
+    #[test]
+    fn test_permission() {
+        if !is_supported() {
+            return;
+        }
+
+        js! { @(no_return)
+            window.__stdweb_notification = Notification;
+            window.Notification = { permission: "granted" };
+        }
+        let granted = Notification::permission();
+        js! { @(no_return)
+            window.Notification.permission = "denied";
+        }
+        let denied = Notification::permission();
+        js! { @(no_return)
+            window.Notification = window.__stdweb_notification;
+            delete window.__stdweb_notification;
+        }
+
+        assert_eq!( granted, NotificationPermission::Granted );
+        assert_eq!( denied, NotificationPermission::Denied );
+    }
+
+    #[test]
+    fn test_data() {
+        if !is_supported() {
+            return;
+        }
+
+        let data = js!( return { answer: 42 }; );
+        let options = NotificationOptions {
+            body: Some( "body" ),
+            tag: Some( "stdweb-test" ),
+            silent: true,
+            data: data,
+            .. NotificationOptions::default()
+        };
+
+        // The browser is free to refuse to show the notification,
+        // e.g. when the permission wasn't granted.
+        let notification = match Notification::new( "title", &options ) {
+            Ok( notification ) => notification,
+            Err( _ ) => return
+        };
+
+        assert_eq!( notification.title(), "title" );
+        assert_eq!( notification.body(), "body" );
+        assert_eq!( notification.tag(), "stdweb-test" );
+
+        let answer: i32 = js!( return @{notification.data()}.answer; ).try_into().unwrap();
+        assert_eq!( answer, 42 );
+        notification.close();
+    }
+}
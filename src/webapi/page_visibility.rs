@@ -0,0 +1,128 @@
+use webapi::document;
+use webapi::event_target::{IEventTarget, EventListenerHandle};
+use webapi::events::dom::VisibilityChangeEvent;
+
+/// The visibility state of a [Document](struct.Document.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/visibilityState)
+// https://w3c.github.io/page-visibility/#visibilitystate-enum
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VisibilityState {
+    /// The page content may be at least partially visible.
+    Visible,
+    /// The page content is not visible to the user, e.g. because the tab
+    /// is in the background or the window is minimized.
+    Hidden,
+    /// Any other state, e.g. the obsolete `prerender`.
+    Unknown( String )
+}
+
+impl VisibilityState {
+    pub(crate) fn from_str( s: &str ) -> Self {
+        match s {
+            "visible" => VisibilityState::Visible,
+            "hidden" => VisibilityState::Hidden,
+            other => VisibilityState::Unknown( other.to_owned() )
+        }
+    }
+}
+
+/// Watches the [visibility state](enum.VisibilityState.html) of the current
+/// [Document](struct.Document.html).
+///
+/// The callback is called immediately with the current state when the watcher
+/// is created, and then every time the state changes. The listener is removed
+/// when the `VisibilityWatcher` is dropped.
+///
+/// # Examples
+///
+/// An animation loop which stops scheduling frames while the page is hidden:
+///
+/// ```rust
+/// fn animate( state: Rc< RefCell< AppState > > ) {
+///     if !state.borrow().visible {
+///         state.borrow_mut().running = false;
+///         return;
+///     }
+///
+///     draw( &state );
+///     window().request_animation_frame( move |_| animate( state ) );
+/// }
+///
+/// let state = Rc::new( RefCell::new( AppState::default() ) );
+/// let watcher = VisibilityWatcher::new({
+///     let state = state.clone();
+///     move |visibility| {
+///         let visible = visibility == VisibilityState::Visible;
+///         let resume = visible && !state.borrow().running;
+///         state.borrow_mut().visible = visible;
+///         if resume {
+///             state.borrow_mut().running = true;
+///             animate( state.clone() );
+///         }
+///     }
+/// });
+/// ```
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Page_Visibility_API)
+#[derive(Debug)]
+pub struct VisibilityWatcher {
+    handle: Option< EventListenerHandle >
+}
+
+impl VisibilityWatcher {
+    /// Creates a new `VisibilityWatcher` which calls `callback` with
+    /// the current visibility state and then on every change.
+    pub fn new< F: FnMut( VisibilityState ) + 'static >( mut callback: F ) -> Self {
+        let document = document::document();
+        callback( document.visibility_state() );
+
+        let handle = document.add_event_listener( move |_: VisibilityChangeEvent| {
+            callback( document::document().visibility_state() );
+        });
+
+        VisibilityWatcher {
+            handle: Some( handle )
+        }
+    }
+}
+
+impl Drop for VisibilityWatcher {
+    fn drop( &mut self ) {
+        if let Some( handle ) = self.handle.take() {
+            handle.remove();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use std::cell::RefCell;
+
+    fn dispatch_visibility_change() {
+        js! { @(no_return)
+            document.dispatchEvent( new Event( "visibilitychange" ) );
+        }
+    }
+
+    #[test]
+    fn test_visibility_watcher() {
+        let states = Rc::new( RefCell::new( Vec::new() ) );
+        let watcher = VisibilityWatcher::new({
+            let states = states.clone();
+            move |state| states.borrow_mut().push( state )
+        });
+
+        let current = document::document().visibility_state();
+        assert_eq!( *states.borrow(), vec![ current.clone() ] );
+
+        dispatch_visibility_change();
+        assert_eq!( *states.borrow(), vec![ current.clone(), current.clone() ] );
+
+        drop( watcher );
+        dispatch_visibility_change();
+        assert_eq!( states.borrow().len(), 2 );
+    }
+}
@@ -0,0 +1,227 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webcore::reference_type::ReferenceType;
+
+/// The `Performance` interface provides access to performance-related
+/// information for the current page.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Performance)
+// https://w3c.github.io/hr-time/#sec-performance
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Performance")]
+pub struct Performance( Reference );
+
+impl Performance {
+    /// Returns a high resolution timestamp in milliseconds, measured
+    /// relative to the time origin of the current page.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Performance/now)
+    // https://w3c.github.io/hr-time/#dom-performance-now
+    pub fn now( &self ) -> f64 {
+        js!( return @{self}.now(); ).try_into().unwrap()
+    }
+
+    /// Returns all of the entries in the performance timeline.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Performance/getEntries)
+    // https://w3c.github.io/performance-timeline/#dom-performance-getentries
+    pub fn get_entries( &self ) -> Vec< PerformanceEntry > {
+        js!( return @{self}.getEntries(); ).try_into().unwrap()
+    }
+
+    /// Returns all of the entries in the performance timeline of the given `entry_type`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Performance/getEntriesByType)
+    // https://w3c.github.io/performance-timeline/#dom-performance-getentriesbytype
+    pub fn get_entries_by_type( &self, entry_type: &str ) -> Vec< PerformanceEntry > {
+        js!( return @{self}.getEntriesByType( @{entry_type} ); ).try_into().unwrap()
+    }
+
+    /// Returns all of the entries in the performance timeline with the given `name`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Performance/getEntriesByName)
+    // https://w3c.github.io/performance-timeline/#dom-performance-getentriesbyname
+    pub fn get_entries_by_name( &self, name: &str ) -> Vec< PerformanceEntry > {
+        js!( return @{self}.getEntriesByName( @{name} ); ).try_into().unwrap()
+    }
+}
+
+/// The `IPerformanceEntry` interface represents a single performance metric
+/// which is part of the performance timeline.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceEntry)
+// https://w3c.github.io/performance-timeline/#the-performanceentry-interface
+pub trait IPerformanceEntry: ReferenceType {
+    /// Returns the name of this entry; for resource entries this is the URL of the resource.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceEntry/name)
+    // https://w3c.github.io/performance-timeline/#dom-performanceentry-name
+    fn name( &self ) -> String {
+        js!( return @{self.as_ref()}.name; ).try_into().unwrap()
+    }
+
+    /// Returns the type of this entry, e.g. `"resource"`, `"mark"` or `"longtask"`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceEntry/entryType)
+    // https://w3c.github.io/performance-timeline/#dom-performanceentry-entrytype
+    fn entry_type( &self ) -> String {
+        js!( return @{self.as_ref()}.entryType; ).try_into().unwrap()
+    }
+
+    /// Returns the time in milliseconds at which this entry has started.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceEntry/startTime)
+    // https://w3c.github.io/performance-timeline/#dom-performanceentry-starttime
+    fn start_time( &self ) -> f64 {
+        js!( return @{self.as_ref()}.startTime; ).try_into().unwrap()
+    }
+
+    /// Returns the duration of this entry in milliseconds.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceEntry/duration)
+    // https://w3c.github.io/performance-timeline/#dom-performanceentry-duration
+    fn duration( &self ) -> f64 {
+        js!( return @{self.as_ref()}.duration; ).try_into().unwrap()
+    }
+}
+
+/// A reference to a JavaScript object which implements the [IPerformanceEntry](trait.IPerformanceEntry.html)
+/// interface.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceEntry)
+// https://w3c.github.io/performance-timeline/#the-performanceentry-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "PerformanceEntry")]
+pub struct PerformanceEntry( Reference );
+
+impl IPerformanceEntry for PerformanceEntry {}
+
+/// Detailed network timing data about the loading of a resource.
+///
+/// All of the timestamps are in milliseconds relative to the time origin of the
+/// current page; for cross-origin resources without a `Timing-Allow-Origin`
+/// header most of them will be zero.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceResourceTiming)
+// https://w3c.github.io/resource-timing/#sec-performanceresourcetiming
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "PerformanceResourceTiming")]
+#[reference(subclass_of(PerformanceEntry))]
+pub struct PerformanceResourceTiming( Reference );
+
+impl IPerformanceEntry for PerformanceResourceTiming {}
+
+impl PerformanceResourceTiming {
+    /// Returns the type of the object which has initiated the load, e.g. `"xmlhttprequest"` or `"img"`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceResourceTiming/initiatorType)
+    // https://w3c.github.io/resource-timing/#dom-performanceresourcetiming-initiatortype
+    pub fn initiator_type( &self ) -> String {
+        js!( return @{self}.initiatorType; ).try_into().unwrap()
+    }
+
+    /// Returns the size in bytes of the fetched resource, including the headers.
+    ///
+    /// This is zero if the resource was fetched from a local cache.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceResourceTiming/transferSize)
+    // https://w3c.github.io/resource-timing/#dom-performanceresourcetiming-transfersize
+    pub fn transfer_size( &self ) -> u64 {
+        js!( return @{self}.transferSize || 0; ).try_into().unwrap()
+    }
+
+    /// Returns the time immediately before the browser started to fetch the resource.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceResourceTiming/fetchStart)
+    // https://w3c.github.io/resource-timing/#dom-performanceresourcetiming-fetchstart
+    pub fn fetch_start( &self ) -> f64 {
+        js!( return @{self}.fetchStart; ).try_into().unwrap()
+    }
+
+    /// Returns the time immediately before the browser started the domain name lookup.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceResourceTiming/domainLookupStart)
+    // https://w3c.github.io/resource-timing/#dom-performanceresourcetiming-domainlookupstart
+    pub fn domain_lookup_start( &self ) -> f64 {
+        js!( return @{self}.domainLookupStart; ).try_into().unwrap()
+    }
+
+    /// Returns the time immediately after the browser finished the domain name lookup.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceResourceTiming/domainLookupEnd)
+    // https://w3c.github.io/resource-timing/#dom-performanceresourcetiming-domainlookupend
+    pub fn domain_lookup_end( &self ) -> f64 {
+        js!( return @{self}.domainLookupEnd; ).try_into().unwrap()
+    }
+
+    /// Returns the time immediately before the browser started to establish the connection to the server.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceResourceTiming/connectStart)
+    // https://w3c.github.io/resource-timing/#dom-performanceresourcetiming-connectstart
+    pub fn connect_start( &self ) -> f64 {
+        js!( return @{self}.connectStart; ).try_into().unwrap()
+    }
+
+    /// Returns the time immediately before the browser started the TLS handshake,
+    /// or zero if the connection isn't secure.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceResourceTiming/secureConnectionStart)
+    // https://w3c.github.io/resource-timing/#dom-performanceresourcetiming-secureconnectionstart
+    pub fn secure_connection_start( &self ) -> f64 {
+        js!( return @{self}.secureConnectionStart || 0; ).try_into().unwrap()
+    }
+
+    /// Returns the time immediately after the browser finished establishing the connection to the server.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceResourceTiming/connectEnd)
+    // https://w3c.github.io/resource-timing/#dom-performanceresourcetiming-connectend
+    pub fn connect_end( &self ) -> f64 {
+        js!( return @{self}.connectEnd; ).try_into().unwrap()
+    }
+
+    /// Returns the time immediately before the browser started requesting the resource.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceResourceTiming/requestStart)
+    // https://w3c.github.io/resource-timing/#dom-performanceresourcetiming-requeststart
+    pub fn request_start( &self ) -> f64 {
+        js!( return @{self}.requestStart; ).try_into().unwrap()
+    }
+
+    /// Returns the time immediately after the browser received the first byte of the response.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceResourceTiming/responseStart)
+    // https://w3c.github.io/resource-timing/#dom-performanceresourcetiming-responsestart
+    pub fn response_start( &self ) -> f64 {
+        js!( return @{self}.responseStart; ).try_into().unwrap()
+    }
+
+    /// Returns the time immediately after the browser received the last byte of the response.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceResourceTiming/responseEnd)
+    // https://w3c.github.io/resource-timing/#dom-performanceresourcetiming-responseend
+    pub fn response_end( &self ) -> f64 {
+        js!( return @{self}.responseEnd; ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::window::window;
+
+    #[test]
+    fn test_entries() {
+        js! { @(no_return)
+            performance.mark( "stdweb-test-entries" );
+        }
+
+        let performance = window().performance();
+        assert!( performance.now() > 0.0 );
+
+        let entries = performance.get_entries_by_name( "stdweb-test-entries" );
+        assert_eq!( entries.len(), 1 );
+        assert_eq!( entries[ 0 ].name(), "stdweb-test-entries" );
+        assert_eq!( entries[ 0 ].entry_type(), "mark" );
+        assert!( performance.get_entries_by_type( "mark" ).contains( &entries[ 0 ] ) );
+    }
+}
@@ -0,0 +1,239 @@
+use std;
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::performance::PerformanceEntry;
+use webapi::error::TypeError;
+
+/// Provides a way to receive notifications about new entries
+/// being recorded in the performance timeline.
+///
+/// # Examples
+///
+/// Logging all of the long tasks, including those which happened
+/// before the observer was created:
+///
+/// ```rust
+/// let observer = PerformanceObserver::new( |entries, _| {
+///     for entry in entries.get_entries() {
+///         console!( warn, format!( "Long task: {}ms", entry.duration() ) );
+///     }
+/// });
+///
+/// observer.observe( &ObserveOptions {
+///     entry_types: &[ "longtask" ],
+///     buffered: true
+/// }).unwrap();
+///
+/// // Keep the observer alive for the lifetime of the page.
+/// std::mem::forget( observer );
+/// ```
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceObserver)
+// https://w3c.github.io/performance-timeline/#the-performanceobserver-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "PerformanceObserver")]
+pub struct PerformanceObserver( Reference );
+
+/// Specifies which entries should be observed by a [`PerformanceObserver`](struct.PerformanceObserver.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceObserver/observe#Parameters)
+// https://w3c.github.io/performance-timeline/#dom-performanceobserverinit
+#[derive(Clone, Debug)]
+pub struct ObserveOptions< 'a > {
+    /// The types of the entries to observe, e.g. `"longtask"`, `"resource"` or `"measure"`.
+    pub entry_types: &'a [&'a str],
+
+    /// If `true` the entries which were recorded before the observer was
+    /// created will also be delivered.
+    pub buffered: bool
+}
+
+impl PerformanceObserver {
+    /// Returns a new [`PerformanceObserverHandle`](struct.PerformanceObserverHandle.html) with the given callback.
+    ///
+    /// The callback will be called with the following arguments when new
+    /// entries of the observed types are recorded:
+    ///
+    /// 1. A list of the new entries.
+    ///
+    /// 2. The `PerformanceObserver`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceObserver/PerformanceObserver)
+    // https://w3c.github.io/performance-timeline/#dom-performanceobserver-constructor
+    pub fn new< F >( callback: F ) -> PerformanceObserverHandle
+        where F: FnMut( PerformanceObserverEntryList, Self ) + 'static {
+        let callback_reference: Reference = js! ( return @{callback}; ).try_into().unwrap();
+
+        PerformanceObserverHandle {
+            callback_reference: callback_reference.clone(),
+
+            performance_observer: js! (
+                return new PerformanceObserver( @{callback_reference} );
+            ).try_into().unwrap()
+        }
+    }
+
+    /// Starts observing the entries of the given types.
+    ///
+    /// Browsers differ in how they treat unsupported entry types; some ignore
+    /// them while others throw. This method ignores the unsupported types and
+    /// returns an error only if **none** of the given types are supported.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceObserver/observe)
+    // https://w3c.github.io/performance-timeline/#dom-performanceobserver-observe
+    pub fn observe( &self, options: &ObserveOptions ) -> Result< (), TypeError > {
+        js_try!( @(no_return)
+            var observer = @{self};
+            var entry_types = @{options.entry_types};
+            var buffered = @{options.buffered};
+
+            var supported = PerformanceObserver.supportedEntryTypes;
+            if( supported ) {
+                entry_types = entry_types.filter( function( entry_type ) {
+                    return supported.indexOf( entry_type ) !== -1;
+                });
+            }
+
+            if( entry_types.length === 0 ) {
+                throw new TypeError( "None of the given performance entry types are supported" );
+            }
+
+            if( buffered ) {
+                // The `buffered` flag is only supported when observing a single type.
+                for( var i = 0; i < entry_types.length; ++i ) {
+                    observer.observe( { type: entry_types[ i ], buffered: true } );
+                }
+            } else {
+                observer.observe( { entryTypes: entry_types } );
+            }
+        ).unwrap()
+    }
+
+    /// Stops observing all entries.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceObserver/disconnect)
+    // https://w3c.github.io/performance-timeline/#dom-performanceobserver-disconnect
+    pub fn disconnect( &self ) {
+        js! { @(no_return)
+            @{self}.disconnect();
+        }
+    }
+
+    /// Empties the `PerformanceObserver`'s queue of pending entries and returns what was in there.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceObserver/takeRecords)
+    // https://w3c.github.io/performance-timeline/#dom-performanceobserver-takerecords
+    pub fn take_records( &self ) -> Vec< PerformanceEntry > {
+        js!( return @{self}.takeRecords(); ).try_into().unwrap()
+    }
+}
+
+/// A wrapper which ensures that memory is properly cleaned up when it's no longer needed.
+///
+/// This is created by the [`PerformanceObserver::new`](struct.PerformanceObserver.html#method.new) method, and
+/// it can use the same methods as [`PerformanceObserver`](struct.PerformanceObserver.html).
+///
+/// When the `PerformanceObserverHandle` is dropped, the [`disconnect`](struct.PerformanceObserver.html#method.disconnect)
+/// method will automatically be called.
+#[derive(Debug)]
+pub struct PerformanceObserverHandle {
+    performance_observer: PerformanceObserver,
+    callback_reference: Reference
+}
+
+impl std::ops::Deref for PerformanceObserverHandle {
+    type Target = PerformanceObserver;
+
+    #[inline]
+    fn deref( &self ) -> &Self::Target {
+        &self.performance_observer
+    }
+}
+
+impl Drop for PerformanceObserverHandle {
+    #[inline]
+    fn drop( &mut self ) {
+        self.disconnect();
+
+        js! { @(no_return)
+            @{&self.callback_reference}.drop();
+        }
+    }
+}
+
+/// A list of the entries delivered to a [`PerformanceObserver`](struct.PerformanceObserver.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceObserverEntryList)
+// https://w3c.github.io/performance-timeline/#performanceobserverentrylist-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "PerformanceObserverEntryList")]
+pub struct PerformanceObserverEntryList( Reference );
+
+impl PerformanceObserverEntryList {
+    /// Returns all of the entries in this list.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceObserverEntryList/getEntries)
+    // https://w3c.github.io/performance-timeline/#dom-performanceobserverentrylist-getentries
+    pub fn get_entries( &self ) -> Vec< PerformanceEntry > {
+        js!( return @{self}.getEntries(); ).try_into().unwrap()
+    }
+
+    /// Returns all of the entries in this list of the given `entry_type`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceObserverEntryList/getEntriesByType)
+    // https://w3c.github.io/performance-timeline/#dom-performanceobserverentrylist-getentriesbytype
+    pub fn get_entries_by_type( &self, entry_type: &str ) -> Vec< PerformanceEntry > {
+        js!( return @{self}.getEntriesByType( @{entry_type} ); ).try_into().unwrap()
+    }
+
+    /// Returns all of the entries in this list with the given `name`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceObserverEntryList/getEntriesByName)
+    // https://w3c.github.io/performance-timeline/#dom-performanceobserverentrylist-getentriesbyname
+    pub fn get_entries_by_name( &self, name: &str ) -> Vec< PerformanceEntry > {
+        js!( return @{self}.getEntriesByName( @{name} ); ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::performance::IPerformanceEntry;
+    use webapi::error::IError;
+
+    #[test]
+    fn test_unsupported_entry_types() {
+        let observer = PerformanceObserver::new( |_, _| {} );
+        let result = observer.observe( &ObserveOptions {
+            entry_types: &[ "stdweb-unknown" ],
+            buffered: false
+        });
+
+        assert_eq!( result.unwrap_err().name(), "TypeError" );
+
+        let result = observer.observe( &ObserveOptions {
+            entry_types: &[],
+            buffered: false
+        });
+
+        assert_eq!( result.unwrap_err().name(), "TypeError" );
+    }
+
+    #[test]
+    fn test_take_records() {
+        let observer = PerformanceObserver::new( |_, _| {} );
+        observer.observe( &ObserveOptions {
+            entry_types: &[ "stdweb-unknown", "mark" ],
+            buffered: false
+        }).unwrap();
+
+        js! { @(no_return)
+            performance.mark( "stdweb-test-observer" );
+        }
+
+        let records = observer.take_records();
+        assert_eq!( records.len(), 1 );
+        assert_eq!( records[ 0 ].name(), "stdweb-test-observer" );
+        assert!( observer.take_records().is_empty() );
+    }
+}
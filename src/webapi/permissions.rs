@@ -0,0 +1,215 @@
+use std::fmt;
+use std::error;
+use webcore::value::{Reference, ConversionError};
+use webcore::try_from::TryInto;
+use webcore::promise::Promise;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::error::{IError, Error};
+
+/// The state of a permission.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PermissionStatus/state)
+// https://w3c.github.io/permissions/#dom-permissionstate
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PermissionState {
+    /// The user has granted the permission.
+    Granted,
+    /// The user has denied the permission.
+    Denied,
+    /// The user will be asked for the permission once it's needed.
+    Prompt
+}
+
+impl PermissionState {
+    pub(crate) fn from_str( s: &str ) -> Result< Self, ConversionError > {
+        match s {
+            "granted" => Ok( PermissionState::Granted ),
+            "denied" => Ok( PermissionState::Denied ),
+            "prompt" | "default" => Ok( PermissionState::Prompt ),
+            other => Err( ConversionError::Custom( format!( "Unknown permission state: {:?}", other ) ) )
+        }
+    }
+}
+
+/// A permission which can be queried with [Permissions::query](struct.Permissions.html#method.query).
+///
+/// Not every browser supports querying every permission; querying an
+/// unsupported one fails with
+/// [UnsupportedPermission](enum.PermissionQueryError.html#variant.UnsupportedPermission).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Permissions/query#Parameters)
+// https://w3c.github.io/permissions/#permission-registry
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PermissionDescriptor {
+    /// The permission to access the location of the user.
+    Geolocation,
+    /// The permission to display notifications.
+    Notifications,
+    /// The permission to use the cameras.
+    Camera,
+    /// The permission to use the microphones.
+    Microphone,
+    /// The permission to read from the clipboard.
+    ClipboardRead,
+    /// The permission to write into the clipboard.
+    ClipboardWrite
+}
+
+impl PermissionDescriptor {
+    fn name( &self ) -> &'static str {
+        match *self {
+            PermissionDescriptor::Geolocation => "geolocation",
+            PermissionDescriptor::Notifications => "notifications",
+            PermissionDescriptor::Camera => "camera",
+            PermissionDescriptor::Microphone => "microphone",
+            PermissionDescriptor::ClipboardRead => "clipboard-read",
+            PermissionDescriptor::ClipboardWrite => "clipboard-write"
+        }
+    }
+}
+
+/// An error which can occur when querying a permission with
+/// [Permissions::query](struct.Permissions.html#method.query).
+#[derive(Clone, Debug)]
+pub enum PermissionQueryError {
+    /// The browser doesn't support querying the given permission.
+    UnsupportedPermission( Error ),
+    /// Any other error.
+    Error( Error )
+}
+
+impl fmt::Display for PermissionQueryError {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        match *self {
+            PermissionQueryError::UnsupportedPermission( ref error ) => error.fmt( formatter ),
+            PermissionQueryError::Error( ref error ) => error.fmt( formatter )
+        }
+    }
+}
+
+impl error::Error for PermissionQueryError {
+    fn description( &self ) -> &str {
+        match *self {
+            PermissionQueryError::UnsupportedPermission( _ ) => "unsupported permission",
+            PermissionQueryError::Error( _ ) => "PermissionQueryError"
+        }
+    }
+}
+
+/// The `Permissions` interface provides a way to query the status of permissions.
+///
+/// # Examples
+///
+/// Explaining to the user why the location is needed before the browser asks for it:
+///
+/// ```rust
+/// let permissions = window().navigator().permissions().unwrap();
+/// permissions.query( PermissionDescriptor::Geolocation, |result| {
+///     match result.ok().and_then( |status| status.state().ok() ) {
+///         Some( PermissionState::Granted ) => show_nearby_shops(),
+///         Some( PermissionState::Prompt ) => {
+///             // Show some UI explaining why the location is needed;
+///             // `show_nearby_shops` will trigger the browser's prompt.
+///             show_location_explanation( show_nearby_shops );
+///         },
+///         Some( PermissionState::Denied ) | None => show_manual_location_form()
+///     }
+/// });
+/// ```
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Permissions)
+// https://w3c.github.io/permissions/#permissions-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Permissions")]
+pub struct Permissions( Reference );
+
+impl Permissions {
+    /// Queries the state of the given permission.
+    ///
+    /// The `callback` is called with the status of the permission, which can
+    /// also be used to listen for changes of the permission.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Permissions/query)
+    // https://w3c.github.io/permissions/#dom-permissions-query
+    pub fn query< F >( &self, descriptor: PermissionDescriptor, callback: F )
+        where F: FnOnce( Result< PermissionStatus, PermissionQueryError > ) + 'static
+    {
+        let promise: Promise = js!(
+            return @{self}.query( { name: @{descriptor.name()} } );
+        ).try_into().unwrap();
+
+        promise.done( move |result: Result< PermissionStatus, Error >| {
+            callback( result.map_err( |error| {
+                if error.name() == "TypeError" {
+                    PermissionQueryError::UnsupportedPermission( error )
+                } else {
+                    PermissionQueryError::Error( error )
+                }
+            }));
+        });
+    }
+}
+
+/// The `PermissionStatus` interface represents the state of a permission.
+///
+/// It fires a [ChangeEvent](struct.ChangeEvent.html) whenever the state
+/// changes, e.g. when the user changes the permission in the browser's settings.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PermissionStatus)
+// https://w3c.github.io/permissions/#permissionstatus-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "PermissionStatus")]
+#[reference(subclass_of(EventTarget))]
+pub struct PermissionStatus( Reference );
+
+impl IEventTarget for PermissionStatus {}
+
+impl PermissionStatus {
+    /// Returns the current state of the permission.
+    ///
+    /// Fails if the browser reports a state which isn't known to this crate.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PermissionStatus/state)
+    // https://w3c.github.io/permissions/#dom-permissionstatus-state
+    pub fn state( &self ) -> Result< PermissionState, ConversionError > {
+        // Older browsers only support the `status` property.
+        let state: String = js!(
+            var status = @{self};
+            return status.state || status.status;
+        ).try_into().unwrap();
+
+        PermissionState::from_str( &state )
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webcore::async_test::async_test;
+    use webapi::notification::{Notification, NotificationPermission};
+
+    #[test]
+    fn test_permission_state() {
+        assert_eq!( PermissionState::from_str( "granted" ), Ok( PermissionState::Granted ) );
+        assert_eq!( PermissionState::from_str( "denied" ), Ok( PermissionState::Denied ) );
+        assert_eq!( PermissionState::from_str( "prompt" ), Ok( PermissionState::Prompt ) );
+        assert!( PermissionState::from_str( "revoked" ).is_err() );
+    }
+
+    #[test]
+    fn test_query() {
+        let permissions: Option< Permissions > = js!( return navigator.permissions; ).try_into().unwrap();
+        let permissions = match permissions {
+            Some( permissions ) => permissions,
+            None => return
+        };
+
+        async_test( "test_query", 5000, move |done| {
+            permissions.query( PermissionDescriptor::Notifications, move |result| {
+                let state = result.unwrap().state().unwrap();
+                assert_eq!( state == PermissionState::Granted, Notification::permission() == NotificationPermission::Granted );
+                done.done();
+            });
+        });
+    }
+}
@@ -0,0 +1,538 @@
+use std::fmt;
+use std::error;
+use std::rc::{Rc, Weak};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventListenerHandle};
+use webapi::events::socket::{SocketOpenEvent, SocketCloseEvent, SocketMessageEvent};
+use webapi::web_socket::{WebSocket, SocketBinaryType, CreationError};
+use webapi::global::set_timeout;
+
+/// Describes how long a [ReconnectingWebSocket](struct.ReconnectingWebSocket.html)
+/// waits before each reconnection attempt.
+///
+/// The delay starts at `initial_ms` and is multiplied by `multiplier` after every
+/// failed attempt, up to `max_ms`. Each delay is then randomly adjusted by up to
+/// `jitter` (e.g. `0.2` for ±20%) so that many clients don't reconnect all at once.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BackoffConfig {
+    /// The delay before the first reconnection attempt, in milliseconds.
+    pub initial_ms: u32,
+    /// The maximum delay between two attempts, in milliseconds.
+    pub max_ms: u32,
+    /// The factor by which the delay grows after every failed attempt.
+    pub multiplier: f64,
+    /// The fraction of the delay by which it's randomly adjusted, between `0.0` and `1.0`.
+    pub jitter: f64
+}
+
+impl Default for BackoffConfig {
+    fn default() -> Self {
+        BackoffConfig {
+            initial_ms: 500,
+            max_ms: 30_000,
+            multiplier: 2.0,
+            jitter: 0.2
+        }
+    }
+}
+
+impl BackoffConfig {
+    // The `random` number must be in the `[0, 1)` range.
+    fn delay_ms( &self, attempt: u32, random: f64 ) -> u32 {
+        let max = self.max_ms as f64;
+        let delay = (self.initial_ms as f64 * self.multiplier.powi( attempt as i32 )).min( max );
+        let jitter = self.jitter.max( 0.0 ).min( 1.0 );
+        let delay = delay * (1.0 + jitter * (2.0 * random - 1.0));
+        delay.max( 0.0 ).min( max ).round() as u32
+    }
+}
+
+/// The state of the connection of a [ReconnectingWebSocket](struct.ReconnectingWebSocket.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConnectionState {
+    /// The first connection is being established.
+    Connecting,
+    /// The connection is open.
+    Open,
+    /// The connection was lost and is being re-established.
+    Reconnecting,
+    /// The socket was closed by [close](struct.ReconnectingWebSocket.html#method.close)
+    /// and will never reconnect.
+    Closed
+}
+
+/// An error returned when sending a message through a
+/// [ReconnectingWebSocket](struct.ReconnectingWebSocket.html) has failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SendError {
+    /// The socket is disconnected and queueing the message would exceed
+    /// the [byte budget](struct.ReconnectingWebSocket.html#method.set_max_queued_bytes).
+    QueueFull,
+    /// The socket was closed.
+    Closed
+}
+
+impl fmt::Display for SendError {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        match *self {
+            SendError::QueueFull => write!( formatter, "the queue of unsent messages is full" ),
+            SendError::Closed => write!( formatter, "the socket was closed" )
+        }
+    }
+}
+
+impl error::Error for SendError {
+    fn description( &self ) -> &str {
+        match *self {
+            SendError::QueueFull => "queue is full",
+            SendError::Closed => "socket was closed"
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Message {
+    Text( String ),
+    Bytes( Vec< u8 > )
+}
+
+impl Message {
+    fn len( &self ) -> usize {
+        match *self {
+            Message::Text( ref text ) => text.len(),
+            Message::Bytes( ref bytes ) => bytes.len()
+        }
+    }
+}
+
+#[derive(Debug)]
+struct MessageQueue {
+    messages: VecDeque< Message >,
+    bytes: usize,
+    max_bytes: usize
+}
+
+impl MessageQueue {
+    fn new( max_bytes: usize ) -> Self {
+        MessageQueue {
+            messages: VecDeque::new(),
+            bytes: 0,
+            max_bytes: max_bytes
+        }
+    }
+
+    fn push( &mut self, message: Message ) -> Result< (), SendError > {
+        if self.bytes + message.len() > self.max_bytes {
+            return Err( SendError::QueueFull );
+        }
+
+        self.bytes += message.len();
+        self.messages.push_back( message );
+        Ok(())
+    }
+
+    fn pop( &mut self ) -> Option< Message > {
+        let message = self.messages.pop_front()?;
+        self.bytes -= message.len();
+        Some( message )
+    }
+
+    fn clear( &mut self ) {
+        self.messages.clear();
+        self.bytes = 0;
+    }
+}
+
+struct State {
+    url: String,
+    protocols: Vec< String >,
+    backoff: BackoffConfig,
+    connection: ConnectionState,
+    attempt: u32,
+    socket: Option< WebSocket >,
+    listeners: Vec< EventListenerHandle >,
+    queue: MessageQueue
+}
+
+struct Shared {
+    state: RefCell< State >,
+    on_open: RefCell< Option< Box< dyn FnMut() > > >,
+    on_close: RefCell< Option< Box< dyn FnMut( SocketCloseEvent ) > > >,
+    on_message: RefCell< Option< Box< dyn FnMut( SocketMessageEvent ) > > >,
+    on_state_change: RefCell< Option< Box< dyn FnMut( ConnectionState ) > > >
+}
+
+// The callbacks are never called while the state or their own slot is borrowed,
+// since they might want to send messages, close the socket or replace themselves.
+// A callback which is already running isn't called again from within itself.
+fn call< F: ?Sized, C: FnOnce( &mut F ) >( slot: &RefCell< Option< Box< F > > >, invoke: C ) {
+    let callback = slot.borrow_mut().take();
+    if let Some( mut callback ) = callback {
+        invoke( &mut *callback );
+
+        let mut slot = slot.borrow_mut();
+        if slot.is_none() {
+            *slot = Some( callback );
+        }
+    }
+}
+
+fn set_connection( shared: &Shared, connection: ConnectionState ) {
+    shared.state.borrow_mut().connection = connection;
+    call( &shared.on_state_change, |callback| callback( connection ) );
+}
+
+fn send_message( socket: &WebSocket, message: &Message ) {
+    match *message {
+        Message::Text( ref text ) => socket.send_text( text ).unwrap(),
+        Message::Bytes( ref bytes ) => socket.send_bytes( bytes ).unwrap()
+    }
+}
+
+fn connect( shared: &Rc< Shared > ) -> Result< (), CreationError > {
+    let socket = {
+        let mut state = shared.state.borrow_mut();
+
+        // The listeners of the previous socket are only removed here,
+        // since its close event is what triggered the reconnection.
+        for listener in state.listeners.drain( .. ) {
+            listener.remove();
+        }
+
+        let protocols: Vec< &str > = state.protocols.iter().map( |protocol| protocol.as_str() ).collect();
+        let socket = WebSocket::new_with_protocols( &state.url, &protocols )?;
+        socket.set_binary_type( SocketBinaryType::ArrayBuffer );
+        state.socket = Some( socket.clone() );
+        socket
+    };
+
+    let weak = Rc::downgrade( shared );
+    let on_open = socket.add_event_listener( move |_: SocketOpenEvent| {
+        if let Some( shared ) = weak.upgrade() {
+            handle_open( &shared );
+        }
+    });
+
+    let weak = Rc::downgrade( shared );
+    let on_message = socket.add_event_listener( move |event: SocketMessageEvent| {
+        if let Some( shared ) = weak.upgrade() {
+            call( &shared.on_message, |callback| callback( event ) );
+        }
+    });
+
+    // An error event is always followed by a close event, so only the latter is handled.
+    let weak = Rc::downgrade( shared );
+    let on_close = socket.add_event_listener( move |event: SocketCloseEvent| {
+        if let Some( shared ) = weak.upgrade() {
+            handle_close( &shared, event );
+        }
+    });
+
+    shared.state.borrow_mut().listeners.extend( vec![ on_open, on_message, on_close ] );
+    Ok(())
+}
+
+fn handle_open( shared: &Rc< Shared > ) {
+    {
+        let mut state = shared.state.borrow_mut();
+        state.attempt = 0;
+        let socket = state.socket.clone().unwrap();
+        while let Some( message ) = state.queue.pop() {
+            send_message( &socket, &message );
+        }
+    }
+
+    set_connection( shared, ConnectionState::Open );
+    call( &shared.on_open, |callback| callback() );
+}
+
+fn handle_close( shared: &Rc< Shared >, event: SocketCloseEvent ) {
+    call( &shared.on_close, |callback| callback( event ) );
+
+    if shared.state.borrow().connection == ConnectionState::Closed {
+        return;
+    }
+
+    shared.state.borrow_mut().socket = None;
+    set_connection( shared, ConnectionState::Reconnecting );
+    schedule_reconnect( shared );
+}
+
+fn schedule_reconnect( shared: &Rc< Shared > ) {
+    let delay = {
+        let mut state = shared.state.borrow_mut();
+        let random: f64 = js!( return Math.random(); ).try_into().unwrap();
+        let delay = state.backoff.delay_ms( state.attempt, random );
+        state.attempt += 1;
+        delay
+    };
+
+    let weak: Weak< Shared > = Rc::downgrade( shared );
+    set_timeout( move || {
+        if let Some( shared ) = weak.upgrade() {
+            if shared.state.borrow().connection == ConnectionState::Reconnecting {
+                // The URL was already valid once, so this can only fail if it's no longer
+                // allowed, e.g. by a changed security policy; that's retried as well.
+                if connect( &shared ).is_err() {
+                    schedule_reconnect( &shared );
+                }
+            }
+        }
+    }, delay );
+}
+
+/// A wrapper around a [WebSocket](struct.WebSocket.html) which transparently
+/// reconnects whenever the connection is lost.
+///
+/// Reconnection attempts are spaced out according to a [BackoffConfig](struct.BackoffConfig.html).
+/// Messages sent while disconnected are queued, up to a byte budget, and are sent
+/// in order once the connection is re-established. Binary messages are received
+/// as an `ArrayBuffer`.
+///
+/// The connection stays up until [close](#method.close) is called or the
+/// `ReconnectingWebSocket` is dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// let socket = ReconnectingWebSocket::new( "wss://example.com/feed", &[], BackoffConfig::default() ).unwrap();
+/// socket.on_state_change( |state| console!( log, format!( "{:?}", state ) ) );
+/// socket.on_message( |event| console!( log, event.data().into_text() ) );
+/// socket.send_str( "subscribe" ).unwrap();
+/// ```
+// https://html.spec.whatwg.org/#websocket
+pub struct ReconnectingWebSocket {
+    shared: Rc< Shared >
+}
+
+impl fmt::Debug for ReconnectingWebSocket {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        write!( formatter, "ReconnectingWebSocket" )
+    }
+}
+
+impl ReconnectingWebSocket {
+    /// Creates a new `ReconnectingWebSocket` and starts connecting to the `url`.
+    ///
+    /// By default up to 1 MiB of messages are queued while disconnected.
+    pub fn new( url: &str, protocols: &[&str], backoff: BackoffConfig ) -> Result< Self, CreationError > {
+        let shared = Rc::new( Shared {
+            state: RefCell::new( State {
+                url: url.to_owned(),
+                protocols: protocols.iter().map( |&protocol| protocol.to_owned() ).collect(),
+                backoff: backoff,
+                connection: ConnectionState::Connecting,
+                attempt: 0,
+                socket: None,
+                listeners: Vec::new(),
+                queue: MessageQueue::new( 1024 * 1024 )
+            }),
+            on_open: RefCell::new( None ),
+            on_close: RefCell::new( None ),
+            on_message: RefCell::new( None ),
+            on_state_change: RefCell::new( None )
+        });
+
+        connect( &shared )?;
+        Ok( ReconnectingWebSocket {
+            shared: shared
+        })
+    }
+
+    /// Sets the maximum total size, in bytes, of the messages queued while disconnected.
+    pub fn set_max_queued_bytes( &self, max_bytes: usize ) {
+        self.shared.state.borrow_mut().queue.max_bytes = max_bytes;
+    }
+
+    /// Returns the total size, in bytes, of the messages waiting to be sent.
+    pub fn queued_bytes( &self ) -> usize {
+        self.shared.state.borrow().queue.bytes
+    }
+
+    /// Returns the current state of the connection.
+    pub fn state( &self ) -> ConnectionState {
+        self.shared.state.borrow().connection
+    }
+
+    /// Sets the callback called every time a connection is opened.
+    pub fn on_open< F: FnMut() + 'static >( &self, callback: F ) {
+        *self.shared.on_open.borrow_mut() = Some( Box::new( callback ) );
+    }
+
+    /// Sets the callback called every time a connection is closed,
+    /// whenever it will be re-established or not.
+    pub fn on_close< F: FnMut( SocketCloseEvent ) + 'static >( &self, callback: F ) {
+        *self.shared.on_close.borrow_mut() = Some( Box::new( callback ) );
+    }
+
+    /// Sets the callback called for every received message.
+    pub fn on_message< F: FnMut( SocketMessageEvent ) + 'static >( &self, callback: F ) {
+        *self.shared.on_message.borrow_mut() = Some( Box::new( callback ) );
+    }
+
+    /// Sets the callback called every time the [state](#method.state) of the connection changes.
+    pub fn on_state_change< F: FnMut( ConnectionState ) + 'static >( &self, callback: F ) {
+        *self.shared.on_state_change.borrow_mut() = Some( Box::new( callback ) );
+    }
+
+    fn send( &self, message: Message ) -> Result< (), SendError > {
+        let mut state = self.shared.state.borrow_mut();
+        match state.connection {
+            ConnectionState::Open => {
+                send_message( state.socket.as_ref().unwrap(), &message );
+                Ok(())
+            },
+            ConnectionState::Connecting | ConnectionState::Reconnecting => state.queue.push( message ),
+            ConnectionState::Closed => Err( SendError::Closed )
+        }
+    }
+
+    /// Sends a text message, or queues it if currently disconnected.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WebSocket/send)
+    // https://html.spec.whatwg.org/#the-websocket-interface:dom-websocket-send
+    pub fn send_str( &self, text: &str ) -> Result< (), SendError > {
+        self.send( Message::Text( text.to_owned() ) )
+    }
+
+    /// Sends a binary message, or queues it if currently disconnected.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WebSocket/send)
+    // https://html.spec.whatwg.org/#the-websocket-interface:dom-websocket-send
+    pub fn send_bytes( &self, bytes: &[u8] ) -> Result< (), SendError > {
+        self.send( Message::Bytes( bytes.to_vec() ) )
+    }
+
+    /// Closes the connection for good; it won't be re-established anymore
+    /// and all of the queued messages are discarded.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WebSocket/close)
+    // https://html.spec.whatwg.org/#the-websocket-interface:dom-websocket-close
+    pub fn close( &self ) {
+        let socket = {
+            let mut state = self.shared.state.borrow_mut();
+            if state.connection == ConnectionState::Closed {
+                return;
+            }
+
+            state.queue.clear();
+            state.socket.take()
+        };
+
+        set_connection( &self.shared, ConnectionState::Closed );
+        if let Some( socket ) = socket {
+            socket.close();
+        }
+    }
+}
+
+impl Drop for ReconnectingWebSocket {
+    fn drop( &mut self ) {
+        self.close();
+        for listener in self.shared.state.borrow_mut().listeners.drain( .. ) {
+            listener.remove();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_schedule() {
+        let backoff = BackoffConfig {
+            initial_ms: 100,
+            max_ms: 1000,
+            multiplier: 2.0,
+            jitter: 0.0
+        };
+
+        let schedule: Vec< u32 > = (0..6).map( |attempt| backoff.delay_ms( attempt, 0.5 ) ).collect();
+        assert_eq!( schedule, vec![ 100, 200, 400, 800, 1000, 1000 ] );
+    }
+
+    #[test]
+    fn test_backoff_jitter() {
+        let backoff = BackoffConfig {
+            jitter: 0.25,
+            ..BackoffConfig::default()
+        };
+
+        assert_eq!( backoff.delay_ms( 0, 0.0 ), 375 );
+        assert_eq!( backoff.delay_ms( 0, 0.5 ), 500 );
+        assert_eq!( backoff.delay_ms( 0, 0.999999 ), 625 );
+        assert_eq!( backoff.delay_ms( 20, 0.999999 ), 30_000 );
+    }
+
+    #[test]
+    fn test_message_queue_budget() {
+        let mut queue = MessageQueue::new( 8 );
+        assert_eq!( queue.push( Message::Text( "abcd".to_owned() ) ), Ok(()) );
+        assert_eq!( queue.push( Message::Bytes( vec![ 1, 2, 3, 4, 5 ] ) ), Err( SendError::QueueFull ) );
+        assert_eq!( queue.push( Message::Bytes( vec![ 1, 2, 3, 4 ] ) ), Ok(()) );
+        assert_eq!( queue.bytes, 8 );
+
+        assert_eq!( queue.pop(), Some( Message::Text( "abcd".to_owned() ) ) );
+        assert_eq!( queue.bytes, 4 );
+        queue.clear();
+        assert_eq!( queue.bytes, 0 );
+        assert_eq!( queue.pop(), None );
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn test_permanent_close() {
+        let states = Rc::new( RefCell::new( Vec::new() ) );
+        let socket = ReconnectingWebSocket::new( "ws://localhost:1", &[], BackoffConfig::default() ).unwrap();
+        socket.on_state_change( {
+            let states = states.clone();
+            move |state| states.borrow_mut().push( state )
+        });
+
+        assert_eq!( socket.state(), ConnectionState::Connecting );
+        socket.set_max_queued_bytes( 4 );
+        assert_eq!( socket.send_str( "ping" ), Ok(()) );
+        assert_eq!( socket.send_bytes( &[ 1 ] ), Err( SendError::QueueFull ) );
+        assert_eq!( socket.queued_bytes(), 4 );
+
+        socket.close();
+        assert_eq!( socket.state(), ConnectionState::Closed );
+        assert_eq!( socket.queued_bytes(), 0 );
+        assert_eq!( socket.send_str( "ping" ), Err( SendError::Closed ) );
+
+        socket.close();
+        assert_eq!( *states.borrow(), vec![ ConnectionState::Closed ] );
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn test_close_from_callback() {
+        let socket = Rc::new( ReconnectingWebSocket::new( "ws://localhost:1", &[], BackoffConfig::default() ).unwrap() );
+        let states = Rc::new( RefCell::new( Vec::new() ) );
+        socket.on_state_change( {
+            let socket = Rc::downgrade( &socket );
+            let states = states.clone();
+            move |state| {
+                states.borrow_mut().push( state );
+                if let Some( socket ) = socket.upgrade() {
+                    // Neither of these may panic with "already borrowed".
+                    socket.close();
+                    socket.on_state_change( |_| {} );
+                }
+            }
+        });
+
+        call( &socket.shared.on_state_change, |callback| callback( ConnectionState::Reconnecting ) );
+        assert_eq!( socket.state(), ConnectionState::Closed );
+        assert_eq!( *states.borrow(), vec![ ConnectionState::Reconnecting ] );
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn test_invalid_url() {
+        assert!( ReconnectingWebSocket::new( "bad url", &[], BackoffConfig::default() ).is_err() );
+    }
+}
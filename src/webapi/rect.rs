@@ -0,0 +1,98 @@
+use webcore::value::{Value, ConversionError};
+use webcore::try_from::{TryFrom, TryInto};
+
+/// A rectangle, e.g. the size and the position of an element.
+///
+/// This is a snapshot of a `DOMRectReadOnly`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMRectReadOnly)
+// https://drafts.fxtf.org/geometry/#domrectreadonly
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub struct Rect {
+    /// The x coordinate of the rectangle's origin.
+    pub x: f64,
+
+    /// The y coordinate of the rectangle's origin.
+    pub y: f64,
+
+    /// The width of the rectangle; can be negative.
+    pub width: f64,
+
+    /// The height of the rectangle; can be negative.
+    pub height: f64
+}
+
+impl Rect {
+    /// Returns the y coordinate of the top edge of the rectangle.
+    pub fn top( &self ) -> f64 {
+        self.y.min( self.y + self.height )
+    }
+
+    /// Returns the x coordinate of the right edge of the rectangle.
+    pub fn right( &self ) -> f64 {
+        self.x.max( self.x + self.width )
+    }
+
+    /// Returns the y coordinate of the bottom edge of the rectangle.
+    pub fn bottom( &self ) -> f64 {
+        self.y.max( self.y + self.height )
+    }
+
+    /// Returns the x coordinate of the left edge of the rectangle.
+    pub fn left( &self ) -> f64 {
+        self.x.min( self.x + self.width )
+    }
+}
+
+impl TryFrom< Value > for Rect {
+    type Error = ConversionError;
+
+    fn try_from( value: Value ) -> Result< Self, Self::Error > {
+        match value {
+            Value::Reference( ref reference ) => {
+                // Older browsers only provide `left` and `top`.
+                let fields: Vec< f64 > = js!(
+                    var rect = @{reference};
+                    var x = rect.x === undefined ? rect.left : rect.x;
+                    var y = rect.y === undefined ? rect.top : rect.y;
+                    return [ x, y, rect.width, rect.height ];
+                ).try_into()?;
+
+                Ok( Rect {
+                    x: fields[ 0 ],
+                    y: fields[ 1 ],
+                    width: fields[ 2 ],
+                    height: fields[ 3 ]
+                })
+            },
+            other => Err( ConversionError::Custom( format!( "Expected a DOMRect but got: {:?}", other ) ) )
+        }
+    }
+}
+
+impl TryFrom< Value > for Option< Rect > {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from( value: Value ) -> Result< Self, Self::Error > {
+        match value {
+            Value::Undefined | Value::Null => Ok( None ),
+            value => value.try_into().map( Some )
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rect() {
+        let rect: Rect = js!( return new DOMRect( 10, 20, -5, 30 ); ).try_into().unwrap();
+        assert_eq!( rect, Rect { x: 10.0, y: 20.0, width: -5.0, height: 30.0 } );
+        assert_eq!( rect.left(), 5.0 );
+        assert_eq!( rect.right(), 10.0 );
+        assert_eq!( rect.top(), 20.0 );
+        assert_eq!( rect.bottom(), 50.0 );
+    }
+}
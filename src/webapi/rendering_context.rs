@@ -3,7 +3,9 @@ use webcore::try_from::{TryFrom, TryInto};
 use webcore::value::{Undefined, Value};
 use webapi::html_elements::{CanvasElement, ImageElement};
 use webapi::html_element::IHtmlElement;
-use webapi::dom_exception::{SyntaxError, IndexSizeError, InvalidStateError, TypeError, SecurityError, NotSupportedError};
+use webapi::typed_array::Uint8ClampedArray;
+use webapi::dom_exception::{SyntaxError, IndexSizeError, InvalidStateError, SecurityError, NotSupportedError};
+use webapi::error::TypeError;
 
 /// Trait implemented by rendering contexts which can be obtained from a canvas.
 pub trait RenderingContext {
@@ -193,6 +195,11 @@ error_enum_boilerplate! {
     IndexSizeError, SecurityError
 }
 
+error_enum_boilerplate! {
+    CreateImageDataError,
+    IndexSizeError, InvalidStateError
+}
+
 impl TryFrom<Value> for CanvasStyle {
     type Error = ConversionError;
 
@@ -207,7 +214,7 @@ impl TryFrom<Value> for CanvasStyle {
         if let Ok(v) = CanvasPattern::try_from(value.clone()) {
             return Ok(CanvasStyle::CanvasPattern(v));
         }
-        Err(::webcore::value::ConversionError::type_mismatch( &value ))
+        Err(::webcore::value::ConversionError::wrong_type( "string, CanvasGradient or CanvasPattern", &value ))
     }
 }
 
@@ -244,20 +251,30 @@ impl CanvasGradient {
 }
 
 impl ImageData {
+    /// Creates a new `ImageData` object with the given pixel `data`, which has to
+    /// contain the RGBA components of a whole number of rows of `width` pixels.
+    ///
+    /// Together with [put_image_data](struct.CanvasRenderingContext2d.html#method.put_image_data)
+    /// this can be used to draw pixels which were processed on the Rust side.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ImageData/ImageData)
+    // https://html.spec.whatwg.org/#dom-imagedata-with-data
+    pub fn new( data: &Uint8ClampedArray, width: u32 ) -> Result< ImageData, CreateImageDataError > {
+        js_try! (
+            return new ImageData( @{data}, @{width} );
+        ).unwrap()
+    }
 
-    /*
     /// Returns a Uint8ClampedArray representing a one-dimensional array containing the data in the RGBA order, 
     /// with integer values between 0 and 255 (included).
     /// 
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ImageData/data)
     // https://html.spec.whatwg.org/#2dcontext:dom-imagedata-data
-    // TODO: Return Uint8ClampedArray reference PR 96: https://github.com/koute/stdweb/pull/96 
-    
-    pub fn get_data(&self) -> TypedArray<u8> {
+    pub fn get_data(&self) -> Uint8ClampedArray {
         js! (
             return @{&self.0}.data;
         ).try_into().unwrap()
-    }*/
+    }
 
     /// Returns the number of rows in the image data object.
     /// 
@@ -1367,3 +1384,27 @@ mod test {
         assert!(res.is_err());
     }
 }
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+
+    #[test]
+    fn test_image_data_round_trip() {
+        let pixels: &[u8] = &[ 255, 0, 0, 255, 0, 0, 255, 255 ];
+        let data: Uint8ClampedArray = pixels.into();
+        let image_data = ImageData::new( &data, 2 ).unwrap();
+        assert_eq!( image_data.get_width(), 2 );
+        assert_eq!( image_data.get_height(), 1 );
+
+        let canvas: CanvasElement = document().create_element( "canvas" ).unwrap().try_into().unwrap();
+        let context: CanvasRenderingContext2d = canvas.get_context().unwrap();
+        context.put_image_data( image_data, 0.0, 0.0 ).unwrap();
+
+        let image_data = context.get_image_data( 0.0, 0.0, 2.0, 1.0 ).unwrap();
+        assert_eq!( image_data.get_data().to_vec(), pixels );
+
+        assert!( ImageData::new( &data, 3 ).is_err() );
+    }
+}
@@ -0,0 +1,229 @@
+use std;
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::element::Element;
+use webapi::rect::Rect;
+
+/// Provides a way to receive notifications about changes to the size of elements.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserver)
+// https://drafts.csswg.org/resize-observer/#resize-observer-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ResizeObserver")]
+pub struct ResizeObserver( Reference );
+
+/// Specifies which box model is observed by a [`ResizeObserver`](struct.ResizeObserver.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserver/observe#Parameters)
+// https://drafts.csswg.org/resize-observer/#enumdef-resizeobserverboxoptions
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResizeObserverBoxOptions {
+    /// The size of the content area as defined in CSS.
+    ContentBox,
+    /// The size of the border area as defined in CSS.
+    BorderBox,
+    /// The size of the content area in device pixels, before any CSS transforms are applied.
+    ///
+    /// This is useful for sizing a canvas so that it's rendered pixel-perfect.
+    DevicePixelContentBox
+}
+
+impl ResizeObserverBoxOptions {
+    fn as_str( &self ) -> &'static str {
+        match *self {
+            ResizeObserverBoxOptions::ContentBox => "content-box",
+            ResizeObserverBoxOptions::BorderBox => "border-box",
+            ResizeObserverBoxOptions::DevicePixelContentBox => "device-pixel-content-box"
+        }
+    }
+}
+
+impl ResizeObserver {
+    /// Returns a new [`ResizeObserverHandle`](struct.ResizeObserverHandle.html) with the given callback.
+    ///
+    /// The callback will be called with the following arguments when the size
+    /// of any of the observed elements changes:
+    ///
+    /// 1. A vector of entries describing the new sizes.
+    ///
+    /// 2. The `ResizeObserver`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserver/ResizeObserver)
+    // https://drafts.csswg.org/resize-observer/#dom-resizeobserver-resizeobserver
+    pub fn new< F >( callback: F ) -> ResizeObserverHandle
+        where F: FnMut( Vec< ResizeObserverEntry >, Self ) + 'static {
+        let callback_reference: Reference = js! ( return @{callback}; ).try_into().unwrap();
+
+        ResizeObserverHandle {
+            callback_reference: callback_reference.clone(),
+
+            resize_observer: js! (
+                return new ResizeObserver( @{callback_reference} );
+            ).try_into().unwrap()
+        }
+    }
+
+    /// Starts observing the content box of the `target`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserver/observe)
+    // https://drafts.csswg.org/resize-observer/#dom-resizeobserver-observe
+    pub fn observe( &self, target: &Element ) {
+        js! { @(no_return)
+            @{self}.observe( @{target} );
+        }
+    }
+
+    /// Starts observing the given `box_options` box of the `target`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserver/observe)
+    // https://drafts.csswg.org/resize-observer/#dom-resizeobserver-observe
+    pub fn observe_with_box( &self, target: &Element, box_options: ResizeObserverBoxOptions ) {
+        js! { @(no_return)
+            @{self}.observe( @{target}, { box: @{box_options.as_str()} } );
+        }
+    }
+
+    /// Stops observing the `target`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserver/unobserve)
+    // https://drafts.csswg.org/resize-observer/#dom-resizeobserver-unobserve
+    pub fn unobserve( &self, target: &Element ) {
+        js! { @(no_return)
+            @{self}.unobserve( @{target} );
+        }
+    }
+
+    /// Stops observing all targets.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserver/disconnect)
+    // https://drafts.csswg.org/resize-observer/#dom-resizeobserver-disconnect
+    pub fn disconnect( &self ) {
+        js! { @(no_return)
+            @{self}.disconnect();
+        }
+    }
+}
+
+/// A wrapper which ensures that memory is properly cleaned up when it's no longer needed.
+///
+/// This is created by the [`ResizeObserver::new`](struct.ResizeObserver.html#method.new) method, and
+/// it can use the same methods as [`ResizeObserver`](struct.ResizeObserver.html).
+///
+/// When the `ResizeObserverHandle` is dropped, the [`disconnect`](struct.ResizeObserver.html#method.disconnect)
+/// method will automatically be called.
+#[derive(Debug)]
+pub struct ResizeObserverHandle {
+    resize_observer: ResizeObserver,
+    callback_reference: Reference
+}
+
+impl std::ops::Deref for ResizeObserverHandle {
+    type Target = ResizeObserver;
+
+    #[inline]
+    fn deref( &self ) -> &Self::Target {
+        &self.resize_observer
+    }
+}
+
+impl Drop for ResizeObserverHandle {
+    #[inline]
+    fn drop( &mut self ) {
+        self.disconnect();
+
+        js! { @(no_return)
+            @{&self.callback_reference}.drop();
+        }
+    }
+}
+
+/// Describes the new size of an element observed by a [`ResizeObserver`](struct.ResizeObserver.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserverEntry)
+// https://drafts.csswg.org/resize-observer/#resize-observer-entry-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ResizeObserverEntry")]
+pub struct ResizeObserverEntry( Reference );
+
+impl ResizeObserverEntry {
+    /// Returns the element whose size has changed.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserverEntry/target)
+    // https://drafts.csswg.org/resize-observer/#dom-resizeobserverentry-target
+    pub fn target( &self ) -> Element {
+        js!( return @{self}.target; ).try_into().unwrap()
+    }
+
+    /// Returns the new size of the content area of the target.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserverEntry/contentRect)
+    // https://drafts.csswg.org/resize-observer/#dom-resizeobserverentry-contentrect
+    pub fn content_rect( &self ) -> Rect {
+        js!( return @{self}.contentRect; ).try_into().unwrap()
+    }
+
+    /// Returns the new `(inline, block)` size of the content box of the target,
+    /// or `None` if the browser doesn't support it.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserverEntry/contentBoxSize)
+    // https://drafts.csswg.org/resize-observer/#dom-resizeobserverentry-contentboxsize
+    pub fn content_box_size( &self ) -> Option< (f64, f64) > {
+        self.box_size( "contentBoxSize" )
+    }
+
+    /// Returns the new `(inline, block)` size of the border box of the target,
+    /// or `None` if the browser doesn't support it.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserverEntry/borderBoxSize)
+    // https://drafts.csswg.org/resize-observer/#dom-resizeobserverentry-borderboxsize
+    pub fn border_box_size( &self ) -> Option< (f64, f64) > {
+        self.box_size( "borderBoxSize" )
+    }
+
+    /// Returns the new `(inline, block)` size of the content box of the target
+    /// in device pixels, or `None` if the browser doesn't support it.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserverEntry/devicePixelContentBoxSize)
+    // https://drafts.csswg.org/resize-observer/#dom-resizeobserverentry-devicepixelcontentboxsize
+    pub fn device_pixel_content_box_size( &self ) -> Option< (f64, f64) > {
+        self.box_size( "devicePixelContentBoxSize" )
+    }
+
+    fn box_size( &self, property: &str ) -> Option< (f64, f64) > {
+        // Older browsers return a single object instead of an array,
+        // and some don't support these properties at all.
+        let size: Option< Vec< f64 > > = js!(
+            var size = @{self}[ @{property} ];
+            if( size === undefined || size === null ) {
+                return null;
+            }
+
+            if( size.length !== undefined ) {
+                if( size.length === 0 ) {
+                    return null;
+                }
+                size = size[ 0 ];
+            }
+
+            return [ size.inlineSize, size.blockSize ];
+        ).try_into().unwrap();
+
+        size.map( |size| (size[ 0 ], size[ 1 ]) )
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+
+    #[test]
+    fn test_observe() {
+        let observer = ResizeObserver::new( |_, _| {} );
+        let element = document().create_element( "canvas" ).unwrap();
+
+        observer.observe( &element );
+        observer.observe_with_box( &element, ResizeObserverBoxOptions::DevicePixelContentBox );
+        observer.unobserve( &element );
+    }
+}
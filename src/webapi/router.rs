@@ -0,0 +1,416 @@
+use std::rc::Rc;
+use std::cell::{Cell, RefCell};
+use webcore::try_from::TryInto;
+use webapi::window::window;
+use webapi::document::document;
+use webapi::event_target::{IEventTarget, EventListenerHandle};
+use webapi::events::history::PopStateEvent;
+use webapi::events::mouse::ClickEvent;
+
+/// A parsed location of a single page application, as passed
+/// to the callback of a [Router](struct.Router.html).
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct Route {
+    /// The percent-decoded, non-empty segments of the pathname,
+    /// e.g. `["users", "42"]` for `/users/42/`.
+    pub segments: Vec< String >,
+
+    /// The percent-decoded name-value pairs of the query string, in order.
+    pub query: Vec< (String, String) >,
+
+    /// The fragment identifier without the leading `#`; it's not percent-decoded.
+    pub hash: String
+}
+
+impl Route {
+    /// Parses a path of the form `/pathname?query#hash`, where both
+    /// the query and the hash are optional.
+    pub fn parse( path: &str ) -> Self {
+        let (path, hash) = match path.find( '#' ) {
+            Some( index ) => (&path[ ..index ], &path[ index + 1.. ]),
+            None => (path, "")
+        };
+
+        let (pathname, query) = match path.find( '?' ) {
+            Some( index ) => (&path[ ..index ], &path[ index + 1.. ]),
+            None => (path, "")
+        };
+
+        let segments = pathname.split( '/' )
+            .filter( |segment| !segment.is_empty() )
+            .map( |segment| percent_decode( segment, false ) )
+            .collect();
+
+        let query = query.split( '&' )
+            .filter( |pair| !pair.is_empty() )
+            .map( |pair| match pair.find( '=' ) {
+                Some( index ) => (percent_decode( &pair[ ..index ], true ), percent_decode( &pair[ index + 1.. ], true )),
+                None => (percent_decode( pair, true ), String::new())
+            })
+            .collect();
+
+        Route {
+            segments: segments,
+            query: query,
+            hash: hash.to_owned()
+        }
+    }
+
+    /// Returns the value of the first query pair with the given `name`.
+    pub fn query_value( &self, name: &str ) -> Option< &str > {
+        self.query.iter()
+            .find( |&&(ref key, _)| key == name )
+            .map( |&(_, ref value)| value.as_str() )
+    }
+}
+
+fn hex_value( byte: u8 ) -> Option< u8 > {
+    match byte {
+        b'0'..=b'9' => Some( byte - b'0' ),
+        b'a'..=b'f' => Some( byte - b'a' + 10 ),
+        b'A'..=b'F' => Some( byte - b'A' + 10 ),
+        _ => None
+    }
+}
+
+fn percent_decode( input: &str, plus_is_space: bool ) -> String {
+    let input = input.as_bytes();
+    let mut output = Vec::with_capacity( input.len() );
+    let mut index = 0;
+    while index < input.len() {
+        let byte = input[ index ];
+        if byte == b'%' && index + 2 < input.len() {
+            if let (Some( high ), Some( low )) = (hex_value( input[ index + 1 ] ), hex_value( input[ index + 2 ] )) {
+                output.push( high << 4 | low );
+                index += 3;
+                continue;
+            }
+        }
+
+        output.push( if byte == b'+' && plus_is_space { b' ' } else { byte } );
+        index += 1;
+    }
+
+    String::from_utf8_lossy( &output ).into_owned()
+}
+
+fn current_path() -> String {
+    js!(
+        var location = window.location;
+        return location.pathname + location.search + location.hash;
+    ).try_into().unwrap()
+}
+
+struct Dispatcher {
+    callback: RefCell< Option< Box< dyn FnMut( Route ) > > >,
+    // Set when the URL changes while the callback is running.
+    pending: Cell< bool >
+}
+
+type Callback = Rc< Dispatcher >;
+
+// The callback is taken out of its slot while it runs, so that it can navigate
+// by itself; it's then called again with the new route once it returns.
+fn dispatch( dispatcher: &Dispatcher ) {
+    let callback = dispatcher.callback.borrow_mut().take();
+    let mut callback = match callback {
+        Some( callback ) => callback,
+        None => {
+            dispatcher.pending.set( true );
+            return;
+        }
+    };
+
+    loop {
+        dispatcher.pending.set( false );
+        callback( Route::parse( &current_path() ) );
+        if !dispatcher.pending.get() {
+            break;
+        }
+    }
+
+    *dispatcher.callback.borrow_mut() = Some( callback );
+}
+
+/// A client-side router for single page applications built on top
+/// of the [History](struct.History.html) API.
+///
+/// The callback is called with the [Route](struct.Route.html) of the current
+/// URL when the router is created, after every [navigate](#method.navigate)
+/// or [replace](#method.replace), and whenever the user traverses the history
+/// with the back and forward buttons. The callback can itself navigate, e.g.
+/// to redirect; it's then called again with the new route once it returns.
+///
+/// The router stops listening for history traversals and clicks when it's dropped.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/History_API)
+pub struct Router {
+    callback: Callback,
+    popstate_handle: Option< EventListenerHandle >,
+    click_handle: Option< EventListenerHandle >
+}
+
+impl ::std::fmt::Debug for Router {
+    fn fmt( &self, formatter: &mut ::std::fmt::Formatter ) -> ::std::fmt::Result {
+        formatter.debug_struct( "Router" )
+            .field( "intercepts_links", &self.click_handle.is_some() )
+            .finish()
+    }
+}
+
+impl Router {
+    /// Creates a new `Router` and immediately calls `callback`
+    /// with the route of the current URL.
+    pub fn new< F: FnMut( Route ) + 'static >( callback: F ) -> Self {
+        let callback: Callback = Rc::new( Dispatcher {
+            callback: RefCell::new( Some( Box::new( callback ) ) ),
+            pending: Cell::new( false )
+        });
+        let popstate_handle = {
+            let callback = callback.clone();
+            window().add_event_listener( move |_: PopStateEvent| {
+                dispatch( &callback );
+            })
+        };
+
+        dispatch( &callback );
+        Router {
+            callback: callback,
+            popstate_handle: Some( popstate_handle ),
+            click_handle: None
+        }
+    }
+
+    /// Adds a new history entry for the given `path`, which is resolved
+    /// relative to the current URL, and calls the callback with its route.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/History/pushState)
+    // https://html.spec.whatwg.org/#the-history-interface:dom-history-pushstate
+    pub fn navigate( &self, path: &str ) {
+        window().history().push_state( (), "", Some( path ) );
+        dispatch( &self.callback );
+    }
+
+    /// Replaces the current history entry with the given `path`, which is resolved
+    /// relative to the current URL, and calls the callback with its route.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/History/replaceState)
+    // https://html.spec.whatwg.org/#the-history-interface:dom-history-replacestate
+    pub fn replace( &self, path: &str ) {
+        window().history().replace_state( (), "", Some( path ) ).unwrap();
+        dispatch( &self.callback );
+    }
+
+    /// Enables or disables turning clicks on same-origin `<a href>` elements
+    /// into client-side navigations; it's disabled by default.
+    ///
+    /// Links to other origins, links with a `target` other than `_self` or with
+    /// a `download` attribute, clicks with a modifier key or with a button other
+    /// than the primary one, and clicks which were already handled are left alone.
+    pub fn set_intercept_links( &mut self, enabled: bool ) {
+        if !enabled {
+            if let Some( handle ) = self.click_handle.take() {
+                handle.remove();
+            }
+            return;
+        }
+
+        if self.click_handle.is_some() {
+            return;
+        }
+
+        let callback = self.callback.clone();
+        let handle = document().add_event_listener( move |event: ClickEvent| {
+            let path: Option< String > = js!(
+                var event = @{event};
+                if( event.defaultPrevented || event.button !== 0 || event.metaKey || event.ctrlKey || event.shiftKey || event.altKey ) {
+                    return null;
+                }
+
+                var target = event.target;
+                var link = target && target.closest ? target.closest( "a[href]" ) : null;
+                if( !link || typeof link.href !== "string" || link.hasAttribute( "download" ) ) {
+                    return null;
+                }
+
+                if( link.target && link.target !== "_self" ) {
+                    return null;
+                }
+
+                var url = new URL( link.href, window.location.href );
+                if( url.origin !== window.location.origin ) {
+                    return null;
+                }
+
+                event.preventDefault();
+                return url.pathname + url.search + url.hash;
+            ).try_into().unwrap();
+
+            if let Some( path ) = path {
+                window().history().push_state( (), "", Some( &path ) );
+                dispatch( &callback );
+            }
+        });
+
+        self.click_handle = Some( handle );
+    }
+}
+
+impl Drop for Router {
+    fn drop( &mut self ) {
+        if let Some( handle ) = self.popstate_handle.take() {
+            handle.remove();
+        }
+
+        if let Some( handle ) = self.click_handle.take() {
+            handle.remove();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route_parse() {
+        assert_eq!( Route::parse( "/" ), Route::default() );
+        assert_eq!( Route::parse( "/users//42/" ).segments, vec![ "users".to_owned(), "42".to_owned() ] );
+
+        let route = Route::parse( "/search/caf%C3%A9?q=a+b%26c&page=2&flag#results" );
+        assert_eq!( route.segments, vec![ "search".to_owned(), "café".to_owned() ] );
+        assert_eq!( route.query, vec![
+            ("q".to_owned(), "a b&c".to_owned()),
+            ("page".to_owned(), "2".to_owned()),
+            ("flag".to_owned(), "".to_owned())
+        ]);
+        assert_eq!( route.query_value( "page" ), Some( "2" ) );
+        assert_eq!( route.query_value( "missing" ), None );
+        assert_eq!( route.hash, "results" );
+    }
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!( percent_decode( "a+b", false ), "a+b" );
+        assert_eq!( percent_decode( "a+b", true ), "a b" );
+        assert_eq!( percent_decode( "100%", true ), "100%" );
+        assert_eq!( percent_decode( "%zz%4", true ), "%zz%4" );
+        assert_eq!( percent_decode( "%41%62", true ), "Ab" );
+    }
+
+    #[cfg(feature = "web_test")]
+    fn with_router< F: FnOnce( &mut Router, &Rc< RefCell< Vec< Route > > > ) >( body: F ) {
+        let original = current_path();
+        let routes = Rc::new( RefCell::new( Vec::new() ) );
+        let mut router = {
+            let routes = routes.clone();
+            Router::new( move |route| routes.borrow_mut().push( route ) )
+        };
+
+        body( &mut router, &routes );
+        drop( router );
+        window().history().replace_state( (), "", Some( &original ) ).unwrap();
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn test_navigation() {
+        with_router( |router, routes| {
+            assert_eq!( routes.borrow().len(), 1 );
+            assert_eq!( routes.borrow()[ 0 ], Route::parse( &current_path() ) );
+
+            router.navigate( "/users/42?tab=posts" );
+            router.replace( "/users/43#top" );
+            assert_eq!( current_path(), "/users/43#top" );
+            assert_eq!( routes.borrow()[ 1 ].query_value( "tab" ), Some( "posts" ) );
+            assert_eq!( routes.borrow()[ 2 ], Route::parse( "/users/43#top" ) );
+        });
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn test_redirect_from_callback() {
+        let original = current_path();
+        let router: Rc< RefCell< Option< Router > > > = Rc::new( RefCell::new( None ) );
+        let routes = Rc::new( RefCell::new( Vec::new() ) );
+        let created = {
+            let router = router.clone();
+            let routes = routes.clone();
+            Router::new( move |route: Route| {
+                let redirect = route.segments == vec![ "old".to_owned() ];
+                routes.borrow_mut().push( route );
+                if redirect {
+                    router.borrow().as_ref().unwrap().replace( "/new" );
+                }
+            })
+        };
+
+        *router.borrow_mut() = Some( created );
+        router.borrow().as_ref().unwrap().navigate( "/old" );
+
+        assert_eq!( current_path(), "/new" );
+        let segments: Vec< Vec< String > > = routes.borrow()[ 1.. ].iter().map( |route| route.segments.clone() ).collect();
+        assert_eq!( segments, vec![ vec![ "old".to_owned() ], vec![ "new".to_owned() ] ] );
+
+        // The router's callback holds a reference to the router itself.
+        router.borrow_mut().take();
+        window().history().replace_state( (), "", Some( &original ) ).unwrap();
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn test_traversal_restores_the_route() {
+        with_router( |router, routes| {
+            router.navigate( "/a" );
+            router.navigate( "/b" );
+
+            // This is what the browser does when going back.
+            js!( @(no_return)
+                history.replaceState( null, "", "/a" );
+                window.dispatchEvent( new PopStateEvent( "popstate", { state: null } ) );
+            );
+
+            assert_eq!( routes.borrow().last().unwrap().segments, vec![ "a".to_owned() ] );
+        });
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn test_link_interception() {
+        with_router( |router, routes| {
+            router.set_intercept_links( true );
+
+            // Returns whenever the click was turned into a client-side navigation;
+            // the default action is always prevented so that the test page stays put.
+            let click = |href: &str, attributes: &str| -> bool {
+                js!(
+                    var container = document.createElement( "div" );
+                    container.innerHTML = "<a href='" + @{href} + "' " + @{attributes} + "><span>link</span></a>";
+                    document.body.appendChild( container );
+                    var intercepted = null;
+                    var guard = function( event ) {
+                        intercepted = event.defaultPrevented;
+                        event.preventDefault();
+                    };
+                    window.addEventListener( "click", guard );
+                    container.querySelector( "span" ).click();
+                    window.removeEventListener( "click", guard );
+                    document.body.removeChild( container );
+                    return intercepted;
+                ) == true
+            };
+
+            assert!( click( "/docs/intro?x=1", "" ) );
+            assert_eq!( routes.borrow().last().unwrap(), &Route::parse( "/docs/intro?x=1" ) );
+
+            let count = routes.borrow().len();
+            assert!( !click( "https://example.com/docs", "" ) );
+            assert!( !click( "/docs", "target='_blank'" ) );
+            assert!( !click( "/docs", "download" ) );
+            assert_eq!( routes.borrow().len(), count );
+
+            router.set_intercept_links( false );
+            assert!( !click( "/docs", "" ) );
+        });
+    }
+}
@@ -0,0 +1,401 @@
+use std::fmt;
+use std::error;
+use webcore::value::{Reference, Value};
+use webcore::try_from::TryInto;
+use webcore::promise::Promise;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::error::{IError, Error};
+
+/// The state of a [ServiceWorker](struct.ServiceWorker.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorker/state)
+// https://w3c.github.io/ServiceWorker/#enumdef-serviceworkerstate
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ServiceWorkerState {
+    /// The script was parsed, but the worker hasn't started installing yet.
+    Parsed,
+    /// The worker is running its `install` event handlers.
+    Installing,
+    /// The worker is installed and is waiting for the currently
+    /// active worker to stop controlling any pages.
+    Installed,
+    /// The worker is running its `activate` event handlers.
+    Activating,
+    /// The worker is active and can control pages.
+    Activated,
+    /// The worker failed to install or was replaced by a newer one.
+    Redundant
+}
+
+impl ServiceWorkerState {
+    pub(crate) fn from_str( s: &str ) -> Self {
+        match s {
+            "parsed" => ServiceWorkerState::Parsed,
+            "installing" => ServiceWorkerState::Installing,
+            "installed" => ServiceWorkerState::Installed,
+            "activating" => ServiceWorkerState::Activating,
+            "activated" => ServiceWorkerState::Activated,
+            "redundant" => ServiceWorkerState::Redundant,
+            other => panic!( "Invalid service worker state: {:?}", other )
+        }
+    }
+}
+
+/// An error which can occur when registering a service worker with
+/// [ServiceWorkerContainer::register](struct.ServiceWorkerContainer.html#method.register).
+#[derive(Clone, Debug)]
+pub enum ServiceWorkerRegistrationError {
+    /// The registration isn't allowed, e.g. because the page isn't a secure
+    /// context, the script or the scope is on another origin or outside of
+    /// the allowed scope, or the script was served with a MIME type which
+    /// isn't a JavaScript one.
+    SecurityError( Error ),
+    /// The script couldn't be fetched or evaluated, e.g. because
+    /// the server responded with 404 or the script threw an exception.
+    ScriptError( Error ),
+    /// Any other error.
+    Error( Error )
+}
+
+impl ServiceWorkerRegistrationError {
+    fn from_error( error: Error ) -> Self {
+        match error.name().as_str() {
+            "SecurityError" => ServiceWorkerRegistrationError::SecurityError( error ),
+            "TypeError" => ServiceWorkerRegistrationError::ScriptError( error ),
+            _ => ServiceWorkerRegistrationError::Error( error )
+        }
+    }
+}
+
+impl fmt::Display for ServiceWorkerRegistrationError {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        match *self {
+            ServiceWorkerRegistrationError::SecurityError( ref error ) => error.fmt( formatter ),
+            ServiceWorkerRegistrationError::ScriptError( ref error ) => error.fmt( formatter ),
+            ServiceWorkerRegistrationError::Error( ref error ) => error.fmt( formatter )
+        }
+    }
+}
+
+impl error::Error for ServiceWorkerRegistrationError {
+    fn description( &self ) -> &str {
+        match *self {
+            ServiceWorkerRegistrationError::SecurityError( _ ) => "service worker registration is not allowed",
+            ServiceWorkerRegistrationError::ScriptError( _ ) => "service worker script could not be loaded",
+            ServiceWorkerRegistrationError::Error( _ ) => "ServiceWorkerRegistrationError"
+        }
+    }
+}
+
+/// The `ServiceWorkerContainer` interface is used to register, unregister
+/// and update service workers, and to access the one controlling the page.
+///
+/// It fires a [ControllerChangeEvent](struct.ControllerChangeEvent.html)
+/// whenever the service worker which controls the page changes.
+///
+/// # Examples
+///
+/// Registering a service worker and, when an updated version of it is waiting,
+/// asking it to take over and reloading the page once it did:
+///
+/// ```rust
+/// let container = window().navigator().service_worker().unwrap();
+/// container.register( "/sw.js", None, |result| {
+///     let registration = match result {
+///         Ok( registration ) => registration,
+///         Err( ServiceWorkerRegistrationError::SecurityError( _ ) ) => return show_error( "not allowed" ),
+///         Err( error ) => return show_error( &error.to_string() )
+///     };
+///
+///     let activate = |worker: ServiceWorker| {
+///         // The worker script calls `self.skipWaiting()` when it gets this message.
+///         worker.post_message( "skipWaiting".into() );
+///     };
+///
+///     if let Some( worker ) = registration.waiting() {
+///         activate( worker );
+///     }
+///
+///     let installing = registration.clone();
+///     registration.add_event_listener( move |_: UpdateFoundEvent| {
+///         let worker = installing.installing().unwrap();
+///         let installed = worker.clone();
+///         worker.add_event_listener( move |_: StateChangeEvent| {
+///             if installed.state() == ServiceWorkerState::Installed {
+///                 activate( installed.clone() );
+///             }
+///         });
+///     });
+/// });
+///
+/// // Fired once the new worker is active and controls the page.
+/// container.add_event_listener( |_: ControllerChangeEvent| {
+///     js!( location.reload(); );
+/// });
+/// ```
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerContainer)
+// https://w3c.github.io/ServiceWorker/#serviceworkercontainer
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ServiceWorkerContainer")]
+#[reference(subclass_of(EventTarget))]
+pub struct ServiceWorkerContainer( Reference );
+
+impl IEventTarget for ServiceWorkerContainer {}
+
+impl ServiceWorkerContainer {
+    /// Registers the service worker at `script_url` for the given `scope`, which
+    /// defaults to the directory of the script; both are resolved relative to
+    /// the URL of the page.
+    ///
+    /// If a service worker is already registered for the scope, its registration
+    /// is updated instead. The `callback` is called with the registration once
+    /// the script was fetched, which is before the worker is installed.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerContainer/register)
+    // https://w3c.github.io/ServiceWorker/#dom-serviceworkercontainer-register
+    pub fn register< F >( &self, script_url: &str, scope: Option< &str >, callback: F )
+        where F: FnOnce( Result< ServiceWorkerRegistration, ServiceWorkerRegistrationError > ) + 'static
+    {
+        let promise: Promise = js!(
+            var container = @{self};
+            var script_url = @{script_url};
+            var scope = @{scope};
+            try {
+                return scope === null ? container.register( script_url ) : container.register( script_url, { scope: scope } );
+            } catch( error ) {
+                return Promise.reject( error );
+            }
+        ).try_into().unwrap();
+
+        promise.done( move |result: Result< ServiceWorkerRegistration, Error >| {
+            callback( result.map_err( ServiceWorkerRegistrationError::from_error ) );
+        });
+    }
+
+    /// Calls the `callback` with the registration of the service worker which
+    /// controls the page once it's active; the `callback` is never called if
+    /// no service worker gets registered for the page.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerContainer/ready)
+    // https://w3c.github.io/ServiceWorker/#navigator-service-worker-ready
+    pub fn ready< F >( &self, callback: F )
+        where F: FnOnce( ServiceWorkerRegistration ) + 'static
+    {
+        let promise: Promise = js!(
+            return @{self}.ready;
+        ).try_into().unwrap();
+
+        promise.done( move |result: Result< ServiceWorkerRegistration, Error >| {
+            callback( result.unwrap() );
+        });
+    }
+
+    /// Calls the `callback` with the registration whose scope matches the given
+    /// `url`, which is resolved relative to the URL of the page, or with `None`
+    /// if there is no such registration.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerContainer/getRegistration)
+    // https://w3c.github.io/ServiceWorker/#dom-serviceworkercontainer-getregistration
+    pub fn get_registration< F >( &self, url: &str, callback: F )
+        where F: FnOnce( Result< Option< ServiceWorkerRegistration >, Error > ) + 'static
+    {
+        let promise: Promise = js!(
+            return @{self}.getRegistration( @{url} );
+        ).try_into().unwrap();
+
+        promise.done( move |result: Result< Value, Error >| {
+            callback( result.map( |value| value.try_into().unwrap() ) );
+        });
+    }
+
+    /// Returns the active service worker which controls the page, if any.
+    ///
+    /// This is `None` after a hard reload, even if a service worker is registered.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerContainer/controller)
+    // https://w3c.github.io/ServiceWorker/#dom-serviceworkercontainer-controller
+    pub fn controller( &self ) -> Option< ServiceWorker > {
+        js!(
+            return @{self}.controller;
+        ).try_into().unwrap()
+    }
+}
+
+/// The `ServiceWorkerRegistration` interface represents the registration
+/// of a service worker for a scope.
+///
+/// It fires an [UpdateFoundEvent](struct.UpdateFoundEvent.html) whenever
+/// a new service worker starts installing.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerRegistration)
+// https://w3c.github.io/ServiceWorker/#serviceworkerregistration
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ServiceWorkerRegistration")]
+#[reference(subclass_of(EventTarget))]
+pub struct ServiceWorkerRegistration( Reference );
+
+impl IEventTarget for ServiceWorkerRegistration {}
+
+impl ServiceWorkerRegistration {
+    /// Returns the URL of the scope of the registration.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerRegistration/scope)
+    // https://w3c.github.io/ServiceWorker/#dom-serviceworkerregistration-scope
+    pub fn scope( &self ) -> String {
+        js!(
+            return @{self}.scope;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the service worker which is currently installing, if any.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerRegistration/installing)
+    // https://w3c.github.io/ServiceWorker/#dom-serviceworkerregistration-installing
+    pub fn installing( &self ) -> Option< ServiceWorker > {
+        js!(
+            return @{self}.installing;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the service worker which is installed and is waiting
+    /// to become active, if any.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerRegistration/waiting)
+    // https://w3c.github.io/ServiceWorker/#dom-serviceworkerregistration-waiting
+    pub fn waiting( &self ) -> Option< ServiceWorker > {
+        js!(
+            return @{self}.waiting;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the service worker which is activating or active, if any.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerRegistration/active)
+    // https://w3c.github.io/ServiceWorker/#dom-serviceworkerregistration-active
+    pub fn active( &self ) -> Option< ServiceWorker > {
+        js!(
+            return @{self}.active;
+        ).try_into().unwrap()
+    }
+
+    /// Checks whether the script of the service worker has changed and, if it
+    /// has, starts installing the new version; the `callback` is called once the
+    /// check is done.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerRegistration/update)
+    // https://w3c.github.io/ServiceWorker/#dom-serviceworkerregistration-update
+    pub fn update< F >( &self, callback: F )
+        where F: FnOnce( Result< (), ServiceWorkerRegistrationError > ) + 'static
+    {
+        let promise: Promise = js!(
+            return @{self}.update().then( function() {} );
+        ).try_into().unwrap();
+
+        promise.done( move |result: Result< (), Error >| {
+            callback( result.map_err( ServiceWorkerRegistrationError::from_error ) );
+        });
+    }
+
+    /// Unregisters the service worker; the `callback` is called with `true` if
+    /// it was unregistered and with `false` if there was nothing to unregister.
+    ///
+    /// Pages which are already controlled by the service worker stay controlled
+    /// until they're closed.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerRegistration/unregister)
+    // https://w3c.github.io/ServiceWorker/#dom-serviceworkerregistration-unregister
+    pub fn unregister< F >( &self, callback: F )
+        where F: FnOnce( bool ) + 'static
+    {
+        let promise: Promise = js!(
+            return @{self}.unregister();
+        ).try_into().unwrap();
+
+        promise.done( move |result: Result< bool, Error >| {
+            callback( result.unwrap_or( false ) );
+        });
+    }
+}
+
+/// The `ServiceWorker` interface represents a service worker.
+///
+/// It fires a [StateChangeEvent](struct.StateChangeEvent.html)
+/// whenever its [state](#method.state) changes.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorker)
+// https://w3c.github.io/ServiceWorker/#serviceworker-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ServiceWorker")]
+#[reference(subclass_of(EventTarget))]
+pub struct ServiceWorker( Reference );
+
+impl IEventTarget for ServiceWorker {}
+
+impl ServiceWorker {
+    /// Returns the URL of the script of the service worker.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorker/scriptURL)
+    // https://w3c.github.io/ServiceWorker/#dom-serviceworker-scripturl
+    pub fn script_url( &self ) -> String {
+        js!(
+            return @{self}.scriptURL;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the current state of the service worker.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorker/state)
+    // https://w3c.github.io/ServiceWorker/#dom-serviceworker-state
+    pub fn state( &self ) -> ServiceWorkerState {
+        let state: String = js!(
+            return @{self}.state;
+        ).try_into().unwrap();
+
+        ServiceWorkerState::from_str( &state )
+    }
+
+    /// Sends a message to the service worker; the `data` is structurally cloned.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorker/postMessage)
+    // https://w3c.github.io/ServiceWorker/#dom-serviceworker-postmessage
+    pub fn post_message( &self, data: Value ) {
+        js! { @(no_return)
+            @{self}.postMessage( @{data} );
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webcore::async_test::async_test;
+
+    #[test]
+    fn test_service_worker_state() {
+        assert_eq!( ServiceWorkerState::from_str( "parsed" ), ServiceWorkerState::Parsed );
+        assert_eq!( ServiceWorkerState::from_str( "installed" ), ServiceWorkerState::Installed );
+        assert_eq!( ServiceWorkerState::from_str( "redundant" ), ServiceWorkerState::Redundant );
+    }
+
+    #[test]
+    fn test_register_missing_script() {
+        let container: Option< ServiceWorkerContainer > = js!( return navigator.serviceWorker; ).try_into().unwrap();
+        let container = match container {
+            Some( container ) => container,
+            None => return
+        };
+
+        async_test( "test_register_missing_script", 5000, move |done| {
+            container.register( "/this-service-worker-does-not-exist.js", None, move |result| {
+                match result {
+                    Err( ServiceWorkerRegistrationError::ScriptError( _ ) ) |
+                    Err( ServiceWorkerRegistrationError::SecurityError( _ ) ) => {},
+                    result => panic!( "Unexpected result: {:?}", result )
+                }
+
+                done.done();
+            });
+        });
+    }
+}
@@ -0,0 +1,176 @@
+use std::fmt;
+use std::error;
+use webcore::try_from::TryInto;
+use webcore::promise::Promise;
+use webcore::unsafe_typed_array::UnsafeTypedArray;
+use webapi::array_buffer::ArrayBuffer;
+use webapi::error::Error;
+
+/// A hash algorithm supported by [digest](fn.digest.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/SubtleCrypto/digest#Supported_algorithms)
+// https://w3c.github.io/webcrypto/#sha
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DigestAlgorithm {
+    /// SHA-1; this is not collision resistant anymore and shouldn't be used for anything security related.
+    Sha1,
+    /// SHA-256.
+    Sha256,
+    /// SHA-384.
+    Sha384,
+    /// SHA-512.
+    Sha512
+}
+
+impl DigestAlgorithm {
+    fn as_str( &self ) -> &'static str {
+        match *self {
+            DigestAlgorithm::Sha1 => "SHA-1",
+            DigestAlgorithm::Sha256 => "SHA-256",
+            DigestAlgorithm::Sha384 => "SHA-384",
+            DigestAlgorithm::Sha512 => "SHA-512"
+        }
+    }
+}
+
+/// An error which can occur when using the [SubtleCrypto](https://developer.mozilla.org/en-US/docs/Web/API/SubtleCrypto) API.
+#[derive(Clone, Debug)]
+pub enum CryptoError {
+    /// The API is not available since the page was not loaded in
+    /// a secure context, i.e. it was not served over `https://`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Security/Secure_Contexts)
+    InsecureContext,
+    /// The operation has failed.
+    Error( Error )
+}
+
+impl fmt::Display for CryptoError {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        match *self {
+            CryptoError::InsecureContext => write!( formatter, "SubtleCrypto is only available in secure contexts" ),
+            CryptoError::Error( ref error ) => error.fmt( formatter )
+        }
+    }
+}
+
+impl error::Error for CryptoError {
+    fn description( &self ) -> &str {
+        match *self {
+            CryptoError::InsecureContext => "SubtleCrypto is only available in secure contexts",
+            CryptoError::Error( _ ) => "CryptoError"
+        }
+    }
+}
+
+fn is_subtle_crypto_available() -> bool {
+    js!(
+        var crypto = self.crypto || self.msCrypto;
+        return !!(crypto && crypto.subtle);
+    ).try_into().unwrap()
+}
+
+/// Computes the hash of `data` with the given `algorithm`.
+///
+/// The `callback` is called with the hash once it was computed.
+///
+/// This is only available in secure contexts; elsewhere the `callback` is
+/// called right away with [InsecureContext](enum.CryptoError.html#variant.InsecureContext).
+///
+/// # Examples
+///
+/// ```rust
+/// digest( DigestAlgorithm::Sha256, b"abc", |result| {
+///     let hash = result.unwrap();
+///     assert_eq!( hash.len(), 32 );
+/// });
+/// ```
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/SubtleCrypto/digest)
+// https://w3c.github.io/webcrypto/#SubtleCrypto-method-digest
+pub fn digest< F >( algorithm: DigestAlgorithm, data: &[u8], callback: F )
+    where F: FnOnce( Result< Vec< u8 >, CryptoError > ) + 'static
+{
+    if !is_subtle_crypto_available() {
+        callback( Err( CryptoError::InsecureContext ) );
+        return;
+    }
+
+    // This is safe since `digest` copies the data before it returns.
+    let data = unsafe { UnsafeTypedArray::new( data ) };
+    let promise: Promise = js!(
+        var crypto = self.crypto || self.msCrypto;
+        return crypto.subtle.digest( @{algorithm.as_str()}, @{data} );
+    ).try_into().unwrap();
+
+    promise.done( move |result: Result< ArrayBuffer, Error >| {
+        callback( result.map( Vec::from ).map_err( CryptoError::Error ) );
+    });
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use std::cell::{Cell, RefCell};
+    use webcore::async_test::async_test;
+
+    fn hex( bytes: &[u8] ) -> String {
+        bytes.iter().map( |byte| format!( "{:02x}", byte ) ).collect()
+    }
+
+    // Checks every digest and finishes the test once all of them have been compared.
+    fn assert_digests( name: &str, cases: &[(DigestAlgorithm, &'static [u8], &'static str)] ) {
+        let cases = cases.to_vec();
+        async_test( name, 5000, move |done| {
+            let remaining = Rc::new( Cell::new( cases.len() ) );
+            let done = Rc::new( RefCell::new( Some( done ) ) );
+            for (algorithm, data, expected) in cases {
+                let remaining = remaining.clone();
+                let done = done.clone();
+                digest( algorithm, data, move |result| {
+                    match result {
+                        Ok( hash ) => assert_eq!( hex( &hash ), expected ),
+                        Err( CryptoError::InsecureContext ) => {},
+                        Err( error ) => panic!( "Unexpected error: {}", error )
+                    }
+
+                    remaining.set( remaining.get() - 1 );
+                    if remaining.get() == 0 {
+                        done.borrow_mut().take().unwrap().done();
+                    }
+                });
+            }
+        });
+    }
+
+    #[test]
+    fn test_sha256_known_answers() {
+        assert_digests( "test_sha256_known_answers", &[
+            (DigestAlgorithm::Sha256, b"", "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
+            (DigestAlgorithm::Sha256, b"abc", "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+            (
+                DigestAlgorithm::Sha256,
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+                "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+            )
+        ]);
+    }
+
+    #[test]
+    fn test_other_algorithms() {
+        assert_digests( "test_other_algorithms", &[
+            (DigestAlgorithm::Sha1, b"abc", "a9993e364706816aba3e25717850c26c9cd0d89d"),
+            (
+                DigestAlgorithm::Sha384,
+                b"abc",
+                "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7"
+            ),
+            (
+                DigestAlgorithm::Sha512,
+                b"abc",
+                "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+            )
+        ]);
+    }
+}
@@ -0,0 +1,412 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::node::{INode, Node};
+use webapi::element::{IElement, Element};
+use webapi::document::document;
+use webapi::rect::Rect;
+use webapi::svg_elements::{
+    SvgSvgElement,
+    SvgPathElement,
+    SvgCircleElement,
+    SvgRectElement,
+    SvgLineElement,
+    SvgTextElement,
+    SvgGElement
+};
+
+/// The namespace of SVG elements.
+pub const SVG_NAMESPACE: &'static str = "http://www.w3.org/2000/svg";
+
+/// The `ISvgElement` interface represents any SVG element.
+///
+/// The attributes of SVG elements, e.g. `cx` or `viewBox`, are in the null
+/// namespace, so they should be set with [set_attribute](trait.IElement.html#method.set_attribute)
+/// and **not** with `set_attribute_ns` and the SVG namespace.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/SVGElement)
+// https://svgwg.org/svg2-draft/types.html#InterfaceSVGElement
+pub trait ISvgElement: IElement {
+    /// Returns the nearest ancestor `<svg>` element, or `None` if this
+    /// is the outermost `<svg>` element.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/SVGElement/ownerSVGElement)
+    // https://svgwg.org/svg2-draft/types.html#__svg__SVGElement__ownerSVGElement
+    fn owner_svg_element( &self ) -> Option< SvgSvgElement > {
+        unsafe {
+            js!( return @{self.as_ref()}.ownerSVGElement; ).into_reference_unchecked()
+        }
+    }
+}
+
+/// A reference to a JavaScript object which implements the [ISvgElement](trait.ISvgElement.html)
+/// interface.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/SVGElement)
+// https://svgwg.org/svg2-draft/types.html#InterfaceSVGElement
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "SVGElement")]
+#[reference(subclass_of(EventTarget, Node, Element))]
+pub struct SvgElement( Reference );
+
+impl IEventTarget for SvgElement {}
+impl INode for SvgElement {}
+impl IElement for SvgElement {}
+impl ISvgElement for SvgElement {}
+
+/// The `ISvgGraphicsElement` interface represents SVG elements which
+/// render graphics, e.g. `<path>`, `<g>` or `<svg>` itself.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/SVGGraphicsElement)
+// https://svgwg.org/svg2-draft/types.html#InterfaceSVGGraphicsElement
+pub trait ISvgGraphicsElement: ISvgElement {
+    /// Returns the bounding box of the element in its own user space.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/SVGGraphicsElement/getBBox)
+    // https://svgwg.org/svg2-draft/types.html#__svg__SVGGraphicsElement__getBBox
+    fn get_bbox( &self ) -> Rect {
+        js!( return @{self.as_ref()}.getBBox(); ).try_into().unwrap()
+    }
+
+    /// Returns the matrix which transforms the user space of this element
+    /// into the user space of its nearest viewport, e.g. the `<svg>` element.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/SVGGraphicsElement/getCTM)
+    // https://svgwg.org/svg2-draft/types.html#__svg__SVGGraphicsElement__getCTM
+    fn get_ctm( &self ) -> Option< SvgMatrix > {
+        unsafe {
+            js!( return @{self.as_ref()}.getCTM(); ).into_reference_unchecked()
+        }
+    }
+
+    /// Returns the matrix which transforms the user space of this element
+    /// into the coordinate system of the screen, i.e. of the mouse events'
+    /// `client_x` and `client_y`.
+    ///
+    /// Its [inverse](struct.SvgMatrix.html#method.inverse) converts the screen
+    /// coordinates into the element's user space, e.g. for hit testing.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/SVGGraphicsElement/getScreenCTM)
+    // https://svgwg.org/svg2-draft/types.html#__svg__SVGGraphicsElement__getScreenCTM
+    fn get_screen_ctm( &self ) -> Option< SvgMatrix > {
+        unsafe {
+            js!( return @{self.as_ref()}.getScreenCTM(); ).into_reference_unchecked()
+        }
+    }
+}
+
+/// A reference to a JavaScript object which implements the [ISvgGraphicsElement](trait.ISvgGraphicsElement.html)
+/// interface.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/SVGGraphicsElement)
+// https://svgwg.org/svg2-draft/types.html#InterfaceSVGGraphicsElement
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "SVGGraphicsElement")]
+#[reference(subclass_of(EventTarget, Node, Element, SvgElement))]
+pub struct SvgGraphicsElement( Reference );
+
+impl IEventTarget for SvgGraphicsElement {}
+impl INode for SvgGraphicsElement {}
+impl IElement for SvgGraphicsElement {}
+impl ISvgElement for SvgGraphicsElement {}
+impl ISvgGraphicsElement for SvgGraphicsElement {}
+
+/// The `ISvgGeometryElement` interface represents SVG elements which are
+/// shapes, e.g. `<path>`, `<circle>` or `<rect>`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/SVGGeometryElement)
+// https://svgwg.org/svg2-draft/types.html#InterfaceSVGGeometryElement
+pub trait ISvgGeometryElement: ISvgGraphicsElement {
+    /// Returns the computed length of the outline of the shape, in user units.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/SVGGeometryElement/getTotalLength)
+    // https://svgwg.org/svg2-draft/types.html#__svg__SVGGeometryElement__getTotalLength
+    fn get_total_length( &self ) -> f64 {
+        js!( return @{self.as_ref()}.getTotalLength(); ).try_into().unwrap()
+    }
+
+    /// Returns the point at the given `distance` along the outline of the shape,
+    /// in user units. The distance is clamped to the length of the outline.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/SVGGeometryElement/getPointAtLength)
+    // https://svgwg.org/svg2-draft/types.html#__svg__SVGGeometryElement__getPointAtLength
+    fn get_point_at_length( &self, distance: f64 ) -> SvgPoint {
+        unsafe {
+            js!( return @{self.as_ref()}.getPointAtLength( @{distance} ); ).into_reference_unchecked().unwrap()
+        }
+    }
+}
+
+/// A reference to a JavaScript object which implements the [ISvgGeometryElement](trait.ISvgGeometryElement.html)
+/// interface.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/SVGGeometryElement)
+// https://svgwg.org/svg2-draft/types.html#InterfaceSVGGeometryElement
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "SVGGeometryElement")]
+#[reference(subclass_of(EventTarget, Node, Element, SvgElement, SvgGraphicsElement))]
+pub struct SvgGeometryElement( Reference );
+
+impl IEventTarget for SvgGeometryElement {}
+impl INode for SvgGeometryElement {}
+impl IElement for SvgGeometryElement {}
+impl ISvgElement for SvgGeometryElement {}
+impl ISvgGraphicsElement for SvgGeometryElement {}
+impl ISvgGeometryElement for SvgGeometryElement {}
+
+/// A point in a two dimensional coordinate system.
+///
+/// Depending on the browser this is either an `SVGPoint` or a `DOMPoint`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMPoint)
+// https://drafts.fxtf.org/geometry/#dompoint
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+pub struct SvgPoint( Reference );
+
+impl ::InstanceOf for SvgPoint {
+    #[inline]
+    fn instance_of( reference: &Reference ) -> bool {
+        js!(
+            var point = @{reference};
+            return (typeof SVGPoint !== "undefined" && point instanceof SVGPoint) ||
+                (typeof DOMPointReadOnly !== "undefined" && point instanceof DOMPointReadOnly);
+        ) == true
+    }
+}
+
+impl SvgPoint {
+    /// Returns the x coordinate of the point.
+    pub fn x( &self ) -> f64 {
+        js!( return @{self}.x; ).try_into().unwrap()
+    }
+
+    /// Sets the x coordinate of the point.
+    pub fn set_x( &self, value: f64 ) {
+        js! { @(no_return)
+            @{self}.x = @{value};
+        }
+    }
+
+    /// Returns the y coordinate of the point.
+    pub fn y( &self ) -> f64 {
+        js!( return @{self}.y; ).try_into().unwrap()
+    }
+
+    /// Sets the y coordinate of the point.
+    pub fn set_y( &self, value: f64 ) {
+        js! { @(no_return)
+            @{self}.y = @{value};
+        }
+    }
+
+    /// Returns a new point which is this point transformed by the given `matrix`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMPointReadOnly/matrixTransform)
+    // https://drafts.fxtf.org/geometry/#dom-dompointreadonly-matrixtransform
+    pub fn matrix_transform( &self, matrix: &SvgMatrix ) -> SvgPoint {
+        unsafe {
+            js!( return @{self}.matrixTransform( @{matrix} ); ).into_reference_unchecked().unwrap()
+        }
+    }
+}
+
+/// A 2D transformation matrix of the form:
+///
+/// ```text
+/// [ a c e ]
+/// [ b d f ]
+/// [ 0 0 1 ]
+/// ```
+///
+/// Depending on the browser this is either an `SVGMatrix` or a `DOMMatrix`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMMatrix)
+// https://drafts.fxtf.org/geometry/#dommatrix
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+pub struct SvgMatrix( Reference );
+
+impl ::InstanceOf for SvgMatrix {
+    #[inline]
+    fn instance_of( reference: &Reference ) -> bool {
+        js!(
+            var matrix = @{reference};
+            return (typeof SVGMatrix !== "undefined" && matrix instanceof SVGMatrix) ||
+                (typeof DOMMatrixReadOnly !== "undefined" && matrix instanceof DOMMatrixReadOnly);
+        ) == true
+    }
+}
+
+impl SvgMatrix {
+    /// Returns the `[a, b, c, d, e, f]` components of the matrix.
+    pub fn components( &self ) -> [f64; 6] {
+        let components: Vec< f64 > = js!(
+            var matrix = @{self};
+            return [ matrix.a, matrix.b, matrix.c, matrix.d, matrix.e, matrix.f ];
+        ).try_into().unwrap();
+
+        [ components[ 0 ], components[ 1 ], components[ 2 ], components[ 3 ], components[ 4 ], components[ 5 ] ]
+    }
+
+    /// Returns the inverse of this matrix, or `None` if the matrix can't be inverted.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMMatrixReadOnly/inverse)
+    // https://drafts.fxtf.org/geometry/#dom-dommatrixreadonly-inverse
+    pub fn inverse( &self ) -> Option< SvgMatrix > {
+        // An `SVGMatrix` throws when it's not invertible, while
+        // a `DOMMatrix` returns a matrix full of `NaN`s.
+        unsafe {
+            js!(
+                try {
+                    var inverse = @{self}.inverse();
+                } catch( error ) {
+                    return null;
+                }
+                return isNaN( inverse.a ) ? null : inverse;
+            ).into_reference_unchecked()
+        }
+    }
+}
+
+/// A factory of SVG elements.
+///
+/// All of the elements are created in the [SVG namespace](constant.SVG_NAMESPACE.html)
+/// of the global [document](fn.document.html); an element created with `Document::create_element`
+/// would instead be an unknown HTML element which doesn't render.
+#[derive(Copy, Clone, Debug)]
+pub struct Svg;
+
+macro_rules! svg_create {
+    ($($(#[$attr:meta])* fn $name:ident() -> $kind:ident = $tag:expr;)+) => {
+        impl Svg {
+            $(
+                $(#[$attr])*
+                pub fn $name() -> $kind {
+                    document().create_element_ns( SVG_NAMESPACE, $tag ).unwrap().try_into().unwrap()
+                }
+            )+
+        }
+    };
+}
+
+svg_create! {
+    /// Creates an `<svg>` element.
+    fn create_svg() -> SvgSvgElement = "svg";
+    /// Creates a `<path>` element.
+    fn create_path() -> SvgPathElement = "path";
+    /// Creates a `<circle>` element.
+    fn create_circle() -> SvgCircleElement = "circle";
+    /// Creates a `<rect>` element.
+    fn create_rect() -> SvgRectElement = "rect";
+    /// Creates a `<line>` element.
+    fn create_line() -> SvgLineElement = "line";
+    /// Creates a `<text>` element.
+    fn create_text() -> SvgTextElement = "text";
+    /// Creates a `<g>` element.
+    fn create_g() -> SvgGElement = "g";
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::node::INode;
+    use webapi::svg_elements::SvgSvgElement;
+
+    fn build_chart() -> (SvgSvgElement, SvgPathElement) {
+        let svg = Svg::create_svg();
+        svg.set_attribute( "width", "200" ).unwrap();
+        svg.set_attribute( "height", "200" ).unwrap();
+        svg.set_attribute( "style", "position: fixed; left: 10px; top: 20px;" ).unwrap();
+        svg.set_view_box( Rect { x: 0.0, y: 0.0, width: 100.0, height: 100.0 } );
+
+        let group = Svg::create_g();
+        let bar = Svg::create_rect();
+        bar.set_attribute( "x", "40" ).unwrap();
+        bar.set_attribute( "y", "10" ).unwrap();
+        bar.set_attribute( "width", "20" ).unwrap();
+        bar.set_attribute( "height", "90" ).unwrap();
+        group.append_child( &bar );
+
+        let axis = Svg::create_path();
+        axis.set_attribute( "d", "M 0 100 L 30 100 L 30 60" ).unwrap();
+        group.append_child( &axis );
+
+        let label = Svg::create_text();
+        label.set_text_content( "Total" );
+        group.append_child( &label );
+
+        svg.append_child( &group );
+        js!( @(no_return) document.body.appendChild( @{&svg} ); );
+        (svg, axis)
+    }
+
+    #[test]
+    fn elements_are_created_in_the_svg_namespace() {
+        let circle = Svg::create_circle();
+        assert_eq!( js!( return @{&circle}.namespaceURI; ), SVG_NAMESPACE );
+
+        circle.set_attribute( "cx", "5" ).unwrap();
+        assert_eq!( circle.get_attribute( "cx" ), Some( "5".to_owned() ) );
+        assert_eq!( circle.get_attribute_ns( None, "cx" ), Some( "5".to_owned() ) );
+        assert_eq!( circle.get_attribute_ns( Some( SVG_NAMESPACE ), "cx" ), None );
+        assert_eq!( js!( return @{&circle}.cx.baseVal.value; ), 5 );
+
+        let line = Svg::create_line();
+        line.set_attribute_ns( Some( "http://www.w3.org/1999/xlink" ), "xlink:title", "axis" ).unwrap();
+        assert_eq!( line.get_attribute_ns( Some( "http://www.w3.org/1999/xlink" ), "title" ), Some( "axis".to_owned() ) );
+        line.remove_attribute_ns( Some( "http://www.w3.org/1999/xlink" ), "title" );
+        assert_eq!( line.get_attribute( "xlink:title" ), None );
+
+        assert!( line.set_attribute_ns( None, "xlink:title", "axis" ).is_err() );
+    }
+
+    #[test]
+    fn view_box() {
+        let svg = Svg::create_svg();
+        assert_eq!( svg.view_box(), None );
+        svg.set_view_box( Rect { x: 1.0, y: 2.0, width: 30.0, height: 40.0 } );
+        assert_eq!( svg.view_box(), Some( Rect { x: 1.0, y: 2.0, width: 30.0, height: 40.0 } ) );
+        assert_eq!( svg.get_attribute( "viewBox" ), Some( "1 2 30 40".to_owned() ) );
+    }
+
+    #[test]
+    fn path_length() {
+        let (svg, axis) = build_chart();
+        assert_eq!( axis.get_total_length(), 70.0 );
+
+        let point = axis.get_point_at_length( 50.0 );
+        assert_eq!( (point.x(), point.y()), (30.0, 80.0) );
+        js!( @(no_return) @{&svg}.remove(); );
+    }
+
+    #[test]
+    fn hit_test_through_the_inverse_of_the_screen_ctm() {
+        let (svg, _) = build_chart();
+        let ctm = svg.get_screen_ctm().unwrap();
+        assert_eq!( ctm.components(), [ 2.0, 0.0, 0.0, 2.0, 10.0, 20.0 ] );
+
+        // The middle of the bar, in screen coordinates.
+        let point = svg.create_svg_point();
+        point.set_x( 10.0 + 50.0 * 2.0 );
+        point.set_y( 20.0 + 50.0 * 2.0 );
+
+        let point = point.matrix_transform( &ctm.inverse().unwrap() );
+        assert_eq!( (point.x(), point.y()), (50.0, 50.0) );
+
+        let hit: bool = js!(
+            var svg = @{&svg};
+            var point = @{&point};
+            var bar = svg.querySelector( "rect" );
+            var box = bar.getBBox();
+            return point.x >= box.x && point.x <= box.x + box.width && point.y >= box.y && point.y <= box.y + box.height;
+        ).try_into().unwrap();
+        assert!( hit );
+        js!( @(no_return) @{&svg}.remove(); );
+    }
+
+    #[test]
+    fn singular_matrix_has_no_inverse() {
+        let svg = Svg::create_svg();
+        let matrix: SvgMatrix = js!( return @{&svg}.createSVGMatrix().scale( 0 ); ).try_into().unwrap();
+        assert!( matrix.inverse().is_none() );
+    }
+}
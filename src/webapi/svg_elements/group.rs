@@ -0,0 +1,20 @@
+use webcore::value::Reference;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::node::{INode, Node};
+use webapi::element::{IElement, Element};
+use webapi::svg_element::{ISvgElement, SvgElement, ISvgGraphicsElement, SvgGraphicsElement};
+
+/// The SVG `<g>` element, which groups other SVG elements.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/SVGGElement)
+// https://svgwg.org/svg2-draft/struct.html#InterfaceSVGGElement
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "SVGGElement")]
+#[reference(subclass_of(EventTarget, Node, Element, SvgElement, SvgGraphicsElement))]
+pub struct SvgGElement( Reference );
+
+impl IEventTarget for SvgGElement {}
+impl INode for SvgGElement {}
+impl IElement for SvgGElement {}
+impl ISvgElement for SvgGElement {}
+impl ISvgGraphicsElement for SvgGElement {}
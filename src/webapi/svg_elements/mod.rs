@@ -0,0 +1,11 @@
+mod svg;
+mod path;
+mod shapes;
+mod text;
+mod group;
+
+pub use self::svg::SvgSvgElement;
+pub use self::path::SvgPathElement;
+pub use self::shapes::{SvgCircleElement, SvgRectElement, SvgLineElement};
+pub use self::text::SvgTextElement;
+pub use self::group::SvgGElement;
@@ -0,0 +1,28 @@
+use webcore::value::Reference;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::node::{INode, Node};
+use webapi::element::{IElement, Element};
+use webapi::svg_element::{
+    ISvgElement,
+    SvgElement,
+    ISvgGraphicsElement,
+    SvgGraphicsElement,
+    ISvgGeometryElement,
+    SvgGeometryElement
+};
+
+/// The SVG `<path>` element, a generic shape defined by the `d` attribute.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/SVGPathElement)
+// https://svgwg.org/svg2-draft/paths.html#InterfaceSVGPathElement
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "SVGPathElement")]
+#[reference(subclass_of(EventTarget, Node, Element, SvgElement, SvgGraphicsElement, SvgGeometryElement))]
+pub struct SvgPathElement( Reference );
+
+impl IEventTarget for SvgPathElement {}
+impl INode for SvgPathElement {}
+impl IElement for SvgPathElement {}
+impl ISvgElement for SvgPathElement {}
+impl ISvgGraphicsElement for SvgPathElement {}
+impl ISvgGeometryElement for SvgPathElement {}
@@ -0,0 +1,60 @@
+use webcore::value::Reference;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::node::{INode, Node};
+use webapi::element::{IElement, Element};
+use webapi::svg_element::{
+    ISvgElement,
+    SvgElement,
+    ISvgGraphicsElement,
+    SvgGraphicsElement,
+    ISvgGeometryElement,
+    SvgGeometryElement
+};
+
+/// The SVG `<circle>` element.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/SVGCircleElement)
+// https://svgwg.org/svg2-draft/shapes.html#InterfaceSVGCircleElement
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "SVGCircleElement")]
+#[reference(subclass_of(EventTarget, Node, Element, SvgElement, SvgGraphicsElement, SvgGeometryElement))]
+pub struct SvgCircleElement( Reference );
+
+impl IEventTarget for SvgCircleElement {}
+impl INode for SvgCircleElement {}
+impl IElement for SvgCircleElement {}
+impl ISvgElement for SvgCircleElement {}
+impl ISvgGraphicsElement for SvgCircleElement {}
+impl ISvgGeometryElement for SvgCircleElement {}
+
+/// The SVG `<rect>` element.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/SVGRectElement)
+// https://svgwg.org/svg2-draft/shapes.html#InterfaceSVGRectElement
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "SVGRectElement")]
+#[reference(subclass_of(EventTarget, Node, Element, SvgElement, SvgGraphicsElement, SvgGeometryElement))]
+pub struct SvgRectElement( Reference );
+
+impl IEventTarget for SvgRectElement {}
+impl INode for SvgRectElement {}
+impl IElement for SvgRectElement {}
+impl ISvgElement for SvgRectElement {}
+impl ISvgGraphicsElement for SvgRectElement {}
+impl ISvgGeometryElement for SvgRectElement {}
+
+/// The SVG `<line>` element.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/SVGLineElement)
+// https://svgwg.org/svg2-draft/shapes.html#InterfaceSVGLineElement
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "SVGLineElement")]
+#[reference(subclass_of(EventTarget, Node, Element, SvgElement, SvgGraphicsElement, SvgGeometryElement))]
+pub struct SvgLineElement( Reference );
+
+impl IEventTarget for SvgLineElement {}
+impl INode for SvgLineElement {}
+impl IElement for SvgLineElement {}
+impl ISvgElement for SvgLineElement {}
+impl ISvgGraphicsElement for SvgLineElement {}
+impl ISvgGeometryElement for SvgLineElement {}
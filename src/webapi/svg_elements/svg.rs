@@ -0,0 +1,61 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::node::{INode, Node};
+use webapi::element::{IElement, Element};
+use webapi::svg_element::{ISvgElement, SvgElement, ISvgGraphicsElement, SvgGraphicsElement, SvgPoint};
+use webapi::rect::Rect;
+
+/// The SVG `<svg>` element, which defines a new coordinate system and viewport.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/SVGSVGElement)
+// https://svgwg.org/svg2-draft/struct.html#InterfaceSVGSVGElement
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "SVGSVGElement")]
+#[reference(subclass_of(EventTarget, Node, Element, SvgElement, SvgGraphicsElement))]
+pub struct SvgSvgElement( Reference );
+
+impl IEventTarget for SvgSvgElement {}
+impl INode for SvgSvgElement {}
+impl IElement for SvgSvgElement {}
+impl ISvgElement for SvgSvgElement {}
+impl ISvgGraphicsElement for SvgSvgElement {}
+
+impl SvgSvgElement {
+    /// Returns the rectangle in user space which is mapped onto the viewport,
+    /// as set by the `viewBox` attribute, or `None` if it's not set.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/viewBox)
+    // https://svgwg.org/svg2-draft/coords.html#__svg__SVGFitToViewBox__viewBox
+    pub fn view_box( &self ) -> Option< Rect > {
+        js!(
+            var element = @{self};
+            if( !element.hasAttribute( "viewBox" ) ) {
+                return null;
+            }
+            return element.viewBox.baseVal;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the `viewBox` attribute.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/viewBox)
+    // https://svgwg.org/svg2-draft/coords.html#ViewBoxAttribute
+    pub fn set_view_box( &self, view_box: Rect ) {
+        let value = format!( "{} {} {} {}", view_box.x, view_box.y, view_box.width, view_box.height );
+        js! { @(no_return)
+            @{self}.setAttribute( "viewBox", @{value} );
+        }
+    }
+
+    /// Creates a new point, initialized to the origin, which can be
+    /// used with [SvgPoint::matrix_transform](struct.SvgPoint.html#method.matrix_transform).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/SVGSVGElement/createSVGPoint)
+    // https://svgwg.org/svg2-draft/struct.html#__svg__SVGSVGElement__createSVGPoint
+    pub fn create_svg_point( &self ) -> SvgPoint {
+        unsafe {
+            js!( return @{self}.createSVGPoint(); ).into_reference_unchecked().unwrap()
+        }
+    }
+}
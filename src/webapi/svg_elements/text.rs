@@ -0,0 +1,20 @@
+use webcore::value::Reference;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::node::{INode, Node};
+use webapi::element::{IElement, Element};
+use webapi::svg_element::{ISvgElement, SvgElement, ISvgGraphicsElement, SvgGraphicsElement};
+
+/// The SVG `<text>` element.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/SVGTextElement)
+// https://svgwg.org/svg2-draft/text.html#InterfaceSVGTextElement
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "SVGTextElement")]
+#[reference(subclass_of(EventTarget, Node, Element, SvgElement, SvgGraphicsElement))]
+pub struct SvgTextElement( Reference );
+
+impl IEventTarget for SvgTextElement {}
+impl INode for SvgTextElement {}
+impl IElement for SvgTextElement {}
+impl ISvgElement for SvgTextElement {}
+impl ISvgGraphicsElement for SvgTextElement {}
@@ -0,0 +1,360 @@
+use std::fmt;
+use std::rc::{Rc, Weak};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use webcore::value::{Reference, Value};
+use webcore::try_from::TryInto;
+
+/// How long before its deadline a task is considered urgent, in milliseconds.
+const URGENCY_MARGIN_MS: f64 = 50.0;
+
+struct Task {
+    callback: Box< dyn FnOnce() >,
+    deadline: Option< f64 >
+}
+
+#[derive(Default)]
+struct Tasks {
+    queue: VecDeque< Task >
+}
+
+impl Tasks {
+    fn is_urgent( task: &Task, now: f64 ) -> bool {
+        task.deadline.map( |deadline| deadline - URGENCY_MARGIN_MS <= now ).unwrap_or( false )
+    }
+
+    // Takes the first task whose deadline is approaching.
+    fn pop_urgent( &mut self, now: f64 ) -> Option< Task > {
+        let index = self.queue.iter().position( |task| Tasks::is_urgent( task, now ) )?;
+        self.queue.remove( index )
+    }
+
+    // Takes the first urgent task, or the first task if none is urgent.
+    fn pop_next( &mut self, now: f64 ) -> Option< Task > {
+        self.pop_urgent( now ).or_else( || self.queue.pop_front() )
+    }
+
+    fn earliest_deadline( &self ) -> Option< f64 > {
+        self.queue.iter()
+            .filter_map( |task| task.deadline )
+            .fold( None, |earliest: Option< f64 >, deadline| Some( earliest.map_or( deadline, |earliest| earliest.min( deadline ) ) ) )
+    }
+}
+
+struct Inner {
+    tasks: Tasks,
+    on_idle: Option< Reference >,
+    on_timer: Option< Reference >,
+    idle_handle: Option< Value >,
+    timer_handle: Option< ( Value, f64 ) >
+}
+
+fn now() -> f64 {
+    js!( return performance.now(); ).try_into().unwrap()
+}
+
+fn cancel_handle( handle: Value ) {
+    js! { @(no_return)
+        var handle = @{handle};
+        if( handle.idle ) {
+            cancelIdleCallback( handle.id );
+        } else {
+            clearTimeout( handle.id );
+        }
+    }
+}
+
+fn schedule( inner: &Rc< RefCell< Inner > > ) {
+    let mut inner = inner.borrow_mut();
+    if inner.tasks.queue.is_empty() {
+        return;
+    }
+
+    if inner.idle_handle.is_none() {
+        inner.idle_handle = Some( js!(
+            var on_idle = @{&inner.on_idle};
+            if( typeof requestIdleCallback === "function" ) {
+                return { idle: true, id: requestIdleCallback( function( deadline ) { on_idle( deadline ); } ) };
+            }
+
+            return { idle: false, id: setTimeout( function() { on_idle( null ); }, 1 ) };
+        ));
+    }
+
+    if let Some( deadline ) = inner.tasks.earliest_deadline() {
+        let now = now();
+        let due = (deadline - URGENCY_MARGIN_MS).max( now );
+        let is_scheduled = inner.timer_handle.as_ref().map( |&(_, timer_due)| timer_due <= due ).unwrap_or( false );
+        if !is_scheduled {
+            if let Some( ( handle, _ ) ) = inner.timer_handle.take() {
+                cancel_handle( handle );
+            }
+
+            let handle = js!(
+                var on_timer = @{&inner.on_timer};
+                return { idle: false, id: setTimeout( function() { on_timer(); }, @{due - now} ) };
+            );
+            inner.timer_handle = Some( ( handle, due ) );
+        }
+    }
+}
+
+fn run_tasks< F: FnMut( &mut Tasks ) -> Option< Task >, C: Fn() -> bool >( inner: &Rc< RefCell< Inner > >, mut next: F, should_continue: C ) {
+    loop {
+        // The task is taken out before it runs so that it can push more tasks.
+        let task = next( &mut inner.borrow_mut().tasks );
+        match task {
+            Some( task ) => (task.callback)(),
+            None => break
+        }
+
+        if !should_continue() {
+            break;
+        }
+    }
+
+    schedule( inner );
+}
+
+fn on_idle( inner: &Rc< RefCell< Inner > >, deadline: Value ) {
+    inner.borrow_mut().idle_handle = None;
+    run_tasks( inner, |tasks| tasks.pop_next( now() ), || {
+        // Without `requestIdleCallback` only a single task is run at a time.
+        match deadline {
+            Value::Reference( ref deadline ) => {
+                let remaining: f64 = js!( return @{deadline}.timeRemaining(); ).try_into().unwrap();
+                remaining > 0.0
+            },
+            _ => false
+        }
+    });
+}
+
+fn on_timer( inner: &Rc< RefCell< Inner > > ) {
+    inner.borrow_mut().timer_handle = None;
+    run_tasks( inner, |tasks| tasks.pop_urgent( now() ), || true );
+}
+
+/// A queue of background tasks which run when the browser is idle,
+/// but which can't be starved forever.
+///
+/// The tasks are run with `requestIdleCallback` when it's available, and with
+/// `setTimeout` otherwise. A task can have a deadline; once the deadline is
+/// approaching the task is promoted, that is it's run from a timer even if
+/// the browser never became idle.
+///
+/// Every task runs exactly once. Urgent tasks run before the others, and
+/// otherwise the tasks run in the order in which they were pushed.
+/// Dropping the queue cancels all of the pending tasks.
+///
+/// # Examples
+///
+/// ```rust
+/// let queue = TaskQueue::new();
+/// queue.push( || prefetch_images(), None );
+/// queue.push( || build_search_index(), Some( 2000 ) );
+/// ```
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/requestIdleCallback)
+// https://w3c.github.io/requestidlecallback/#the-requestidlecallback-method
+pub struct TaskQueue {
+    inner: Rc< RefCell< Inner > >
+}
+
+impl fmt::Debug for TaskQueue {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        write!( formatter, "TaskQueue" )
+    }
+}
+
+impl TaskQueue {
+    /// Creates a new empty `TaskQueue`.
+    pub fn new() -> Self {
+        let inner = Rc::new( RefCell::new( Inner {
+            tasks: Tasks::default(),
+            on_idle: None,
+            on_timer: None,
+            idle_handle: None,
+            timer_handle: None
+        }));
+
+        let weak: Weak< RefCell< Inner > > = Rc::downgrade( &inner );
+        let on_idle_callback = move |deadline: Value| {
+            if let Some( inner ) = weak.upgrade() {
+                on_idle( &inner, deadline );
+            }
+        };
+
+        let weak: Weak< RefCell< Inner > > = Rc::downgrade( &inner );
+        let on_timer_callback = move || {
+            if let Some( inner ) = weak.upgrade() {
+                on_timer( &inner );
+            }
+        };
+
+        {
+            let mut inner = inner.borrow_mut();
+            inner.on_idle = Some( js!( return @{on_idle_callback}; ).try_into().unwrap() );
+            inner.on_timer = Some( js!( return @{on_timer_callback}; ).try_into().unwrap() );
+        }
+
+        TaskQueue {
+            inner: inner
+        }
+    }
+
+    /// Pushes a new `task` into the queue.
+    ///
+    /// If a `deadline_ms` is given the task will run within roughly that many
+    /// milliseconds, even if the browser is never idle in the meantime.
+    pub fn push< F: FnOnce() + 'static >( &self, task: F, deadline_ms: Option< u32 > ) {
+        let deadline = deadline_ms.map( |deadline_ms| now() + deadline_ms as f64 );
+        self.inner.borrow_mut().tasks.queue.push_back( Task {
+            callback: Box::new( task ),
+            deadline: deadline
+        });
+
+        schedule( &self.inner );
+    }
+
+    /// Removes all of the pending tasks without running them.
+    pub fn cancel_all( &self ) {
+        let mut inner = self.inner.borrow_mut();
+        inner.tasks.queue.clear();
+        if let Some( handle ) = inner.idle_handle.take() {
+            cancel_handle( handle );
+        }
+
+        if let Some( ( handle, _ ) ) = inner.timer_handle.take() {
+            cancel_handle( handle );
+        }
+    }
+
+    /// Returns the number of pending tasks.
+    pub fn len( &self ) -> usize {
+        self.inner.borrow().tasks.queue.len()
+    }
+
+    /// Returns whenever there are no pending tasks.
+    pub fn is_empty( &self ) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Drop for TaskQueue {
+    fn drop( &mut self ) {
+        self.cancel_all();
+
+        let mut inner = self.inner.borrow_mut();
+        for callback in inner.on_idle.take().into_iter().chain( inner.on_timer.take() ) {
+            js! { @(no_return)
+                @{callback}.drop();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task( log: &Rc< RefCell< Vec< u32 > > >, id: u32, deadline: Option< f64 > ) -> Task {
+        let log = log.clone();
+        Task {
+            callback: Box::new( move || log.borrow_mut().push( id ) ),
+            deadline: deadline
+        }
+    }
+
+    #[test]
+    fn test_promotion() {
+        let log = Rc::new( RefCell::new( Vec::new() ) );
+        let mut tasks = Tasks::default();
+        tasks.queue.push_back( task( &log, 1, None ) );
+        tasks.queue.push_back( task( &log, 2, Some( 200.0 ) ) );
+        tasks.queue.push_back( task( &log, 3, None ) );
+        tasks.queue.push_back( task( &log, 4, Some( 100.0 ) ) );
+        assert_eq!( tasks.earliest_deadline(), Some( 100.0 ) );
+
+        // Nothing is urgent yet, so the tasks run in order.
+        assert!( tasks.pop_urgent( 0.0 ).is_none() );
+        (tasks.pop_next( 0.0 ).unwrap().callback)();
+
+        // The deadlines are approaching, so those tasks jump the queue.
+        (tasks.pop_next( 160.0 ).unwrap().callback)();
+        (tasks.pop_next( 160.0 ).unwrap().callback)();
+        (tasks.pop_next( 160.0 ).unwrap().callback)();
+        assert!( tasks.pop_next( 160.0 ).is_none() );
+
+        assert_eq!( *log.borrow(), vec![ 1, 2, 4, 3 ] );
+    }
+
+    #[test]
+    fn test_fifo_among_urgent() {
+        let log = Rc::new( RefCell::new( Vec::new() ) );
+        let mut tasks = Tasks::default();
+        tasks.queue.push_back( task( &log, 1, Some( 20.0 ) ) );
+        tasks.queue.push_back( task( &log, 2, Some( 10.0 ) ) );
+        while let Some( task ) = tasks.pop_urgent( 0.0 ) {
+            (task.callback)();
+        }
+
+        assert_eq!( *log.borrow(), vec![ 1, 2 ] );
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn test_cancel_all() {
+        use std::cell::Cell;
+
+        let ran = Rc::new( Cell::new( false ) );
+        let queue = TaskQueue::new();
+        queue.push( { let ran = ran.clone(); move || ran.set( true ) }, None );
+        queue.push( || {}, Some( 0 ) );
+        assert_eq!( queue.len(), 2 );
+
+        queue.cancel_all();
+        assert!( queue.is_empty() );
+        assert!( queue.inner.borrow().idle_handle.is_none() );
+        assert!( queue.inner.borrow().timer_handle.is_none() );
+        assert!( !ran.get() );
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn test_promotion_while_busy() {
+        use std::cell::Cell;
+        use webapi::global::set_timeout;
+        use webcore::async_test::async_test;
+
+        async_test( "test_promotion_while_busy", 1000, |done| {
+            // Pretend the main thread is too busy to ever become idle.
+            js! { @(no_return)
+                self.__stdweb_request_idle_callback = self.requestIdleCallback;
+                self.requestIdleCallback = function() { return 0; };
+            }
+
+            let urgent = Rc::new( Cell::new( false ) );
+            let background = Rc::new( Cell::new( false ) );
+            let queue = TaskQueue::new();
+            queue.push( { let background = background.clone(); move || background.set( true ) }, None );
+            queue.push( { let urgent = urgent.clone(); move || urgent.set( true ) }, Some( 100 ) );
+
+            js! { @(no_return)
+                if( self.__stdweb_request_idle_callback ) {
+                    self.requestIdleCallback = self.__stdweb_request_idle_callback;
+                } else {
+                    delete self.requestIdleCallback;
+                }
+                delete self.__stdweb_request_idle_callback;
+            }
+
+            set_timeout( move || {
+                assert!( urgent.get() );
+                assert!( !background.get() );
+                assert_eq!( queue.len(), 1 );
+                done.done();
+            }, 500 );
+        });
+    }
+}
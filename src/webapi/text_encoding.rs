@@ -0,0 +1,202 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webcore::unsafe_typed_array::UnsafeTypedArray;
+use webapi::typed_array::TypedArray;
+use webapi::error::{RangeError, TypeError};
+
+/// The `TextEncoder` interface encodes strings into UTF-8 bytes.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TextEncoder)
+// https://encoding.spec.whatwg.org/#interface-textencoder
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "TextEncoder")]
+pub struct TextEncoder( Reference );
+
+impl TextEncoder {
+    /// Creates a new `TextEncoder`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TextEncoder/TextEncoder)
+    // https://encoding.spec.whatwg.org/#dom-textencoder
+    pub fn new() -> TextEncoder {
+        js!( return new TextEncoder(); ).try_into().unwrap()
+    }
+
+    /// Encodes the given `text` into UTF-8 bytes.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TextEncoder/encode)
+    // https://encoding.spec.whatwg.org/#dom-textencoder-encode
+    pub fn encode( &self, text: &str ) -> Vec< u8 > {
+        let array: TypedArray< u8 > = js!( return @{self}.encode( @{text} ); ).try_into().unwrap();
+        array.to_vec()
+    }
+}
+
+/// Options for [TextDecoder::with_options](struct.TextDecoder.html#method.with_options).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TextDecoderOptions {
+    /// Whether malformed data should result in a [TypeError](struct.TypeError.html);
+    /// otherwise it's replaced with U+FFFD.
+    pub fatal: bool,
+    /// Whether the byte order mark should be kept in the output instead of being stripped.
+    pub ignore_bom: bool
+}
+
+/// The `TextDecoder` interface decodes bytes in a given encoding, e.g. UTF-8 or
+/// windows-1252, into strings.
+///
+/// # Examples
+///
+/// Decoding data which arrives in chunks, where a multi-byte character
+/// can be split across two chunks:
+///
+/// ```rust
+/// let decoder = TextDecoder::new( "utf-8" ).unwrap();
+/// let mut text = decoder.decode_stream( &[0x68, 0xC3], false ).unwrap();
+/// text += &decoder.decode_stream( &[0xA9], true ).unwrap();
+/// assert_eq!( text, "hé" );
+/// ```
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder)
+// https://encoding.spec.whatwg.org/#interface-textdecoder
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "TextDecoder")]
+pub struct TextDecoder( Reference );
+
+impl TextDecoder {
+    /// Creates a new `TextDecoder` for the encoding with the given `label`,
+    /// e.g. `"utf-8"` or `"latin1"`.
+    ///
+    /// Malformed data is replaced with U+FFFD.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [RangeError](struct.RangeError.html) if the encoding is unknown.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/TextDecoder)
+    // https://encoding.spec.whatwg.org/#dom-textdecoder
+    pub fn new( label: &str ) -> Result< TextDecoder, RangeError > {
+        TextDecoder::with_options( label, &TextDecoderOptions::default() )
+    }
+
+    /// Creates a new `TextDecoder` for the encoding with the given `label` and `options`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [RangeError](struct.RangeError.html) if the encoding is unknown.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/TextDecoder)
+    // https://encoding.spec.whatwg.org/#dom-textdecoder
+    pub fn with_options( label: &str, options: &TextDecoderOptions ) -> Result< TextDecoder, RangeError > {
+        js_try!(
+            return new TextDecoder( @{label}, {
+                fatal: @{options.fatal},
+                ignoreBOM: @{options.ignore_bom}
+            });
+        ).unwrap()
+    }
+
+    /// Returns the canonical name of the encoding of this decoder, e.g. `"windows-1252"` for `"latin1"`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/encoding)
+    // https://encoding.spec.whatwg.org/#dom-textdecoder-encoding
+    pub fn encoding( &self ) -> String {
+        js!( return @{self}.encoding; ).try_into().unwrap()
+    }
+
+    /// Returns whether malformed data results in an error.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/fatal)
+    // https://encoding.spec.whatwg.org/#dom-textdecoder-fatal
+    pub fn fatal( &self ) -> bool {
+        js!( return @{self}.fatal; ).try_into().unwrap()
+    }
+
+    /// Decodes the given `bytes` into a string.
+    ///
+    /// Any incomplete data buffered by previous calls to
+    /// [decode_stream](#method.decode_stream) is flushed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [TypeError](struct.TypeError.html) if the decoder is
+    /// [fatal](#method.fatal) and the data is malformed.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/decode)
+    // https://encoding.spec.whatwg.org/#dom-textdecoder-decode
+    pub fn decode( &self, bytes: &[u8] ) -> Result< String, TypeError > {
+        self.decode_stream( bytes, true )
+    }
+
+    /// Decodes one chunk of a stream of `bytes`; `last` should be `true` for the last chunk.
+    ///
+    /// Incomplete multi-byte sequences at the end of a chunk which isn't
+    /// the `last` one are buffered and decoded along with the next chunk.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [TypeError](struct.TypeError.html) if the decoder is
+    /// [fatal](#method.fatal) and the data is malformed.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/decode)
+    // https://encoding.spec.whatwg.org/#dom-textdecoder-decode
+    pub fn decode_stream( &self, bytes: &[u8], last: bool ) -> Result< String, TypeError > {
+        // This is safe since `decode` doesn't hold onto the array after it returns.
+        let bytes = unsafe { UnsafeTypedArray::new( bytes ) };
+        js_try!(
+            return @{self}.decode( @{bytes}, { stream: !@{last} } );
+        ).unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode() {
+        let text = "Zażółć gęślą jaźń ☃";
+        let bytes = TextEncoder::new().encode( text );
+        assert_eq!( bytes, text.as_bytes() );
+
+        let decoder = TextDecoder::new( "utf-8" ).unwrap();
+        assert_eq!( decoder.encoding(), "utf-8" );
+        assert_eq!( decoder.decode( &bytes ).unwrap(), text );
+    }
+
+    #[test]
+    fn test_unknown_encoding() {
+        assert!( TextDecoder::new( "no-such-encoding" ).is_err() );
+    }
+
+    #[test]
+    fn test_stream_split_character() {
+        let bytes = "a☃b".as_bytes();
+        let decoder = TextDecoder::with_options( "utf-8", &TextDecoderOptions { fatal: true, ignore_bom: false } ).unwrap();
+
+        let mut text = decoder.decode_stream( &bytes[ ..2 ], false ).unwrap();
+        assert_eq!( text, "a" );
+        text += &decoder.decode_stream( &bytes[ 2.. ], true ).unwrap();
+        assert_eq!( text, "a☃b" );
+    }
+
+    #[test]
+    fn test_malformed() {
+        let bytes = [0x61, 0xFF, 0x62];
+        assert_eq!( TextDecoder::new( "utf-8" ).unwrap().decode( &bytes ).unwrap(), "a\u{FFFD}b" );
+
+        let decoder = TextDecoder::with_options( "utf-8", &TextDecoderOptions { fatal: true, ignore_bom: false } ).unwrap();
+        assert!( decoder.fatal() );
+        assert!( decoder.decode( &bytes ).is_err() );
+
+        // An incomplete sequence at the end is only an error once the stream ends.
+        assert!( decoder.decode_stream( &[0xE2, 0x98], false ).is_ok() );
+        assert!( decoder.decode_stream( &[], true ).is_err() );
+    }
+
+    #[test]
+    fn test_latin1() {
+        let decoder = TextDecoder::new( "latin1" ).unwrap();
+        assert_eq!( decoder.encoding(), "windows-1252" );
+        assert_eq!( decoder.decode( &[0x63, 0x61, 0x66, 0xE9, 0x20, 0x80] ).unwrap(), "café €" );
+    }
+}
@@ -3,13 +3,16 @@ use std::mem::size_of;
 use webcore::value::Reference;
 use webcore::try_from::TryInto;
 use webcore::instance_of::InstanceOf;
+use webcore::unsafe_typed_array::{UnsafeTypedArray, UnsafeTypedArrayMut};
 use webapi::array_buffer::ArrayBuffer;
+use webapi::error::RangeError;
 
 pub trait ArrayKind: Sized {
     fn is_typed_array( reference: &Reference ) -> bool;
     fn into_typed_array( slice: &[Self] ) -> TypedArray< Self >;
     fn into_typed_array_from_array_buffer( buffer: &ArrayBuffer ) -> TypedArray< Self >;
     fn from_typed_array( array: &TypedArray< Self > ) -> Vec< Self >;
+    fn set_from_slice( array: &TypedArray< Self >, offset: u32, slice: &[Self] ) -> Result< (), RangeError >;
 }
 
 macro_rules! arraykind {
@@ -70,6 +73,14 @@ macro_rules! arraykind {
 
                 vector
             }
+
+            fn set_from_slice( array: &TypedArray< Self >, offset: u32, slice: &[Self] ) -> Result< (), RangeError > {
+                let slice_ptr = (slice.as_ptr() as usize / size_of::<$element_type>()) as i32;
+                js_try!( @(no_return)
+                    var pointer = @{slice_ptr};
+                    @{array}.set( $heap_type.subarray( pointer, pointer + @{slice.len() as u32} ), @{offset} );
+                ).unwrap()
+            }
         }
 
         impl From< TypedArray< $element_type > > for Vec< $element_type > {
@@ -130,6 +141,29 @@ impl< T: ArrayKind > TypedArray< T > {
     pub fn to_vec( &self ) -> Vec< T > {
         T::from_typed_array( self )
     }
+
+    /// Returns a new typed array which views the elements from `start` (inclusive)
+    /// to `end` (exclusive) of this one; the elements are **not** copied.
+    ///
+    /// The indexes are clamped to the length of the array.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/subarray)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-%typedarray%.prototype.subarray
+    pub fn subarray( &self, start: u32, end: u32 ) -> TypedArray< T > {
+        js!( return @{self}.subarray( @{start}, @{end} ); ).try_into().unwrap()
+    }
+
+    /// Copies the elements of `slice` into this array, starting at the element with index `offset`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [RangeError](struct.RangeError.html) if the `slice` doesn't fit in the array.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/set)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-%typedarray%.prototype.set-typedarray-offset
+    pub fn set_from_slice( &self, offset: u32, slice: &[T] ) -> Result< (), RangeError > {
+        T::set_from_slice( self, offset, slice )
+    }
 }
 
 impl< 'a, T: ArrayKind > From< &'a [T] > for TypedArray< T > {
@@ -150,6 +184,97 @@ impl< 'a, T: ArrayKind > From< &'a ArrayBuffer > for TypedArray< T > {
     }
 }
 
+impl From< TypedArray< u8 > > for ArrayBuffer {
+    fn from( array: TypedArray< u8 > ) -> Self {
+        (&array).into()
+    }
+}
+
+/// Copies the bytes viewed by the typed array into a new `ArrayBuffer`.
+impl< 'a > From< &'a TypedArray< u8 > > for ArrayBuffer {
+    fn from( array: &'a TypedArray< u8 > ) -> Self {
+        js!(
+            var array = @{array};
+            return array.buffer.slice( array.byteOffset, array.byteOffset + array.byteLength );
+        ).try_into().unwrap()
+    }
+}
+
+/// A typed array of 8-bit unsigned integers which are clamped to the 0-255 range.
+///
+/// Unlike a `TypedArray< u8 >` (an `Uint8Array`), which wraps values around on write,
+/// every value written into this array from JavaScript saturates instead: anything
+/// below `0` becomes `0`, anything above `255` becomes `255`, and fractional values
+/// are rounded to the nearest integer (with ties going to the even one).
+/// This is the type of the pixel data of an [ImageData](struct.ImageData.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint8ClampedArray)
+// https://www.ecma-international.org/ecma-262/6.0/#table-49
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Uint8ClampedArray")]
+pub struct Uint8ClampedArray( Reference );
+
+impl Uint8ClampedArray {
+    /// Returns the [ArrayBuffer](struct.ArrayBuffer.html) referenced by this typed array.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/buffer)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-get-%typedarray%.prototype.buffer
+    pub fn buffer( &self ) -> ArrayBuffer {
+        js!( return @{self}.buffer; ).try_into().unwrap()
+    }
+
+    /// Returns the number of elements in the buffer.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/length)
+    pub fn len( &self ) -> u32 {
+        js!( return @{self}.length; ).try_into().unwrap()
+    }
+
+    /// Copies `self` into a new `Vec`.
+    pub fn to_vec( &self ) -> Vec< u8 > {
+        let mut vector = vec![ 0; self.len() as usize ];
+        {
+            let slice = unsafe { UnsafeTypedArrayMut::new( &mut vector ) };
+            js!( @(no_return)
+                @{slice}.set( @{self} );
+            );
+        }
+
+        vector
+    }
+}
+
+impl< 'a > From< &'a [u8] > for Uint8ClampedArray {
+    fn from( slice: &'a [u8] ) -> Self {
+        let slice = unsafe { UnsafeTypedArray::new( slice ) };
+        js!( return new Uint8ClampedArray( @{slice} ); ).try_into().unwrap()
+    }
+}
+
+impl< 'a > From< &'a ArrayBuffer > for Uint8ClampedArray {
+    fn from( buffer: &'a ArrayBuffer ) -> Self {
+        js!( return new Uint8ClampedArray( @{buffer} ); ).try_into().unwrap()
+    }
+}
+
+impl From< ArrayBuffer > for Uint8ClampedArray {
+    fn from( buffer: ArrayBuffer ) -> Self {
+        (&buffer).into()
+    }
+}
+
+impl From< Uint8ClampedArray > for Vec< u8 > {
+    fn from( array: Uint8ClampedArray ) -> Self {
+        array.to_vec()
+    }
+}
+
+impl< 'a > From< &'a Uint8ClampedArray > for Vec< u8 > {
+    fn from( array: &'a Uint8ClampedArray ) -> Self {
+        array.to_vec()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     macro_rules! arraykind_test {
@@ -205,10 +330,83 @@ mod tests {
                     assert_eq!( vec.len(), ARRAY.len() );
                     assert_eq!( vec, ARRAY );
                 }
+
+                #[test]
+                fn round_trip() {
+                    let typed_array: TypedArray< $element_type > = ARRAY.into();
+                    assert_eq!( typed_array.to_vec(), ARRAY );
+
+                    let empty: &[$element_type] = &[];
+                    let typed_array: TypedArray< $element_type > = empty.into();
+                    assert_eq!( typed_array.len(), 0 );
+                    assert_eq!( typed_array.to_vec(), empty );
+                }
+
+                #[test]
+                fn subarray() {
+                    let typed_array: TypedArray< $element_type > = ARRAY.into();
+                    assert_eq!( typed_array.subarray( 1, 2 ).to_vec(), &ARRAY[ 1..2 ] );
+                    assert_eq!( typed_array.subarray( 1, 1 ).len(), 0 );
+                }
+
+                #[test]
+                fn set_from_slice() {
+                    let initial: &[$element_type] = &[ ARRAY[ 1 ]; 3 ];
+                    let typed_array: TypedArray< $element_type > = initial.into();
+                    typed_array.set_from_slice( 1, ARRAY ).unwrap();
+                    assert_eq!( typed_array.to_vec(), &[ ARRAY[ 1 ], ARRAY[ 0 ], ARRAY[ 1 ] ] );
+                    typed_array.set_from_slice( 3, &[] ).unwrap();
+                    assert!( typed_array.set_from_slice( 2, ARRAY ).is_err() );
+                }
+
+                #[test]
+                fn try_from_wrong_type() {
+                    let value = js!( return new Array( 2 ); );
+                    let result: Result< TypedArray< $element_type >, _ > = value.try_into();
+                    assert!( result.is_err() );
+                }
             }
         }
     }
 
+    #[test]
+    fn array_buffer_of_subarray() {
+        use super::TypedArray;
+        use webapi::array_buffer::ArrayBuffer;
+
+        let typed_array: TypedArray< u8 > = (&[1_u8, 2, 3, 4][..]).into();
+        let buffer: ArrayBuffer = typed_array.subarray( 1, 3 ).into();
+        assert_eq!( buffer.len(), 2 );
+        assert_eq!( Vec::< u8 >::from( buffer ), &[2, 3] );
+    }
+
+    #[test]
+    fn uint8_clamped_array() {
+        use super::{TypedArray, Uint8ClampedArray};
+        use webcore::try_from::TryInto;
+
+        let array: Uint8ClampedArray = (&[0_u8, 128, 255][..]).into();
+        assert_eq!( array.len(), 3 );
+        assert_eq!( array.to_vec(), &[0, 128, 255] );
+
+        js!( @(no_return)
+            var array = @{&array};
+            array[ 0 ] = -20;
+            array[ 1 ] = 300;
+            array[ 2 ] = 1.5;
+        );
+        assert_eq!( Vec::< u8 >::from( &array ), &[0, 255, 2] );
+
+        let array: Uint8ClampedArray = js!( return new Uint8ClampedArray( [ 1, 2 ] ); ).try_into().unwrap();
+        assert_eq!( array.to_vec(), &[1, 2] );
+
+        let array: Uint8ClampedArray = array.buffer().into();
+        assert_eq!( array.to_vec(), &[1, 2] );
+
+        let result: Result< TypedArray< u8 >, _ > = js!( return @{&array}; ).try_into();
+        assert!( result.is_err() );
+    }
+
     arraykind_test!(i8, Int8Array);
     arraykind_test!(u8, Uint8Array);
     arraykind_test!(i16, Int16Array);
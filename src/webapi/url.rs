@@ -0,0 +1,232 @@
+use std::fmt;
+use std::error;
+use webcore::try_from::TryInto;
+use webapi::blob::IBlob;
+use webapi::error::TypeError;
+
+/// Creates a new URL which refers to the given `blob`, e.g. to play back
+/// recorded media or to download generated data.
+///
+/// The URL keeps the `blob` alive until it's released with
+/// [revoke_object_url](fn.revoke_object_url.html) or until the page is unloaded.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/createObjectURL)
+// https://w3c.github.io/FileAPI/#dfn-createObjectURL
+pub fn create_object_url< T: IBlob >( blob: &T ) -> String {
+    js!( return URL.createObjectURL( @{blob.as_ref()} ); ).try_into().unwrap()
+}
+
+/// Releases an URL previously created with [create_object_url](fn.create_object_url.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/revokeObjectURL)
+// https://w3c.github.io/FileAPI/#dfn-revokeObjectURL
+pub fn revoke_object_url( url: &str ) {
+    js! { @(no_return)
+        URL.revokeObjectURL( @{url} );
+    }
+}
+
+/// Resolves `path` relative to the `base` URL, exactly as a browser resolves
+/// a link, and returns the resulting absolute URL.
+///
+/// Relative paths replace the last segment of the `base` path, paths starting
+/// with `/` replace the whole path, and fully qualified URLs are returned as-is.
+/// The query string and the fragment of the `base` are dropped whenever the
+/// `path` has a path of its own, while a `path` consisting only of a query
+/// string or a fragment keeps the rest of the `base`.
+///
+/// # Errors
+///
+/// Returns a [TypeError](struct.TypeError.html) if the `base`
+/// isn't an absolute URL, or if the result can't be parsed.
+///
+/// # Examples
+///
+/// ```rust
+/// let base = "https://example.com/api/v1/";
+/// assert_eq!( resolve_url( base, "items?page=2" ).unwrap(), "https://example.com/api/v1/items?page=2" );
+/// assert_eq!( resolve_url( base, "/health" ).unwrap(), "https://example.com/health" );
+///
+/// let request = XmlHttpRequest::new();
+/// request.open( "GET", &resolve_url( base, "items" ).unwrap() ).unwrap();
+/// ```
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/URL)
+// https://url.spec.whatwg.org/#dom-url-url
+pub fn resolve_url( base: &str, path: &str ) -> Result< String, TypeError > {
+    js_try!(
+        return new URL( @{path}, @{base} ).href;
+    ).unwrap()
+}
+
+/// The error returned by [expand_template](fn.expand_template.html)
+/// when the template can't be expanded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UrlTemplateError {
+    /// No value was given for the placeholder with this name.
+    MissingParameter( String ),
+    /// A `{` at this byte offset isn't closed by a `}`.
+    UnclosedPlaceholder( usize )
+}
+
+impl fmt::Display for UrlTemplateError {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        match *self {
+            UrlTemplateError::MissingParameter( ref name ) => write!( formatter, "no value for the `{{{}}}` placeholder", name ),
+            UrlTemplateError::UnclosedPlaceholder( offset ) => write!( formatter, "unclosed placeholder at offset {}", offset )
+        }
+    }
+}
+
+impl error::Error for UrlTemplateError {
+    fn description( &self ) -> &str {
+        match *self {
+            UrlTemplateError::MissingParameter( _ ) => "missing template parameter",
+            UrlTemplateError::UnclosedPlaceholder( _ ) => "unclosed template placeholder"
+        }
+    }
+}
+
+/// Percent-encodes everything except the characters which
+/// `encodeURIComponent` leaves alone.
+pub(crate) fn percent_encode( input: &str, output: &mut String ) {
+    for &byte in input.as_bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' |
+            b'-' | b'_' | b'.' | b'!' | b'~' | b'*' | b'\'' | b'(' | b')' => output.push( byte as char ),
+            _ => output.push_str( &format!( "%{:02X}", byte ) )
+        }
+    }
+}
+
+fn expand( template: &str, params: &[(&str, &str)], used: &mut [bool] ) -> Result< String, UrlTemplateError > {
+    let mut output = String::with_capacity( template.len() );
+    let mut rest = template;
+    while let Some( start ) = rest.find( '{' ) {
+        output.push_str( &rest[ ..start ] );
+        let end = match rest[ start.. ].find( '}' ) {
+            Some( end ) => start + end,
+            None => return Err( UrlTemplateError::UnclosedPlaceholder( template.len() - rest.len() + start ) )
+        };
+
+        let name = &rest[ start + 1..end ];
+        match params.iter().position( |&(key, _)| key == name ) {
+            Some( index ) => {
+                used[ index ] = true;
+                percent_encode( params[ index ].1, &mut output );
+            },
+            None => return Err( UrlTemplateError::MissingParameter( name.to_owned() ) )
+        }
+
+        rest = &rest[ end + 1.. ];
+    }
+
+    output.push_str( rest );
+    Ok( output )
+}
+
+/// Replaces the `{name}` placeholders in a URL `template` with the
+/// percent-encoded values of the matching `params`.
+///
+/// Values are encoded like `encodeURIComponent` does, so a value can never
+/// introduce another path segment or a query string. Parameters which don't
+/// appear in the template are ignored; use
+/// [expand_template_with_query](fn.expand_template_with_query.html) to send
+/// them as query parameters instead.
+///
+/// # Errors
+///
+/// Returns an error if a placeholder has no matching parameter,
+/// or if a `{` isn't closed.
+///
+/// # Examples
+///
+/// ```rust
+/// let url = expand_template( "/users/{id}/posts/{postId}", &[ ("id", "42"), ("postId", "a/b") ] ).unwrap();
+/// assert_eq!( url, "/users/42/posts/a%2Fb" );
+/// ```
+pub fn expand_template( template: &str, params: &[(&str, &str)] ) -> Result< String, UrlTemplateError > {
+    let mut used = vec![ false; params.len() ];
+    expand( template, params, &mut used )
+}
+
+/// Works like [expand_template](fn.expand_template.html), except that the
+/// parameters which don't appear in the template are appended to the URL
+/// as percent-encoded query parameters, in order.
+///
+/// # Examples
+///
+/// ```rust
+/// let url = expand_template_with_query( "/users/{id}/posts", &[ ("id", "42"), ("sort", "new first") ] ).unwrap();
+/// assert_eq!( url, "/users/42/posts?sort=new%20first" );
+/// ```
+pub fn expand_template_with_query( template: &str, params: &[(&str, &str)] ) -> Result< String, UrlTemplateError > {
+    let mut used = vec![ false; params.len() ];
+    let mut output = expand( template, params, &mut used )?;
+    let mut separator = if output.contains( '?' ) { '&' } else { '?' };
+    for (&(key, value), &used) in params.iter().zip( used.iter() ) {
+        if used {
+            continue;
+        }
+
+        output.push( separator );
+        percent_encode( key, &mut output );
+        output.push( '=' );
+        percent_encode( value, &mut output );
+        separator = '&';
+    }
+
+    Ok( output )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_template() {
+        assert_eq!( expand_template( "/users/{id}/posts/{postId}", &[ ("postId", "7"), ("id", "42") ] ).unwrap(), "/users/42/posts/7" );
+        assert_eq!( expand_template( "/search/{q}", &[ ("q", "a b/c?d&é"), ("unused", "x") ] ).unwrap(), "/search/a%20b%2Fc%3Fd%26%C3%A9" );
+        assert_eq!( expand_template( "/static", &[] ).unwrap(), "/static" );
+        assert_eq!( expand_template( "/{a}{a}", &[ ("a", "x") ] ).unwrap(), "/xx" );
+    }
+
+    #[test]
+    fn test_expand_template_errors() {
+        assert_eq!( expand_template( "/users/{id}/posts/{postId}", &[ ("id", "42") ] ), Err( UrlTemplateError::MissingParameter( "postId".to_owned() ) ) );
+        assert_eq!( expand_template( "/users/{id", &[ ("id", "42") ] ), Err( UrlTemplateError::UnclosedPlaceholder( 7 ) ) );
+    }
+
+    #[test]
+    fn test_expand_template_with_query() {
+        let params = [ ("id", "42"), ("sort", "new first"), ("tag", "a&b") ];
+        assert_eq!( expand_template_with_query( "/users/{id}", &params ).unwrap(), "/users/42?sort=new%20first&tag=a%26b" );
+        assert_eq!( expand_template_with_query( "/users/{id}?page=2", &params ).unwrap(), "/users/42?page=2&sort=new%20first&tag=a%26b" );
+        assert_eq!( expand_template_with_query( "/users/{id}", &params[ ..1 ] ).unwrap(), "/users/42" );
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn test_resolve_url() {
+        let base = "https://example.com/api/v1/items?page=1#top";
+        assert_eq!( resolve_url( base, "details" ).unwrap(), "https://example.com/api/v1/details" );
+        assert_eq!( resolve_url( base, "../v2/" ).unwrap(), "https://example.com/api/v2/" );
+        assert_eq!( resolve_url( base, "/health" ).unwrap(), "https://example.com/health" );
+        assert_eq!( resolve_url( base, "?page=2" ).unwrap(), "https://example.com/api/v1/items?page=2" );
+        assert_eq!( resolve_url( base, "#bottom" ).unwrap(), "https://example.com/api/v1/items?page=1#bottom" );
+        assert_eq!( resolve_url( base, "//cdn.example.com/a.js" ).unwrap(), "https://cdn.example.com/a.js" );
+        assert_eq!( resolve_url( base, "http://other.com/x?y=1" ).unwrap(), "http://other.com/x?y=1" );
+        assert_eq!( resolve_url( base, "" ).unwrap(), "https://example.com/api/v1/items?page=1" );
+
+        // Without a trailing slash the last segment of the base is replaced.
+        assert_eq!( resolve_url( "https://example.com/api", "items" ).unwrap(), "https://example.com/items" );
+        assert_eq!( resolve_url( "https://example.com/api/", "items" ).unwrap(), "https://example.com/api/items" );
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn test_resolve_url_invalid() {
+        assert!( resolve_url( "/relative/base", "items" ).is_err() );
+        assert!( resolve_url( "https://example.com", "http://[invalid" ).is_err() );
+    }
+}
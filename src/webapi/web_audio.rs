@@ -0,0 +1,569 @@
+use webcore::value::{Value, Reference};
+use webcore::try_from::{TryFrom, TryInto};
+use webcore::unsafe_typed_array::UnsafeTypedArray;
+use webcore::promise::Promise;
+use webcore::once::Once;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::typed_array::TypedArray;
+use webapi::dom_exception::{EncodingError, IndexSizeError, NotSupportedError};
+use webapi::error::Error;
+
+error_enum_boilerplate! {
+    /// Errors which can occur when decoding audio data with
+    /// [AudioContext::decode_audio_data](struct.AudioContext.html#method.decode_audio_data).
+    DecodeError,
+    /// The data is corrupt, or is in a format which is not supported.
+    EncodingError,
+    /// Any other error.
+    Error
+}
+
+/// The state of an [AudioContext](struct.AudioContext.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BaseAudioContext/state)
+// https://webaudio.github.io/web-audio-api/#enumdef-audiocontextstate
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AudioContextState {
+    /// The context is suspended; the time doesn't advance and no audio is played.
+    ///
+    /// Most browsers create contexts in this state until the user interacts with the page.
+    Suspended,
+    /// The context is processing audio.
+    Running,
+    /// The context was closed and can't be used anymore.
+    Closed
+}
+
+/// The `AudioContext` interface represents an audio-processing graph built from
+/// audio nodes linked together.
+///
+/// # Examples
+///
+/// Playing a generated sine wave once the user clicks a button; browsers only allow
+/// a context to start playing after a user gesture, so it has to be resumed from
+/// within the event handler:
+///
+/// ```rust
+/// let context = AudioContext::new().unwrap();
+/// let sample_rate = context.sample_rate();
+/// let samples: Vec< f32 > = (0..sample_rate as usize)
+///     .map( |index| (index as f32 * 440.0 * 2.0 * ::std::f32::consts::PI / sample_rate).sin() )
+///     .collect();
+///
+/// let buffer = context.create_buffer_from_samples( &samples, sample_rate ).unwrap();
+/// let gain = context.create_gain();
+/// gain.gain().set_value( 0.25 );
+/// gain.connect( &context.destination() );
+///
+/// let button = document().query_selector( "#play" ).unwrap().unwrap();
+/// button.add_event_listener( move |_: ClickEvent| {
+///     let source = context.create_buffer_source();
+///     source.set_buffer( Some( &buffer ) );
+///     source.connect( &gain );
+///
+///     if context.state() == AudioContextState::Suspended {
+///         context.resume( move |_| source.start() );
+///     } else {
+///         source.start();
+///     }
+/// });
+/// ```
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioContext)
+// https://webaudio.github.io/web-audio-api/#AudioContext
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "AudioContext")]
+#[reference(subclass_of(EventTarget))]
+pub struct AudioContext( Reference );
+
+impl IEventTarget for AudioContext {}
+
+impl AudioContext {
+    /// Creates a new `AudioContext`.
+    ///
+    /// This will fail if the browser doesn't support the Web Audio API,
+    /// or if too many contexts were already created.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioContext/AudioContext)
+    // https://webaudio.github.io/web-audio-api/#dom-audiocontext-audiocontext
+    pub fn new() -> Result< AudioContext, Error > {
+        js_try!(
+            var AudioContext = window.AudioContext || window.webkitAudioContext;
+            if( !AudioContext ) {
+                throw new Error( "The Web Audio API is not supported" );
+            }
+
+            return new AudioContext();
+        ).unwrap()
+    }
+
+    /// Returns the node to which all of the audio which should be heard has to be
+    /// routed; it usually represents the speakers.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BaseAudioContext/destination)
+    // https://webaudio.github.io/web-audio-api/#dom-baseaudiocontext-destination
+    pub fn destination( &self ) -> AudioDestinationNode {
+        js!( return @{self}.destination; ).try_into().unwrap()
+    }
+
+    /// Returns the time in seconds since the context was created; this doesn't advance
+    /// while the context is suspended.
+    ///
+    /// Every time passed to e.g. [AudioBufferSourceNode::start_at](struct.AudioBufferSourceNode.html#method.start_at)
+    /// is relative to this clock.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BaseAudioContext/currentTime)
+    // https://webaudio.github.io/web-audio-api/#dom-baseaudiocontext-currenttime
+    pub fn current_time( &self ) -> f64 {
+        js!( return @{self}.currentTime; ).try_into().unwrap()
+    }
+
+    /// Returns the sample rate, in samples per second, used by all of the nodes of the context.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BaseAudioContext/sampleRate)
+    // https://webaudio.github.io/web-audio-api/#dom-baseaudiocontext-samplerate
+    pub fn sample_rate( &self ) -> f32 {
+        let value: f64 = js!( return @{self}.sampleRate; ).try_into().unwrap();
+        value as f32
+    }
+
+    /// Returns the current state of the context.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BaseAudioContext/state)
+    // https://webaudio.github.io/web-audio-api/#dom-baseaudiocontext-state
+    pub fn state( &self ) -> AudioContextState {
+        let state: String = js!( return @{self}.state; ).try_into().unwrap();
+        match state.as_str() {
+            "suspended" => AudioContextState::Suspended,
+            "running" => AudioContextState::Running,
+            "closed" => AudioContextState::Closed,
+            other => panic!( "Invalid AudioContext state: {:?}", other )
+        }
+    }
+
+    /// Resumes a suspended context.
+    ///
+    /// This will only succeed when called from within a user gesture handler,
+    /// or after the user has already interacted with the page.
+    ///
+    /// The `callback` is called once the context has been resumed.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioContext/resume)
+    // https://webaudio.github.io/web-audio-api/#dom-audiocontext-resume
+    pub fn resume< F: FnOnce( Result< (), Error > ) + 'static >( &self, callback: F ) {
+        let promise: Promise = js!( return @{self}.resume(); ).try_into().unwrap();
+        promise.done( callback );
+    }
+
+    /// Decodes the given encoded audio `data`, e.g. the contents of an `.ogg` file,
+    /// into an [AudioBuffer](struct.AudioBuffer.html).
+    ///
+    /// The `callback` is called once the data has been decoded.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BaseAudioContext/decodeAudioData)
+    // https://webaudio.github.io/web-audio-api/#dom-baseaudiocontext-decodeaudiodata
+    pub fn decode_audio_data< F >( &self, data: &[u8], callback: F )
+        where F: FnOnce( Result< AudioBuffer, DecodeError > ) + 'static
+    {
+        let callback = move |success: bool, value: Value| {
+            if success {
+                callback( Ok( value.try_into().unwrap() ) );
+            } else {
+                callback( Err( value.try_into().unwrap() ) );
+            }
+        };
+
+        let data = unsafe { UnsafeTypedArray::new( data ) };
+        js! { @(no_return)
+            // `decodeAudioData` detaches the buffer it's given, so it needs its own copy.
+            var buffer = new Uint8Array( @{data} ).buffer;
+            var callback = @{Once( callback )};
+            var called = false;
+            var done = function( success, value ) {
+                if( !called ) {
+                    called = true;
+                    callback( success, value );
+                }
+            };
+
+            // Older browsers only support the callback based version,
+            // and call the error callback without an error.
+            var promise = @{self}.decodeAudioData( buffer, function( audio_buffer ) {
+                done( true, audio_buffer );
+            }, function( error ) {
+                done( false, error || new Error( "Unable to decode the audio data" ) );
+            });
+
+            if( promise ) {
+                promise.catch( function( error ) {
+                    done( false, error );
+                });
+            }
+        }
+    }
+
+    /// Creates a new empty [AudioBuffer](struct.AudioBuffer.html) with the given
+    /// number of channels, length in samples and sample rate.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [NotSupportedError](struct.NotSupportedError.html) if any of the
+    /// arguments is zero or outside of the range supported by the browser.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BaseAudioContext/createBuffer)
+    // https://webaudio.github.io/web-audio-api/#dom-baseaudiocontext-createbuffer
+    pub fn create_buffer( &self, number_of_channels: u32, length: u32, sample_rate: f32 ) -> Result< AudioBuffer, NotSupportedError > {
+        js_try!(
+            return @{self}.createBuffer( @{number_of_channels}, @{length}, @{sample_rate} );
+        ).unwrap()
+    }
+
+    /// Creates a new single channel [AudioBuffer](struct.AudioBuffer.html)
+    /// containing the given `samples`, which should be in the `-1.0` to `1.0` range.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [NotSupportedError](struct.NotSupportedError.html) if `samples` is empty
+    /// or if the `sample_rate` is outside of the range supported by the browser.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BaseAudioContext/createBuffer)
+    // https://webaudio.github.io/web-audio-api/#dom-baseaudiocontext-createbuffer
+    pub fn create_buffer_from_samples( &self, samples: &[f32], sample_rate: f32 ) -> Result< AudioBuffer, NotSupportedError > {
+        let samples = unsafe { UnsafeTypedArray::new( samples ) };
+        js_try!(
+            var samples = @{samples};
+            var buffer = @{self}.createBuffer( 1, samples.length, @{sample_rate} );
+            buffer.getChannelData( 0 ).set( samples );
+            return buffer;
+        ).unwrap()
+    }
+
+    /// Creates a new [AudioBufferSourceNode](struct.AudioBufferSourceNode.html),
+    /// which plays back the contents of an [AudioBuffer](struct.AudioBuffer.html).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BaseAudioContext/createBufferSource)
+    // https://webaudio.github.io/web-audio-api/#dom-baseaudiocontext-createbuffersource
+    pub fn create_buffer_source( &self ) -> AudioBufferSourceNode {
+        js!( return @{self}.createBufferSource(); ).try_into().unwrap()
+    }
+
+    /// Creates a new [GainNode](struct.GainNode.html), which can be used to control the volume.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BaseAudioContext/createGain)
+    // https://webaudio.github.io/web-audio-api/#dom-baseaudiocontext-creategain
+    pub fn create_gain( &self ) -> GainNode {
+        js!( return @{self}.createGain(); ).try_into().unwrap()
+    }
+}
+
+/// The `IAudioNode` interface represents a node of an audio-processing graph,
+/// e.g. an audio source or a volume control.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioNode)
+// https://webaudio.github.io/web-audio-api/#AudioNode
+pub trait IAudioNode: IEventTarget {
+    /// Routes the output of this node into the given `destination` node.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioNode/connect)
+    // https://webaudio.github.io/web-audio-api/#dom-audionode-connect
+    fn connect< T: IAudioNode >( &self, destination: &T ) {
+        js! { @(no_return)
+            @{self.as_ref()}.connect( @{destination.as_ref()} );
+        }
+    }
+
+    /// Disconnects all of the outgoing connections of this node.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioNode/disconnect)
+    // https://webaudio.github.io/web-audio-api/#dom-audionode-disconnect
+    fn disconnect( &self ) {
+        js! { @(no_return)
+            @{self.as_ref()}.disconnect();
+        }
+    }
+}
+
+/// A reference to a JavaScript object which implements the [IAudioNode](trait.IAudioNode.html)
+/// interface.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioNode)
+// https://webaudio.github.io/web-audio-api/#AudioNode
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "AudioNode")]
+#[reference(subclass_of(EventTarget))]
+pub struct AudioNode( Reference );
+
+impl IEventTarget for AudioNode {}
+impl IAudioNode for AudioNode {}
+
+/// The `AudioDestinationNode` represents the final destination of the audio
+/// of an [AudioContext](struct.AudioContext.html), usually the speakers.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioDestinationNode)
+// https://webaudio.github.io/web-audio-api/#AudioDestinationNode
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "AudioDestinationNode")]
+#[reference(subclass_of(EventTarget, AudioNode))]
+pub struct AudioDestinationNode( Reference );
+
+impl IEventTarget for AudioDestinationNode {}
+impl IAudioNode for AudioDestinationNode {}
+
+/// The `AudioBufferSourceNode` plays back the audio data stored in an
+/// [AudioBuffer](struct.AudioBuffer.html).
+///
+/// A source node can only be started once; to play the same buffer
+/// again a new source node has to be created.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioBufferSourceNode)
+// https://webaudio.github.io/web-audio-api/#AudioBufferSourceNode
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "AudioBufferSourceNode")]
+#[reference(subclass_of(EventTarget, AudioNode))]
+pub struct AudioBufferSourceNode( Reference );
+
+impl IEventTarget for AudioBufferSourceNode {}
+impl IAudioNode for AudioBufferSourceNode {}
+
+impl AudioBufferSourceNode {
+    /// Returns the buffer which will be played back, if any.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioBufferSourceNode/buffer)
+    // https://webaudio.github.io/web-audio-api/#dom-audiobuffersourcenode-buffer
+    pub fn buffer( &self ) -> Option< AudioBuffer > {
+        js!( return @{self}.buffer; ).try_into().unwrap()
+    }
+
+    /// Sets the buffer which will be played back.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioBufferSourceNode/buffer)
+    // https://webaudio.github.io/web-audio-api/#dom-audiobuffersourcenode-buffer
+    pub fn set_buffer( &self, buffer: Option< &AudioBuffer > ) {
+        js! { @(no_return)
+            @{self}.buffer = @{buffer};
+        }
+    }
+
+    /// Starts the playback immediately.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioBufferSourceNode/start)
+    // https://webaudio.github.io/web-audio-api/#dom-audiobuffersourcenode-start
+    pub fn start( &self ) {
+        js! { @(no_return)
+            @{self}.start();
+        }
+    }
+
+    /// Schedules the playback to start at the given time, in seconds, of the
+    /// [AudioContext::current_time](struct.AudioContext.html#method.current_time) clock.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioBufferSourceNode/start)
+    // https://webaudio.github.io/web-audio-api/#dom-audiobuffersourcenode-start
+    pub fn start_at( &self, when: f64 ) {
+        js! { @(no_return)
+            @{self}.start( @{when} );
+        }
+    }
+
+    /// Stops the playback immediately.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioScheduledSourceNode/stop)
+    // https://webaudio.github.io/web-audio-api/#dom-audioscheduledsourcenode-stop
+    pub fn stop( &self ) {
+        js! { @(no_return)
+            @{self}.stop();
+        }
+    }
+}
+
+/// The `GainNode` changes the volume of the audio passing through it.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/GainNode)
+// https://webaudio.github.io/web-audio-api/#GainNode
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "GainNode")]
+#[reference(subclass_of(EventTarget, AudioNode))]
+pub struct GainNode( Reference );
+
+impl IEventTarget for GainNode {}
+impl IAudioNode for GainNode {}
+
+impl GainNode {
+    /// Returns the amount of gain to apply; `1.0` leaves the volume unchanged.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/GainNode/gain)
+    // https://webaudio.github.io/web-audio-api/#dom-gainnode-gain
+    pub fn gain( &self ) -> AudioParam {
+        js!( return @{self}.gain; ).try_into().unwrap()
+    }
+}
+
+/// The `AudioParam` interface represents a parameter of an audio node,
+/// e.g. the gain of a [GainNode](struct.GainNode.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioParam)
+// https://webaudio.github.io/web-audio-api/#AudioParam
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "AudioParam")]
+pub struct AudioParam( Reference );
+
+impl AudioParam {
+    /// Returns the current value of the parameter.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioParam/value)
+    // https://webaudio.github.io/web-audio-api/#dom-audioparam-value
+    pub fn value( &self ) -> f32 {
+        let value: f64 = js!( return @{self}.value; ).try_into().unwrap();
+        value as f32
+    }
+
+    /// Sets the value of the parameter immediately.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioParam/value)
+    // https://webaudio.github.io/web-audio-api/#dom-audioparam-value
+    pub fn set_value( &self, value: f32 ) {
+        js! { @(no_return)
+            @{self}.value = @{value};
+        }
+    }
+
+    /// Schedules the parameter to change to the given `value` at the given time, in seconds,
+    /// of the [AudioContext::current_time](struct.AudioContext.html#method.current_time) clock.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioParam/setValueAtTime)
+    // https://webaudio.github.io/web-audio-api/#dom-audioparam-setvalueattime
+    pub fn set_value_at_time( &self, value: f32, time: f64 ) {
+        js! { @(no_return)
+            @{self}.setValueAtTime( @{value}, @{time} );
+        }
+    }
+}
+
+/// The `AudioBuffer` interface represents a short audio asset residing in memory.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioBuffer)
+// https://webaudio.github.io/web-audio-api/#AudioBuffer
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "AudioBuffer")]
+pub struct AudioBuffer( Reference );
+
+impl AudioBuffer {
+    /// Returns the sample rate of the buffer, in samples per second.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioBuffer/sampleRate)
+    // https://webaudio.github.io/web-audio-api/#dom-audiobuffer-samplerate
+    pub fn sample_rate( &self ) -> f32 {
+        let value: f64 = js!( return @{self}.sampleRate; ).try_into().unwrap();
+        value as f32
+    }
+
+    /// Returns the length of the buffer in samples.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioBuffer/length)
+    // https://webaudio.github.io/web-audio-api/#dom-audiobuffer-length
+    pub fn length( &self ) -> u32 {
+        js!( return @{self}.length; ).try_into().unwrap()
+    }
+
+    /// Returns the duration of the buffer in seconds.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioBuffer/duration)
+    // https://webaudio.github.io/web-audio-api/#dom-audiobuffer-duration
+    pub fn duration( &self ) -> f64 {
+        js!( return @{self}.duration; ).try_into().unwrap()
+    }
+
+    /// Returns the number of channels of the buffer.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioBuffer/numberOfChannels)
+    // https://webaudio.github.io/web-audio-api/#dom-audiobuffer-numberofchannels
+    pub fn number_of_channels( &self ) -> u32 {
+        js!( return @{self}.numberOfChannels; ).try_into().unwrap()
+    }
+
+    /// Returns a copy of the samples of the given channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [IndexSizeError](struct.IndexSizeError.html) if there is no such `channel`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioBuffer/getChannelData)
+    // https://webaudio.github.io/web-audio-api/#dom-audiobuffer-getchanneldata
+    pub fn get_channel_data( &self, channel: u32 ) -> Result< Vec< f32 >, IndexSizeError > {
+        let data: Result< TypedArray< f32 >, IndexSizeError > = js_try!(
+            return @{self}.getChannelData( @{channel} );
+        ).unwrap();
+
+        data.map( |data| data.to_vec() )
+    }
+
+    /// Copies the given `samples` into the given channel, starting at its beginning.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [IndexSizeError](struct.IndexSizeError.html) if there is no such `channel`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioBuffer/copyToChannel)
+    // https://webaudio.github.io/web-audio-api/#dom-audiobuffer-copytochannel
+    pub fn copy_to_channel( &self, samples: &[f32], channel: u32 ) -> Result< (), IndexSizeError > {
+        let samples = unsafe { UnsafeTypedArray::new( samples ) };
+        js_try!( @(no_return)
+            var samples = @{samples};
+            var data = @{self}.getChannelData( @{channel} );
+            data.set( samples.length > data.length ? samples.subarray( 0, data.length ) : samples );
+        ).unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    fn sine_wave( sample_rate: f32 ) -> Vec< f32 > {
+        (0..sample_rate as usize / 10)
+            .map( |index| (index as f32 * 440.0 * 2.0 * PI / sample_rate).sin() )
+            .collect()
+    }
+
+    #[test]
+    fn test_play_sine_wave() {
+        let context = AudioContext::new().unwrap();
+        assert_ne!( context.state(), AudioContextState::Closed );
+
+        let sample_rate = context.sample_rate();
+        let samples = sine_wave( sample_rate );
+        let buffer = context.create_buffer_from_samples( &samples, sample_rate ).unwrap();
+        assert_eq!( buffer.number_of_channels(), 1 );
+        assert_eq!( buffer.length() as usize, samples.len() );
+        assert_eq!( buffer.sample_rate(), sample_rate );
+        assert_eq!( buffer.get_channel_data( 0 ).unwrap(), samples );
+        assert!( buffer.get_channel_data( 1 ).is_err() );
+
+        let gain = context.create_gain();
+        gain.gain().set_value( 0.5 );
+        assert_eq!( gain.gain().value(), 0.5 );
+        gain.connect( &context.destination() );
+
+        let source = context.create_buffer_source();
+        assert_eq!( source.buffer(), None );
+        source.set_buffer( Some( &buffer ) );
+        assert_eq!( source.buffer(), Some( buffer ) );
+        source.connect( &gain );
+        source.start_at( context.current_time() );
+        source.stop();
+    }
+
+    #[test]
+    fn test_create_buffer() {
+        let context = AudioContext::new().unwrap();
+        assert!( context.create_buffer( 1, 0, 44100.0 ).is_err() );
+        assert!( context.create_buffer_from_samples( &[], 44100.0 ).is_err() );
+
+        let buffer = context.create_buffer( 2, 4, 44100.0 ).unwrap();
+        buffer.copy_to_channel( &[ 0.5, -0.5 ], 1 ).unwrap();
+        assert_eq!( buffer.get_channel_data( 0 ).unwrap(), vec![ 0.0; 4 ] );
+        assert_eq!( buffer.get_channel_data( 1 ).unwrap(), vec![ 0.5, -0.5, 0.0, 0.0 ] );
+        assert!( buffer.copy_to_channel( &[ 0.5 ], 2 ).is_err() );
+    }
+}
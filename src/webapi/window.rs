@@ -5,6 +5,8 @@ use webapi::window_or_worker::IWindowOrWorker;
 use webapi::storage::Storage;
 use webapi::location::Location;
 use webapi::history::History;
+use webapi::navigator::Navigator;
+use webapi::performance::Performance;
 use webcore::once::Once;
 use webcore::value::Value;
 
@@ -48,6 +50,8 @@ impl Window {
     /// The Window.alert() method displays an alert dialog
     /// with the optional specified content and an OK button.
     ///
+    /// This blocks the event loop until the dialog is dismissed.
+    ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/alert)
     // https://html.spec.whatwg.org/#the-window-object:dom-alert
     pub fn alert( &self, message: &str ) {
@@ -56,6 +60,42 @@ impl Window {
         );
     }
 
+    /// The Window.confirm() method displays a modal dialog with the specified
+    /// message and two buttons, OK and Cancel.
+    ///
+    /// Returns `true` if the user clicked OK.
+    ///
+    /// This blocks the event loop until the dialog is dismissed.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/confirm)
+    // https://html.spec.whatwg.org/#dom-confirm
+    pub fn confirm( &self, message: &str ) -> bool {
+        js!(
+            return !!@{self}.confirm( @{message} );
+        ).try_into().unwrap()
+    }
+
+    /// The Window.prompt() method displays a dialog with the specified message
+    /// prompting the user to input some text, optionally prefilled with `default`.
+    ///
+    /// Returns `None` if the user cancelled the dialog; submitting an empty
+    /// input returns `Some` with an empty string.
+    ///
+    /// This blocks the event loop until the dialog is dismissed.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/prompt)
+    // https://html.spec.whatwg.org/#dom-prompt
+    pub fn prompt( &self, message: &str, default: Option< &str > ) -> Option< String > {
+        js!(
+            var default_value = @{default};
+            if( default_value === null ) {
+                return @{self}.prompt( @{message} );
+            } else {
+                return @{self}.prompt( @{message}, default_value );
+            }
+        ).try_into().unwrap()
+    }
+
     /// The `local_storage` property allows you to access a local [Storage](struct.Storage.html)
     /// object.
     ///
@@ -74,6 +114,19 @@ impl Window {
         }
     }
 
+    /// Returns the [Performance](struct.Performance.html) object which gives
+    /// access to performance-related information for the current page.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/performance)
+    // https://w3c.github.io/hr-time/#dom-windoworworkerglobalscope-performance
+    pub fn performance( &self ) -> Performance {
+        unsafe {
+            js!(
+                return @{self.as_ref()}.performance;
+            ).into_reference_unchecked().unwrap()
+        }
+    }
+
     /// The `session_storage` property allows you to access a session [Storage](struct.Storage.html)
     /// object for the current origin.
     ///
@@ -145,6 +198,19 @@ impl Window {
         }
     }
 
+    /// Returns the [Navigator](struct.Navigator.html) object, which provides information
+    /// about the user agent and access to a number of browser features.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/navigator)
+    // https://html.spec.whatwg.org/#the-window-object:dom-navigator
+    pub fn navigator(&self) -> Navigator {
+        unsafe {
+            js!(
+                return @{self}.navigator;
+            ).into_reference_unchecked().unwrap()
+        }
+    }
+
     /// Returns the width (in pixels) of the browser window viewport including, if rendered,
     /// the vertical scrollbar.
     ///
@@ -191,3 +257,44 @@ impl Window {
         ).try_into().unwrap()
     }
 }
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    fn with_prompt_returning< R, F: FnOnce() -> R >( result: Option< &str >, callback: F ) -> R {
+        js! { @(no_return)
+            var result = @{result};
+            window.__stdweb_original_prompt = window.prompt;
+            window.prompt = function() {
+                return result;
+            };
+        }
+
+        let output = callback();
+        js! { @(no_return)
+            window.prompt = window.__stdweb_original_prompt;
+            delete window.__stdweb_original_prompt;
+        }
+
+        output
+    }
+
+    #[test]
+    fn test_prompt_cancelled() {
+        let result = with_prompt_returning( None, || window().prompt( "Name?", None ) );
+        assert_eq!( result, None );
+    }
+
+    #[test]
+    fn test_prompt_empty() {
+        let result = with_prompt_returning( Some( "" ), || window().prompt( "Name?", Some( "default" ) ) );
+        assert_eq!( result, Some( "".to_owned() ) );
+    }
+
+    #[test]
+    fn test_prompt_value() {
+        let result = with_prompt_returning( Some( "Bob" ), || window().prompt( "Name?", None ) );
+        assert_eq!( result, Some( "Bob".to_owned() ) );
+    }
+}
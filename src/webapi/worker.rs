@@ -0,0 +1,178 @@
+use webcore::value::{Reference, Value};
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::window_or_worker::IWindowOrWorker;
+use webapi::error::Error;
+
+/// The `Worker` interface represents a background task which runs a script
+/// on a separate thread; it communicates with its creator by passing messages.
+///
+/// Messages sent by the worker are received as [MessageEvent](struct.MessageEvent.html)s.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Worker)
+// https://html.spec.whatwg.org/#dedicated-workers-and-the-worker-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Worker")]
+#[reference(subclass_of(EventTarget))]
+pub struct Worker( Reference );
+
+impl IEventTarget for Worker {}
+
+impl Worker {
+    /// Creates a new worker which will execute the script at the given `script_url`.
+    ///
+    /// This will fail if the `script_url` can't be parsed or if the page
+    /// isn't allowed to start the worker, e.g. because the script has a different origin.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Worker/Worker)
+    // https://html.spec.whatwg.org/#dom-worker
+    pub fn new( script_url: &str ) -> Result< Worker, Error > {
+        js_try!(
+            return new Worker( @{script_url} );
+        ).unwrap()
+    }
+
+    /// Sends a message to the worker; the `data` is structurally cloned.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Worker/postMessage)
+    // https://html.spec.whatwg.org/#dom-worker-postmessage
+    pub fn post_message( &self, data: Value ) {
+        js! { @(no_return)
+            @{self}.postMessage( @{data} );
+        }
+    }
+
+    /// Sends a message to the worker, transferring the ownership of the objects
+    /// in `transfer` (e.g. `ArrayBuffer`s) to it without copying them.
+    ///
+    /// The transferred objects become unusable on the sending side.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Worker/postMessage)
+    // https://html.spec.whatwg.org/#dom-worker-postmessage
+    pub fn post_message_with_transfer( &self, data: Value, transfer: &[Reference] ) {
+        js! { @(no_return)
+            @{self}.postMessage( @{data}, @{transfer} );
+        }
+    }
+
+    /// Immediately terminates the worker.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Worker/terminate)
+    // https://html.spec.whatwg.org/#dom-worker-terminate
+    pub fn terminate( &self ) {
+        js! { @(no_return)
+            @{self}.terminate();
+        }
+    }
+}
+
+/// The `DedicatedWorkerGlobalScope` is the global scope of code running inside
+/// of a [Worker](struct.Worker.html).
+///
+/// Messages sent by the creator of the worker are received on it
+/// as [MessageEvent](struct.MessageEvent.html)s.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DedicatedWorkerGlobalScope)
+// https://html.spec.whatwg.org/#dedicatedworkerglobalscope
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "DedicatedWorkerGlobalScope")]
+#[reference(subclass_of(EventTarget))]
+pub struct DedicatedWorkerGlobalScope( Reference );
+
+impl IEventTarget for DedicatedWorkerGlobalScope {}
+impl IWindowOrWorker for DedicatedWorkerGlobalScope {}
+
+/// Returns the global scope of the current dedicated worker,
+/// or `None` if the code isn't running inside of one.
+pub fn worker_global_scope() -> Option< DedicatedWorkerGlobalScope > {
+    js!(
+        if( typeof DedicatedWorkerGlobalScope !== "undefined" && self instanceof DedicatedWorkerGlobalScope ) {
+            return self;
+        } else {
+            return null;
+        }
+    ).try_into().unwrap()
+}
+
+impl DedicatedWorkerGlobalScope {
+    /// Sends a message to the creator of the worker; the `data` is structurally cloned.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DedicatedWorkerGlobalScope/postMessage)
+    // https://html.spec.whatwg.org/#dom-dedicatedworkerglobalscope-postmessage
+    pub fn post_message( &self, data: Value ) {
+        js! { @(no_return)
+            @{self}.postMessage( @{data} );
+        }
+    }
+
+    /// Sends a message to the creator of the worker, transferring the ownership
+    /// of the objects in `transfer` (e.g. `ArrayBuffer`s) without copying them.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DedicatedWorkerGlobalScope/postMessage)
+    // https://html.spec.whatwg.org/#dom-dedicatedworkerglobalscope-postmessage
+    pub fn post_message_with_transfer( &self, data: Value, transfer: &[Reference] ) {
+        js! { @(no_return)
+            @{self}.postMessage( @{data}, @{transfer} );
+        }
+    }
+
+    /// Synchronously loads and executes the given scripts inside of the worker.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WorkerGlobalScope/importScripts)
+    // https://html.spec.whatwg.org/#dom-workerglobalscope-importscripts
+    pub fn import_scripts( &self, urls: &[&str] ) -> Result< (), Error > {
+        js_try!( @(no_return)
+            var scope = @{self};
+            scope.importScripts.apply( scope, @{urls} );
+        ).unwrap()
+    }
+
+    /// Discards any pending tasks and terminates the worker from the inside.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DedicatedWorkerGlobalScope/close)
+    // https://html.spec.whatwg.org/#dom-dedicatedworkerglobalscope-close
+    pub fn close( &self ) {
+        js! { @(no_return)
+            @{self}.close();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    fn doubling_worker() -> Worker {
+        let url: String = js!(
+            var source = "self.onmessage = function( event ) {" +
+                "var array = event.data;" +
+                "for( var i = 0; i < array.length; ++i ) { array[ i ] *= 2; }" +
+                "self.postMessage( array, [ array.buffer ] );" +
+            "};";
+
+            return URL.createObjectURL( new Blob( [ source ], { type: "application/javascript" } ) );
+        ).try_into().unwrap();
+
+        Worker::new( &url ).unwrap()
+    }
+
+    #[test]
+    fn test_post_message_with_transfer() {
+        let worker = doubling_worker();
+        let array = js!( return new Uint8Array( [ 1, 2, 3 ] ); );
+        let buffer: Reference = js!( return @{&array}.buffer; ).try_into().unwrap();
+
+        worker.post_message_with_transfer( array.clone(), &[ buffer ] );
+
+        // The buffer was moved to the worker without copying.
+        let length: u32 = js!( return @{&array}.buffer.byteLength; ).try_into().unwrap();
+        assert_eq!( length, 0 );
+
+        worker.terminate();
+    }
+
+    #[test]
+    fn test_not_in_worker() {
+        assert!( worker_global_scope().is_none() );
+    }
+}
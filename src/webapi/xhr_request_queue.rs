@@ -0,0 +1,216 @@
+use std::fmt;
+use std::rc::Rc;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use futures::{Future, Poll, Async};
+use futures::unsync::oneshot;
+use webapi::xml_http_request::{XmlHttpRequest, XhrResponse, XhrError, is_mixed_content};
+
+struct QueuedRequest {
+    id: u64,
+    method: String,
+    url: String,
+    sender: oneshot::Sender< Result< XhrResponse, XhrError > >
+}
+
+struct QueueState {
+    max_concurrency: usize,
+    running: usize,
+    next_id: u64,
+    queued: VecDeque< QueuedRequest >
+}
+
+/// A queue which limits the number of concurrently running requests.
+///
+/// Browsers limit the number of connections per host on their own, but
+/// they queue the excess requests opaquely; this queue makes that explicit.
+/// At most `max_concurrency` requests are in flight at a time, and every
+/// other request waits in the queue until one of them finishes.
+///
+/// Dropping a [RequestQueueFuture](struct.RequestQueueFuture.html) of a request which
+/// is still queued removes it from the queue, so it won't ever be sent.
+///
+/// # Examples
+///
+/// ```rust
+/// let queue = RequestQueue::new( 4 );
+/// for index in 0..100 {
+///     PromiseFuture::spawn(
+///         queue.request( "GET", &format!( "/api/items/{}", index ) )
+///             .map( |response| console!( log, response.body() ) )
+///             .map_err( |error| console!( error, format!( "{}", error ) ) )
+///     );
+/// }
+///
+/// assert_eq!( queue.running(), 4 );
+/// assert_eq!( queue.queue_depth(), 96 );
+/// ```
+#[derive(Clone)]
+pub struct RequestQueue {
+    state: Rc< RefCell< QueueState > >
+}
+
+impl fmt::Debug for RequestQueue {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        write!( formatter, "RequestQueue" )
+    }
+}
+
+impl RequestQueue {
+    /// Creates a new `RequestQueue` which runs at most `max_concurrency` requests at a time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_concurrency` is zero.
+    pub fn new( max_concurrency: usize ) -> Self {
+        assert!( max_concurrency > 0, "max_concurrency must be greater than zero" );
+        RequestQueue {
+            state: Rc::new( RefCell::new( QueueState {
+                max_concurrency: max_concurrency,
+                running: 0,
+                next_id: 0,
+                queued: VecDeque::new()
+            }))
+        }
+    }
+
+    /// Returns the maximum number of requests which can run at the same time.
+    pub fn max_concurrency( &self ) -> usize {
+        self.state.borrow().max_concurrency
+    }
+
+    /// Returns the number of requests which are currently in flight.
+    pub fn running( &self ) -> usize {
+        self.state.borrow().running
+    }
+
+    /// Returns the number of requests which are waiting for a free slot.
+    pub fn queue_depth( &self ) -> usize {
+        self.state.borrow().queued.len()
+    }
+
+    /// Queues a request with the given `method` to the given `url`; it will be sent
+    /// as soon as there are less than [max_concurrency](#method.max_concurrency)
+    /// requests in flight.
+    ///
+    /// See [XmlHttpRequest::request](struct.XmlHttpRequest.html#method.request).
+    pub fn request( &self, method: &str, url: &str ) -> RequestQueueFuture {
+        let ( sender, receiver ) = oneshot::channel();
+        let id = {
+            let mut state = self.state.borrow_mut();
+            let id = state.next_id;
+            state.next_id += 1;
+            state.queued.push_back( QueuedRequest {
+                id: id,
+                method: method.to_owned(),
+                url: url.to_owned(),
+                sender: sender
+            });
+
+            id
+        };
+
+        start_queued( &self.state );
+        RequestQueueFuture {
+            id: id,
+            receiver: receiver,
+            state: self.state.clone()
+        }
+    }
+}
+
+fn start_queued( state: &Rc< RefCell< QueueState > > ) {
+    loop {
+        let queued = {
+            let mut state = state.borrow_mut();
+            if state.running >= state.max_concurrency {
+                return;
+            }
+
+            match state.queued.pop_front() {
+                Some( queued ) => queued,
+                None => return
+            }
+        };
+
+        if is_mixed_content( &queued.url ) {
+            let _ = queued.sender.send( Err( XhrError::MixedContent ) );
+            continue;
+        }
+
+        state.borrow_mut().running += 1;
+
+        let request = XmlHttpRequest::new();
+        request.open( &queued.method, &queued.url ).unwrap();
+
+        let sender = queued.sender;
+        let queue_state = state.clone();
+        request.on_finish( move |result| {
+            let _ = sender.send( result );
+            queue_state.borrow_mut().running -= 1;
+            start_queued( &queue_state );
+        });
+
+        request.send().unwrap();
+    }
+}
+
+/// A future returned by [RequestQueue::request](struct.RequestQueue.html#method.request).
+pub struct RequestQueueFuture {
+    id: u64,
+    receiver: oneshot::Receiver< Result< XhrResponse, XhrError > >,
+    state: Rc< RefCell< QueueState > >
+}
+
+impl fmt::Debug for RequestQueueFuture {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        write!( formatter, "RequestQueueFuture" )
+    }
+}
+
+impl Future for RequestQueueFuture {
+    type Item = XhrResponse;
+    type Error = XhrError;
+
+    fn poll( &mut self ) -> Poll< Self::Item, Self::Error > {
+        match self.receiver.poll() {
+            Ok( Async::Ready( Ok( response ) ) ) => Ok( Async::Ready( response ) ),
+            Ok( Async::Ready( Err( error ) ) ) => Err( error ),
+            Ok( Async::NotReady ) => Ok( Async::NotReady ),
+            Err( _ ) => Err( XhrError::Aborted )
+        }
+    }
+}
+
+impl Drop for RequestQueueFuture {
+    fn drop( &mut self ) {
+        let id = self.id;
+        self.state.borrow_mut().queued.retain( |queued| queued.id != id );
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queue_depth() {
+        let queue = RequestQueue::new( 1 );
+        let first = queue.request( "GET", "/__stdweb_request_queue_1" );
+        let second = queue.request( "GET", "/__stdweb_request_queue_2" );
+        let third = queue.request( "GET", "/__stdweb_request_queue_3" );
+        assert_eq!( queue.running(), 1 );
+        assert_eq!( queue.queue_depth(), 2 );
+
+        drop( second );
+        assert_eq!( queue.queue_depth(), 1 );
+
+        // Dropping a running request doesn't free its slot until it finishes.
+        drop( first );
+        assert_eq!( queue.running(), 1 );
+        assert_eq!( queue.queue_depth(), 1 );
+
+        drop( third );
+        assert_eq!( queue.queue_depth(), 0 );
+    }
+}
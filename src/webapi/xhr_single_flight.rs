@@ -0,0 +1,225 @@
+use std::fmt;
+use std::rc::Rc;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use futures::{Future, Poll, Async};
+use futures::future::Shared;
+use webapi::xml_http_request::{XmlHttpRequest, XhrFuture, XhrResponse, XhrError};
+
+type FlightKey = (String, String);
+
+struct Flight {
+    id: u64,
+    waiters: usize,
+    future: Shared< XhrFuture >
+}
+
+/// A client which deduplicates identical concurrent requests.
+///
+/// As long as a request with a given `(method, url)` pair is in flight every other
+/// request with the same `(method, url)` pair will share its result instead of
+/// sending another [XmlHttpRequest](struct.XmlHttpRequest.html). Once the request
+/// settles, or once every future waiting for it is dropped, the pair is forgotten,
+/// so any later request will start afresh.
+///
+/// This is mostly useful for idempotent requests, e.g. `GET`s.
+///
+/// # Examples
+///
+/// ```rust
+/// let client = SingleFlightClient::new();
+///
+/// // Only one request will be sent.
+/// let a = client.request( "GET", "/api/user" );
+/// let b = client.request( "GET", "/api/user" );
+/// ```
+#[derive(Clone, Default)]
+pub struct SingleFlightClient {
+    next_id: Rc< Cell< u64 > >,
+    in_flight: Rc< RefCell< HashMap< FlightKey, Flight > > >
+}
+
+impl fmt::Debug for SingleFlightClient {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        write!( formatter, "SingleFlightClient" )
+    }
+}
+
+impl SingleFlightClient {
+    /// Creates a new `SingleFlightClient`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of distinct requests which are currently in flight.
+    pub fn in_flight( &self ) -> usize {
+        self.in_flight.borrow().len()
+    }
+
+    /// Sends a request with the given `method` to the given `url`, unless an identical
+    /// request is already in flight, in which case its result will be shared.
+    pub fn request( &self, method: &str, url: &str ) -> SingleFlightFuture {
+        let key = (method.to_owned(), url.to_owned());
+        if let Some( flight ) = self.in_flight.borrow_mut().get_mut( &key ) {
+            flight.waiters += 1;
+            return SingleFlightFuture {
+                settled: false,
+                id: flight.id,
+                key: key,
+                future: flight.future.clone(),
+                in_flight: self.in_flight.clone()
+            };
+        }
+
+        let id = self.next_id.get();
+        self.next_id.set( id + 1 );
+
+        let future = XmlHttpRequest::request( method, url ).shared();
+        self.in_flight.borrow_mut().insert( key.clone(), Flight {
+            id: id,
+            waiters: 1,
+            future: future.clone()
+        });
+
+        SingleFlightFuture {
+            settled: false,
+            id: id,
+            key: key,
+            future: future,
+            in_flight: self.in_flight.clone()
+        }
+    }
+}
+
+/// A future returned by [SingleFlightClient::request](struct.SingleFlightClient.html#method.request).
+///
+/// Dropping every future which waits for a given request forgets it, even if
+/// it's still in flight, so that the next identical request is sent again.
+pub struct SingleFlightFuture {
+    settled: bool,
+    id: u64,
+    key: FlightKey,
+    future: Shared< XhrFuture >,
+    in_flight: Rc< RefCell< HashMap< FlightKey, Flight > > >
+}
+
+impl fmt::Debug for SingleFlightFuture {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        write!( formatter, "SingleFlightFuture" )
+    }
+}
+
+impl SingleFlightFuture {
+    // Forgets the flight once it has settled, so that it can't be joined anymore.
+    fn settle( &mut self ) {
+        self.settled = true;
+        let mut in_flight = self.in_flight.borrow_mut();
+        let is_current = in_flight.get( &self.key ).map( |flight| flight.id == self.id ).unwrap_or( false );
+        if is_current {
+            in_flight.remove( &self.key );
+        }
+    }
+}
+
+impl Drop for SingleFlightFuture {
+    fn drop( &mut self ) {
+        if self.settled {
+            return;
+        }
+
+        let mut in_flight = self.in_flight.borrow_mut();
+        let is_abandoned = match in_flight.get_mut( &self.key ) {
+            Some( flight ) if flight.id == self.id => {
+                flight.waiters -= 1;
+                flight.waiters == 0
+            },
+            _ => false
+        };
+
+        if is_abandoned {
+            in_flight.remove( &self.key );
+        }
+    }
+}
+
+impl Future for SingleFlightFuture {
+    type Item = XhrResponse;
+    type Error = XhrError;
+
+    fn poll( &mut self ) -> Poll< Self::Item, Self::Error > {
+        match self.future.poll() {
+            Ok( Async::Ready( response ) ) => {
+                self.settle();
+                Ok( Async::Ready( (*response).clone() ) )
+            },
+            Ok( Async::NotReady ) => Ok( Async::NotReady ),
+            Err( error ) => {
+                self.settle();
+                Err( (*error).clone() )
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webcore::async_test::async_test;
+    use webcore::promise_future::PromiseFuture;
+
+    #[test]
+    fn test_deduplication() {
+        let client = SingleFlightClient::new();
+        let a = client.request( "GET", "/" );
+        let b = client.request( "GET", "/" );
+        let c = client.request( "POST", "/" );
+        assert_eq!( client.in_flight(), 2 );
+
+        async_test( "test_deduplication", 5000, move |done| {
+            PromiseFuture::spawn( a.join( b ).then( move |result| {
+                let (a, b) = result.unwrap();
+                assert_eq!( a.status(), b.status() );
+                assert_eq!( a.body(), b.body() );
+                assert_eq!( client.in_flight(), 1 );
+                drop( c );
+                assert_eq!( client.in_flight(), 0 );
+                done.done();
+                Ok( () )
+            }));
+        });
+    }
+
+    #[test]
+    fn test_drop() {
+        let client = SingleFlightClient::new();
+        let a = client.request( "GET", "/" );
+        let b = client.request( "GET", "/" );
+
+        drop( a );
+        assert_eq!( client.in_flight(), 1 );
+        drop( b );
+        assert_eq!( client.in_flight(), 0 );
+
+        // A dropped flight is never joined, even if it's still running.
+        let c = client.request( "GET", "/" );
+        assert_eq!( client.in_flight.borrow()[ &c.key ].id, 1 );
+    }
+
+    #[test]
+    fn test_error() {
+        let client = SingleFlightClient::new();
+        let future = client.request( "GET", "http://0.0.0.0:1/" );
+
+        async_test( "test_error", 5000, move |done| {
+            PromiseFuture::spawn( future.then( move |result| {
+                assert!( result.is_err() );
+                assert_eq!( client.in_flight(), 0 );
+
+                let retry = client.request( "GET", "http://0.0.0.0:1/" );
+                assert_eq!( client.in_flight.borrow()[ &retry.key ].id, 1 );
+                done.done();
+                Ok( () )
+            }));
+        });
+    }
+}
@@ -1,5 +1,31 @@
-use webapi::event_target::{IEventTarget, EventTarget};
+use std::fmt;
+use std::error;
+use std::io;
+#[cfg(feature = "futures")]
+use std::io::Write;
+use std::rc::Rc;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::ops::Range;
+#[cfg(feature = "futures")]
+use futures::{Future, Poll, Async};
+#[cfg(feature = "futures")]
+use futures::unsync::oneshot;
+use webapi::event_target::{IEventTarget, EventTarget, EventListenerHandle};
+use webapi::events::progress::ProgressAbortEvent;
+use webapi::dom_exception::InvalidStateError;
+use webapi::error::JsError;
+use webapi::array_buffer::ArrayBuffer;
+#[cfg(feature = "futures")]
+use webapi::typed_array::TypedArray;
+use webapi::text_encoding::TextDecoder;
+#[cfg(feature = "futures")]
+use webapi::network_state::NetworkState;
+#[cfg(feature = "futures")]
+use webapi::url::percent_encode;
+use webapi::performance::{IPerformanceEntry, PerformanceEntry, PerformanceResourceTiming};
 use webcore::unsafe_typed_array::UnsafeTypedArray;
+use webcore::once::Once;
 use webcore::value::{
     Reference,
     Value,
@@ -37,8 +63,241 @@ pub enum XhrReadyState {
     Done,
 }
 
+/// An enum describing the type of the response of a `XmlHttpRequest`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/responseType)
+// https://xhr.spec.whatwg.org/#xmlhttprequestresponsetype
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum XhrResponseType {
+    /// The response is an `ArrayBuffer` containing binary data.
+    ArrayBuffer,
+    /// The response is a [Blob](struct.Blob.html) containing binary data.
+    Blob,
+    /// The response is an HTML or XML `Document`.
+    Document,
+    /// The response is a JavaScript object parsed from JSON.
+    Json,
+    /// The response is text; this is the default.
+    Text
+}
+
+impl XhrResponseType {
+    fn as_str( &self ) -> &'static str {
+        match *self {
+            XhrResponseType::ArrayBuffer => "arraybuffer",
+            XhrResponseType::Blob => "blob",
+            XhrResponseType::Document => "document",
+            XhrResponseType::Json => "json",
+            XhrResponseType::Text => "text"
+        }
+    }
+
+    fn from_str( s: &str ) -> Self {
+        match s {
+            "arraybuffer" => XhrResponseType::ArrayBuffer,
+            "blob" => XhrResponseType::Blob,
+            "document" => XhrResponseType::Document,
+            "json" => XhrResponseType::Json,
+            _ => XhrResponseType::Text
+        }
+    }
+
+    /// Returns the `Accept` header value matching this response type, if any.
+    fn accept( &self ) -> Option< &'static str > {
+        match *self {
+            XhrResponseType::Document => Some( "text/html" ),
+            XhrResponseType::Json => Some( "application/json" ),
+            XhrResponseType::ArrayBuffer | XhrResponseType::Blob | XhrResponseType::Text => None
+        }
+    }
+}
+
 impl IEventTarget for XmlHttpRequest {}
 
+/// A unique identifier of an [XmlHttpRequest](struct.XmlHttpRequest.html) which can
+/// be used to correlate log entries and metrics belonging to the same request.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct XhrRequestId( pub u64 );
+
+impl fmt::Display for XhrRequestId {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        write!( formatter, "xhr-{}", self.0 )
+    }
+}
+
+/// Timing information of an [XmlHttpRequest](struct.XmlHttpRequest.html)
+/// as passed to an [XhrObserver](trait.XhrObserver.html).
+///
+/// All of the times are in milliseconds and are measured from the moment the request was sent.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct XhrTiming {
+    /// Time until the response headers were received, or `None` if they never were.
+    pub time_to_first_byte: Option< f64 >,
+    /// Time until the request has finished (successfully or not), or `None` if it's still in flight.
+    pub duration: Option< f64 >
+}
+
+/// A breakdown of the network timings of a finished request, taken from
+/// the Resource Timing entry the browser recorded for it.
+///
+/// All of the times are in milliseconds. For cross-origin requests whose
+/// response lacks a `Timing-Allow-Origin` header the browser doesn't expose
+/// the breakdown, so everything except the `duration_ms` is `None`.
+///
+/// See [XhrResponse::timing](struct.XhrResponse.html#method.timing).
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ResourceTiming {
+    /// Total time from the start of the fetch until the last byte of the response was received.
+    pub duration_ms: f64,
+    /// Time spent on the DNS lookup.
+    pub dns_ms: Option< f64 >,
+    /// Time spent on establishing the connection, including the TLS handshake.
+    pub connect_ms: Option< f64 >,
+    /// Time spent on the TLS handshake, or `None` if the connection is not secure.
+    pub tls_ms: Option< f64 >,
+    /// Time from sending the request until the first byte of the response was received.
+    pub ttfb_ms: Option< f64 >,
+    /// Time spent on receiving the response, from its first byte until its last.
+    pub transfer_ms: Option< f64 >
+}
+
+impl ResourceTiming {
+    fn from_entry( entry: &PerformanceResourceTiming ) -> Self {
+        let request_start = entry.request_start();
+        let mut timing = ResourceTiming {
+            duration_ms: entry.duration(),
+            dns_ms: None,
+            connect_ms: None,
+            tls_ms: None,
+            ttfb_ms: None,
+            transfer_ms: None
+        };
+
+        // Opaque entries have all of these zeroed.
+        if request_start == 0.0 {
+            return timing;
+        }
+
+        let connect_end = entry.connect_end();
+        let secure_connection_start = entry.secure_connection_start();
+        let response_start = entry.response_start();
+
+        timing.dns_ms = Some( entry.domain_lookup_end() - entry.domain_lookup_start() );
+        timing.connect_ms = Some( connect_end - entry.connect_start() );
+        if secure_connection_start > 0.0 {
+            timing.tls_ms = Some( connect_end - secure_connection_start );
+        }
+        timing.ttfb_ms = Some( response_start - request_start );
+        timing.transfer_ms = Some( entry.response_end() - response_start );
+        timing
+    }
+}
+
+/// The metrics of a finished request, as returned by [XhrResponse::metrics](struct.XhrResponse.html#method.metrics).
+///
+/// All of the times are in milliseconds and come from the Resource Timing entry
+/// of the request; they're `None` if there's no such entry, or if the browser
+/// doesn't expose them for an opaque cross-origin response.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct XhrMetrics {
+    /// Time from the start of the fetch until the request was sent, which
+    /// includes queueing, the DNS lookup and establishing the connection.
+    pub dns_queue_ms: Option< f64 >,
+    /// Time from sending the request until the first byte of the response was received.
+    pub ttfb_ms: Option< f64 >,
+    /// Time spent on receiving the response, from its first byte until its last.
+    pub download_ms: Option< f64 >,
+    /// Total time from the start of the fetch until the last byte of the response was received.
+    pub total_ms: Option< f64 >,
+    /// The size of the request body in bytes.
+    pub request_bytes: u64,
+    /// The number of bytes of the response body which were transferred, or `None`
+    /// if the response wasn't received through an [XhrFuture](struct.XhrFuture.html).
+    pub response_bytes: Option< u64 >,
+    /// The HTTP status code of the response.
+    pub status: u16
+}
+
+/// An observer which gets notified about the lifecycle transitions of
+/// [XmlHttpRequest](struct.XmlHttpRequest.html)s.
+///
+/// An observer can be attached either to a single request with
+/// [XmlHttpRequest::set_observer](struct.XmlHttpRequest.html#method.set_observer),
+/// or to every request with [set_global_xhr_observer](fn.set_global_xhr_observer.html).
+///
+/// All of the methods have empty default implementations, so you only need to
+/// implement the ones you're interested in.
+pub trait XhrObserver {
+    /// Called when [open](struct.XmlHttpRequest.html#method.open) is called on the request.
+    fn on_open( &self, _id: XhrRequestId, _method: &str, _url: &str ) {}
+
+    /// Called when the request is sent.
+    fn on_send( &self, _id: XhrRequestId ) {}
+
+    /// Called when the response headers were received.
+    fn on_first_byte( &self, _id: XhrRequestId, _timing: &XhrTiming ) {}
+
+    /// Called when the request has finished successfully.
+    fn on_complete( &self, _id: XhrRequestId, _status: u16, _timing: &XhrTiming ) {}
+
+    /// Called when the request has failed, was aborted or has timed out.
+    fn on_error( &self, _id: XhrRequestId, _timing: &XhrTiming ) {}
+}
+
+/// The parts of a request which are passed to a signer set with
+/// [XmlHttpRequest::sign_request](struct.XmlHttpRequest.html#method.sign_request).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SignableRequest< 'a > {
+    /// The method passed to [open](struct.XmlHttpRequest.html#method.open).
+    pub method: &'a str,
+    /// The URL passed to [open](struct.XmlHttpRequest.html#method.open).
+    pub url: &'a str,
+    /// The request headers which were set so far, in the order in which they were set.
+    pub headers: &'a [(String, String)],
+    /// The body of the request; empty if there's none.
+    pub body: &'a [u8]
+}
+
+type RequestSigner = Box< dyn Fn( &SignableRequest ) -> Vec< (String, String) > >;
+
+thread_local! {
+    static NEXT_REQUEST_ID: Cell< u64 > = Cell::new( 1 );
+    static GLOBAL_OBSERVER: RefCell< Option< Rc< dyn XhrObserver > > > = RefCell::new( None );
+    static REQUEST_OBSERVERS: RefCell< HashMap< XhrRequestId, Rc< dyn XhrObserver > > > = RefCell::new( HashMap::new() );
+    static REQUEST_SIGNERS: RefCell< HashMap< XhrRequestId, RequestSigner > > = RefCell::new( HashMap::new() );
+    static NEXT_JSONP_ID: Cell< u64 > = Cell::new( 1 );
+}
+
+/// Sets an observer which will be notified about every [XmlHttpRequest](struct.XmlHttpRequest.html)
+/// in addition to the observers attached to the individual requests.
+///
+/// Passing `None` removes the current global observer.
+pub fn set_global_xhr_observer( observer: Option< Rc< dyn XhrObserver > > ) {
+    GLOBAL_OBSERVER.with( |global| *global.borrow_mut() = observer );
+}
+
+fn notify_observers< F: Fn( &dyn XhrObserver ) >( id: XhrRequestId, callback: F ) {
+    let global = GLOBAL_OBSERVER.with( |global| global.borrow().clone() );
+    let local = REQUEST_OBSERVERS.with( |observers| observers.borrow().get( &id ).cloned() );
+
+    if let Some( observer ) = global {
+        callback( &*observer );
+    }
+
+    if let Some( observer ) = local {
+        callback( &*observer );
+    }
+}
+
+fn detach_observer( id: XhrRequestId ) {
+    REQUEST_OBSERVERS.with( |observers| observers.borrow_mut().remove( &id ) );
+}
+
+fn has_observers( id: XhrRequestId ) -> bool {
+    GLOBAL_OBSERVER.with( |global| global.borrow().is_some() ) ||
+    REQUEST_OBSERVERS.with( |observers| observers.borrow().contains_key( &id ) )
+}
+
 
 impl XmlHttpRequest {
     /// Creates new `XmlHttpRequest`.
@@ -47,6 +306,163 @@ impl XmlHttpRequest {
         js!( return new XMLHttpRequest(); ).try_into().unwrap()
     }
 
+    /// Returns the unique ID of this request.
+    ///
+    /// The ID is assigned lazily the first time it's needed and stays
+    /// the same for the whole lifetime of the request.
+    pub fn request_id( &self ) -> XhrRequestId {
+        let id: Option< u64 > = js!( return @{self}.__stdweb_request_id; ).try_into().unwrap_or( None );
+        if let Some( id ) = id {
+            return XhrRequestId( id );
+        }
+
+        let id = NEXT_REQUEST_ID.with( |next_id| {
+            let id = next_id.get();
+            next_id.set( id + 1 );
+            id
+        });
+
+        let raw_id: Value = id.try_into().unwrap();
+        js! { @(no_return)
+            @{self}.__stdweb_request_id = @{raw_id};
+        }
+
+        XhrRequestId( id )
+    }
+
+    /// Attaches an [XhrObserver](trait.XhrObserver.html) to this request,
+    /// replacing the previously attached one, if any.
+    ///
+    /// The observer is automatically detached once the request finishes or is
+    /// [aborted](#method.abort), or when the request itself is garbage collected
+    /// without ever being sent.
+    pub fn set_observer( &self, observer: Rc< dyn XhrObserver > ) {
+        let id = self.request_id();
+        let previous = REQUEST_OBSERVERS.with( |observers| observers.borrow_mut().insert( id, observer ) );
+        if previous.is_none() {
+            // Nothing else would ever remove the observer of a request which is never sent.
+            self.as_ref().on_finalize( move || detach_observer( id ) );
+        }
+    }
+
+    /// Sets a `signer` which is called right before the request is sent, once its body is known.
+    ///
+    /// The signer receives the method, the URL, the headers set so far and the body
+    /// of the request, and returns additional headers which are then attached to it,
+    /// e.g. an HMAC signature over the other parts. This makes it possible to plug in any
+    /// signing scheme without this crate having to know about it.
+    ///
+    /// The signer is used for a single `send` only and replaces the previously set one, if any.
+    /// It's released as soon as the request is sent or [aborted](#method.abort), so a request
+    /// which ends up not being sent should be aborted to free it.
+    pub fn sign_request< F >( &self, signer: F )
+        where F: Fn( &SignableRequest ) -> Vec< (String, String) > + 'static
+    {
+        let id = self.request_id();
+        REQUEST_SIGNERS.with( |signers| signers.borrow_mut().insert( id, Box::new( signer ) ) );
+    }
+
+    fn take_signer( &self ) -> Option< RequestSigner > {
+        let id = self.request_id();
+        REQUEST_SIGNERS.with( |signers| signers.borrow_mut().remove( &id ) )
+    }
+
+    fn sign( &self, body: &[u8] ) {
+        let signer = match self.take_signer() {
+            Some( signer ) => signer,
+            None => return
+        };
+
+        let method: String = js!( return @{self}.__stdweb_method || ""; ).try_into().unwrap();
+        let url: String = js!( return @{self}.__stdweb_url || ""; ).try_into().unwrap();
+        let headers: Vec< Vec< String > > = js!( return @{self}.__stdweb_headers || []; ).try_into().unwrap();
+        let headers: Vec< (String, String) > = headers.into_iter().map( |mut pair| {
+            let value = pair.pop().unwrap();
+            let name = pair.pop().unwrap();
+            (name, value)
+        }).collect();
+
+        let extra_headers = signer( &SignableRequest {
+            method: &method,
+            url: &url,
+            headers: &headers,
+            body: body
+        });
+
+        for (name, value) in extra_headers {
+            self.set_request_header( &name, &value ).unwrap();
+        }
+    }
+
+    fn notify_send( &self ) {
+        let id = self.request_id();
+        if !has_observers( id ) {
+            return;
+        }
+
+        notify_observers( id, |observer| observer.on_send( id ) );
+
+        let on_first_byte = move |time_to_first_byte: f64| {
+            let timing = XhrTiming {
+                time_to_first_byte: Some( time_to_first_byte ),
+                duration: None
+            };
+
+            notify_observers( id, |observer| observer.on_first_byte( id, &timing ) );
+        };
+
+        let on_loadend = move |status: u16, failed: bool, time_to_first_byte: Option< f64 >, duration: f64| {
+            let timing = XhrTiming {
+                time_to_first_byte: time_to_first_byte,
+                duration: Some( duration )
+            };
+
+            if failed {
+                notify_observers( id, |observer| observer.on_error( id, &timing ) );
+            } else {
+                notify_observers( id, |observer| observer.on_complete( id, status, &timing ) );
+            }
+
+            detach_observer( id );
+        };
+
+        js! { @(no_return)
+            var xhr = @{self};
+            var on_first_byte = @{Once( on_first_byte )};
+            var on_loadend = @{Once( on_loadend )};
+            var start = performance.now();
+            var time_to_first_byte = null;
+            var failed = false;
+
+            var on_ready_state_change = function() {
+                if( xhr.readyState >= 2 && time_to_first_byte === null ) {
+                    time_to_first_byte = performance.now() - start;
+                    on_first_byte( time_to_first_byte );
+                }
+            };
+
+            var on_failure = function() {
+                failed = true;
+            };
+
+            var on_end = function() {
+                xhr.removeEventListener( "readystatechange", on_ready_state_change );
+                xhr.removeEventListener( "error", on_failure );
+                xhr.removeEventListener( "abort", on_failure );
+                xhr.removeEventListener( "timeout", on_failure );
+                xhr.removeEventListener( "loadend", on_end );
+                on_first_byte.drop();
+                on_loadend( xhr.status, failed, time_to_first_byte, performance.now() - start );
+            };
+
+            xhr.addEventListener( "readystatechange", on_ready_state_change );
+            xhr.addEventListener( "error", on_failure );
+            xhr.addEventListener( "abort", on_failure );
+            xhr.addEventListener( "timeout", on_failure );
+            xhr.addEventListener( "loadend", on_end );
+        }
+    }
+
     /// Returns the current state of the request as a [XhrReadyState](enum.XhrReadyState.html).
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/readyState)
@@ -78,6 +494,43 @@ impl XmlHttpRequest {
         }
     }
 
+    /// Returns the response as text, decoded using the charset from the
+    /// `Content-Type` header of the response.
+    ///
+    /// This is meant for legacy endpoints which respond in charsets other than
+    /// UTF-8, e.g. `Shift_JIS` or `ISO-8859-1`. For it to work the response type
+    /// has to be set to [ArrayBuffer](enum.XhrResponseType.html#variant.ArrayBuffer)
+    /// before the request is sent; the raw bytes of the response are then decoded
+    /// with a [TextDecoder](struct.TextDecoder.html). If the header has no charset,
+    /// or if the charset is not supported, the response is decoded as UTF-8.
+    ///
+    /// For the [Text](enum.XhrResponseType.html#variant.Text) response type this
+    /// returns the text as decoded by the browser. For the other response types,
+    /// or if there is no response yet, an empty string is returned.
+    pub fn response_text_decoded(&self) -> String {
+        match self.response_type() {
+            XhrResponseType::ArrayBuffer => {},
+            XhrResponseType::Text => return self.response_text().unwrap().unwrap_or_default(),
+            _ => return String::new()
+        }
+
+        let buffer: Option< ArrayBuffer > = js!( return @{self}.response; ).try_into().unwrap();
+        let bytes: Vec< u8 > = match buffer {
+            Some( buffer ) => buffer.into(),
+            None => return String::new()
+        };
+
+        let charset = self.get_response_header( "Content-Type" )
+            .and_then( |content_type| content_type_charset( &content_type ) );
+
+        let decoder = charset
+            .and_then( |charset| TextDecoder::new( &charset ).ok() )
+            .unwrap_or_else( || TextDecoder::new( "utf-8" ).unwrap() );
+
+        // A non-fatal decoder never fails.
+        decoder.decode( &bytes ).unwrap()
+    }
+
     /// Returns an unsigned short with the status of the response of the request.
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/status)
@@ -86,14 +539,54 @@ impl XmlHttpRequest {
         js!(return @{self}.status;).try_into().unwrap()
     }
 
+    /// Returns the response's status message as returned by the HTTP server.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/statusText)
+    // https://xhr.spec.whatwg.org/#ref-for-dom-xmlhttprequest-statustext
+    pub fn status_text(&self) -> String {
+        js!(return @{self}.statusText;).try_into().unwrap()
+    }
+
+    /// Returns the serialized URL of the response or the empty string if the URL is null.
+    /// If the URL is returned, URL fragment if present in the URL will be stripped away.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/responseURL)
+    // https://xhr.spec.whatwg.org/#ref-for-dom-xmlhttprequest-responseurl
+    pub fn response_url(&self) -> String {
+        js!(return @{self}.responseURL;).try_into().unwrap()
+    }
+
     /// Open connection with given method (ie GET or POST), and the url to hit.
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/open)
     // https://xhr.spec.whatwg.org/#ref-for-dom-xmlhttprequest-open
     pub fn open(&self, method: &str, url: &str) -> Result< (), TODO > {
-        js! { @(no_return)
-            @{self}.open(@{method}, @{url}, true);
-        };
+        if let Err( error ) = self.try_open( method, url ) {
+            panic!( "{}", error );
+        }
+
+        Ok(())
+    }
+
+    // Opens the request just like `open`, but returns the error thrown by the browser
+    // instead of panicking, e.g. when the `method` or the `url` is malformed.
+    fn try_open( &self, method: &str, url: &str ) -> Result< (), JsError > {
+        js_try! { @(no_return)
+            var xhr = @{self};
+            var method = @{method};
+            var url = @{url};
+            xhr.open(method, url, true);
+            delete xhr.__stdweb_accept_set;
+            delete xhr.__stdweb_too_large;
+            delete xhr.__stdweb_request_bytes;
+            delete xhr.__stdweb_response_bytes;
+            xhr.__stdweb_method = method;
+            xhr.__stdweb_url = url;
+            xhr.__stdweb_headers = [];
+        }.unwrap()?;
+
+        let id = self.request_id();
+        notify_observers( id, |observer| observer.on_open( id, method, url ) );
 
         Ok(())
     }
@@ -113,6 +606,20 @@ impl XmlHttpRequest {
         }
     }
 
+    /// Returns all of the response headers, separated by CRLF, or `None`
+    /// if no response has been received.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/getAllResponseHeaders)
+    // https://xhr.spec.whatwg.org/#ref-for-dom-xmlhttprequest-getallresponseheaders
+    pub fn get_all_response_headers(&self) -> Option<String> {
+        let headers = js!( return @{self}.getAllResponseHeaders(); );
+        match headers {
+            Value::Null => None,
+            Value::String(text) => Some(text),
+            _ => unreachable!(),
+        }
+    }
+
     /// Sets the value of an HTTP request header. Must be called after `open()`,
     /// but before `send()`. If this method is called several times with the same
     /// header, the values are merged into one single request header.
@@ -120,49 +627,168 @@ impl XmlHttpRequest {
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/setRequestHeader)
     // https://xhr.spec.whatwg.org/#ref-for-dom-xmlhttprequest-setrequestheader
     pub fn set_request_header(&self, header: &str, value: &str) -> Result< (), TODO > {
-        js! { @(no_return)
-            @{self}.setRequestHeader(@{header}, @{value});
-        };
+        if let Err( error ) = self.try_set_request_header( header, value ) {
+            panic!( "{}", error );
+        }
 
         Ok(())
     }
 
+    // Sets a request header just like `set_request_header`, but returns the error thrown
+    // by the browser instead of panicking, e.g. when the `header` is malformed.
+    fn try_set_request_header( &self, header: &str, value: &str ) -> Result< (), JsError > {
+        js_try! { @(no_return)
+            var xhr = @{self};
+            var header = @{header};
+            var value = @{value};
+            xhr.setRequestHeader(header, value);
+            if (header.toLowerCase() === "accept") {
+                xhr.__stdweb_accept_set = true;
+            }
+            if (xhr.__stdweb_headers) {
+                xhr.__stdweb_headers.push([header, value]);
+            }
+        }.unwrap()
+    }
+
+    /// Returns the type of the response.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/responseType)
+    // https://xhr.spec.whatwg.org/#dom-xmlhttprequest-responsetype
+    pub fn response_type(&self) -> XhrResponseType {
+        let response_type: String = js!( return @{self}.responseType; ).try_into().unwrap();
+        XhrResponseType::from_str( &response_type )
+    }
+
+    /// Sets the type of the response. Must be called before the response starts loading.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/responseType)
+    // https://xhr.spec.whatwg.org/#dom-xmlhttprequest-responsetype
+    pub fn set_response_type(&self, response_type: XhrResponseType) -> Result< (), InvalidStateError > {
+        js_try! { @(no_return)
+            @{self}.responseType = @{response_type.as_str()};
+        }.unwrap()
+    }
+
+    /// Sets the type of the response, and also sets a matching `Accept` header,
+    /// i.e. `application/json` for [Json](enum.XhrResponseType.html#variant.Json) and
+    /// `text/html` for [Document](enum.XhrResponseType.html#variant.Document).
+    ///
+    /// An `Accept` header which was already set with [set_request_header](#method.set_request_header)
+    /// is not overridden, and no header is set for the other response types.
+    ///
+    /// Must be called after `open()`, but before `send()`.
+    pub fn set_response_type_with_accept(&self, response_type: XhrResponseType) -> Result< (), InvalidStateError > {
+        self.set_response_type( response_type )?;
+        if let Some( accept ) = response_type.accept() {
+            js_try! { @(no_return)
+                var xhr = @{self};
+                if (!xhr.__stdweb_accept_set) {
+                    var accept = @{accept};
+                    xhr.setRequestHeader("Accept", accept);
+                    xhr.__stdweb_accept_set = true;
+                    if (xhr.__stdweb_headers) {
+                        xhr.__stdweb_headers.push(["Accept", accept]);
+                    }
+                }
+            }.unwrap()
+        } else {
+            Ok(())
+        }
+    }
+
     /// Send request on an open connection with no data
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/send)
     // https://xhr.spec.whatwg.org/#ref-for-dom-xmlhttprequest-send
     pub fn send(&self) -> Result< (), TODO > {
-        js! { @(no_return)
-            @{self}.send();
-        };
+        if let Err( error ) = self.try_send() {
+            panic!( "{}", error );
+        }
 
         Ok(())
     }
 
+    // Sends the request just like `send`, but returns the error thrown by the browser
+    // instead of panicking, e.g. when the request wasn't opened.
+    fn try_send( &self ) -> Result< (), JsError > {
+        self.sign( &[] );
+        self.notify_send();
+        js_try! { @(no_return)
+            @{self}.send();
+        }.unwrap()
+    }
+
     /// Send request on an open connection with string body
     ///
+    /// The body is always encoded as UTF-8. If no `Content-Type` was set the browser
+    /// sends `text/plain;charset=UTF-8`; if one was set with a `charset` parameter the
+    /// browser rewrites it to `UTF-8`, but if one was set **without** a `charset` it's
+    /// sent as-is, and the server may then decode the body with a different encoding.
+    /// Use [send_with_string_typed](#method.send_with_string_typed) to avoid that.
+    ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/send)
     // https://xhr.spec.whatwg.org/#ref-for-dom-xmlhttprequest-send
     pub fn send_with_string(&self, body: &str) -> Result< (), TODO > {
+        self.sign( body.as_bytes() );
+        self.notify_send();
         js! { @(no_return)
-            @{self}.send(@{body});
+            var xhr = @{self};
+            xhr.__stdweb_request_bytes = @{body.len() as f64};
+            xhr.send(@{body});
         };
 
         Ok(())
     }
 
+    /// Sets the `Content-Type` header to the given `mime` type and sends
+    /// the request on an open connection with string body.
+    ///
+    /// Since the body is always encoded as UTF-8 `; charset=utf-8` is appended
+    /// to the `mime` type, unless it already has a `charset` parameter.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/send)
+    // https://xhr.spec.whatwg.org/#ref-for-dom-xmlhttprequest-send
+    pub fn send_with_string_typed(&self, body: &str, mime: &str) -> Result< (), TODO > {
+        self.set_request_header( "Content-Type", &with_utf8_charset( mime ) )?;
+        self.send_with_string( body )
+    }
+
     /// Send request on an open connection with a byte array body
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/send)
     // https://xhr.spec.whatwg.org/#ref-for-dom-xmlhttprequest-send
     pub fn send_with_bytes(&self, body: &[u8]) -> Result< (), TODO > {
+        self.sign( body );
+        self.notify_send();
         js! { @(no_return)
-            @{self}.send(@{UnsafeTypedArray(body)});
+            var xhr = @{self};
+            xhr.__stdweb_request_bytes = @{body.len() as f64};
+            xhr.send(@{UnsafeTypedArray(body)});
         };
 
         Ok(())
     }
 
+    /// Sends a single chunk of a larger upload on an open connection, e.g. to
+    /// implement resumable uploads.
+    ///
+    /// The `body` contains the bytes from `start` (inclusive) to `end` (exclusive)
+    /// of the whole upload, which is `total` bytes long; the `Content-Range` header is
+    /// set accordingly. An empty `body` sends `Content-Range: bytes */total`.
+    ///
+    /// This will fail without sending anything if `start <= end <= total` doesn't hold,
+    /// or if the length of the `body` isn't equal to `end - start`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Range)
+    // https://tools.ietf.org/html/rfc7233#section-4.2
+    pub fn send_range( &self, body: &[u8], start: u64, end: u64, total: u64 ) -> Result< (), InvalidRangeError > {
+        let content_range = content_range( body.len(), start, end, total )?;
+        self.set_request_header( "Content-Range", &content_range ).unwrap();
+        self.send_with_bytes( body ).unwrap();
+        Ok(())
+    }
+
     /// Aborts the request if it has already been sent.
     /// When a request is aborted, its [ready_state](struct.XmlHttpRequest.html#method.ready_state) is changed to [Done](enum.XhrReadyState.html#variant.Done)
     /// and the [status](struct.XmlHttpRequest.html#method.status) code is set to
@@ -171,8 +797,1936 @@ impl XmlHttpRequest {
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/abort)
     // https://xhr.spec.whatwg.org/#ref-for-dom-xmlhttprequest-abort
     pub fn abort(&self) {
+        self.take_signer();
         js! { @(no_return)
             @{self}.abort();
         };
+
+        // Aborting a request which was sent already notifies the observers synchronously.
+        detach_observer( self.request_id() );
+    }
+
+    /// Registers a callback which is called whenever this request is aborted,
+    /// either by a call to [abort](struct.XmlHttpRequest.html#method.abort) or
+    /// by an `AbortSignal`.
+    ///
+    /// Unlike a `ProgressErrorEvent` listener this is never triggered by a network
+    /// failure, so it can be used to tell user-initiated cancellations apart from errors.
+    /// This is a shorthand for `add_event_listener` with a `ProgressAbortEvent`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/abort_event)
+    // https://xhr.spec.whatwg.org/#event-xhr-abort
+    pub fn on_abort< F >( &self, mut callback: F ) -> EventListenerHandle
+        where F: FnMut() + 'static
+    {
+        self.add_event_listener( move |_: ProgressAbortEvent| callback() )
+    }
+
+    /// Aborts the request as soon as it's known that its response is larger than `max_bytes`.
+    ///
+    /// The `Content-Length` header is checked once the headers are received, so an
+    /// oversized response is rejected before any of its body is downloaded. When the
+    /// header is missing (e.g. for chunked responses) the request is aborted once more
+    /// than `max_bytes` bytes have arrived instead.
+    ///
+    /// A request rejected this way fails with
+    /// [XhrError::ResponseTooLarge](enum.XhrError.html#variant.ResponseTooLarge)
+    /// instead of [XhrError::Aborted](enum.XhrError.html#variant.Aborted).
+    ///
+    /// This has to be called **before** the request is sent.
+    pub fn reject_if_larger_than( &self, max_bytes: u64 ) {
+        let is_too_large = move |content_length: Option< String >| -> bool {
+            content_length_exceeds( content_length.as_ref().map( |length| length.as_str() ), max_bytes )
+        };
+
+        js! { @(no_return)
+            var xhr = @{self};
+            var is_too_large = @{is_too_large};
+            var max_bytes = @{max_bytes as f64};
+
+            var reject = function() {
+                if( !xhr.__stdweb_too_large ) {
+                    xhr.__stdweb_too_large = true;
+                    xhr.abort();
+                }
+            };
+
+            var on_ready_state_change = function() {
+                if( xhr.readyState === 2 && is_too_large( xhr.getResponseHeader( "Content-Length" ) ) ) {
+                    reject();
+                }
+            };
+
+            // This is also checked when the header is present, in case it lies.
+            var on_progress = function( event ) {
+                if( event.loaded > max_bytes ) {
+                    reject();
+                }
+            };
+
+            var on_loadend = function() {
+                xhr.removeEventListener( "readystatechange", on_ready_state_change );
+                xhr.removeEventListener( "progress", on_progress );
+                xhr.removeEventListener( "loadend", on_loadend );
+                is_too_large.drop();
+            };
+
+            xhr.addEventListener( "readystatechange", on_ready_state_change );
+            xhr.addEventListener( "progress", on_progress );
+            xhr.addEventListener( "loadend", on_loadend );
+        }
+    }
+}
+
+// A missing or malformed `Content-Length` isn't considered to exceed the limit.
+fn content_length_exceeds( content_length: Option< &str >, max_bytes: u64 ) -> bool {
+    content_length
+        .and_then( |length| length.trim().parse::< u64 >().ok() )
+        .map( |length| length > max_bytes )
+        .unwrap_or( false )
+}
+
+/// An error returned by [XmlHttpRequest::send_range](struct.XmlHttpRequest.html#method.send_range)
+/// when the given range is inconsistent.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidRangeError {
+    length: usize,
+    start: u64,
+    end: u64,
+    total: u64
+}
+
+impl fmt::Display for InvalidRangeError {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        write!( formatter, "invalid range {}..{} of {} bytes for a body of {} bytes", self.start, self.end, self.total, self.length )
+    }
+}
+
+impl error::Error for InvalidRangeError {
+    fn description( &self ) -> &str {
+        "invalid range"
+    }
+}
+
+fn content_range( length: usize, start: u64, end: u64, total: u64 ) -> Result< String, InvalidRangeError > {
+    if start > end || end > total || end - start != length as u64 {
+        return Err( InvalidRangeError {
+            length: length,
+            start: start,
+            end: end,
+            total: total
+        });
+    }
+
+    if start == end {
+        Ok( format!( "bytes */{}", total ) )
+    } else {
+        Ok( format!( "bytes {}-{}/{}", start, end - 1, total ) )
+    }
+}
+
+/// An error which can occur when performing a request through a [XhrFuture](struct.XhrFuture.html).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum XhrError {
+    /// The request has failed due to a network error.
+    NetworkError,
+    /// The request has timed out.
+    Timeout,
+    /// The request was aborted.
+    Aborted,
+    /// The request was not sent since it would be blocked by the browser
+    /// as mixed content, i.e. an `http://` URL requested from an `https://` page.
+    ///
+    /// See [is_mixed_content](fn.is_mixed_content.html).
+    MixedContent,
+    /// The request has failed while the browser was offline.
+    ///
+    /// See [NetworkState](struct.NetworkState.html).
+    Offline,
+    /// The request was aborted since its response was too large.
+    ///
+    /// See [XmlHttpRequest::reject_if_larger_than](struct.XmlHttpRequest.html#method.reject_if_larger_than).
+    ResponseTooLarge,
+    /// The request couldn't be opened or sent, e.g. since its method or its URL
+    /// is malformed; contains the message of the error thrown by the browser.
+    InvalidRequest( String )
+}
+
+#[cfg(feature = "futures")]
+impl XhrError {
+    // A failed request looks the same whenever the browser is offline
+    // or not, so this is only decided after the fact.
+    fn network_error() -> Self {
+        if NetworkState::is_online() {
+            XhrError::NetworkError
+        } else {
+            XhrError::Offline
+        }
+    }
+}
+
+impl fmt::Display for XhrError {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        match *self {
+            XhrError::NetworkError => write!( formatter, "network error" ),
+            XhrError::Timeout => write!( formatter, "request timed out" ),
+            XhrError::Aborted => write!( formatter, "request was aborted" ),
+            XhrError::MixedContent => write!( formatter, "request to an insecure URL from a secure page was blocked as mixed content" ),
+            XhrError::Offline => write!( formatter, "request failed since the browser is offline" ),
+            XhrError::ResponseTooLarge => write!( formatter, "request was aborted since the response is too large" ),
+            XhrError::InvalidRequest( ref message ) => write!( formatter, "invalid request: {}", message )
+        }
+    }
+}
+
+impl error::Error for XhrError {
+    fn description( &self ) -> &str {
+        match *self {
+            XhrError::NetworkError => "network error",
+            XhrError::Timeout => "request timed out",
+            XhrError::Aborted => "request was aborted",
+            XhrError::MixedContent => "request was blocked as mixed content",
+            XhrError::Offline => "browser is offline",
+            XhrError::ResponseTooLarge => "response is too large",
+            XhrError::InvalidRequest( _ ) => "invalid request"
+        }
+    }
+}
+
+/// An error which can occur when streaming a response through a
+/// [XhrStreamFuture](struct.XhrStreamFuture.html).
+#[derive(Debug)]
+pub enum XhrStreamError {
+    /// The request itself has failed.
+    Request( XhrError ),
+    /// Writing into the sink has failed; the request was aborted.
+    Write( io::Error )
+}
+
+impl fmt::Display for XhrStreamError {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        match *self {
+            XhrStreamError::Request( ref error ) => write!( formatter, "{}", error ),
+            XhrStreamError::Write( ref error ) => write!( formatter, "failed to write the response: {}", error )
+        }
+    }
+}
+
+impl error::Error for XhrStreamError {
+    fn description( &self ) -> &str {
+        match *self {
+            XhrStreamError::Request( ref error ) => error::Error::description( error ),
+            XhrStreamError::Write( _ ) => "failed to write the response"
+        }
+    }
+}
+
+/// A snapshot of a finished [XmlHttpRequest](struct.XmlHttpRequest.html).
+///
+/// This is what a [XhrFuture](struct.XhrFuture.html) resolves to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XhrResponse {
+    status: u16,
+    status_text: String,
+    url: String,
+    requested_url: String,
+    request_bytes: u64,
+    response_bytes: Option< u64 >,
+    headers: String,
+    body: String
+}
+
+impl XhrResponse {
+    pub(crate) fn from_request( request: &XmlHttpRequest ) -> Self {
+        // The URL passed to `open`, resolved the same way as `responseURL` is.
+        let requested_url: String = js!(
+            var xhr = @{request};
+            if( typeof xhr.__stdweb_url !== "string" ) {
+                return xhr.responseURL;
+            }
+
+            var base = typeof document !== "undefined" ? document.baseURI : self.location.href;
+            try {
+                var url = new URL( xhr.__stdweb_url, base );
+                url.hash = "";
+                return url.href;
+            } catch( error ) {
+                return xhr.__stdweb_url;
+            }
+        ).try_into().unwrap();
+
+        let request_bytes: u64 = js!( return @{request}.__stdweb_request_bytes || 0; ).try_into().unwrap();
+        let response_bytes: Option< u64 > = js!( return @{request}.__stdweb_response_bytes; ).try_into().unwrap_or( None );
+
+        XhrResponse {
+            status: request.status(),
+            status_text: request.status_text(),
+            url: request.response_url(),
+            requested_url: requested_url,
+            request_bytes: request_bytes,
+            response_bytes: response_bytes,
+            headers: request.get_all_response_headers().unwrap_or_default(),
+            body: request.response_text().ok().and_then( |body| body ).unwrap_or_default()
+        }
+    }
+
+    /// Returns the HTTP status code of the response.
+    pub fn status( &self ) -> u16 {
+        self.status
+    }
+
+    /// Returns the HTTP status message of the response.
+    pub fn status_text( &self ) -> &str {
+        &self.status_text
+    }
+
+    /// Returns whenever the status code of the response is in the `200-299` range.
+    pub fn is_success( &self ) -> bool {
+        self.status >= 200 && self.status < 300
+    }
+
+    /// Returns the final URL of the response, after any redirects.
+    pub fn url( &self ) -> &str {
+        &self.url
+    }
+
+    /// Returns the absolute URL which was passed to
+    /// [open](struct.XmlHttpRequest.html#method.open), without its fragment.
+    pub fn requested_url( &self ) -> &str {
+        &self.requested_url
+    }
+
+    /// Returns whenever the request was redirected, i.e. whenever the final
+    /// [url](#method.url) differs from the [requested_url](#method.requested_url).
+    ///
+    /// Browsers follow redirects transparently and only expose the final URL,
+    /// so the intermediate URLs of a redirect chain can't be recovered, and
+    /// a chain which ends up at the requested URL again isn't detected.
+    pub fn was_redirected( &self ) -> bool {
+        !self.url.is_empty() && self.url != self.requested_url
+    }
+
+    /// Returns the final URL of the response if the request
+    /// [was redirected](#method.was_redirected).
+    ///
+    /// This is the only URL of a redirect chain which browsers expose.
+    pub fn redirect_target( &self ) -> Option< String > {
+        if self.was_redirected() {
+            Some( self.url.clone() )
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value of the given response header. The lookup is case-insensitive.
+    ///
+    /// If there are multiple headers with the same name their values
+    /// are joined together with `", "`.
+    pub fn header( &self, name: &str ) -> Option< String > {
+        let mut output: Option< String > = None;
+        for line in self.headers.split( "\r\n" ) {
+            let mut parts = line.splitn( 2, ':' );
+            let key = parts.next().unwrap_or( "" ).trim();
+            let value = match parts.next() {
+                Some( value ) => value.trim(),
+                None => continue
+            };
+
+            if !key.eq_ignore_ascii_case( name ) {
+                continue;
+            }
+
+            output = Some( match output {
+                Some( output ) => output + ", " + value,
+                None => value.to_owned()
+            });
+        }
+
+        output
+    }
+
+    /// Returns the network timings of the request, i.e. how long the DNS lookup,
+    /// the connection setup or the transfer of the response took.
+    ///
+    /// This looks up the last Resource Timing entry the browser has recorded
+    /// for the [requested_url](#method.requested_url), since the entry of a
+    /// redirected request is named after the URL it started with; `None` is
+    /// returned if there is no such entry, e.g. because the browser's resource
+    /// timing buffer is full.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Resource_Timing_API)
+    pub fn timing( &self ) -> Option< ResourceTiming > {
+        self.timing_entry().map( |entry| ResourceTiming::from_entry( &entry ) )
+    }
+
+    fn timing_entry( &self ) -> Option< PerformanceResourceTiming > {
+        let entries: Vec< PerformanceEntry > = js!(
+            var performance = self.performance;
+            if( !performance || typeof performance.getEntriesByName !== "function" ) {
+                return [];
+            }
+
+            return performance.getEntriesByName( @{&self.requested_url} );
+        ).try_into().unwrap();
+
+        entries.into_iter().rev()
+            .filter_map( |entry| entry.try_into().ok() )
+            .find( |entry: &PerformanceResourceTiming| entry.initiator_type() == "xmlhttprequest" )
+    }
+
+    /// Returns the metrics of the request, combining its [timing](#method.timing)
+    /// with the number of bytes which were sent and received, e.g. for feeding
+    /// them into a telemetry pipeline.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// PromiseFuture::spawn(
+    ///     XmlHttpRequest::request( "GET", "/api/items" )
+    ///         .map( |response| telemetry.record( response.metrics() ) )
+    ///         .map_err( |error| console!( error, format!( "{}", error ) ) )
+    /// );
+    /// ```
+    pub fn metrics( &self ) -> XhrMetrics {
+        let entry = self.timing_entry();
+        let timing = entry.as_ref().map( ResourceTiming::from_entry );
+        let dns_queue_ms = entry.as_ref().and_then( |entry| {
+            let request_start = entry.request_start();
+            // Opaque entries have this zeroed.
+            if request_start == 0.0 {
+                None
+            } else {
+                Some( request_start - entry.fetch_start() )
+            }
+        });
+
+        XhrMetrics {
+            dns_queue_ms: dns_queue_ms,
+            ttfb_ms: timing.and_then( |timing| timing.ttfb_ms ),
+            download_ms: timing.and_then( |timing| timing.transfer_ms ),
+            total_ms: timing.map( |timing| timing.duration_ms ),
+            request_bytes: self.request_bytes,
+            response_bytes: self.response_bytes,
+            status: self.status
+        }
+    }
+
+    /// Returns the body of the response as text.
+    pub fn body( &self ) -> &str {
+        &self.body
+    }
+
+    /// Consumes the response and returns its body.
+    pub fn into_body( self ) -> String {
+        self.body
+    }
+}
+
+/// A [Future](https://docs.rs/futures/0.1.18/futures/future/trait.Future.html) which
+/// resolves to a [XhrResponse](struct.XhrResponse.html) once the underlying
+/// [XmlHttpRequest](struct.XmlHttpRequest.html) finishes.
+///
+/// Note that any HTTP status code (including `404` or `500`) is considered
+/// a successful response; only network errors, timeouts and aborts are errors.
+#[cfg(feature = "futures")]
+pub struct XhrFuture {
+    request: XmlHttpRequest,
+    receiver: oneshot::Receiver< Result< XhrResponse, XhrError > >
+}
+
+#[cfg(feature = "futures")]
+impl XhrFuture {
+    // Returns a future which immediately fails with the given `error`.
+    fn failed( request: XmlHttpRequest, error: XhrError ) -> Self {
+        let ( sender, receiver ) = oneshot::channel();
+        let _ = sender.send( Err( error ) );
+        XhrFuture {
+            request: request,
+            receiver: receiver
+        }
+    }
+
+    /// Returns the underlying request.
+    pub fn request( &self ) -> &XmlHttpRequest {
+        &self.request
+    }
+}
+
+#[cfg(feature = "futures")]
+impl fmt::Debug for XhrFuture {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        write!( formatter, "XhrFuture" )
+    }
+}
+
+#[cfg(feature = "futures")]
+impl Future for XhrFuture {
+    type Item = XhrResponse;
+    type Error = XhrError;
+
+    fn poll( &mut self ) -> Poll< Self::Item, Self::Error > {
+        match self.receiver.poll() {
+            Ok( Async::Ready( Ok( response ) ) ) => Ok( Async::Ready( response ) ),
+            Ok( Async::Ready( Err( error ) ) ) => Err( error ),
+            Ok( Async::NotReady ) => Ok( Async::NotReady ),
+            Err( _ ) => Err( XhrError::Aborted )
+        }
+    }
+}
+
+/// A [Future](https://docs.rs/futures/0.1.18/futures/future/trait.Future.html) which
+/// resolves once the whole response of an [XmlHttpRequest](struct.XmlHttpRequest.html)
+/// was written into a sink.
+///
+/// See [XmlHttpRequest::stream_to](struct.XmlHttpRequest.html#method.stream_to).
+///
+/// Dropping this future before it resolves aborts the request.
+#[cfg(feature = "futures")]
+pub struct XhrStreamFuture {
+    request: XmlHttpRequest,
+    receiver: oneshot::Receiver< Result< (), XhrStreamError > >,
+    finished: bool
+}
+
+#[cfg(feature = "futures")]
+impl XhrStreamFuture {
+    /// Returns the underlying request.
+    pub fn request( &self ) -> &XmlHttpRequest {
+        &self.request
+    }
+}
+
+#[cfg(feature = "futures")]
+impl fmt::Debug for XhrStreamFuture {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        write!( formatter, "XhrStreamFuture" )
+    }
+}
+
+#[cfg(feature = "futures")]
+impl Future for XhrStreamFuture {
+    type Item = ();
+    type Error = XhrStreamError;
+
+    fn poll( &mut self ) -> Poll< Self::Item, Self::Error > {
+        let result = match self.receiver.poll() {
+            Ok( Async::Ready( result ) ) => result.map( Async::Ready ),
+            Ok( Async::NotReady ) => return Ok( Async::NotReady ),
+            Err( _ ) => Err( XhrStreamError::Request( XhrError::Aborted ) )
+        };
+
+        self.finished = true;
+        result
+    }
+}
+
+#[cfg(feature = "futures")]
+impl Drop for XhrStreamFuture {
+    fn drop( &mut self ) {
+        if !self.finished {
+            self.request.abort();
+        }
+    }
+}
+
+#[cfg(feature = "futures")]
+impl XmlHttpRequest {
+    /// Returns a future which will resolve once this request finishes.
+    ///
+    /// This has to be called **before** the request is sent.
+    pub fn to_future( &self ) -> XhrFuture {
+        let ( sender, receiver ) = oneshot::channel();
+        self.on_finish( move |result| {
+            let _ = sender.send( result );
+        });
+
+        XhrFuture {
+            request: self.clone(),
+            receiver: receiver
+        }
+    }
+
+    /// Writes the body of the response into `sink` chunk by chunk as it arrives,
+    /// and returns a future which will resolve once the whole response was written.
+    ///
+    /// This is meant for large downloads which should be processed incrementally.
+    /// The response is read as binary through a `text/plain; charset=x-user-defined`
+    /// MIME type override, so the [response_type](#method.response_type) must be left
+    /// as the default `Text`.
+    ///
+    /// Note that the browser still buffers the whole response in `responseText` until
+    /// the request finishes, since an `XMLHttpRequest` can't discard the parts which
+    /// were already read, so this doesn't reduce the peak memory usage. If that matters
+    /// use [fetch](fn.fetch.html) and read the body through a
+    /// [ReadableStreamReader](struct.ReadableStreamReader.html) instead.
+    ///
+    /// If writing into the `sink` fails the request is aborted and the future
+    /// fails with [XhrStreamError::Write](enum.XhrStreamError.html#variant.Write).
+    ///
+    /// Dropping the returned future before it resolves aborts the request.
+    ///
+    /// This has to be called **before** the request is sent.
+    pub fn stream_to< W: Write + 'static >( &self, sink: W ) -> XhrStreamFuture {
+        let ( sender, receiver ) = oneshot::channel();
+        let sink = Rc::new( RefCell::new( sink ) );
+        let write_error = Rc::new( RefCell::new( None ) );
+
+        let on_chunk = {
+            let sink = sink.clone();
+            let write_error = write_error.clone();
+            move |chunk: TypedArray< u8 >| -> bool {
+                match sink.borrow_mut().write_all( &chunk.to_vec() ) {
+                    Ok(()) => true,
+                    Err( error ) => {
+                        *write_error.borrow_mut() = Some( error );
+                        false
+                    }
+                }
+            }
+        };
+
+        let on_end = move |failure: Option< String >| {
+            let result = if let Some( error ) = write_error.borrow_mut().take() {
+                Err( XhrStreamError::Write( error ) )
+            } else {
+                match failure.as_ref().map( |failure| failure.as_str() ) {
+                    None => sink.borrow_mut().flush().map_err( XhrStreamError::Write ),
+                    Some( "timeout" ) => Err( XhrStreamError::Request( XhrError::Timeout ) ),
+                    Some( "abort" ) => Err( XhrStreamError::Request( XhrError::Aborted ) ),
+                    Some( "too_large" ) => Err( XhrStreamError::Request( XhrError::ResponseTooLarge ) ),
+                    Some( _ ) => Err( XhrStreamError::Request( XhrError::network_error() ) )
+                }
+            };
+
+            let _ = sender.send( result );
+        };
+
+        js! { @(no_return)
+            var xhr = @{self};
+            var on_chunk = @{on_chunk};
+            var on_end = @{Once( on_end )};
+            var offset = 0;
+            var failure = null;
+
+            xhr.overrideMimeType( "text/plain; charset=x-user-defined" );
+
+            var write_pending = function() {
+                var text = xhr.responseText;
+                if( text.length <= offset ) {
+                    return true;
+                }
+
+                var chunk = new Uint8Array( text.length - offset );
+                for( var i = 0; i < chunk.length; ++i ) {
+                    chunk[ i ] = text.charCodeAt( offset + i ) & 0xff;
+                }
+
+                offset = text.length;
+                return on_chunk( chunk );
+            };
+
+            var on_progress = function() {
+                if( failure === null && !write_pending() ) {
+                    failure = "write";
+                    xhr.abort();
+                }
+            };
+
+            var on_error = function() { if( failure === null ) { failure = "error"; } };
+            var on_timeout = function() { if( failure === null ) { failure = "timeout"; } };
+            var on_abort = function() { if( failure === null ) { failure = xhr.__stdweb_too_large ? "too_large" : "abort"; } };
+            var on_loadend = function() {
+                xhr.removeEventListener( "progress", on_progress );
+                xhr.removeEventListener( "error", on_error );
+                xhr.removeEventListener( "timeout", on_timeout );
+                xhr.removeEventListener( "abort", on_abort );
+                xhr.removeEventListener( "loadend", on_loadend );
+                if( failure === null && !write_pending() ) {
+                    failure = "write";
+                }
+
+                on_chunk.drop();
+                on_end( failure );
+            };
+
+            xhr.addEventListener( "progress", on_progress );
+            xhr.addEventListener( "error", on_error );
+            xhr.addEventListener( "timeout", on_timeout );
+            xhr.addEventListener( "abort", on_abort );
+            xhr.addEventListener( "loadend", on_loadend );
+        }
+
+        XhrStreamFuture {
+            request: self.clone(),
+            receiver: receiver,
+            finished: false
+        }
+    }
+
+    pub(crate) fn on_finish< F >( &self, callback: F )
+        where F: FnOnce( Result< XhrResponse, XhrError > ) + 'static
+    {
+        let request = self.clone();
+        let callback = move |failure: Option< String >| {
+            let result = match failure.as_ref().map( |failure| failure.as_str() ) {
+                None => Ok( XhrResponse::from_request( &request ) ),
+                Some( "timeout" ) => Err( XhrError::Timeout ),
+                Some( "abort" ) => Err( XhrError::Aborted ),
+                Some( "too_large" ) => Err( XhrError::ResponseTooLarge ),
+                Some( _ ) => Err( XhrError::network_error() )
+            };
+
+            callback( result );
+        };
+
+        js! { @(no_return)
+            var xhr = @{self};
+            var callback = @{Once( callback )};
+            var failure = null;
+
+            var on_error = function() { failure = "error"; };
+            var on_timeout = function() { failure = "timeout"; };
+            var on_abort = function() { failure = xhr.__stdweb_too_large ? "too_large" : "abort"; };
+            var on_loadend = function( event ) {
+                xhr.removeEventListener( "error", on_error );
+                xhr.removeEventListener( "timeout", on_timeout );
+                xhr.removeEventListener( "abort", on_abort );
+                xhr.removeEventListener( "loadend", on_loadend );
+                xhr.__stdweb_response_bytes = event.loaded;
+                callback( failure );
+            };
+
+            xhr.addEventListener( "error", on_error );
+            xhr.addEventListener( "timeout", on_timeout );
+            xhr.addEventListener( "abort", on_abort );
+            xhr.addEventListener( "loadend", on_loadend );
+        }
+    }
+
+    /// Creates and sends a new request with the given `method` to the given `url`,
+    /// and returns a future which will resolve once the request finishes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// PromiseFuture::spawn(
+    ///     XmlHttpRequest::request( "GET", "/api/items" )
+    ///         .map( |response| console!( log, response.body() ) )
+    ///         .map_err( |error| console!( error, format!( "{}", error ) ) )
+    /// );
+    /// ```
+    ///
+    /// If the request would be blocked as mixed content the future will
+    /// resolve to [XhrError::MixedContent](enum.XhrError.html#variant.MixedContent)
+    /// without the request ever being sent, and if the `method` or the `url` is
+    /// malformed it will resolve to [XhrError::InvalidRequest](enum.XhrError.html#variant.InvalidRequest).
+    pub fn request( method: &str, url: &str ) -> XhrFuture {
+        let request = XmlHttpRequest::new();
+        if is_mixed_content( url ) {
+            return XhrFuture::failed( request, XhrError::MixedContent );
+        }
+
+        if let Err( error ) = request.try_open( method, url ) {
+            return XhrFuture::failed( request, XhrError::InvalidRequest( error.message() ) );
+        }
+
+        let future = request.to_future();
+        if let Err( error ) = request.try_send() {
+            return XhrFuture::failed( request, XhrError::InvalidRequest( error.message() ) );
+        }
+
+        future
+    }
+}
+
+fn with_utf8_charset( mime: &str ) -> String {
+    let parameters = content_type_parameters( mime );
+    if parameters.iter().any( |&(ref name, ref value, _)| is_charset( name, value ) ) {
+        return mime.to_owned();
+    }
+
+    // An empty `charset=` would conflict with the one we append, so it's dropped.
+    let mut output = String::new();
+    let mut position = 0;
+    for (name, _, range) in parameters {
+        if name.eq_ignore_ascii_case( "charset" ) {
+            output.push_str( &mime[ position..range.start ] );
+            position = range.end;
+        }
+    }
+
+    output.push_str( &mime[ position.. ] );
+    format!( "{}; charset=utf-8", output.trim_right().trim_right_matches( ';' ) )
+}
+
+fn is_charset( name: &str, value: &str ) -> bool {
+    name.eq_ignore_ascii_case( "charset" ) && !value.is_empty()
+}
+
+fn content_type_charset( content_type: &str ) -> Option< String > {
+    content_type_parameters( content_type ).into_iter()
+        .find( |&(ref name, ref value, _)| is_charset( name, value ) )
+        .map( |(_, value, _)| value )
+}
+
+// Returns the name, the unquoted value and the byte range of every parameter of
+// the `content_type`; the range starts at the `;` which precedes the parameter.
+fn content_type_parameters( content_type: &str ) -> Vec< (String, String, Range< usize >) > {
+    let mut parameters = Vec::new();
+    let mut rest = match content_type.find( ';' ) {
+        Some( index ) => &content_type[ index.. ],
+        None => return parameters
+    };
+
+    while rest.starts_with( ';' ) {
+        let start = content_type.len() - rest.len();
+        rest = rest[ 1.. ].trim_left();
+
+        let name_end = rest.find( |character| character == '=' || character == ';' ).unwrap_or( rest.len() );
+        let name = rest[ ..name_end ].trim().to_owned();
+        rest = &rest[ name_end.. ];
+
+        let mut value = String::new();
+        if rest.starts_with( '=' ) {
+            rest = &rest[ 1.. ];
+            if rest.starts_with( '"' ) {
+                let mut end = rest.len();
+                let mut is_escaped = false;
+                for (index, character) in rest.char_indices().skip( 1 ) {
+                    if is_escaped {
+                        value.push( character );
+                        is_escaped = false;
+                    } else if character == '\\' {
+                        is_escaped = true;
+                    } else if character == '"' {
+                        end = index + 1;
+                        break;
+                    } else {
+                        value.push( character );
+                    }
+                }
+
+                rest = &rest[ end.. ];
+                rest = &rest[ rest.find( ';' ).unwrap_or( rest.len() ).. ];
+            } else {
+                let end = rest.find( ';' ).unwrap_or( rest.len() );
+                value = rest[ ..end ].trim().to_owned();
+                rest = &rest[ end.. ];
+            }
+        }
+
+        let end = content_type.len() - rest.len();
+        parameters.push( (name, value, start..end) );
+    }
+
+    parameters
+}
+
+/// The metadata of a resource, as returned by a `HEAD` request made with [head](fn.head.html).
+///
+/// Headers which are missing, or which the browser doesn't expose, are `None`.
+/// Note that for cross-origin requests the browser only exposes the `ETag`
+/// if the server lists it in the `Access-Control-Expose-Headers` header.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HeadResult {
+    /// The HTTP status code of the response.
+    pub status: u16,
+    /// The size of the resource in bytes, from the `Content-Length` header.
+    pub content_length: Option< u64 >,
+    /// The MIME type of the resource, from the `Content-Type` header.
+    pub content_type: Option< String >,
+    /// The date at which the resource was last modified, from the `Last-Modified` header.
+    pub last_modified: Option< String >,
+    /// The version identifier of the resource, from the `ETag` header.
+    pub etag: Option< String >
+}
+
+#[cfg(feature = "futures")]
+impl HeadResult {
+    fn from_response( response: &XhrResponse ) -> Self {
+        HeadResult {
+            status: response.status(),
+            content_length: response.header( "Content-Length" ).and_then( |length| length.trim().parse().ok() ),
+            content_type: response.header( "Content-Type" ),
+            last_modified: response.header( "Last-Modified" ),
+            etag: response.header( "ETag" )
+        }
+    }
+}
+
+/// A future which will resolve once a `HEAD` request started with [head](fn.head.html) finishes.
+#[cfg(feature = "futures")]
+pub struct HeadFuture( XhrFuture );
+
+#[cfg(feature = "futures")]
+impl fmt::Debug for HeadFuture {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        write!( formatter, "HeadFuture" )
+    }
+}
+
+#[cfg(feature = "futures")]
+impl Future for HeadFuture {
+    type Item = HeadResult;
+    type Error = XhrError;
+
+    fn poll( &mut self ) -> Poll< Self::Item, Self::Error > {
+        match self.0.poll()? {
+            Async::Ready( response ) => Ok( Async::Ready( HeadResult::from_response( &response ) ) ),
+            Async::NotReady => Ok( Async::NotReady )
+        }
+    }
+}
+
+/// Sends a `HEAD` request to the given `url`, and returns a future which will
+/// resolve to the metadata of the resource, e.g. its size and its type,
+/// without downloading it.
+///
+/// As with [XmlHttpRequest::request](struct.XmlHttpRequest.html#method.request)
+/// any HTTP status code is considered a successful response.
+///
+/// # Examples
+///
+/// ```rust
+/// PromiseFuture::spawn(
+///     head( "/downloads/video.mp4" )
+///         .map( |result| console!( log, format!( "{:?} bytes", result.content_length ) ) )
+///         .map_err( |error| console!( error, format!( "{}", error ) ) )
+/// );
+/// ```
+#[cfg(feature = "futures")]
+pub fn head( url: &str ) -> HeadFuture {
+    HeadFuture( XmlHttpRequest::request( "HEAD", url ) )
+}
+
+/// The result of a request made through a [ConditionalRequest](struct.ConditionalRequest.html).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConditionalResponse {
+    /// The server has sent a full response.
+    Modified( XhrResponse ),
+    /// The server has responded with `304 Not Modified`; this contains
+    /// the previous `200` response for the same URL, including its body.
+    Cached( XhrResponse ),
+    /// The server has responded with `304 Not Modified`, but the previous response
+    /// wasn't kept since the request was created with
+    /// [ConditionalRequest::without_bodies](struct.ConditionalRequest.html#method.without_bodies).
+    NotModified
+}
+
+impl ConditionalResponse {
+    /// Returns the response, regardless of whenever it was cached; returns `None`
+    /// for [NotModified](#variant.NotModified) since no response was kept.
+    pub fn response( &self ) -> Option< &XhrResponse > {
+        match *self {
+            ConditionalResponse::Modified( ref response ) | ConditionalResponse::Cached( ref response ) => Some( response ),
+            ConditionalResponse::NotModified => None
+        }
+    }
+
+    /// Returns whenever the server has responded with `304 Not Modified`.
+    pub fn is_cached( &self ) -> bool {
+        match *self {
+            ConditionalResponse::Modified( _ ) => false,
+            ConditionalResponse::Cached( _ ) | ConditionalResponse::NotModified => true
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct CachedResponse {
+    etag: Option< String >,
+    last_modified: Option< String >,
+    response: Option< XhrResponse >
+}
+
+/// A helper for polling resources with conditional requests.
+///
+/// It remembers the `ETag` and `Last-Modified` headers of the last `200`
+/// response for every URL, and sends them back as `If-None-Match` and
+/// `If-Modified-Since` on the next request to the same URL. If the server
+/// responds with `304 Not Modified` the previous response is returned
+/// as [ConditionalResponse::Cached](enum.ConditionalResponse.html#variant.Cached),
+/// so the body doesn't have to be downloaded again.
+///
+/// If the caller keeps the data it needs by itself the previous responses
+/// don't have to be kept around; see [without_bodies](#method.without_bodies).
+///
+/// The state is kept per instance, so separate instances never affect each other.
+///
+/// Note that for cross-origin requests the browser only exposes the `ETag`
+/// if the server lists it in the `Access-Control-Expose-Headers` header.
+///
+/// # Examples
+///
+/// ```rust
+/// let requests = ConditionalRequest::new();
+/// PromiseFuture::spawn(
+///     requests.get( "/api/dashboard" )
+///         .map( |response| {
+///             if !response.is_cached() {
+///                 console!( log, response.response().unwrap().body() );
+///             }
+///         })
+///         .map_err( |error| console!( error, format!( "{}", error ) ) )
+/// );
+/// ```
+#[derive(Clone)]
+pub struct ConditionalRequest {
+    cache: Rc< RefCell< HashMap< String, CachedResponse > > >,
+    keep_bodies: bool
+}
+
+impl Default for ConditionalRequest {
+    fn default() -> Self {
+        ConditionalRequest::new()
+    }
+}
+
+impl fmt::Debug for ConditionalRequest {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        write!( formatter, "ConditionalRequest" )
+    }
+}
+
+impl ConditionalRequest {
+    /// Creates a new `ConditionalRequest` which doesn't remember any responses yet.
+    pub fn new() -> Self {
+        ConditionalRequest {
+            cache: Rc::new( RefCell::new( HashMap::new() ) ),
+            keep_bodies: true
+        }
+    }
+
+    /// Creates a new `ConditionalRequest` which only remembers the `ETag` and
+    /// `Last-Modified` headers, and not the whole responses; a `304 Not Modified`
+    /// is then reported as [ConditionalResponse::NotModified](enum.ConditionalResponse.html#variant.NotModified)
+    /// so that the caller can skip reprocessing data it already has.
+    pub fn without_bodies() -> Self {
+        ConditionalRequest {
+            keep_bodies: false,
+            ..ConditionalRequest::new()
+        }
+    }
+
+    /// Creates and opens a new request to the given `url`, setting the
+    /// `If-None-Match` and `If-Modified-Since` headers if a response
+    /// for the same `url` was received before.
+    ///
+    /// The returned request still has to be sent, and its response
+    /// has to be passed to [resolve](#method.resolve).
+    pub fn open( &self, method: &str, url: &str ) -> Result< XmlHttpRequest, TODO > {
+        let request = XmlHttpRequest::new();
+        if let Err( error ) = self.try_open( &request, method, url ) {
+            panic!( "{}", error );
+        }
+
+        Ok( request )
+    }
+
+    fn try_open( &self, request: &XmlHttpRequest, method: &str, url: &str ) -> Result< (), JsError > {
+        request.try_open( method, url )?;
+        if let Some( cached ) = self.cache.borrow().get( url ) {
+            if let Some( ref etag ) = cached.etag {
+                request.try_set_request_header( "If-None-Match", etag )?;
+            }
+
+            if let Some( ref last_modified ) = cached.last_modified {
+                request.try_set_request_header( "If-Modified-Since", last_modified )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Interprets a `response` received for the given `url`, remembering
+    /// it for the next request.
+    ///
+    /// A `304` is only reported as not modified if a response for the `url`
+    /// was remembered; any other status is always `Modified`.
+    pub fn resolve( &self, url: &str, response: XhrResponse ) -> ConditionalResponse {
+        let mut cache = self.cache.borrow_mut();
+        match response.status() {
+            304 => {
+                if let Some( cached ) = cache.get( url ) {
+                    return match cached.response {
+                        Some( ref response ) => ConditionalResponse::Cached( response.clone() ),
+                        None => ConditionalResponse::NotModified
+                    };
+                }
+            },
+            200 => {
+                let etag = response.header( "ETag" );
+                let last_modified = response.header( "Last-Modified" );
+                if etag.is_none() && last_modified.is_none() {
+                    cache.remove( url );
+                } else {
+                    cache.insert( url.to_owned(), CachedResponse {
+                        etag: etag,
+                        last_modified: last_modified,
+                        response: if self.keep_bodies { Some( response.clone() ) } else { None }
+                    });
+                }
+            },
+            _ => {}
+        }
+
+        ConditionalResponse::Modified( response )
+    }
+
+    /// Sends a `GET` request to the given `url`, with conditional headers
+    /// if a response for the same `url` was received before.
+    ///
+    /// As with [XmlHttpRequest::request](struct.XmlHttpRequest.html#method.request)
+    /// any HTTP status code is considered a successful response, and a malformed `url`
+    /// resolves to [XhrError::InvalidRequest](enum.XhrError.html#variant.InvalidRequest).
+    #[cfg(feature = "futures")]
+    pub fn get( &self, url: &str ) -> ConditionalFuture {
+        let future = if is_mixed_content( url ) {
+            XmlHttpRequest::request( "GET", url )
+        } else {
+            let request = XmlHttpRequest::new();
+            match self.send( &request, url ) {
+                Ok( future ) => future,
+                Err( error ) => XhrFuture::failed( request, XhrError::InvalidRequest( error.message() ) )
+            }
+        };
+
+        ConditionalFuture {
+            future: future,
+            url: url.to_owned(),
+            request: self.clone()
+        }
+    }
+
+    #[cfg(feature = "futures")]
+    fn send( &self, request: &XmlHttpRequest, url: &str ) -> Result< XhrFuture, JsError > {
+        self.try_open( request, "GET", url )?;
+        let future = request.to_future();
+        request.try_send()?;
+        Ok( future )
+    }
+
+    /// Forgets the response remembered for the given `url`, so the next
+    /// request to it will be unconditional.
+    pub fn forget( &self, url: &str ) {
+        self.cache.borrow_mut().remove( url );
+    }
+
+    /// Forgets all of the remembered responses.
+    pub fn clear( &self ) {
+        self.cache.borrow_mut().clear();
+    }
+}
+
+/// A future returned by [ConditionalRequest::get](struct.ConditionalRequest.html#method.get).
+#[cfg(feature = "futures")]
+pub struct ConditionalFuture {
+    future: XhrFuture,
+    url: String,
+    request: ConditionalRequest
+}
+
+#[cfg(feature = "futures")]
+impl fmt::Debug for ConditionalFuture {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        write!( formatter, "ConditionalFuture" )
+    }
+}
+
+#[cfg(feature = "futures")]
+impl Future for ConditionalFuture {
+    type Item = ConditionalResponse;
+    type Error = XhrError;
+
+    fn poll( &mut self ) -> Poll< Self::Item, Self::Error > {
+        match self.future.poll()? {
+            Async::Ready( response ) => Ok( Async::Ready( self.request.resolve( &self.url, response ) ) ),
+            Async::NotReady => Ok( Async::NotReady )
+        }
+    }
+}
+
+/// Checks whenever a request to the given `url` would be blocked by the
+/// browser as mixed content, that is whenever the current page was loaded
+/// over `https:` while the `url` uses an insecure scheme like `http:`.
+///
+/// Such requests fail with a status of `0` without any further explanation,
+/// so checking this beforehand can make the failure easier to diagnose.
+///
+/// Relative URLs are never considered to be mixed content, and neither are
+/// requests to `localhost` since browsers treat them as trustworthy.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Security/Mixed_content)
+// https://w3c.github.io/webappsec-mixed-content/#should-block-fetch
+pub fn is_mixed_content( url: &str ) -> bool {
+    let page_protocol: String = js!( return location.protocol; ).try_into().unwrap();
+    is_mixed_content_for( &page_protocol, url )
+}
+
+fn is_mixed_content_for( page_protocol: &str, url: &str ) -> bool {
+    if !page_protocol.eq_ignore_ascii_case( "https:" ) {
+        return false;
+    }
+
+    let url = url.trim();
+    let (scheme, rest) = match url.find( ':' ) {
+        Some( index ) => (&url[ ..index ], &url[ index + 1.. ]),
+        None => return false
+    };
+
+    // Anything with a `/`, `?` or `#` before the colon is a relative URL.
+    if scheme.is_empty() || scheme.contains( |character| character == '/' || character == '?' || character == '#' ) {
+        return false;
+    }
+
+    if !scheme.eq_ignore_ascii_case( "http" ) && !scheme.eq_ignore_ascii_case( "ws" ) {
+        return false;
+    }
+
+    let authority = rest.trim_left_matches( '/' );
+    let authority = authority.split( |character| character == '/' || character == '?' || character == '#' ).next().unwrap_or( "" );
+    let host = authority.rsplit( '@' ).next().unwrap_or( "" );
+    let host = if host.starts_with( '[' ) {
+        host.split( ']' ).next().unwrap_or( "" ).trim_left_matches( '[' )
+    } else {
+        host.split( ':' ).next().unwrap_or( "" )
+    };
+
+    let host = host.to_ascii_lowercase();
+    let is_loopback = host == "localhost" || host.ends_with( ".localhost" ) || host.starts_with( "127." ) || host == "::1";
+    !is_loopback
+}
+
+/// The default timeout used by [jsonp](fn.jsonp.html), in milliseconds.
+#[cfg(feature = "futures")]
+pub const DEFAULT_JSONP_TIMEOUT: u32 = 30_000;
+
+#[cfg(feature = "futures")]
+fn jsonp_url( url: &str, callback_param: &str, callback_name: &str ) -> String {
+    let mut output = url.to_owned();
+    output.push( if url.contains( '?' ) { '&' } else { '?' } );
+    percent_encode( callback_param, &mut output );
+    output.push( '=' );
+    output.push_str( callback_name );
+    output
+}
+
+/// A future which will resolve once a JSONP request started
+/// with [jsonp](fn.jsonp.html) finishes.
+#[cfg(feature = "futures")]
+pub struct JsonpFuture {
+    receiver: oneshot::Receiver< Result< Value, XhrError > >
+}
+
+#[cfg(feature = "futures")]
+impl fmt::Debug for JsonpFuture {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        write!( formatter, "JsonpFuture" )
+    }
+}
+
+#[cfg(feature = "futures")]
+impl Future for JsonpFuture {
+    type Item = Value;
+    type Error = XhrError;
+
+    fn poll( &mut self ) -> Poll< Self::Item, Self::Error > {
+        match self.receiver.poll() {
+            Ok( Async::Ready( Ok( value ) ) ) => Ok( Async::Ready( value ) ),
+            Ok( Async::Ready( Err( error ) ) ) => Err( error ),
+            Ok( Async::NotReady ) => Ok( Async::NotReady ),
+            Err( _ ) => Err( XhrError::Aborted )
+        }
+    }
+}
+
+/// Performs a [JSONP](https://en.wikipedia.org/wiki/JSONP) request to the given `url`
+/// and returns a future which will resolve with the value passed by the server
+/// to the callback.
+///
+/// This is for legacy cross-origin APIs which don't support CORS. It works by
+/// injecting a `<script>` tag into the document, so it's not actually performed
+/// through an [XmlHttpRequest](struct.XmlHttpRequest.html); the name of a uniquely
+/// named global callback is passed in the `callback_param` query parameter.
+///
+/// The script tag and the global callback are removed once the request finishes;
+/// if it fails the callback is replaced with a no-op instead, so that a response
+/// which arrives late doesn't throw.
+/// If the script fails to load the future will resolve to a
+/// [NetworkError](enum.XhrError.html#variant.NetworkError); if the server doesn't
+/// call the callback within [DEFAULT_JSONP_TIMEOUT](constant.DEFAULT_JSONP_TIMEOUT.html)
+/// milliseconds it will resolve to a [Timeout](enum.XhrError.html#variant.Timeout).
+///
+/// **Only use this with servers you trust**, as the response is executed as a script!
+///
+/// # Examples
+///
+/// ```rust
+/// PromiseFuture::spawn(
+///     jsonp( "https://example.com/api/items", "callback" )
+///         .map( |value| console!( log, value ) )
+///         .map_err( |error| console!( error, format!( "{}", error ) ) )
+/// );
+/// ```
+#[cfg(feature = "futures")]
+pub fn jsonp( url: &str, callback_param: &str ) -> JsonpFuture {
+    jsonp_with_timeout( url, callback_param, DEFAULT_JSONP_TIMEOUT )
+}
+
+/// Same as [jsonp](fn.jsonp.html), but with a custom `timeout` in milliseconds.
+#[cfg(feature = "futures")]
+pub fn jsonp_with_timeout( url: &str, callback_param: &str, timeout: u32 ) -> JsonpFuture {
+    let ( sender, receiver ) = oneshot::channel();
+    let callback = move |failure: Option< String >, value: Value| {
+        let result = match failure.as_ref().map( |failure| failure.as_str() ) {
+            None => Ok( value ),
+            Some( "timeout" ) => Err( XhrError::Timeout ),
+            Some( _ ) => Err( XhrError::network_error() )
+        };
+
+        let _ = sender.send( result );
+    };
+
+    let id = NEXT_JSONP_ID.with( |next_id| {
+        let id = next_id.get();
+        next_id.set( id + 1 );
+        id
+    });
+
+    let callback_name = format!( "__stdweb_jsonp_{}", id );
+    let url = jsonp_url( url, callback_param, &callback_name );
+
+    js! { @(no_return)
+        var callback = @{Once( callback )};
+        var callback_name = @{callback_name};
+        var script = document.createElement( "script" );
+        var timer = null;
+
+        var finish = function( failure, value ) {
+            clearTimeout( timer );
+            if( failure === null ) {
+                delete window[ callback_name ];
+            } else {
+                // The script can still call the callback after we've given up on it.
+                window[ callback_name ] = function() {};
+            }
+
+            script.onerror = null;
+            if( script.parentNode ) {
+                script.parentNode.removeChild( script );
+            }
+            callback( failure, value );
+        };
+
+        window[ callback_name ] = function( value ) {
+            finish( null, value );
+        };
+
+        script.onerror = function() {
+            finish( "error", null );
+        };
+
+        timer = setTimeout( function() {
+            finish( "timeout", null );
+        }, @{timeout} );
+
+        script.src = @{url};
+        document.head.appendChild( script );
+    }
+
+    JsonpFuture {
+        receiver: receiver
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_response_type_accept() {
+        assert_eq!( XhrResponseType::Json.accept(), Some( "application/json" ) );
+        assert_eq!( XhrResponseType::Document.accept(), Some( "text/html" ) );
+        assert_eq!( XhrResponseType::Blob.accept(), None );
+        assert_eq!( XhrResponseType::from_str( XhrResponseType::ArrayBuffer.as_str() ), XhrResponseType::ArrayBuffer );
+        assert_eq!( XhrResponseType::from_str( "" ), XhrResponseType::Text );
+    }
+
+    #[cfg(feature = "web_test")]
+    #[derive(Default)]
+    struct RecordingObserver {
+        events: RefCell< Vec< (XhrRequestId, String) > >,
+        timings: RefCell< Vec< XhrTiming > >
+    }
+
+    #[cfg(feature = "web_test")]
+    impl XhrObserver for RecordingObserver {
+        fn on_open( &self, id: XhrRequestId, method: &str, url: &str ) {
+            self.events.borrow_mut().push( (id, format!( "open {} {}", method, url )) );
+        }
+
+        fn on_send( &self, id: XhrRequestId ) {
+            self.events.borrow_mut().push( (id, "send".to_owned()) );
+        }
+
+        fn on_first_byte( &self, id: XhrRequestId, timing: &XhrTiming ) {
+            self.events.borrow_mut().push( (id, "first byte".to_owned()) );
+            self.timings.borrow_mut().push( *timing );
+        }
+
+        fn on_complete( &self, id: XhrRequestId, status: u16, timing: &XhrTiming ) {
+            self.events.borrow_mut().push( (id, format!( "complete {}", status )) );
+            self.timings.borrow_mut().push( *timing );
+        }
+
+        fn on_error( &self, id: XhrRequestId, timing: &XhrTiming ) {
+            self.events.borrow_mut().push( (id, "error".to_owned()) );
+            self.timings.borrow_mut().push( *timing );
+        }
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn test_request_id() {
+        let xhr = XmlHttpRequest::new();
+        assert_eq!( xhr.request_id(), xhr.request_id() );
+        assert_ne!( xhr.request_id(), XmlHttpRequest::new().request_id() );
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn test_observer() {
+        use webcore::async_test::async_test;
+        use webapi::events::progress::LoadEndEvent;
+
+        let observer = Rc::new( RecordingObserver::default() );
+        let xhr = XmlHttpRequest::new();
+        let id = xhr.request_id();
+        xhr.set_observer( observer.clone() );
+        xhr.open( "GET", "data:text/plain,hello" ).unwrap();
+        xhr.send().unwrap();
+        assert_eq!( *observer.events.borrow(), vec![ (id, "open GET data:text/plain,hello".to_owned()), (id, "send".to_owned()) ] );
+
+        async_test( "test_observer", 5000, move |done| {
+            let mut done = Some( done );
+            xhr.add_event_listener( move |_: LoadEndEvent| {
+                let events: Vec< String > = observer.events.borrow().iter().map( |&(event_id, ref event)| {
+                    assert_eq!( event_id, id );
+                    event.clone()
+                }).collect();
+                assert_eq!( events, vec![ "open GET data:text/plain,hello", "send", "first byte", "complete 200" ] );
+
+                let timings = observer.timings.borrow();
+                assert_eq!( timings[ 0 ].duration, None );
+                assert_eq!( timings[ 1 ].time_to_first_byte, timings[ 0 ].time_to_first_byte );
+                assert!( timings[ 1 ].duration.unwrap() >= timings[ 1 ].time_to_first_byte.unwrap() );
+
+                // The observer is detached once the request finishes.
+                assert!( !REQUEST_OBSERVERS.with( |observers| observers.borrow().contains_key( &id ) ) );
+                done.take().unwrap().done();
+            });
+        });
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn test_observer_released_on_abort() {
+        let xhr = XmlHttpRequest::new();
+        let id = xhr.request_id();
+        xhr.set_observer( Rc::new( RecordingObserver::default() ) );
+        xhr.open( "GET", "data:text/plain,hello" ).unwrap();
+        assert!( REQUEST_OBSERVERS.with( |observers| observers.borrow().contains_key( &id ) ) );
+
+        xhr.abort();
+        assert!( !REQUEST_OBSERVERS.with( |observers| observers.borrow().contains_key( &id ) ) );
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn test_global_observer_on_error() {
+        use webcore::async_test::async_test;
+        use webapi::events::progress::LoadEndEvent;
+
+        let observer = Rc::new( RecordingObserver::default() );
+        set_global_xhr_observer( Some( observer.clone() ) );
+        let xhr = XmlHttpRequest::new();
+        let id = xhr.request_id();
+        xhr.open( "GET", "http://0.0.0.0:1/" ).unwrap();
+        xhr.send().unwrap();
+
+        async_test( "test_global_observer_on_error", 5000, move |done| {
+            let mut done = Some( done );
+            xhr.add_event_listener( move |_: LoadEndEvent| {
+                set_global_xhr_observer( None );
+
+                // The global observer also sees the requests of the other tests.
+                let events: Vec< String > = observer.events.borrow().iter()
+                    .filter( |&&(event_id, _)| event_id == id )
+                    .map( |&(_, ref event)| event.clone() )
+                    .collect();
+
+                assert_eq!( events, vec![ "open GET http://0.0.0.0:1/", "send", "error" ] );
+                done.take().unwrap().done();
+            });
+        });
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn test_on_abort() {
+        let aborted = Rc::new( Cell::new( 0 ) );
+        let xhr = XmlHttpRequest::new();
+        let handle = xhr.on_abort({
+            let aborted = aborted.clone();
+            move || aborted.set( aborted.get() + 1 )
+        });
+
+        xhr.open( "GET", "/" ).unwrap();
+        xhr.send().unwrap();
+        xhr.abort();
+        assert_eq!( aborted.get(), 1 );
+
+        handle.remove();
+        xhr.open( "GET", "/" ).unwrap();
+        xhr.send().unwrap();
+        xhr.abort();
+        assert_eq!( aborted.get(), 1 );
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn test_sign_request() {
+        let xhr = XmlHttpRequest::new();
+        js! { @(no_return)
+            var xhr = @{&xhr};
+            var set_request_header = xhr.setRequestHeader;
+            xhr.__sent_headers = [];
+            xhr.setRequestHeader = function(header, value) {
+                xhr.__sent_headers.push(header + ": " + value);
+                return set_request_header.call(xhr, header, value);
+            };
+        }
+
+        xhr.open( "POST", "/api/items" ).unwrap();
+        xhr.set_request_header( "X-Date", "20181016" ).unwrap();
+        xhr.sign_request( |request| {
+            let signature = format!( "{} {} {}={} {}", request.method, request.url, request.headers[ 0 ].0, request.headers[ 0 ].1, request.body.len() );
+            vec![ ("X-Signature".to_owned(), signature) ]
+        });
+        xhr.send_with_string( "body" ).unwrap();
+        xhr.abort();
+
+        let headers: Vec< String > = js!( return @{&xhr}.__sent_headers; ).try_into().unwrap();
+        assert_eq!( headers, vec![
+            "X-Date: 20181016".to_owned(),
+            "X-Signature: POST /api/items X-Date=20181016 4".to_owned()
+        ]);
+        assert!( xhr.take_signer().is_none() );
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn test_signer_released_on_abort() {
+        let xhr = XmlHttpRequest::new();
+        xhr.open( "GET", "/api/items" ).unwrap();
+        xhr.sign_request( |_| Vec::new() );
+        xhr.abort();
+
+        let id = xhr.request_id();
+        assert!( !REQUEST_SIGNERS.with( |signers| signers.borrow().contains_key( &id ) ) );
+    }
+
+    #[cfg(all(feature = "web_test", feature = "futures"))]
+    #[derive(Clone, Default)]
+    struct SharedSink( Rc< RefCell< Vec< u8 > > > );
+
+    #[cfg(all(feature = "web_test", feature = "futures"))]
+    impl Write for SharedSink {
+        fn write( &mut self, buffer: &[u8] ) -> io::Result< usize > {
+            self.0.borrow_mut().write( buffer )
+        }
+
+        fn flush( &mut self ) -> io::Result< () > {
+            Ok(())
+        }
+    }
+
+    #[cfg(all(feature = "web_test", feature = "futures"))]
+    #[test]
+    fn test_stream_to() {
+        use webcore::async_test::async_test;
+        use webcore::base64;
+        use webcore::promise_future::PromiseFuture;
+
+        let bytes: Vec< u8 > = (0..256).map( |byte| byte as u8 ).collect();
+        let url = format!( "data:application/octet-stream;base64,{}", base64::encode( &bytes ) );
+        let xhr = XmlHttpRequest::new();
+        xhr.open( "GET", &url ).unwrap();
+
+        let sink = SharedSink::default();
+        let future = xhr.stream_to( sink.clone() );
+        xhr.send().unwrap();
+
+        async_test( "test_stream_to", 5000, move |done| {
+            PromiseFuture::spawn( future.then( move |result| {
+                assert!( result.is_ok() );
+                assert_eq!( *sink.0.borrow(), bytes );
+                done.done();
+                Ok( () )
+            }));
+        });
+    }
+
+    #[cfg(all(feature = "web_test", feature = "futures"))]
+    #[test]
+    fn test_stream_to_aborts_on_drop() {
+        let xhr = XmlHttpRequest::new();
+        xhr.open( "GET", "/" ).unwrap();
+        let future = xhr.stream_to( SharedSink::default() );
+        xhr.send().unwrap();
+
+        drop( future );
+        assert_eq!( xhr.ready_state(), XhrReadyState::Unsent );
+    }
+
+    #[cfg(all(feature = "web_test", feature = "futures"))]
+    #[test]
+    fn test_offline_error() {
+        use webcore::async_test::async_test;
+        use webcore::promise_future::PromiseFuture;
+
+        js!( @(no_return) Object.defineProperty( navigator, "onLine", { get: function() { return false; }, configurable: true } ); );
+        let future = XmlHttpRequest::request( "GET", "http://0.0.0.0:1/" );
+
+        async_test( "test_offline_error", 5000, move |done| {
+            PromiseFuture::spawn( future.then( move |result| {
+                js!( @(no_return) delete navigator.onLine; );
+                assert_eq!( result.unwrap_err(), XhrError::Offline );
+                assert_eq!( XhrError::network_error(), XhrError::NetworkError );
+                done.done();
+                Ok( () )
+            }));
+        });
+    }
+
+    #[cfg(all(feature = "web_test", feature = "futures"))]
+    #[test]
+    fn test_request_invalid() {
+        use webcore::async_test::async_test;
+        use webcore::promise_future::PromiseFuture;
+
+        let future = XmlHttpRequest::request( "GE T", "/" );
+        async_test( "test_request_invalid", 5000, move |done| {
+            PromiseFuture::spawn( future.then( move |result| {
+                match result {
+                    Err( XhrError::InvalidRequest( _ ) ) => {},
+                    result => panic!( "Unexpected result: {:?}", result )
+                }
+
+                done.done();
+                Ok( () )
+            }));
+        });
+    }
+
+    #[cfg(all(feature = "web_test", feature = "futures"))]
+    #[test]
+    fn test_conditional_request_invalid() {
+        use webcore::async_test::async_test;
+        use webcore::promise_future::PromiseFuture;
+
+        let future = ConditionalRequest::new().get( "//[" );
+        async_test( "test_conditional_request_invalid", 5000, move |done| {
+            PromiseFuture::spawn( future.then( move |result| {
+                match result {
+                    Err( XhrError::InvalidRequest( _ ) ) => {},
+                    result => panic!( "Unexpected result: {:?}", result )
+                }
+
+                done.done();
+                Ok( () )
+            }));
+        });
+    }
+
+    #[test]
+    fn test_content_range() {
+        assert_eq!( content_range( 100, 0, 100, 250 ), Ok( "bytes 0-99/250".to_owned() ) );
+        assert_eq!( content_range( 50, 200, 250, 250 ), Ok( "bytes 200-249/250".to_owned() ) );
+        assert_eq!( content_range( 0, 250, 250, 250 ), Ok( "bytes */250".to_owned() ) );
+        assert!( content_range( 10, 20, 10, 250 ).is_err() );
+        assert!( content_range( 60, 200, 260, 250 ).is_err() );
+        assert!( content_range( 99, 0, 100, 250 ).is_err() );
+    }
+
+    #[test]
+    fn test_content_length_exceeds() {
+        assert!( content_length_exceeds( Some( "1025" ), 1024 ) );
+        assert!( content_length_exceeds( Some( " 4096 " ), 1024 ) );
+        assert!( !content_length_exceeds( Some( "1024" ), 1024 ) );
+        assert!( !content_length_exceeds( Some( "" ), 1024 ) );
+        assert!( !content_length_exceeds( Some( "-5" ), 1024 ) );
+        assert!( !content_length_exceeds( None, 0 ) );
+    }
+
+    #[test]
+    fn test_with_utf8_charset() {
+        assert_eq!( with_utf8_charset( "application/json" ), "application/json; charset=utf-8" );
+        assert_eq!( with_utf8_charset( "text/csv; header=present" ), "text/csv; header=present; charset=utf-8" );
+        assert_eq!( with_utf8_charset( "text/plain;" ), "text/plain; charset=utf-8" );
+        assert_eq!( with_utf8_charset( "text/plain; charset=utf-8" ), "text/plain; charset=utf-8" );
+        assert_eq!( with_utf8_charset( "text/plain;Charset=\"UTF-8\"" ), "text/plain;Charset=\"UTF-8\"" );
+        assert_eq!( with_utf8_charset( "text/plain; charset=iso-8859-1" ), "text/plain; charset=iso-8859-1" );
+        assert_eq!( with_utf8_charset( "text/plain; charset=" ), "text/plain; charset=utf-8" );
+        assert_eq!( with_utf8_charset( "text/plain; charset=\"\"; format=flowed" ), "text/plain; format=flowed; charset=utf-8" );
+    }
+
+    #[test]
+    fn test_content_type_charset() {
+        assert_eq!( content_type_charset( "text/plain" ), None );
+        assert_eq!( content_type_charset( "text/plain; charset=Shift_JIS" ), Some( "Shift_JIS".to_owned() ) );
+        assert_eq!( content_type_charset( "text/plain;CHARSET=iso-8859-1 ;format=flowed" ), Some( "iso-8859-1".to_owned() ) );
+        assert_eq!( content_type_charset( "text/plain; format=\"a;b\"; charset=\"utf-8\"" ), Some( "utf-8".to_owned() ) );
+        assert_eq!( content_type_charset( "text/plain; charset=\"we\\\"ird\"" ), Some( "we\"ird".to_owned() ) );
+        assert_eq!( content_type_charset( "text/plain; charset=; foo" ), None );
+        assert_eq!( content_type_charset( "text/plain; charset=\"unterminated" ), Some( "unterminated".to_owned() ) );
+    }
+
+    #[test]
+    fn test_is_mixed_content() {
+        assert!( is_mixed_content_for( "https:", "http://example.com/api" ) );
+        assert!( is_mixed_content_for( "https:", "HTTP://example.com" ) );
+        assert!( is_mixed_content_for( "https:", "ws://example.com:8080/socket" ) );
+        assert!( !is_mixed_content_for( "https:", "https://example.com/api" ) );
+        assert!( !is_mixed_content_for( "https:", "/api" ) );
+        assert!( !is_mixed_content_for( "https:", "//example.com/api" ) );
+        assert!( !is_mixed_content_for( "https:", "api?redirect=http://example.com" ) );
+        assert!( !is_mixed_content_for( "https:", "http://localhost:8000/api" ) );
+        assert!( !is_mixed_content_for( "https:", "http://127.0.0.1/api" ) );
+        assert!( !is_mixed_content_for( "https:", "http://[::1]:8000/api" ) );
+        assert!( !is_mixed_content_for( "http:", "http://example.com/api" ) );
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn test_jsonp_url() {
+        assert_eq!( jsonp_url( "/api", "callback", "cb_1" ), "/api?callback=cb_1" );
+        assert_eq!( jsonp_url( "/api?q=1", "jsonp", "cb_2" ), "/api?q=1&jsonp=cb_2" );
+        assert_eq!( jsonp_url( "/api", "call back&x", "cb_3" ), "/api?call%20back%26x=cb_3" );
+    }
+
+    #[cfg(all(feature = "web_test", feature = "futures"))]
+    #[test]
+    fn test_jsonp_late_response() {
+        use webcore::async_test::async_test;
+        use webcore::promise_future::PromiseFuture;
+
+        let callback_name = format!( "__stdweb_jsonp_{}", NEXT_JSONP_ID.with( |next_id| next_id.get() ) );
+        let future = jsonp_with_timeout( "data:text/javascript,//", "callback", 0 );
+
+        async_test( "test_jsonp_late_response", 5000, move |done| {
+            PromiseFuture::spawn( future.then( move |result| {
+                assert_eq!( result.unwrap_err(), XhrError::Timeout );
+
+                // The server only calls the callback now; this mustn't throw.
+                js!( @(no_return) window[ @{callback_name} ]( 1 ); );
+                done.done();
+                Ok( () )
+            }));
+        });
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn test_timing_without_entry() {
+        let response = XhrResponse {
+            status: 200,
+            status_text: "OK".to_owned(),
+            url: "http://localhost/__stdweb_no_such_resource".to_owned(),
+            requested_url: "http://localhost/__stdweb_no_such_resource".to_owned(),
+            request_bytes: 0,
+            response_bytes: None,
+            headers: String::new(),
+            body: String::new()
+        };
+
+        assert_eq!( response.timing(), None );
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn test_metrics_without_timing() {
+        let response = XhrResponse {
+            status: 201,
+            status_text: "Created".to_owned(),
+            url: "http://localhost/__stdweb_no_such_resource".to_owned(),
+            requested_url: "http://localhost/__stdweb_no_such_resource".to_owned(),
+            request_bytes: 12,
+            response_bytes: Some( 34 ),
+            headers: String::new(),
+            body: String::new()
+        };
+
+        assert_eq!( response.metrics(), XhrMetrics {
+            dns_queue_ms: None,
+            ttfb_ms: None,
+            download_ms: None,
+            total_ms: None,
+            request_bytes: 12,
+            response_bytes: Some( 34 ),
+            status: 201
+        });
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn test_metrics_reset_on_open() {
+        let xhr = XmlHttpRequest::new();
+        xhr.open( "POST", "/__stdweb_first" ).unwrap();
+        js! { @(no_return)
+            var xhr = @{&xhr};
+            xhr.__stdweb_request_bytes = 12;
+            xhr.__stdweb_response_bytes = 34;
+        }
+
+        xhr.open( "GET", "/__stdweb_second" ).unwrap();
+        let metrics = XhrResponse::from_request( &xhr ).metrics();
+        assert_eq!( metrics.request_bytes, 0 );
+        assert_eq!( metrics.response_bytes, None );
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn test_head_result() {
+        let response = XhrResponse {
+            status: 200,
+            status_text: "OK".to_owned(),
+            url: "http://localhost/video.mp4".to_owned(),
+            requested_url: "http://localhost/video.mp4".to_owned(),
+            request_bytes: 0,
+            response_bytes: None,
+            headers: "content-length: 1048576\r\nContent-Type: video/mp4\r\nETag: \"abc\"\r\n".to_owned(),
+            body: String::new()
+        };
+
+        assert_eq!( HeadResult::from_response( &response ), HeadResult {
+            status: 200,
+            content_length: Some( 1048576 ),
+            content_type: Some( "video/mp4".to_owned() ),
+            last_modified: None,
+            etag: Some( "\"abc\"".to_owned() )
+        });
+    }
+
+    fn conditional_response( status: u16, headers: &str, body: &str ) -> XhrResponse {
+        XhrResponse {
+            status: status,
+            status_text: String::new(),
+            url: "http://localhost/api".to_owned(),
+            requested_url: "http://localhost/api".to_owned(),
+            request_bytes: 0,
+            response_bytes: None,
+            headers: headers.to_owned(),
+            body: body.to_owned()
+        }
+    }
+
+    #[test]
+    fn test_conditional_resolve() {
+        let response = conditional_response;
+        let requests = ConditionalRequest::new();
+        let url = "/api";
+
+        let first = response( 200, "ETag: \"v1\"\r\n", "first" );
+        assert_eq!( requests.resolve( url, first.clone() ), ConditionalResponse::Modified( first.clone() ) );
+        assert_eq!( requests.cache.borrow()[ url ].etag, Some( "\"v1\"".to_owned() ) );
+
+        let cached = requests.resolve( url, response( 304, "", "" ) );
+        assert!( cached.is_cached() );
+        assert_eq!( cached.response().unwrap().body(), "first" );
+
+        let second = response( 200, "Last-Modified: Wed, 21 Oct 2015 07:28:00 GMT\r\n", "second" );
+        requests.resolve( url, second );
+        assert_eq!( requests.cache.borrow()[ url ].etag, None );
+        assert_eq!( requests.cache.borrow()[ url ].last_modified, Some( "Wed, 21 Oct 2015 07:28:00 GMT".to_owned() ) );
+        assert_eq!( requests.resolve( url, response( 304, "", "" ) ).response().unwrap().body(), "second" );
+
+        // Other instances don't share the cache.
+        assert!( !ConditionalRequest::new().resolve( url, response( 304, "", "" ) ).is_cached() );
+
+        // Errors don't affect the remembered response.
+        assert!( !requests.resolve( url, response( 500, "", "" ) ).is_cached() );
+        assert_eq!( requests.cache.borrow()[ url ].response.as_ref().unwrap().body(), "second" );
+
+        // A response without any validators can't be revalidated.
+        requests.resolve( url, response( 200, "", "third" ) );
+        assert!( requests.cache.borrow().get( url ).is_none() );
+        assert!( !requests.resolve( url, response( 304, "", "" ) ).is_cached() );
+    }
+
+    #[test]
+    fn test_conditional_resolve_without_bodies() {
+        let response = conditional_response;
+        let requests = ConditionalRequest::without_bodies();
+        let url = "/api";
+
+        let first = response( 200, "ETag: \"v1\"\r\n", "first" );
+        assert_eq!( requests.resolve( url, first.clone() ), ConditionalResponse::Modified( first ) );
+        assert_eq!( requests.cache.borrow()[ url ].etag, Some( "\"v1\"".to_owned() ) );
+        assert!( requests.cache.borrow()[ url ].response.is_none() );
+
+        let not_modified = requests.resolve( url, response( 304, "", "" ) );
+        assert_eq!( not_modified, ConditionalResponse::NotModified );
+        assert!( not_modified.is_cached() );
+        assert!( not_modified.response().is_none() );
+    }
+
+    #[test]
+    fn test_response_header() {
+        let response = XhrResponse {
+            status: 200,
+            status_text: "OK".to_owned(),
+            url: "http://localhost/".to_owned(),
+            requested_url: "http://localhost/".to_owned(),
+            request_bytes: 0,
+            response_bytes: None,
+            headers: "content-type: text/plain\r\nX-Foo: a\r\nx-foo: b\r\n".to_owned(),
+            body: String::new()
+        };
+
+        assert_eq!( response.header( "Content-Type" ), Some( "text/plain".to_owned() ) );
+        assert_eq!( response.header( "x-foo" ), Some( "a, b".to_owned() ) );
+        assert_eq!( response.header( "x-bar" ), None );
+        assert!( response.is_success() );
+    }
+
+    #[test]
+    fn test_redirect_target() {
+        let mut response = XhrResponse {
+            status: 200,
+            status_text: "OK".to_owned(),
+            url: "http://localhost/new".to_owned(),
+            requested_url: "http://localhost/old".to_owned(),
+            request_bytes: 0,
+            response_bytes: None,
+            headers: String::new(),
+            body: String::new()
+        };
+
+        assert!( response.was_redirected() );
+        assert_eq!( response.redirect_target(), Some( "http://localhost/new".to_owned() ) );
+
+        response.requested_url = response.url.clone();
+        assert!( !response.was_redirected() );
+        assert_eq!( response.redirect_target(), None );
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn test_requested_url() {
+        let request = XmlHttpRequest::new();
+        request.open( "GET", "/some/path?x=1#fragment" ).unwrap();
+        let expected: String = js!( return new URL( "/some/path?x=1", document.baseURI ).href; ).try_into().unwrap();
+        assert_eq!( XhrResponse::from_request( &request ).requested_url(), expected );
     }
 }
@@ -0,0 +1,325 @@
+use webcore::value::{Reference, Value};
+use webcore::try_from::{TryFrom, TryInto};
+use webapi::node::{INode, Node};
+use webapi::dom_exception::{SyntaxError, NamespaceError, InvalidStateError};
+
+/// The type of the result of an XPath expression.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XPathResult/resultType)
+// https://www.w3.org/TR/DOM-Level-3-XPath/xpath.html#XPathResult
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum XPathResultType {
+    /// Whatever type naturally results from the expression.
+    Any,
+    /// A single number, e.g. the result of `count()`.
+    Number,
+    /// A single string.
+    String,
+    /// A single boolean.
+    Boolean,
+    /// An iterator over the matching nodes, in no particular order.
+    UnorderedNodeIterator,
+    /// An iterator over the matching nodes, in document order.
+    OrderedNodeIterator,
+    /// A snapshot of the matching nodes, in no particular order.
+    UnorderedNodeSnapshot,
+    /// A snapshot of the matching nodes, in document order.
+    OrderedNodeSnapshot,
+    /// Any single matching node.
+    AnyUnorderedNode,
+    /// The first matching node in document order.
+    FirstOrderedNode
+}
+
+impl XPathResultType {
+    fn as_u16( self ) -> u16 {
+        match self {
+            XPathResultType::Any => 0,
+            XPathResultType::Number => 1,
+            XPathResultType::String => 2,
+            XPathResultType::Boolean => 3,
+            XPathResultType::UnorderedNodeIterator => 4,
+            XPathResultType::OrderedNodeIterator => 5,
+            XPathResultType::UnorderedNodeSnapshot => 6,
+            XPathResultType::OrderedNodeSnapshot => 7,
+            XPathResultType::AnyUnorderedNode => 8,
+            XPathResultType::FirstOrderedNode => 9
+        }
+    }
+
+    fn from_u16( value: u16 ) -> Self {
+        match value {
+            1 => XPathResultType::Number,
+            2 => XPathResultType::String,
+            3 => XPathResultType::Boolean,
+            4 => XPathResultType::UnorderedNodeIterator,
+            5 => XPathResultType::OrderedNodeIterator,
+            6 => XPathResultType::UnorderedNodeSnapshot,
+            7 => XPathResultType::OrderedNodeSnapshot,
+            8 => XPathResultType::AnyUnorderedNode,
+            9 => XPathResultType::FirstOrderedNode,
+            _ => XPathResultType::Any
+        }
+    }
+}
+
+error_enum_boilerplate! {
+    /// Errors which can occur when evaluating an XPath expression.
+    XPathError,
+    /// The expression is not a valid XPath expression.
+    SyntaxError,
+    /// The expression uses a namespace prefix which couldn't be resolved.
+    NamespaceError
+}
+
+/// The result of an XPath expression evaluated with
+/// [Document::evaluate_xpath](struct.Document.html#method.evaluate_xpath).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XPathResult)
+// https://www.w3.org/TR/DOM-Level-3-XPath/xpath.html#XPathResult
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "XPathResult")]
+pub struct XPathResult( Reference );
+
+impl XPathResult {
+    /// Returns the type of this result.
+    ///
+    /// If the expression was evaluated with [XPathResultType::Any](enum.XPathResultType.html#variant.Any)
+    /// this is the type which was picked for it.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XPathResult/resultType)
+    pub fn result_type( &self ) -> XPathResultType {
+        let value: u16 = js!( return @{self}.resultType; ).try_into().unwrap();
+        XPathResultType::from_u16( value )
+    }
+
+    /// Returns the value of a [Number](enum.XPathResultType.html#variant.Number) result,
+    /// or `None` if this is a result of another type.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XPathResult/numberValue)
+    pub fn number_value( &self ) -> Option< f64 > {
+        match self.result_type() {
+            XPathResultType::Number => Some( js!( return @{self}.numberValue; ).try_into().unwrap() ),
+            _ => None
+        }
+    }
+
+    /// Returns the value of a [String](enum.XPathResultType.html#variant.String) result,
+    /// or `None` if this is a result of another type.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XPathResult/stringValue)
+    pub fn string_value( &self ) -> Option< String > {
+        match self.result_type() {
+            XPathResultType::String => Some( js!( return @{self}.stringValue; ).try_into().unwrap() ),
+            _ => None
+        }
+    }
+
+    /// Returns the value of a [Boolean](enum.XPathResultType.html#variant.Boolean) result,
+    /// or `None` if this is a result of another type.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XPathResult/booleanValue)
+    pub fn boolean_value( &self ) -> Option< bool > {
+        match self.result_type() {
+            XPathResultType::Boolean => Some( js!( return @{self}.booleanValue; ).try_into().unwrap() ),
+            _ => None
+        }
+    }
+
+    /// Returns the node of an [AnyUnorderedNode](enum.XPathResultType.html#variant.AnyUnorderedNode)
+    /// or a [FirstOrderedNode](enum.XPathResultType.html#variant.FirstOrderedNode) result,
+    /// or `None` if nothing has matched or if this is a result of another type.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XPathResult/singleNodeValue)
+    pub fn single_node_value( &self ) -> Option< Node > {
+        match self.result_type() {
+            XPathResultType::AnyUnorderedNode | XPathResultType::FirstOrderedNode =>
+                js!( return @{self}.singleNodeValue; ).try_into().unwrap(),
+            _ => None
+        }
+    }
+
+    /// Returns the number of nodes in a snapshot result, or `0` if this
+    /// is a result of another type.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XPathResult/snapshotLength)
+    pub fn snapshot_length( &self ) -> u32 {
+        match self.result_type() {
+            XPathResultType::UnorderedNodeSnapshot | XPathResultType::OrderedNodeSnapshot =>
+                js!( return @{self}.snapshotLength; ).try_into().unwrap(),
+            _ => 0
+        }
+    }
+
+    /// Returns the node with the given `index` in a snapshot result, or `None`
+    /// if the `index` is out of bounds or if this is a result of another type.
+    ///
+    /// Unlike the iterators the snapshots stay valid when the document is changed.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XPathResult/snapshotItem)
+    pub fn snapshot_item( &self, index: u32 ) -> Option< Node > {
+        match self.result_type() {
+            XPathResultType::UnorderedNodeSnapshot | XPathResultType::OrderedNodeSnapshot =>
+                js!( return @{self}.snapshotItem( @{index} ); ).try_into().unwrap(),
+            _ => None
+        }
+    }
+
+    /// Returns an iterator over the nodes of an iterator result; for results
+    /// of other types the iterator is empty.
+    ///
+    /// If the document is changed while iterating the iterator is invalidated,
+    /// and the next call to `next` returns an [InvalidStateError](struct.InvalidStateError.html).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XPathResult/iterateNext)
+    pub fn iter_nodes( &self ) -> XPathNodeIter {
+        let is_iterator = match self.result_type() {
+            XPathResultType::UnorderedNodeIterator | XPathResultType::OrderedNodeIterator => true,
+            _ => false
+        };
+
+        XPathNodeIter {
+            result: self.clone(),
+            done: !is_iterator
+        }
+    }
+}
+
+/// An iterator over the nodes of an [XPathResult](struct.XPathResult.html).
+///
+/// See [XPathResult::iter_nodes](struct.XPathResult.html#method.iter_nodes).
+#[derive(Debug)]
+pub struct XPathNodeIter {
+    result: XPathResult,
+    done: bool
+}
+
+impl Iterator for XPathNodeIter {
+    type Item = Result< Node, InvalidStateError >;
+
+    fn next( &mut self ) -> Option< Self::Item > {
+        if self.done {
+            return None;
+        }
+
+        let result: Result< Option< Node >, InvalidStateError > = js_try!(
+            return @{&self.result}.iterateNext();
+        ).unwrap();
+
+        match result {
+            Ok( Some( node ) ) => Some( Ok( node ) ),
+            Ok( None ) => {
+                self.done = true;
+                None
+            },
+            Err( error ) => {
+                self.done = true;
+                Some( Err( error ) )
+            }
+        }
+    }
+}
+
+pub(crate) fn evaluate< T: INode >( document: &Reference, expression: &str, context: &T, result_type: XPathResultType, resolver: Value ) -> Result< XPathResult, XPathError > {
+    js_try!(
+        return @{document}.evaluate( @{expression}, @{context.as_ref()}, @{resolver}, @{result_type.as_u16()}, null );
+    ).unwrap()
+}
+
+pub(crate) fn evaluate_with_resolver< T, F >( document: &Reference, expression: &str, context: &T, result_type: XPathResultType, resolver: F ) -> Result< XPathResult, XPathError >
+    where T: INode,
+          F: Fn( &str ) -> Option< String > + 'static
+{
+    let resolver = move |prefix: String| resolver( &prefix );
+    let resolver: Reference = js!( return @{resolver}; ).try_into().unwrap();
+
+    // The resolver is only ever called while the expression is being evaluated.
+    let result = evaluate( document, expression, context, result_type, js!(
+        var resolver = @{&resolver};
+        return function( prefix ) {
+            return resolver( prefix );
+        };
+    ));
+
+    js! { @(no_return)
+        @{resolver}.drop();
+    }
+
+    result
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::Document;
+    use webapi::node::NodeType;
+
+    fn fixture() -> Document {
+        js!(
+            return new DOMParser().parseFromString(
+                "<catalog xmlns:bk=\"urn:books\"><bk:book id=\"1\"/><bk:book id=\"2\"/><book id=\"3\"/></catalog>",
+                "text/xml"
+            );
+        ).try_into().unwrap()
+    }
+
+    fn resolver( prefix: &str ) -> Option< String > {
+        match prefix {
+            "bk" => Some( "urn:books".to_owned() ),
+            _ => None
+        }
+    }
+
+    #[test]
+    fn test_node_snapshot() {
+        let document = fixture();
+        let result = document.evaluate_xpath_with_resolver( "//bk:book", &document, XPathResultType::OrderedNodeSnapshot, resolver ).unwrap();
+        assert_eq!( result.snapshot_length(), 2 );
+
+        let ids: Vec< String > = (0..result.snapshot_length()).map( |index| {
+            let node = result.snapshot_item( index ).unwrap();
+            assert_eq!( node.node_type(), NodeType::Element );
+            js!( return @{node}.getAttribute( "id" ); ).try_into().unwrap()
+        }).collect();
+        assert_eq!( ids, vec![ "1", "2" ] );
+        assert_eq!( result.snapshot_item( 2 ), None );
+        assert_eq!( result.number_value(), None );
+    }
+
+    #[test]
+    fn test_count() {
+        let document = fixture();
+        let result = document.evaluate_xpath_with_resolver( "count(//bk:book)", &document, XPathResultType::Number, resolver ).unwrap();
+        assert_eq!( result.number_value(), Some( 2.0 ) );
+
+        let result = document.evaluate_xpath( "count(//book)", &document, XPathResultType::Any ).unwrap();
+        assert_eq!( result.result_type(), XPathResultType::Number );
+        assert_eq!( result.number_value(), Some( 1.0 ) );
+    }
+
+    #[test]
+    fn test_iterator_invalidation() {
+        let document = fixture();
+        let result = document.evaluate_xpath( "//*", &document, XPathResultType::OrderedNodeIterator ).unwrap();
+        let mut nodes = result.iter_nodes();
+        assert!( nodes.next().unwrap().is_ok() );
+
+        js!( @(no_return) @{&document}.documentElement.appendChild( @{&document}.createElement( "extra" ) ); );
+        assert!( nodes.next().unwrap().is_err() );
+        assert!( nodes.next().is_none() );
+    }
+
+    #[test]
+    fn test_errors() {
+        let document = fixture();
+        match document.evaluate_xpath( "//[", &document, XPathResultType::Any ) {
+            Err( XPathError::SyntaxError( _ ) ) => {},
+            other => panic!( "unexpected result: {:?}", other )
+        }
+
+        match document.evaluate_xpath( "//bk:book", &document, XPathResultType::Any ) {
+            Err( XPathError::NamespaceError( _ ) ) => {},
+            other => panic!( "unexpected result: {:?}", other )
+        }
+    }
+}
@@ -1,6 +1,8 @@
+use std::vec;
 use webcore::try_from::{TryFrom, TryInto};
 use webcore::value::{Reference, Value, ConversionError};
 use webcore::serialization::{JsSerialize, deserialize_array};
+use webcore::object::unwrap_found;
 
 /// A type representing a JavaScript array.
 #[derive(Clone, PartialEq, Eq, Debug, ReferenceType)]
@@ -14,6 +16,49 @@ impl Array {
             return @{self}.length;
         ).try_into().unwrap()
     }
+
+    /// Returns the element at the given `index`, or `None` if the index is out of bounds.
+    pub fn get_index( &self, index: usize ) -> Option< Value > {
+        let value = js!(
+            var array = @{self};
+            var index = @{index as u32};
+            if( index >= array.length ) {
+                return null;
+            }
+
+            return [ array[ index ] ];
+        );
+
+        unwrap_found( value )
+    }
+
+    /// Appends the given `value` to the end of this array.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/push)
+    pub fn push< V: JsSerialize >( &self, value: V ) {
+        js! { @(no_return)
+            @{self}.push( @{value} );
+        }
+    }
+}
+
+/// Iterates over the elements of the array; all of them are extracted at once.
+impl IntoIterator for Array {
+    type Item = Value;
+    type IntoIter = vec::IntoIter< Value >;
+
+    fn into_iter( self ) -> Self::IntoIter {
+        Vec::< Value >::from( self ).into_iter()
+    }
+}
+
+impl< 'a > IntoIterator for &'a Array {
+    type Item = Value;
+    type IntoIter = vec::IntoIter< Value >;
+
+    fn into_iter( self ) -> Self::IntoIter {
+        Vec::< Value >::from( self ).into_iter()
+    }
 }
 
 impl From< Array > for Vec< Value > {
@@ -87,12 +132,12 @@ impl< E: Into< ConversionError >, V: TryFrom< Value, Error = E > > TryFrom< Arra
     fn try_from( array: Array ) -> Result< Self, Self::Error > {
         deserialize_array( array.as_ref(), |deserializer| {
             let mut output = Vec::with_capacity( deserializer.len() );
-            for value in deserializer {
+            for (index, value) in deserializer.enumerate() {
                 let result: Result< _, E > = value.try_into();
                 let value = match result {
                     Ok( value ) => value,
                     Err( error ) => {
-                        return Err( ConversionError::value_conversion_error( error.into() ) );
+                        return Err( ConversionError::at_index( index, error.into() ) );
                     }
                 };
 
@@ -102,3 +147,26 @@ impl< E: Into< ConversionError >, V: TryFrom< Value, Error = E > > TryFrom< Arra
         })
     }
 }
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_elements() {
+        let array: Array = js!( return [ 1, undefined ]; ).try_into().unwrap();
+        assert_eq!( array.get_index( 0 ), Some( Value::Number( 1.into() ) ) );
+        assert_eq!( array.get_index( 1 ), Some( Value::Undefined ) );
+        assert_eq!( array.get_index( 2 ), None );
+
+        array.push( "text" );
+        assert_eq!( array.len(), 3 );
+
+        let elements: Vec< Value > = array.into_iter().collect();
+        assert_eq!( elements, vec![
+            Value::Number( 1.into() ),
+            Value::Undefined,
+            Value::String( "text".to_owned() )
+        ]);
+    }
+}
@@ -0,0 +1,32 @@
+//! A helper for tests which can only check their results asynchronously,
+//! e.g. because the web API they exercise reports them through a `Promise`
+//! or an event.
+
+use std::rc::Rc;
+use std::cell::Cell;
+use webapi::global::set_timeout;
+
+/// The handle through which an [async_test](fn.async_test.html) signals that it's finished.
+pub struct Done( Rc< Cell< bool > > );
+
+impl Done {
+    /// Marks the test as finished; call this after the last assertion.
+    pub fn done( self ) {
+        self.0.set( true );
+    }
+}
+
+/// Runs an asynchronous test called `name`.
+///
+/// The `body` has to call [Done::done](struct.Done.html#method.done) within
+/// `timeout` milliseconds, otherwise the test panics. This way a test whose
+/// callback never runs fails instead of passing vacuously.
+pub fn async_test< F: FnOnce( Done ) >( name: &str, timeout: u32, body: F ) {
+    let name = name.to_owned();
+    let finished = Rc::new( Cell::new( false ) );
+    body( Done( finished.clone() ) );
+
+    set_timeout( move || {
+        assert!( finished.get(), "{}: the test didn't finish within {}ms", name, timeout );
+    }, timeout );
+}
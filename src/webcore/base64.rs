@@ -0,0 +1,277 @@
+use std::fmt;
+use std::error;
+use webcore::value::Value;
+
+const STANDARD_ALPHABET: &'static [u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_SAFE_ALPHABET: &'static [u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+const PADDING: u8 = b'=';
+const INVALID: u8 = 0xff;
+
+/// The alphabet used to encode base64 data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Base64Alphabet {
+    /// The standard alphabet with `+` and `/`, padded with `=`,
+    /// as used by [encode](fn.encode.html).
+    Standard,
+    /// The URL and filename safe alphabet with `-` and `_`, unpadded,
+    /// as used by [encode_url_safe](fn.encode_url_safe.html).
+    UrlSafe
+}
+
+impl Base64Alphabet {
+    pub(crate) fn encode( self, bytes: &[u8] ) -> String {
+        match self {
+            Base64Alphabet::Standard => encode( bytes ),
+            Base64Alphabet::UrlSafe => encode_url_safe( bytes )
+        }
+    }
+
+    pub(crate) fn decode( self, input: &str ) -> Result< Vec< u8 >, Base64Error > {
+        match self {
+            Base64Alphabet::Standard => decode( input ),
+            Base64Alphabet::UrlSafe => decode_url_safe( input )
+        }
+    }
+}
+
+/// An error returned when decoding malformed base64 data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Base64Error {
+    /// The input contains a character which is not a part of the alphabet.
+    InvalidCharacter {
+        /// The invalid character.
+        character: char,
+        /// The byte offset of the character in the input.
+        index: usize
+    },
+    /// The input is padded incorrectly, or has a length which is
+    /// impossible for base64 data.
+    InvalidPadding
+}
+
+impl fmt::Display for Base64Error {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        match *self {
+            Base64Error::InvalidCharacter { character, index } =>
+                write!( formatter, "invalid base64 character {:?} at offset {}", character, index ),
+            Base64Error::InvalidPadding => write!( formatter, "invalid base64 padding" )
+        }
+    }
+}
+
+impl error::Error for Base64Error {
+    fn description( &self ) -> &str {
+        match *self {
+            Base64Error::InvalidCharacter { .. } => "invalid base64 character",
+            Base64Error::InvalidPadding => "invalid base64 padding"
+        }
+    }
+}
+
+fn encode_with( bytes: &[u8], alphabet: &[u8; 64], pad: bool ) -> String {
+    let mut output = Vec::with_capacity( (bytes.len() + 2) / 3 * 4 );
+    for chunk in bytes.chunks( 3 ) {
+        let b0 = chunk[ 0 ] as u32;
+        let b1 = chunk.get( 1 ).cloned().unwrap_or( 0 ) as u32;
+        let b2 = chunk.get( 2 ).cloned().unwrap_or( 0 ) as u32;
+        let group = b0 << 16 | b1 << 8 | b2;
+
+        output.push( alphabet[ (group >> 18) as usize & 63 ] );
+        output.push( alphabet[ (group >> 12) as usize & 63 ] );
+        if chunk.len() > 1 {
+            output.push( alphabet[ (group >> 6) as usize & 63 ] );
+        } else if pad {
+            output.push( PADDING );
+        }
+
+        if chunk.len() > 2 {
+            output.push( alphabet[ group as usize & 63 ] );
+        } else if pad {
+            output.push( PADDING );
+        }
+    }
+
+    // The output only ever contains ASCII characters from the alphabet.
+    String::from_utf8( output ).unwrap()
+}
+
+fn decode_with( input: &str, alphabet: &[u8; 64] ) -> Result< Vec< u8 >, Base64Error > {
+    let mut lookup = [INVALID; 256];
+    for (value, &character) in alphabet.iter().enumerate() {
+        lookup[ character as usize ] = value as u8;
+    }
+
+    let input = input.as_bytes();
+    let data_length = input.iter().rposition( |&byte| byte != PADDING ).map( |index| index + 1 ).unwrap_or( 0 );
+    let padding = input.len() - data_length;
+    if padding > 0 && (padding > 2 || input.len() % 4 != 0) {
+        return Err( Base64Error::InvalidPadding );
+    }
+
+    let mut output = Vec::with_capacity( data_length / 4 * 3 + 2 );
+    let mut group: u32 = 0;
+    let mut count = 0;
+    for (index, &byte) in input[ ..data_length ].iter().enumerate() {
+        let value = lookup[ byte as usize ];
+        if value == INVALID {
+            let character = ::std::str::from_utf8( &input[ index.. ] ).ok()
+                .and_then( |rest| rest.chars().next() )
+                .unwrap_or( byte as char );
+
+            return Err( Base64Error::InvalidCharacter { character: character, index: index } );
+        }
+
+        group = group << 6 | value as u32;
+        count += 1;
+        if count == 4 {
+            output.push( (group >> 16) as u8 );
+            output.push( (group >> 8) as u8 );
+            output.push( group as u8 );
+            group = 0;
+            count = 0;
+        }
+    }
+
+    match count {
+        2 => output.push( (group >> 4) as u8 ),
+        3 => {
+            output.push( (group >> 10) as u8 );
+            output.push( (group >> 2) as u8 );
+        },
+        1 => return Err( Base64Error::InvalidPadding ),
+        _ => {}
+    }
+
+    Ok( output )
+}
+
+/// Encodes arbitrary binary data as padded base64 using the standard alphabet.
+///
+/// Unlike the JavaScript `btoa` this works on bytes instead of strings,
+/// so it doesn't choke on characters outside of latin1. It's implemented in
+/// pure Rust, which avoids building an intermediate binary string on the
+/// JavaScript side and is suitable for multi-megabyte inputs, e.g. when
+/// generating `data:` URLs.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!( base64::encode( b"Hello!" ), "SGVsbG8h" );
+/// assert_eq!( base64::encode( &[0xff, 0x00] ), "/wA=" );
+/// ```
+pub fn encode( bytes: &[u8] ) -> String {
+    encode_with( bytes, STANDARD_ALPHABET, true )
+}
+
+/// Decodes base64 data encoded with the standard alphabet.
+///
+/// The padding is optional, but if present it has to be correct.
+/// Whitespace is not allowed.
+///
+/// # Errors
+///
+/// Returns a [Base64Error](enum.Base64Error.html) if the input contains
+/// characters which are not in the alphabet, or if it's padded incorrectly.
+pub fn decode( input: &str ) -> Result< Vec< u8 >, Base64Error > {
+    decode_with( input, STANDARD_ALPHABET )
+}
+
+/// Encodes arbitrary binary data as unpadded base64 using the URL and filename
+/// safe alphabet, where `+` and `/` are replaced with `-` and `_`.
+///
+/// [(RFC)](https://tools.ietf.org/html/rfc4648#section-5)
+pub fn encode_url_safe( bytes: &[u8] ) -> String {
+    encode_with( bytes, URL_SAFE_ALPHABET, false )
+}
+
+/// Decodes base64 data encoded with the URL and filename safe alphabet.
+///
+/// Both padded and unpadded input is accepted.
+///
+/// # Errors
+///
+/// Returns a [Base64Error](enum.Base64Error.html) if the input contains
+/// characters which are not in the alphabet, or if it's padded incorrectly.
+pub fn decode_url_safe( input: &str ) -> Result< Vec< u8 >, Base64Error > {
+    decode_with( input, URL_SAFE_ALPHABET )
+}
+
+/// Encodes arbitrary binary data as base64 and wraps it in a string `Value`,
+/// e.g. to put it into a JSON payload.
+///
+/// This is the inverse of [Value::to_base64_bytes](../enum.Value.html#method.to_base64_bytes).
+///
+/// # Examples
+///
+/// ```rust
+/// let payload = js!( return { data: @{base64::bytes_to_base64_value( &bytes, Base64Alphabet::Standard )} }; );
+/// ```
+pub fn bytes_to_base64_value( bytes: &[u8], alphabet: Base64Alphabet ) -> Value {
+    Value::String( alphabet.encode( bytes ) )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_values() {
+        assert_eq!( encode( b"" ), "" );
+        assert_eq!( encode( b"f" ), "Zg==" );
+        assert_eq!( encode( b"fo" ), "Zm8=" );
+        assert_eq!( encode( b"foo" ), "Zm9v" );
+        assert_eq!( encode( b"foob" ), "Zm9vYg==" );
+        assert_eq!( encode( b"fooba" ), "Zm9vYmE=" );
+        assert_eq!( encode( b"foobar" ), "Zm9vYmFy" );
+
+        assert_eq!( decode( "Zm9vYmE=" ).unwrap(), b"fooba" );
+        assert_eq!( decode( "Zm9vYmE" ).unwrap(), b"fooba" );
+        assert_eq!( decode( "" ).unwrap(), b"" );
+    }
+
+    #[test]
+    fn test_round_trip_all_bytes() {
+        let bytes: Vec< u8 > = (0..256).map( |byte| byte as u8 ).collect();
+        for length in 0..bytes.len() {
+            let data = &bytes[ length.. ];
+            assert_eq!( decode( &encode( data ) ).unwrap(), data );
+            assert_eq!( decode_url_safe( &encode_url_safe( data ) ).unwrap(), data );
+        }
+    }
+
+    #[test]
+    fn test_url_safe() {
+        assert_eq!( encode( &[0xfb, 0xff] ), "+/8=" );
+        assert_eq!( encode_url_safe( &[0xfb, 0xff] ), "-_8" );
+        assert_eq!( decode_url_safe( "-_8=" ).unwrap(), [0xfb, 0xff] );
+        assert!( decode_url_safe( "+/8=" ).is_err() );
+    }
+
+    #[test]
+    fn test_values() {
+        let value = bytes_to_base64_value( &[0xfb, 0xff], Base64Alphabet::Standard );
+        assert_eq!( value, Value::String( "+/8=".to_owned() ) );
+        assert_eq!( value.to_base64_bytes( Base64Alphabet::Standard ), Some( vec![ 0xfb, 0xff ] ) );
+        assert_eq!( value.to_base64_bytes( Base64Alphabet::UrlSafe ), None );
+
+        let value = bytes_to_base64_value( &[0xfb, 0xff], Base64Alphabet::UrlSafe );
+        assert_eq!( value, Value::String( "-_8".to_owned() ) );
+        assert_eq!( value.to_base64_bytes( Base64Alphabet::UrlSafe ), Some( vec![ 0xfb, 0xff ] ) );
+
+        assert_eq!( Value::String( "Zm9v!mFy".to_owned() ).to_base64_bytes( Base64Alphabet::Standard ), None );
+        assert_eq!( Value::Number( 1.into() ).to_base64_bytes( Base64Alphabet::Standard ), None );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert_eq!( decode( "Zm9v!mFy" ), Err( Base64Error::InvalidCharacter { character: '!', index: 4 } ) );
+        assert_eq!( decode( "Zm9vż" ), Err( Base64Error::InvalidCharacter { character: 'ż', index: 4 } ) );
+        assert_eq!( decode( "Zm9v YmFy" ), Err( Base64Error::InvalidCharacter { character: ' ', index: 4 } ) );
+        assert_eq!( decode( "Zm=v" ), Err( Base64Error::InvalidCharacter { character: '=', index: 2 } ) );
+        assert_eq!( decode( "Zm9vY" ), Err( Base64Error::InvalidPadding ) );
+        assert_eq!( decode( "Zg=" ), Err( Base64Error::InvalidPadding ) );
+        assert_eq!( decode( "Zg===" ), Err( Base64Error::InvalidPadding ) );
+        assert_eq!( decode( "Z===" ), Err( Base64Error::InvalidPadding ) );
+        assert_eq!( decode( "====" ), Err( Base64Error::InvalidPadding ) );
+    }
+}
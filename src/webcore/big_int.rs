@@ -0,0 +1,103 @@
+use std::fmt;
+use webcore::value::Value;
+use webcore::try_from::TryInto;
+
+/// A type representing a JavaScript `BigInt`.
+///
+/// This is what 64-bit integers which are outside of the range
+/// of integers which can be exactly represented by a JavaScript
+/// number (that is, with a magnitude bigger than 2^53 - 1) are
+/// converted into.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt)
+// https://tc39.github.io/ecma262/#sec-bigint-objects
+pub struct BigInt( pub(crate) i32 );
+
+impl BigInt {
+    /// Returns the decimal representation of this `BigInt`.
+    pub fn to_decimal_string( &self ) -> String {
+        js!(
+            return Module.STDWEB_PRIVATE.get_raw_value( @{self.0} ).toString();
+        ).try_into().unwrap()
+    }
+
+    fn from_decimal_string( digits: &str ) -> Self {
+        let id = js!(
+            return Module.STDWEB_PRIVATE.register_raw_value( BigInt( @{digits} ) );
+        ).try_into().unwrap();
+
+        BigInt( id )
+    }
+}
+
+impl Clone for BigInt {
+    fn clone( &self ) -> Self {
+        let id = __js_raw_asm!( concat!(
+            "var value = Module.STDWEB_PRIVATE.get_raw_value( $0 );",
+            "return Module.STDWEB_PRIVATE.register_raw_value( value );"
+        ), self.0 );
+
+        BigInt( id )
+    }
+}
+
+impl PartialEq for BigInt {
+    fn eq( &self, rhs: &BigInt ) -> bool {
+        js!(
+            return Module.STDWEB_PRIVATE.get_raw_value( @{self.0} ) === Module.STDWEB_PRIVATE.get_raw_value( @{rhs.0} );
+        ).try_into().unwrap()
+    }
+}
+
+impl Drop for BigInt {
+    fn drop( &mut self ) {
+        js!( @(no_return)
+            Module.STDWEB_PRIVATE.unregister_raw_value( @{self.0} );
+        );
+    }
+}
+
+impl fmt::Debug for BigInt {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> Result< (), fmt::Error > {
+        write!( formatter, "BigInt({})", self.to_decimal_string() )
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> Result< (), fmt::Error > {
+        write!( formatter, "{}", self.to_decimal_string() )
+    }
+}
+
+impl From< i64 > for BigInt {
+    #[inline]
+    fn from( value: i64 ) -> Self {
+        BigInt::from_decimal_string( &value.to_string() )
+    }
+}
+
+impl From< u64 > for BigInt {
+    #[inline]
+    fn from( value: u64 ) -> Self {
+        BigInt::from_decimal_string( &value.to_string() )
+    }
+}
+impl From< i128 > for BigInt {
+    #[inline]
+    fn from( value: i128 ) -> Self {
+        BigInt::from_decimal_string( &value.to_string() )
+    }
+}
+impl From< u128 > for BigInt {
+    #[inline]
+    fn from( value: u128 ) -> Self {
+        BigInt::from_decimal_string( &value.to_string() )
+    }
+}
+
+impl From< BigInt > for Value {
+    #[inline]
+    fn from( value: BigInt ) -> Self {
+        Value::BigInt( value )
+    }
+}
@@ -0,0 +1,151 @@
+use std::fmt;
+use std::error;
+
+const OP_COPY: u8 = 0x01;
+const OP_INSERT: u8 = 0x02;
+
+/// An error returned by [apply_binary_patch](fn.apply_binary_patch.html)
+/// when the patch is malformed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PatchError {
+    /// The patch ended in the middle of an operation.
+    UnexpectedEnd,
+    /// The patch contains an operation with an unknown tag.
+    UnknownOperation( u8 ),
+    /// A copy operation refers to bytes outside of the base buffer.
+    CopyOutOfBounds {
+        /// The offset into the base buffer at which the copy starts.
+        offset: u32,
+        /// The number of bytes to copy.
+        length: u32
+    }
+}
+
+impl fmt::Display for PatchError {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        match *self {
+            PatchError::UnexpectedEnd => write!( formatter, "unexpected end of patch" ),
+            PatchError::UnknownOperation( tag ) => write!( formatter, "unknown patch operation: {:#04x}", tag ),
+            PatchError::CopyOutOfBounds { offset, length } =>
+                write!( formatter, "copy of {} bytes at offset {} is out of bounds of the base buffer", length, offset )
+        }
+    }
+}
+
+impl error::Error for PatchError {
+    fn description( &self ) -> &str {
+        match *self {
+            PatchError::UnexpectedEnd => "unexpected end of patch",
+            PatchError::UnknownOperation( _ ) => "unknown patch operation",
+            PatchError::CopyOutOfBounds { .. } => "copy out of bounds of the base buffer"
+        }
+    }
+}
+
+struct Reader< 'a > {
+    data: &'a [u8]
+}
+
+impl< 'a > Reader< 'a > {
+    fn bytes( &mut self, length: usize ) -> Result< &'a [u8], PatchError > {
+        if self.data.len() < length {
+            return Err( PatchError::UnexpectedEnd );
+        }
+
+        let (bytes, rest) = self.data.split_at( length );
+        self.data = rest;
+        Ok( bytes )
+    }
+
+    fn u32( &mut self ) -> Result< u32, PatchError > {
+        let bytes = self.bytes( 4 )?;
+        Ok( bytes[ 0 ] as u32 | (bytes[ 1 ] as u32) << 8 | (bytes[ 2 ] as u32) << 16 | (bytes[ 3 ] as u32) << 24 )
+    }
+}
+
+/// Applies a binary `patch` to the `base` buffer and returns the patched buffer.
+///
+/// The patch is a sequence of operations, each starting with a one byte tag;
+/// all of the integers are 32-bit little endian:
+///
+///   * `0x01`, `offset`, `length` - copies `length` bytes of `base` starting at `offset`,
+///   * `0x02`, `length`, followed by `length` bytes - inserts the given bytes.
+///
+/// The output is the concatenation of the results of all of the operations,
+/// so an empty patch produces an empty buffer.
+///
+/// This is meant to be used with binary responses, e.g. an `ArrayBuffer`
+/// converted into a `Vec< u8 >`.
+///
+/// # Examples
+///
+/// ```rust
+/// let base = b"Hello world!";
+/// let patch = [
+///     0x01, 0, 0, 0, 0, 6, 0, 0, 0,           // Copy "Hello ".
+///     0x02, 5, 0, 0, 0, b'R', b'u', b's', b't', b'!' // Insert "Rust!".
+/// ];
+///
+/// assert_eq!( apply_binary_patch( base, &patch ).unwrap(), b"Hello Rust!" );
+/// ```
+pub fn apply_binary_patch( base: &[u8], patch: &[u8] ) -> Result< Vec< u8 >, PatchError > {
+    let mut reader = Reader { data: patch };
+    let mut output = Vec::with_capacity( base.len() );
+    while !reader.data.is_empty() {
+        let tag = reader.bytes( 1 )?[ 0 ];
+        match tag {
+            OP_COPY => {
+                let offset = reader.u32()?;
+                let length = reader.u32()?;
+                let start = offset as usize;
+                let end = start.checked_add( length as usize );
+                match end {
+                    Some( end ) if end <= base.len() => output.extend_from_slice( &base[ start..end ] ),
+                    _ => return Err( PatchError::CopyOutOfBounds { offset: offset, length: length } )
+                }
+            },
+            OP_INSERT => {
+                let length = reader.u32()?;
+                output.extend_from_slice( reader.bytes( length as usize )? );
+            },
+            tag => return Err( PatchError::UnknownOperation( tag ) )
+        }
+    }
+
+    Ok( output )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_patch() {
+        let base = b"Hello world!";
+        let patch = [
+            0x01, 0, 0, 0, 0, 6, 0, 0, 0,
+            0x02, 4, 0, 0, 0, b'R', b'u', b's', b't',
+            0x01, 11, 0, 0, 0, 1, 0, 0, 0
+        ];
+
+        assert_eq!( apply_binary_patch( base, &patch ).unwrap(), b"Hello Rust!".to_vec() );
+        assert_eq!( apply_binary_patch( base, &[] ).unwrap(), Vec::< u8 >::new() );
+        assert_eq!( apply_binary_patch( &[], &[ 0x01, 0, 0, 0, 0, 0, 0, 0, 0 ] ).unwrap(), Vec::< u8 >::new() );
+    }
+
+    #[test]
+    fn malformed_patch() {
+        let base = b"abc";
+        assert_eq!( apply_binary_patch( base, &[ 0x01, 0, 0 ] ), Err( PatchError::UnexpectedEnd ) );
+        assert_eq!( apply_binary_patch( base, &[ 0x02, 3, 0, 0, 0, b'x' ] ), Err( PatchError::UnexpectedEnd ) );
+        assert_eq!( apply_binary_patch( base, &[ 0x03 ] ), Err( PatchError::UnknownOperation( 0x03 ) ) );
+        assert_eq!(
+            apply_binary_patch( base, &[ 0x01, 2, 0, 0, 0, 2, 0, 0, 0 ] ),
+            Err( PatchError::CopyOutOfBounds { offset: 2, length: 2 } )
+        );
+        assert_eq!(
+            apply_binary_patch( base, &[ 0x01, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff ] ),
+            Err( PatchError::CopyOutOfBounds { offset: 0xffffffff, length: 0xffffffff } )
+        );
+    }
+}
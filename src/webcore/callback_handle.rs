@@ -0,0 +1,164 @@
+use webcore::value::{Value, Reference, ConversionError};
+use webcore::try_from::{TryFrom, TryInto};
+use webcore::serialization::{JsSerialize, PreallocatedArena, SerializedValue};
+
+/// An owning handle to a Rust closure which was passed into the `js!` macro.
+///
+/// Every closure passed into `js!` is turned into a JavaScript function which keeps
+/// the closure alive until its `drop()` method is called. This handle makes it possible
+/// to do that from the Rust side: once the handle is dropped the closure is freed and
+/// any further calls to the function throw a `ReferenceError` instead of calling into
+/// freed memory.
+///
+/// This works both with `FnMut` closures and with `FnOnce` ones wrapped in [Once](struct.Once.html);
+/// dropping a handle to a `FnOnce` closure which was already called is a no-op.
+///
+/// # Examples
+///
+/// ```rust
+/// let mut counter = 0;
+/// let handle: CallbackHandle = js!( return @{move || { counter += 1; counter }}; ).try_into().unwrap();
+/// js!( @(no_return) document.body.addEventListener( "click", @{&handle} ); );
+///
+/// // Frees the closure; the event listener now throws instead.
+/// drop( handle );
+/// ```
+#[derive(Debug)]
+pub struct CallbackHandle( Reference );
+
+impl CallbackHandle {
+    /// Returns the JavaScript function which wraps the closure.
+    #[inline]
+    pub fn as_function( &self ) -> &Reference {
+        &self.0
+    }
+}
+
+impl Drop for CallbackHandle {
+    #[inline]
+    fn drop( &mut self ) {
+        js! { @(no_return)
+            @{&self.0}.drop();
+        }
+    }
+}
+
+impl TryFrom< Value > for CallbackHandle {
+    type Error = ConversionError;
+
+    fn try_from( value: Value ) -> Result< Self, Self::Error > {
+        let reference: Reference = value.try_into()?;
+        let is_rust_function: bool = js!(
+            var function = @{&reference};
+            return typeof function === "function" && typeof function.drop === "function";
+        ).try_into().unwrap();
+
+        if is_rust_function {
+            Ok( CallbackHandle( reference ) )
+        } else {
+            Err( ConversionError::Custom( "expected a function wrapping a Rust closure".to_owned() ) )
+        }
+    }
+}
+
+impl JsSerialize for CallbackHandle {
+    #[doc(hidden)]
+    #[inline]
+    fn _into_js< 'a >( &'a self, arena: &'a PreallocatedArena ) -> SerializedValue< 'a > {
+        self.0._into_js( arena )
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn _memory_required( &self ) -> usize {
+        self.0._memory_required()
+    }
+}
+
+__js_serializable_boilerplate!( CallbackHandle );
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use std::cell::Cell;
+    use webcore::once::Once;
+
+    fn live_reference_count() -> u32 {
+        js!( return Object.keys( Module.STDWEB_PRIVATE.id_to_ref_map ).length; ).try_into().unwrap()
+    }
+
+    #[test]
+    fn fn_mut_callback() {
+        let mut counter = 0;
+        let handle: CallbackHandle = js!( return @{move || { counter += 1; counter }}; ).try_into().unwrap();
+        assert_eq!( js!( return @{&handle}(); ), 1 );
+        assert_eq!( js!( return @{&handle}(); ), 2 );
+
+        let function = handle.as_function().clone();
+        drop( handle );
+
+        let result: Result< Value, Value > = js_try!( return @{&function}(); ).unwrap();
+        let is_reference_error: bool = js!( return @{result.unwrap_err()} instanceof ReferenceError; ).try_into().unwrap();
+        assert!( is_reference_error );
+    }
+
+    #[test]
+    fn fn_once_callback() {
+        let dropped = Rc::new( Cell::new( false ) );
+        struct SetOnDrop( Rc< Cell< bool > > );
+        impl Drop for SetOnDrop {
+            fn drop( &mut self ) {
+                self.0.set( true );
+            }
+        }
+
+        let guard = SetOnDrop( dropped.clone() );
+        let text = "consumed".to_owned();
+        let handle: CallbackHandle = js!( return @{Once( move || { let _guard = guard; text } )}; ).try_into().unwrap();
+        assert_eq!( js!( return @{&handle}(); ), "consumed" );
+        assert!( dropped.get() );
+
+        // Dropping an already called `FnOnce` is fine.
+        drop( handle );
+    }
+
+    #[test]
+    fn uncalled_callback_is_freed() {
+        let dropped = Rc::new( Cell::new( false ) );
+        let flag = dropped.clone();
+        let handle: CallbackHandle = js!( return @{Once( move || flag.set( true ) )}; ).try_into().unwrap();
+        assert_eq!( Rc::strong_count( &dropped ), 2 );
+        drop( handle );
+        assert_eq!( Rc::strong_count( &dropped ), 1 );
+        assert!( !dropped.get() );
+    }
+
+    #[test]
+    fn seven_arguments() {
+        let handle: CallbackHandle = js!(
+            return @{|a: i32, b: u8, c: f64, d: String, e: bool, f: Option< i32 >, g: Vec< i32 >| {
+                format!( "{} {} {} {} {} {:?} {:?}", a, b, c, d, e, f, g )
+            }};
+        ).try_into().unwrap();
+
+        assert_eq!( js!( return @{&handle}( 1, 2, 3.5, "d", true, null, [ 7 ] ); ), "1 2 3.5 d true None [7]" );
+    }
+
+    #[test]
+    fn not_a_callback() {
+        let result: Result< CallbackHandle, _ > = js!( return function() {}; ).try_into();
+        assert!( result.is_err() );
+    }
+
+    #[test]
+    fn no_leaks() {
+        let initial_count = live_reference_count();
+        for index in 0..10000 {
+            let handle: CallbackHandle = js!( return @{move || index}; ).try_into().unwrap();
+            drop( handle );
+        }
+
+        assert_eq!( live_reference_count(), initial_count );
+    }
+}
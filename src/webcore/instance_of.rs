@@ -1,6 +1,9 @@
 use webcore::value::Reference;
 
 /// A trait to check whenever a given [Reference](struct.Reference.html) is of a certain type.
+///
+/// This is what the checked conversions into a [ReferenceType](trait.ReferenceType.html)
+/// use; see there for how to implement it for custom types.
 pub trait InstanceOf {
     /// Checks whenever a given [Reference](struct.Reference.html) if of type `Self`.
     fn instance_of( reference: &Reference ) -> bool;
@@ -0,0 +1,153 @@
+use std::fmt;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use webcore::value::Value;
+use webcore::serialization::JsSerialize;
+use webcore::try_from::TryInto;
+
+thread_local! {
+    static INTERNED: RefCell< HashMap< Rc< str >, InternedString > > = RefCell::new( HashMap::new() );
+}
+
+/// A string which is cached on the JavaScript side.
+///
+/// Passing a normal string to JavaScript requires copying it into the JavaScript
+/// heap and decoding it from UTF-8 every time it crosses the boundary; an
+/// `InternedString` is decoded only once, when it's created with [intern](fn.intern.html),
+/// and afterwards only its index is passed around.
+///
+/// This is useful for property names which are used over and over again,
+/// e.g. when creating many objects of the same shape with [Value::set](enum.Value.html#method.set).
+///
+/// Interned strings are never freed, so only intern a bounded set of strings.
+///
+/// See the `intern` benchmark in `examples/benchmarks` for the savings this brings.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct InternedString {
+    index: u32,
+    string: Rc< str >
+}
+
+impl InternedString {
+    /// Returns the string which was interned.
+    pub fn as_str( &self ) -> &str {
+        &self.string
+    }
+}
+
+impl fmt::Debug for InternedString {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        write!( formatter, "InternedString({:?})", self.as_str() )
+    }
+}
+
+/// Caches the given string on the JavaScript side, returning a handle
+/// which can be used to refer to it cheaply.
+///
+/// Interning the same string multiple times returns the same handle.
+///
+/// # Examples
+///
+/// ```rust
+/// let loaded = intern( "loaded" );
+/// let total = intern( "total" );
+///
+/// xhr.add_event_listener( move |event: ProgressEvent| {
+///     let stats = js!( return {}; );
+///     stats.set( &loaded, event.loaded() as f64 );
+///     stats.set( &total, event.total() as f64 );
+///     report( stats );
+/// });
+/// ```
+pub fn intern( string: &str ) -> InternedString {
+    INTERNED.with( |interned| {
+        if let Some( interned_string ) = interned.borrow().get( string ) {
+            return interned_string.clone();
+        }
+
+        let index: u32 = js!(
+            var strings = Module.STDWEB_PRIVATE.interned_strings;
+            if( strings === undefined ) {
+                strings = Module.STDWEB_PRIVATE.interned_strings = [];
+            }
+
+            strings.push( @{string} );
+            return strings.length - 1;
+        ).try_into().unwrap();
+
+        let string: Rc< str > = string.into();
+        let interned_string = InternedString {
+            index: index,
+            string: string.clone()
+        };
+
+        interned.borrow_mut().insert( string, interned_string.clone() );
+        interned_string
+    })
+}
+
+/// A type which can be used as a property name with
+/// [Value::get](enum.Value.html#method.get) and [Value::set](enum.Value.html#method.set).
+///
+/// This is implemented for `&str` and [InternedString](struct.InternedString.html).
+pub trait PropertyKey {
+    #[doc(hidden)]
+    fn get_property( &self, object: &Value ) -> Value;
+
+    #[doc(hidden)]
+    fn set_property< V: JsSerialize >( &self, object: &Value, value: V );
+}
+
+impl< 'a > PropertyKey for &'a str {
+    fn get_property( &self, object: &Value ) -> Value {
+        js!( return @{object}[ @{*self} ]; )
+    }
+
+    fn set_property< V: JsSerialize >( &self, object: &Value, value: V ) {
+        js! { @(no_return)
+            @{object}[ @{*self} ] = @{value};
+        }
+    }
+}
+
+impl< 'a > PropertyKey for &'a InternedString {
+    fn get_property( &self, object: &Value ) -> Value {
+        js!( return @{object}[ Module.STDWEB_PRIVATE.interned_strings[ @{self.index} ] ]; )
+    }
+
+    fn set_property< V: JsSerialize >( &self, object: &Value, value: V ) {
+        js! { @(no_return)
+            @{object}[ Module.STDWEB_PRIVATE.interned_strings[ @{self.index} ] ] = @{value};
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intern() {
+        let first = intern( "stdweb-intern" );
+        let second = intern( "stdweb-intern" );
+        let other = intern( "stdweb-intern-other" );
+        assert_eq!( first, second );
+        assert_ne!( first, other );
+        assert_eq!( first.as_str(), "stdweb-intern" );
+    }
+
+    #[test]
+    fn test_get_and_set() {
+        let key = intern( "foo" );
+        let object = js!( return {}; );
+        object.set( &key, 1 );
+        object.set( "bar", "2" );
+
+        assert_eq!( object.get( &key ), 1 );
+        assert_eq!( object.get( "foo" ), 1 );
+        assert_eq!( object.get( "bar" ), "2" );
+        assert_eq!( object.get( "baz" ), Value::Undefined );
+    }
+}
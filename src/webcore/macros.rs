@@ -610,6 +610,29 @@ macro_rules! newtype_enum {
 /// }.unwrap();
 /// assert_eq!(result, Err("error".to_string()));
 /// ```
+///
+/// Use [JsError](web/error/struct.JsError.html) as the error type to capture
+/// whatever was thrown, including its name and stack trace:
+///
+/// ```
+/// let result: Result<String, JsError> = js_try! {
+///     return JSON.parse("{");
+/// }.unwrap();
+/// assert_eq!(result.unwrap_err().name(), "SyntaxError");
+/// ```
+///
+/// With `Result<Value, Value>` the conversion can never fail and the thrown value
+/// is passed through untouched, whatever it is; a `DOMException` can then be inspected
+/// through [DomException::kind](web/error/struct.DomException.html#method.kind):
+///
+/// ```
+/// let result: Result<Value, Value> = js_try! {
+///     return document.querySelector("!");
+/// }.unwrap();
+/// let error: DomException = result.unwrap_err().try_into().unwrap();
+/// assert_eq!(error.kind(), DomExceptionKind::SyntaxError);
+/// ```
+#[macro_export]
 macro_rules! js_try {
     (@(no_return) $($token:tt)*) => {{
         let result = js! {
@@ -626,7 +649,7 @@ macro_rules! js_try {
             }
         };
 
-        use ::webcore::try_from::TryInto;
+        use $crate::unstable::TryInto;
         if js!( return @{result.as_ref()}.success; ) == true {
             Ok(Ok(()))
         } else {
@@ -652,7 +675,7 @@ macro_rules! js_try {
             }
         };
 
-        use webcore::try_from::TryInto;
+        use $crate::unstable::TryInto;
         if js!( return @{result.as_ref()}.success; ) == true {
             match js!( return @{result}.value; ).try_into() {
                 Ok(t) => Ok(Ok(t)),
@@ -667,11 +690,36 @@ macro_rules! js_try {
     }};
 }
 
+/// Asserts that two [Value](enum.Value.html)s are structurally equal,
+/// as determined by [Value::deep_eq](enum.Value.html#method.deep_eq).
+///
+/// On failure the panic message contains the path at which the values
+/// differ, along with the differing values themselves.
+///
+/// # Examples
+///
+/// ```
+/// let response = js!( return JSON.parse( "{\"id\": 1, \"tags\": [\"a\"]}" ); );
+/// assert_value_eq!( response, js!( return { tags: [ "a" ], id: 1 }; ) );
+/// ```
+#[macro_export]
+macro_rules! assert_value_eq {
+    ($left:expr, $right:expr) => {{
+        let left: &$crate::Value = &$left;
+        let right: &$crate::Value = &$right;
+        if let Some( (path, left_value, right_value) ) = $crate::private::deep_difference( left, right ) {
+            let path = if path.is_empty() { "<root>".to_owned() } else { path };
+            panic!( "assertion failed: `(left deep_eq right)`, differs at `{}`\n  left: `{:?}`,\n right: `{:?}`", path, left_value, right_value );
+        }
+    }};
+}
+
 macro_rules! error_enum_boilerplate {
-    ($error_name:ident, $($variant:ident),*) => {
+    ($(#[$attr:meta])* $error_name:ident, $($(#[$variant_attr:meta])* $variant:ident),*) => {
+        $(#[$attr])*
         #[derive(Debug, Clone)]
         pub enum $error_name {
-            $($variant($variant)),*
+            $($(#[$variant_attr])* $variant($variant)),*
         }
 
         impl TryFrom<::webcore::value::Value> for $error_name {
@@ -684,7 +732,7 @@ macro_rules! error_enum_boilerplate {
                     }
                 )*
 
-                Err(::webcore::value::ConversionError::type_mismatch( &value ))
+                Err(::webcore::value::ConversionError::wrong_type( stringify!( $error_name ), &value ))
             }
         }
 
@@ -764,14 +812,14 @@ mod tests {
 
         let v: Result< Result<i32, i32>, _ > = js_try!( return "f"; );
         match v {
-            Err(ConversionError::TypeMismatch { actual_type: _ }) => (),
-            _ => panic!("Expected ConversionError::TypeMistmatch, got {:?}", v),
+            Err(ConversionError::WrongType { .. }) => (),
+            _ => panic!("Expected ConversionError::WrongType, got {:?}", v),
         }
 
         let v: Result< Result<i32, i32>, _ > = js_try!( throw "Broken"; );
         match v {
-            Err(ConversionError::TypeMismatch { actual_type: _ }) => (),
-            _ => panic!("Expected ConversionError::TypeMistmatch, got {:?}", v),
+            Err(ConversionError::WrongType { .. }) => (),
+            _ => panic!("Expected ConversionError::WrongType, got {:?}", v),
         }
     }
 }
@@ -0,0 +1,193 @@
+use std::collections::{BTreeMap, HashMap};
+use webcore::try_from::{TryFrom, TryInto};
+use webcore::value::{Reference, Value, ConversionError};
+use webcore::serialization::JsSerialize;
+
+/// A type representing a JavaScript `Map`.
+///
+/// Unlike an [Object](struct.Object.html) a `Map` can have keys of any type,
+/// and preserves the order in which its entries were inserted.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map)
+// https://tc39.github.io/ecma262/#sec-map-objects
+#[derive(Clone, PartialEq, Eq, Debug, ReferenceType)]
+#[reference(instance_of = "Map")]
+pub struct JsMap( Reference );
+
+impl JsMap {
+    /// Creates a new empty `Map`.
+    pub fn new() -> Self {
+        js!( return new Map(); ).try_into().unwrap()
+    }
+
+    /// Returns the number of entries in the map.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/size)
+    pub fn len( &self ) -> usize {
+        js!( return @{self}.size; ).try_into().unwrap()
+    }
+
+    /// Returns the value associated with the given `key`, or `Value::Undefined` if there is none.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/get)
+    pub fn get< K: JsSerialize >( &self, key: K ) -> Value {
+        js!( return @{self}.get( @{key} ); )
+    }
+
+    /// Associates the given `value` with the given `key`, replacing the previous value if any.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/set)
+    pub fn set< K: JsSerialize, V: JsSerialize >( &self, key: K, value: V ) {
+        js! { @(no_return)
+            @{self}.set( @{key}, @{value} );
+        }
+    }
+
+    /// Returns whether the map has an entry with the given `key`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/has)
+    pub fn has< K: JsSerialize >( &self, key: K ) -> bool {
+        js!( return @{self}.has( @{key} ); ).try_into().unwrap()
+    }
+
+    /// Removes the entry with the given `key`; returns whether there was such an entry.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/delete)
+    pub fn delete< K: JsSerialize >( &self, key: K ) -> bool {
+        js!( return @{self}.delete( @{key} ); ).try_into().unwrap()
+    }
+
+    /// Removes all of the entries of the map.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/clear)
+    pub fn clear( &self ) {
+        js! { @(no_return)
+            @{self}.clear();
+        }
+    }
+
+    /// Returns the keys of the map, in insertion order.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/keys)
+    pub fn keys( &self ) -> Vec< Value > {
+        js!( return Array.from( @{self}.keys() ); ).try_into().unwrap()
+    }
+
+    /// Returns the values of the map, in insertion order.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/values)
+    pub fn values( &self ) -> Vec< Value > {
+        js!( return Array.from( @{self}.values() ); ).try_into().unwrap()
+    }
+
+    /// Returns the `(key, value)` pairs of the map, in insertion order.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/entries)
+    pub fn entries( &self ) -> Vec< (Value, Value) > {
+        let mut flattened: Vec< Value > = js!(
+            var output = [];
+            @{self}.forEach( function( value, key ) {
+                output.push( key, value );
+            });
+            return output;
+        ).try_into().unwrap();
+
+        let mut entries = Vec::with_capacity( flattened.len() / 2 );
+        let mut iter = flattened.drain( .. );
+        while let (Some( key ), Some( value )) = (iter.next(), iter.next()) {
+            entries.push( (key, value) );
+        }
+
+        entries
+    }
+}
+
+impl< E: Into< ConversionError >, V: TryFrom< Value, Error = E > > TryFrom< JsMap > for BTreeMap< String, V > {
+    type Error = ConversionError;
+
+    fn try_from( map: JsMap ) -> Result< Self, Self::Error > {
+        let mut output = BTreeMap::new();
+        for (key, value) in map.entries() {
+            let key = match key {
+                Value::String( key ) => key,
+                key => return Err( ConversionError::wrong_type( "string", &key ) )
+            };
+
+            let value = value.try_into().map_err( |error: E| ConversionError::at_key( &key, error.into() ) )?;
+            output.insert( key, value );
+        }
+
+        Ok( output )
+    }
+}
+
+impl< E: Into< ConversionError >, V: TryFrom< Value, Error = E > > TryFrom< JsMap > for HashMap< String, V > {
+    type Error = ConversionError;
+
+    fn try_from( map: JsMap ) -> Result< Self, Self::Error > {
+        let entries = map.entries();
+        let mut output = HashMap::with_capacity( entries.len() );
+        for (key, value) in entries {
+            let key = match key {
+                Value::String( key ) => key,
+                key => return Err( ConversionError::wrong_type( "string", &key ) )
+            };
+
+            let value = value.try_into().map_err( |error: E| ConversionError::at_key( &key, error.into() ) )?;
+            output.insert( key, value );
+        }
+
+        Ok( output )
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map() {
+        let map = JsMap::new();
+        assert_eq!( map.len(), 0 );
+
+        map.set( "b", 1 );
+        map.set( "a", 2 );
+        map.set( 3, "three" );
+        assert_eq!( map.len(), 3 );
+        assert_eq!( map.get( "a" ), 2 );
+        assert_eq!( map.get( 3 ), "three" );
+        assert_eq!( map.get( "3" ), Value::Undefined );
+        assert!( map.has( "b" ) );
+
+        assert_eq!( map.keys(), vec![ Value::from( "b" ), Value::from( "a" ), Value::from( 3 ) ] );
+        assert_eq!( map.values(), vec![ Value::from( 1 ), Value::from( 2 ), Value::from( "three" ) ] );
+        assert_eq!( map.entries()[ 2 ], (Value::from( 3 ), Value::from( "three" )) );
+
+        assert!( map.delete( 3 ) );
+        assert!( !map.delete( 3 ) );
+        assert_eq!( map.len(), 2 );
+
+        map.clear();
+        assert_eq!( map.len(), 0 );
+    }
+
+    #[test]
+    fn map_from_value() {
+        let value = js!( return new Map( [ [ "a", 1 ], [ "b", 2 ] ] ); );
+        let map: JsMap = value.try_into().unwrap();
+        let map: HashMap< String, i32 > = map.try_into().unwrap();
+        assert_eq!( map.len(), 2 );
+        assert_eq!( map[ "a" ], 1 );
+        assert_eq!( map[ "b" ], 2 );
+
+        let object = js!( return {}; );
+        assert!( JsMap::try_from( object ).is_err() );
+    }
+
+    #[test]
+    fn map_with_non_string_keys() {
+        let map: JsMap = js!( return new Map( [ [ 1, 1 ] ] ); ).try_into().unwrap();
+        let result: Result< BTreeMap< String, Value >, _ > = map.try_into();
+        assert!( result.is_err() );
+    }
+}
@@ -10,13 +10,23 @@ pub mod newtype;
 pub mod try_from;
 pub mod object;
 pub mod array;
+pub mod map;
+pub mod set;
 pub mod symbol;
+pub mod big_int;
 pub mod type_name;
 pub mod unsafe_typed_array;
 pub mod once;
+pub mod callback_handle;
 pub mod instance_of;
 pub mod reference_type;
 pub mod promise;
+pub mod intern;
+pub mod binary_patch;
+pub mod base64;
+
+#[cfg(test)]
+pub mod async_test;
 
 #[cfg(feature = "futures")]
 pub mod promise_future;
@@ -2,6 +2,7 @@ use std::collections::{BTreeMap, HashMap};
 use std::hash::Hash;
 use webcore::try_from::{TryFrom, TryInto};
 use webcore::value::{Reference, Value, ConversionError};
+use webcore::array::Array;
 use webcore::serialization::{JsSerialize, deserialize_object};
 
 /// A type representing a JavaScript object.
@@ -10,12 +11,128 @@ use webcore::serialization::{JsSerialize, deserialize_object};
 pub struct Object( Reference );
 
 impl Object {
+    /// Creates a new empty object.
+    ///
+    /// Together with [set](#method.set) this can be used to build
+    /// options objects which are passed to JavaScript.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let controller = AbortController::new();
+    /// let init = Object::new()
+    ///     .set( "method", "POST" )
+    ///     .set( "signal", controller.signal() );
+    ///
+    /// js! { fetch( "/api/items", @{init} ); }
+    /// ```
+    pub fn new() -> Self {
+        js!( return {}; ).try_into().unwrap()
+    }
+
+    /// Sets the property `key` of this object to the given `value`,
+    /// and returns the object back.
+    ///
+    /// The `value` can be anything which can be passed to JavaScript, including
+    /// references, e.g. a [Blob](web/struct.Blob.html) or an
+    /// [AbortSignal](web/struct.AbortSignal.html), which are stored as the very
+    /// same objects rather than as copies.
+    pub fn set< V: JsSerialize >( self, key: &str, value: V ) -> Self {
+        js! { @(no_return)
+            @{&self}[ @{key} ] = @{value};
+        }
+
+        self
+    }
+
     /// Returns the number of elements in this particular object.
     pub fn len( &self ) -> usize {
         js!(
             return Object.keys( @{self} ).length;
         ).try_into().unwrap()
     }
+
+    /// Returns the value of the own property `name` of this object,
+    /// or `None` if there is no such property.
+    ///
+    /// Unlike [Value::get](enum.Value.html#method.get) this distinguishes
+    /// a missing property from one which is set to `undefined`, and
+    /// ignores properties inherited from the prototype, e.g. `toString`.
+    pub fn get_field( &self, name: &str ) -> Option< Value > {
+        let value = js!(
+            var object = @{self};
+            var name = @{name};
+            if( !Object.prototype.hasOwnProperty.call( object, name ) ) {
+                return null;
+            }
+
+            return [ object[ name ] ];
+        );
+
+        unwrap_found( value )
+    }
+
+    /// Sets the property `name` of this object to the given `value`.
+    ///
+    /// This is the non-consuming counterpart of [set](#method.set).
+    pub fn set_field< V: JsSerialize >( &self, name: &str, value: V ) {
+        js! { @(no_return)
+            @{self}[ @{name} ] = @{value};
+        }
+    }
+
+    /// Checks whenever this object has an own property `name`.
+    pub fn has_field( &self, name: &str ) -> bool {
+        js!(
+            return Object.prototype.hasOwnProperty.call( @{self}, @{name} );
+        ).try_into().unwrap()
+    }
+
+    /// Removes the own property `name` of this object, returning its
+    /// previous value, or `None` if there was no such property.
+    pub fn remove_field( &self, name: &str ) -> Option< Value > {
+        let value = js!(
+            var object = @{self};
+            var name = @{name};
+            if( !Object.prototype.hasOwnProperty.call( object, name ) ) {
+                return null;
+            }
+
+            var value = object[ name ];
+            delete object[ name ];
+            return [ value ];
+        );
+
+        unwrap_found( value )
+    }
+
+    /// Returns the names of the own enumerable properties of this object.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/keys)
+    pub fn keys( &self ) -> Vec< String > {
+        js!(
+            return Object.keys( @{self} );
+        ).try_into().unwrap()
+    }
+
+    /// Returns the own enumerable properties of this object along with their values.
+    ///
+    /// All of the properties are extracted at once, so this is much faster
+    /// than calling [get_field](#method.get_field) for every one of the [keys](#method.keys).
+    pub fn entries( &self ) -> Vec< (String, Value) > {
+        deserialize_object( &self.0, |iter| iter.collect() )
+    }
+}
+
+/// Converts the `[ value ]` or `null` returned by the lookups into an `Option`.
+pub(crate) fn unwrap_found( value: Value ) -> Option< Value > {
+    match value {
+        Value::Reference( reference ) => {
+            let found: Array = reference.try_into().unwrap();
+            Vec::< Value >::from( found ).into_iter().next()
+        },
+        _ => None
+    }
 }
 
 impl From< Object > for BTreeMap< String, Value > {
@@ -119,13 +236,14 @@ impl< E: Into< ConversionError >, V: TryFrom< Value, Error = E > > TryFrom< Obje
     type Error = ConversionError;
 
     fn try_from( object: Object ) -> Result< Self, Self::Error > {
-        deserialize_object( object.as_ref(), |deserializer| -> Result< BTreeMap< String, V >, E > {
+        deserialize_object( object.as_ref(), |deserializer| {
             let mut output = BTreeMap::new();
             for (key, value) in deserializer {
-                output.insert( key, value.try_into()? );
+                let value = value.try_into().map_err( |error: E| ConversionError::at_key( &key, error.into() ) )?;
+                output.insert( key, value );
             }
             Ok( output )
-        }).map_err( |err| err.into() )
+        })
     }
 }
 
@@ -133,13 +251,61 @@ impl< E: Into< ConversionError >, V: TryFrom< Value, Error = E > > TryFrom< Obje
     type Error = ConversionError;
 
     fn try_from( object: Object ) -> Result< Self, Self::Error > {
-        deserialize_object( object.as_ref(), |deserializer| -> Result< HashMap< String, V >, E > {
+        deserialize_object( object.as_ref(), |deserializer| {
             let mut output = HashMap::with_capacity( deserializer.len() );
             for (key, value) in deserializer {
-                output.insert( key, value.try_into()? );
+                let value = value.try_into().map_err( |error: E| ConversionError::at_key( &key, error.into() ) )?;
+                output.insert( key, value );
             }
 
             Ok( output )
-        }).map_err( |err| err.into() )
+        })
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder() {
+        let target: Reference = js!( return new EventTarget(); ).try_into().unwrap();
+        let object = Object::new()
+            .set( "number", 1 )
+            .set( "string", "text" )
+            .set( "target", &target );
+
+        assert_eq!( object.len(), 3 );
+        assert_eq!( js!( return @{&object}.number; ), 1 );
+        assert_eq!( js!( return @{&object}.string; ), "text" );
+
+        let is_same: bool = js!( return @{&object}.target === @{&target}; ).try_into().unwrap();
+        assert!( is_same );
+
+        let stored: Reference = js!( return @{&object}.target; ).try_into().unwrap();
+        assert_eq!( stored, target );
+    }
+
+    #[test]
+    fn test_fields() {
+        let object: Object = js!( return { a: 1, b: undefined }; ).try_into().unwrap();
+        assert_eq!( object.get_field( "a" ), Some( Value::Number( 1.into() ) ) );
+        assert_eq!( object.get_field( "b" ), Some( Value::Undefined ) );
+        assert_eq!( object.get_field( "c" ), None );
+        assert_eq!( object.get_field( "toString" ), None );
+        assert!( object.has_field( "b" ) );
+        assert!( !object.has_field( "toString" ) );
+
+        object.set_field( "c", "text" );
+        assert_eq!( object.keys(), vec![ "a".to_owned(), "b".to_owned(), "c".to_owned() ] );
+        assert_eq!( object.entries(), vec![
+            ("a".to_owned(), Value::Number( 1.into() )),
+            ("b".to_owned(), Value::Undefined),
+            ("c".to_owned(), Value::String( "text".to_owned() ))
+        ]);
+
+        assert_eq!( object.remove_field( "a" ), Some( Value::Number( 1.into() ) ) );
+        assert_eq!( object.remove_field( "a" ), None );
+        assert_eq!( object.len(), 2 );
     }
 }
@@ -6,7 +6,9 @@ use std::fmt;
 /// `drop()` them manually on the JavaScript side provided they
 /// were actually called.
 ///
-/// You still need to `drop()` any callbacks which were **not** called.
+/// You still need to `drop()` any callbacks which were **not** called,
+/// either on the JavaScript side or by dropping a
+/// [CallbackHandle](struct.CallbackHandle.html).
 ///
 /// # Examples
 ///
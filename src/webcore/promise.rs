@@ -22,7 +22,7 @@ pub struct Promise( Reference );
 
 impl Promise {
     // https://www.ecma-international.org/ecma-262/6.0/#sec-promise-resolve-functions
-    fn is_thenable( input: &Reference ) -> bool {
+    pub(crate) fn is_thenable( input: &Reference ) -> bool {
         (js! {
             var input = @{input};
             // This emulates the `Type(input) is Object` and `IsCallable(input.then)` ECMAScript abstract operations.
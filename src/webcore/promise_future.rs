@@ -19,6 +19,12 @@ use super::promise::Promise;
 /// ```rust
 /// let future: PromiseFuture<String> = js!( return Promise.resolve("foo"); ).try_into().unwrap();
 /// ```
+///
+/// Promise-like objects (which have a callable `then` method) are accepted too:
+///
+/// ```rust
+/// let future: PromiseFuture<String> = js!( return $.get("test.php"); ).try_into().unwrap();
+/// ```
 pub struct PromiseFuture< Value, Error = error::Error > {
     pub(crate) future: Receiver< Result< Value, Error > >,
 }
@@ -110,7 +116,60 @@ impl< A, B > TryFrom< Value > for PromiseFuture< A, B >
     type Error = ConversionError;
 
     fn try_from( v: Value ) -> Result< Self, Self::Error > {
-        let promise: Promise = v.try_into()?;
+        // Any thenable is accepted, not only a native `Promise`.
+        let thenable = v.as_reference().and_then( Promise::from_thenable );
+        let promise: Promise = match thenable {
+            Some( promise ) => promise,
+            None => v.try_into()?
+        };
+
         Ok( promise.to_future() )
     }
 }
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webcore::async_test::async_test;
+
+    #[test]
+    fn test_thenable() {
+        let value = js!(
+            return {
+                then: function( resolve, reject ) {
+                    resolve( 42 );
+                }
+            };
+        );
+
+        assert!( value.clone().try_into().map( |_: Promise| () ).is_err() );
+        let future: PromiseFuture< u32, String > = value.try_into().unwrap();
+
+        async_test( "test_thenable", 1000, move |done| {
+            PromiseFuture::spawn( future.then( move |result| {
+                assert_eq!( result, Ok( 42 ) );
+                done.done();
+                Ok( () )
+            }));
+        });
+    }
+
+    #[test]
+    fn test_rejected_thenable() {
+        let future: PromiseFuture< u32, String > = js!(
+            return {
+                then: function( resolve, reject ) {
+                    reject( "failed" );
+                }
+            };
+        ).try_into().unwrap();
+
+        async_test( "test_rejected_thenable", 1000, move |done| {
+            PromiseFuture::spawn( future.then( move |result| {
+                assert_eq!( result, Err( "failed".to_owned() ) );
+                done.done();
+                Ok( () )
+            }));
+        });
+    }
+}
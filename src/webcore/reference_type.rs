@@ -3,8 +3,140 @@ use webcore::instance_of::InstanceOf;
 use webcore::try_from::TryFrom;
 
 /// A trait for types which wrap a reference to a JavaScript object.
+///
+/// Upcasting (e.g. from a `CanvasElement` into an `Element`) is always
+/// possible through the infallible `From` impls generated for every
+/// `#[reference(subclass_of(...))]` entry; downcasting has to go through
+/// either [from_reference_checked](#method.from_reference_checked) or
+/// [try_into_typed](#method.try_into_typed), which perform an `instanceof` check.
+///
+/// # Implementing
+///
+/// This trait can also be implemented outside of this crate to wrap types
+/// which `stdweb` doesn't know about. The easiest way to do that is the
+/// [ReferenceType](derive.ReferenceType.html) derive macro, which works on
+/// tuple structs with a single [Reference](struct.Reference.html) field:
+///
+/// ```rust
+/// #[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+/// #[reference(instance_of = "IDBDatabase")]
+/// #[reference(subclass_of(EventTarget))]
+/// pub struct Database( Reference );
+///
+/// impl IEventTarget for Database {}
+/// ```
+///
+/// Besides this trait it implements `AsRef< Reference >`, the conversions
+/// from and into a [Value](enum.Value.html) and a [Reference](struct.Reference.html),
+/// the upcasts for every `subclass_of` entry, and [InstanceOf](trait.InstanceOf.html)
+/// with an `instanceof` check against the given `instance_of` constructor.
+///
+/// If there is no `instance_of` the [InstanceOf](trait.InstanceOf.html) has to be
+/// implemented by hand, which makes it possible to accept objects based on their shape:
+///
+/// ```rust
+/// #[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+/// pub struct XhrLike( Reference );
+///
+/// impl InstanceOf for XhrLike {
+///     fn instance_of( reference: &Reference ) -> bool {
+///         js!(
+///             var object = @{reference};
+///             return typeof object.open === "function" && typeof object.send === "function";
+///         ).try_into().unwrap()
+///     }
+/// }
+/// ```
+///
+/// Whichever way it's implemented, the checked conversions rely on
+/// [InstanceOf](trait.InstanceOf.html) to only accept references which
+/// can be safely used through the wrapper, and the wrapper must never hold
+/// anything else than the reference it was created with.
 pub trait ReferenceType: AsRef< Reference > + InstanceOf + TryFrom< Value > + TryFrom< Reference > {
     /// Converts a given reference into a concrete reference-like wrapper.
     /// Doesn't do any type checking; highly unsafe to use!
     unsafe fn from_reference_unchecked( reference: Reference ) -> Self;
+
+    /// Converts a given reference into a concrete reference-like wrapper;
+    /// returns `None` if the reference isn't of type `Self`.
+    #[inline]
+    fn from_reference_checked( reference: Reference ) -> Option< Self > {
+        reference.downcast()
+    }
+
+    /// Converts this value into another reference type `T`, e.g. an `EventTarget`
+    /// taken out of an event into an `InputElement`; returns `None` if the
+    /// underlying object isn't of type `T`.
+    #[inline]
+    fn try_into_typed< T: ReferenceType >( &self ) -> Option< T > {
+        self.as_ref().try_into_typed()
+    }
+}
+
+/// Checks whenever a given [Reference](struct.Reference.html) is of type `T`.
+#[inline]
+pub fn is_instance_of< T: InstanceOf >( reference: &Reference ) -> bool {
+    T::instance_of( reference )
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::event_target::EventTarget;
+    use webapi::node::Node;
+    use webapi::element::Element;
+    use webapi::html_element::HtmlElement;
+    use webapi::html_elements::{CanvasElement, InputElement};
+    use webapi::xml_http_request::XmlHttpRequest;
+    use webcore::try_from::TryInto;
+
+    fn canvas() -> Reference {
+        js!( return document.createElement( "canvas" ); ).try_into().unwrap()
+    }
+
+    #[test]
+    fn downcast_success() {
+        let target: EventTarget = EventTarget::from_reference_checked( canvas() ).unwrap();
+        let canvas: CanvasElement = target.try_into_typed().unwrap();
+        assert_eq!( canvas.as_ref(), target.as_ref() );
+
+        assert!( CanvasElement::from_reference_checked( canvas.as_ref().clone() ).is_some() );
+        assert!( is_instance_of::< HtmlElement >( canvas.as_ref() ) );
+        assert!( is_instance_of::< Node >( canvas.as_ref() ) );
+    }
+
+    #[test]
+    fn downcast_failure() {
+        let target: EventTarget = canvas().try_into_typed().unwrap();
+        let input: Option< InputElement > = target.try_into_typed();
+        assert!( input.is_none() );
+
+        assert!( InputElement::from_reference_checked( canvas() ).is_none() );
+        assert!( !is_instance_of::< InputElement >( &canvas() ) );
+    }
+
+    #[test]
+    fn downcast_across_hierarchies() {
+        let element: Element = canvas().try_into_typed().unwrap();
+        let request: Option< XmlHttpRequest > = element.try_into_typed();
+        assert!( request.is_none() );
+
+        let request = XmlHttpRequest::new();
+        let element: Option< Element > = request.try_into_typed();
+        assert!( element.is_none() );
+
+        let target: EventTarget = request.into();
+        assert!( target.try_into_typed::< XmlHttpRequest >().is_some() );
+    }
+
+    #[test]
+    fn upcast() {
+        let canvas: CanvasElement = canvas().try_into_typed().unwrap();
+        let element: Element = canvas.clone().into();
+        let node: Node = canvas.clone().into();
+        let target: EventTarget = canvas.clone().into();
+        assert_eq!( element.as_ref(), canvas.as_ref() );
+        assert_eq!( node.as_ref(), canvas.as_ref() );
+        assert_eq!( target.as_ref(), canvas.as_ref() );
+    }
 }
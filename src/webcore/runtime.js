@@ -1,14 +1,53 @@
 Module.STDWEB_PRIVATE = {};
 
+// Gotcha: charCodeAt returns a 16-bit word that is a UTF-16 encoded code unit, not a Unicode code point of the character! So decode UTF16->UTF32->UTF8.
+// See http://unicode.org/faq/utf_bom.html#utf16-3
+//
+// An unpaired surrogate can't be represented in UTF-8, so just like `TextEncoder`
+// we replace it with U+FFFD instead of merging it with the following code unit.
+Module.STDWEB_PRIVATE.code_point_at = function code_point_at( str, i ) {
+    var u = str.charCodeAt( i ); // possibly a lead surrogate
+    if( u >= 0xD800 && u <= 0xDFFF ) {
+        var next = str.charCodeAt( i + 1 );
+        if( u <= 0xDBFF && next >= 0xDC00 && next <= 0xDFFF ) {
+            return 0x10000 + ((u & 0x3FF) << 10) | (next & 0x3FF);
+        }
+
+        return 0xFFFD;
+    }
+
+    return u;
+};
+
+// This is based on code from Emscripten's preamble.js.
+Module.STDWEB_PRIVATE.utf8_len = function utf8_len( str ) {
+    var len = 0;
+    for( var i = 0; i < str.length; ++i ) {
+        var u = Module.STDWEB_PRIVATE.code_point_at( str, i );
+        if( u > 0xFFFF ) {
+            ++i;
+        }
+
+        if( u <= 0x7F ) {
+            ++len;
+        } else if( u <= 0x7FF ) {
+            len += 2;
+        } else if( u <= 0xFFFF ) {
+            len += 3;
+        } else {
+            len += 4;
+        }
+    }
+    return len;
+};
+
 // This is based on code from Emscripten's preamble.js.
 Module.STDWEB_PRIVATE.to_utf8 = function to_utf8( str, addr ) {
     for( var i = 0; i < str.length; ++i ) {
-        // Gotcha: charCodeAt returns a 16-bit word that is a UTF-16 encoded code unit, not a Unicode code point of the character! So decode UTF16->UTF32->UTF8.
-        // See http://unicode.org/faq/utf_bom.html#utf16-3
         // For UTF8 byte structure, see http://en.wikipedia.org/wiki/UTF-8#Description and https://www.ietf.org/rfc/rfc2279.txt and https://tools.ietf.org/html/rfc3629
-        var u = str.charCodeAt( i ); // possibly a lead surrogate
-        if( u >= 0xD800 && u <= 0xDFFF ) {
-            u = 0x10000 + ((u & 0x3FF) << 10) | (str.charCodeAt( ++i ) & 0x3FF);
+        var u = Module.STDWEB_PRIVATE.code_point_at( str, i );
+        if( u > 0xFFFF ) {
+            ++i;
         }
 
         if( u <= 0x7F ) {
@@ -168,6 +207,8 @@ Module.STDWEB_PRIVATE.to_js = function to_js( address ) {
         }
     } else if( kind === 15 ) {
         return Module.STDWEB_PRIVATE.get_raw_value( HEAPU32[ address / 4 ] );
+    } else if( kind === 16 ) {
+        return Module.STDWEB_PRIVATE.get_raw_value( HEAPU32[ address / 4 ] );
     }
 };
 
@@ -208,6 +249,14 @@ Module.STDWEB_PRIVATE.serialize_array = function serialize_array( address, value
 Module.STDWEB_PRIVATE.from_js = function from_js( address, value ) {
     var kind = Object.prototype.toString.call( value );
     if( kind === "[object String]" ) {
+        // A string made of a single unpaired surrogate would end up as a lone U+FFFD,
+        // which couldn't be told apart from a genuine one when it's converted into a `char`,
+        // so we replace every byte of the surrogate's three byte encoding instead,
+        // just like `String::from_utf8_lossy` would.
+        if( value.length === 1 && /[\uD800-\uDFFF]/.test( value ) ) {
+            value = "\uFFFD\uFFFD\uFFFD";
+        }
+
         var length = Module.STDWEB_PRIVATE.utf8_len( value );
         var pointer = 0;
         if( length > 0 ) {
@@ -237,6 +286,10 @@ Module.STDWEB_PRIVATE.from_js = function from_js( address, value ) {
         var id = Module.STDWEB_PRIVATE.register_raw_value( value );
         HEAPU8[ address + 12 ] = 15;
         HEAP32[ address / 4 ] = id;
+    } else if( kind === "[object BigInt]" ) {
+        var id = Module.STDWEB_PRIVATE.register_raw_value( value );
+        HEAPU8[ address + 12 ] = 16;
+        HEAP32[ address / 4 ] = id;
     } else {
         var refid = Module.STDWEB_PRIVATE.acquire_rust_reference( value );
         HEAPU8[ address + 12 ] = 9;
@@ -5,7 +5,3 @@ Module.STDWEB_PRIVATE.alloc = function alloc( size ) {
 Module.STDWEB_PRIVATE.dyncall = function( signature, ptr, args ) {
     return Runtime.dynCall( signature, ptr, args );
 };
-
-Module.STDWEB_PRIVATE.utf8_len = function utf8_len( str ) {
-    return lengthBytesUTF8( str );
-};
@@ -5,6 +5,7 @@ use std::collections::{BTreeMap, HashMap};
 use std::marker::PhantomData;
 use std::cell::{Cell, UnsafeCell};
 use std::hash::Hash;
+use std::time::Duration;
 
 use webcore::ffi;
 use webcore::callfn::{CallOnce, CallMut};
@@ -13,7 +14,8 @@ use webcore::try_from::{TryFrom, TryInto};
 use webcore::number::Number;
 use webcore::type_name::type_name;
 use webcore::symbol::Symbol;
-use webcore::unsafe_typed_array::UnsafeTypedArray;
+use webcore::big_int::BigInt;
+use webcore::unsafe_typed_array::{UnsafeTypedArray, UnsafeTypedArrayMut};
 use webcore::once::Once;
 
 use webcore::value::{
@@ -39,7 +41,8 @@ pub enum Tag {
     Function = 10,
     FunctionOnce = 13,
     UnsafeTypedArray = 14,
-    Symbol = 15
+    Symbol = 15,
+    BigInt = 16
 }
 
 impl Default for Tag {
@@ -199,6 +202,12 @@ struct SerializedUntaggedSymbol {
     id: i32
 }
 
+#[repr(C)]
+#[derive(Debug)]
+struct SerializedUntaggedBigInt {
+    id: i32
+}
+
 #[repr(C)]
 #[derive(Debug)]
 struct SerializedUntaggedReference {
@@ -392,6 +401,13 @@ impl SerializedUntaggedSymbol {
     }
 }
 
+impl SerializedUntaggedBigInt {
+    #[inline]
+    fn deserialize( &self ) -> BigInt {
+        BigInt( self.id )
+    }
+}
+
 impl SerializedUntaggedReference {
     #[inline]
     fn deserialize( &self ) -> Reference {
@@ -448,6 +464,7 @@ untagged_boilerplate!( test_object, as_object, Tag::Object, SerializedUntaggedOb
 untagged_boilerplate!( test_string, as_string, Tag::Str, SerializedUntaggedString );
 untagged_boilerplate!( test_array, as_array, Tag::Array, SerializedUntaggedArray );
 untagged_boilerplate!( test_symbol, as_symbol, Tag::Symbol, SerializedUntaggedSymbol );
+untagged_boilerplate!( test_big_int, as_big_int, Tag::BigInt, SerializedUntaggedBigInt );
 untagged_boilerplate!( test_reference, as_reference, Tag::Reference, SerializedUntaggedReference );
 untagged_boilerplate!( test_function, as_function, Tag::Function, SerializedUntaggedFunction );
 untagged_boilerplate!( test_function_once, as_function_once, Tag::FunctionOnce, SerializedUntaggedFunctionOnce );
@@ -467,6 +484,7 @@ impl< 'a > SerializedValue< 'a > {
             Tag::True => Value::Bool( true ),
             Tag::Reference => self.as_reference().deserialize().into(),
             Tag::Symbol => self.as_symbol().deserialize().into(),
+            Tag::BigInt => self.as_big_int().deserialize().into(),
             Tag::Function |
             Tag::FunctionOnce |
             Tag::Object |
@@ -542,6 +560,24 @@ impl JsSerialize for Symbol {
 
 __js_serializable_boilerplate!( Symbol );
 
+impl JsSerialize for BigInt {
+    #[doc(hidden)]
+    #[inline]
+    fn _into_js< 'a >( &'a self, _: &'a PreallocatedArena ) -> SerializedValue< 'a > {
+        SerializedUntaggedBigInt {
+            id: self.0
+        }.into()
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn _memory_required( &self ) -> usize {
+        0
+    }
+}
+
+__js_serializable_boilerplate!( BigInt );
+
 impl JsSerialize for Reference {
     #[doc(hidden)]
     #[inline]
@@ -759,6 +795,57 @@ impl JsSerialize for f64 {
 
 __js_serializable_boilerplate!( f64 );
 
+// Integers which can't be represented exactly as a number are passed as a `BigInt`;
+// 128-bit integers are always passed as a `BigInt`.
+macro_rules! impl_for_big_integer {
+    ($($kind:ty)+) => {
+        $(
+            impl JsSerialize for $kind {
+                #[doc(hidden)]
+                fn _into_js< 'a >( &'a self, arena: &'a PreallocatedArena ) -> SerializedValue< 'a > {
+                    match Value::from( *self ) {
+                        Value::Number( number ) => {
+                            let value: f64 = number.try_into().unwrap();
+                            SerializedUntaggedF64 {
+                                value
+                            }.into()
+                        },
+                        value => arena.save( value )._into_js( arena )
+                    }
+                }
+
+                #[doc(hidden)]
+                #[inline]
+                fn _memory_required( &self ) -> usize {
+                    0
+                }
+            }
+
+            __js_serializable_boilerplate!( $kind );
+        )+
+    };
+}
+
+impl_for_big_integer!( i64 u64 i128 u128 );
+
+impl JsSerialize for Duration {
+    #[doc(hidden)]
+    #[inline]
+    fn _into_js< 'a >( &'a self, _: &'a PreallocatedArena ) -> SerializedValue< 'a > {
+        SerializedUntaggedF64 {
+            value: self.as_secs() as f64 * 1000.0 + self.subsec_nanos() as f64 / 1_000_000.0
+        }.into()
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn _memory_required( &self ) -> usize {
+        0
+    }
+}
+
+__js_serializable_boilerplate!( Duration );
+
 impl JsSerialize for Number {
     #[doc(hidden)]
     #[inline]
@@ -828,6 +915,22 @@ impl< T: JsSerialize > JsSerialize for [T] {
 
 __js_serializable_boilerplate!( impl< 'a, T > for &'a [T] where T: JsSerialize );
 
+impl< T: JsSerialize, const N: usize > JsSerialize for [T; N] {
+    #[doc(hidden)]
+    #[inline]
+    fn _into_js< 'a >( &'a self, arena: &'a PreallocatedArena ) -> SerializedValue< 'a > {
+        self[..]._into_js( arena )
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn _memory_required( &self ) -> usize {
+        self[..]._memory_required()
+    }
+}
+
+__js_serializable_boilerplate!( (T, const N: usize) ([T; N]) (T: JsSerialize) );
+
 impl< T: JsSerialize > JsSerialize for Vec< T > {
     #[doc(hidden)]
     #[inline]
@@ -906,6 +1009,7 @@ impl JsSerialize for Value {
             Value::Bool( ref value ) => value._into_js( arena ),
             Value::Number( ref value ) => value._into_js( arena ),
             Value::Symbol( ref value ) => value._into_js( arena ),
+            Value::BigInt( ref value ) => value._into_js( arena ),
             Value::String( ref value ) => value._into_js( arena ),
             Value::Reference( ref value ) => value._into_js( arena )
         }
@@ -919,6 +1023,7 @@ impl JsSerialize for Value {
             Value::Bool( value ) => value._memory_required(),
             Value::Number( value ) => value._memory_required(),
             Value::Symbol( ref value ) => value._memory_required(),
+            Value::BigInt( ref value ) => value._memory_required(),
             Value::String( ref value ) => value._memory_required(),
             Value::Reference( ref value ) => value._memory_required()
         }
@@ -948,6 +1053,26 @@ macro_rules! impl_for_unsafe_typed_array {
         }
 
         __js_serializable_boilerplate!( impl< 'a > for UnsafeTypedArray< 'a, $ty > );
+
+        impl< 'r > JsSerialize for UnsafeTypedArrayMut< 'r, $ty > {
+            #[doc(hidden)]
+            #[inline]
+            fn _into_js< 'a >( &'a self, _: &'a PreallocatedArena ) -> SerializedValue< 'a > {
+                SerializedUntaggedUnsafeTypedArray {
+                    pointer: self.0.as_ptr() as u32 / mem::size_of::< $ty >() as u32,
+                    length: self.0.len() as u32,
+                    kind: $kind
+                }.into()
+            }
+
+            #[doc(hidden)]
+            #[inline]
+            fn _memory_required( &self ) -> usize {
+                0
+            }
+        }
+
+        __js_serializable_boilerplate!( impl< 'a > for UnsafeTypedArrayMut< 'a, $ty > );
     }
 }
 
@@ -1244,6 +1369,14 @@ mod test_deserialization {
         assert!( value.is_symbol() );
     }
 
+    #[test]
+    fn big_int() {
+        let value = js! { return BigInt( "18446744073709551615" ); };
+        assert!( value.is_big_int() );
+        assert_eq!( value, u64::MAX );
+        assert_eq!( format!( "{:?}", value ), "BigInt(BigInt(18446744073709551615))" );
+    }
+
     #[test]
     fn array() {
         assert_eq!( js! { return [1, 2]; }.is_array(), true );
@@ -1744,11 +1877,41 @@ mod test_serialization {
     test_unsafe_typed_array!( test_unsafe_typed_array_i32, i32, Int32Array );
     test_unsafe_typed_array!( test_unsafe_typed_array_f32, f32, Float32Array );
     test_unsafe_typed_array!( test_unsafe_typed_array_f64, f64, Float64Array );
+
+    #[test]
+    fn test_unsafe_typed_array_mut() {
+        let mut buffer = [0_u8; 16];
+        {
+            let array = unsafe { UnsafeTypedArrayMut::new( &mut buffer ) };
+            js!( @(no_return)
+                var array = @{array};
+                for( var i = 0; i < array.length; ++i ) {
+                    array[ i ] = i + 1;
+                }
+            );
+        }
+
+        assert_eq!( buffer, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16] );
+    }
+
+    #[test]
+    fn test_unsafe_typed_array_mut_random_values() {
+        let mut buffer = [0_u8; 16];
+        {
+            let array = unsafe { UnsafeTypedArrayMut::new( &mut buffer ) };
+            js!( @(no_return)
+                crypto.getRandomValues( @{array} );
+            );
+        }
+
+        assert!( buffer.iter().any( |&byte| byte != 0 ) );
+    }
 }
 
 #[cfg(test)]
 mod test_reserialization {
     use super::*;
+    use webcore::value::ConversionError;
     use webcore::array::Array;
 
     #[test]
@@ -1843,6 +2006,74 @@ mod test_reserialization {
         assert_eq!( js! { return @{value_1} !== @{value_2}; }, true );
     }
 
+    #[test]
+    fn big_integers_round_trip() {
+        const MAX_SAFE_INTEGER: i64 = 9007199254740991;
+        for &value in &[ MAX_SAFE_INTEGER - 1, MAX_SAFE_INTEGER, MAX_SAFE_INTEGER + 1, -MAX_SAFE_INTEGER - 1, i64::MIN, i64::MAX ] {
+            let output: i64 = js! { return @{value}; }.try_into().unwrap();
+            assert_eq!( output, value );
+        }
+
+        for &value in &[ MAX_SAFE_INTEGER as u64 + 1, u64::MAX - 1, u64::MAX ] {
+            let output: u64 = js! { return @{value}; }.try_into().unwrap();
+            assert_eq!( output, value );
+        }
+    }
+
+    #[test]
+    fn big_integers_are_passed_as_numbers_when_safe() {
+        assert_eq!( js! { return typeof @{9007199254740991_i64}; }, "number" );
+        assert_eq!( js! { return typeof @{9007199254740992_i64}; }, "bigint" );
+        assert_eq!( js! { return typeof @{18446744073709551615_u64}; }, "bigint" );
+        assert_eq!( js! { return @{18446744073709551615_u64} === BigInt( "18446744073709551615" ); }, true );
+    }
+
+    #[test]
+    fn explicit_big_int() {
+        assert_eq!( js! { return typeof @{Value::big_int( 1 )}; }, "bigint" );
+        let output: i64 = Value::big_int( -1 ).try_into().unwrap();
+        assert_eq!( output, -1 );
+    }
+
+    #[test]
+    fn unsafe_numbers_are_not_converted_into_big_integers() {
+        let result: Result< i64, _ > = js! { return 9007199254740993; }.try_into();
+        assert_eq!( result, Err( ConversionError::OutOfRange ) );
+        let result: Result< u64, _ > = js! { return 1e20; }.try_into();
+        assert_eq!( result, Err( ConversionError::OutOfRange ) );
+    }
+
+    #[test]
+    fn big_int_out_of_range() {
+        let result: Result< u64, _ > = js! { return BigInt( -1 ); }.try_into();
+        assert_eq!( result, Err( ConversionError::OutOfRange ) );
+        let result: Result< i64, _ > = js! { return BigInt( "18446744073709551615" ); }.try_into();
+        assert_eq!( result, Err( ConversionError::OutOfRange ) );
+    }
+
+    #[test]
+    fn big_integers_128_bit_round_trip() {
+        for &value in &[ 0, -1, i64::MIN as i128 - 1, i128::MIN, i128::MAX ] {
+            assert_eq!( js! { return typeof @{value}; }, "bigint" );
+            let output: i128 = js! { return @{value}; }.try_into().unwrap();
+            assert_eq!( output, value );
+        }
+
+        let output: u128 = js! { return @{u128::MAX}; }.try_into().unwrap();
+        assert_eq!( output, u128::MAX );
+        assert_eq!( js! { return @{u128::MAX} === BigInt( "340282366920938463463374607431768211455" ); }, true );
+    }
+
+    #[test]
+    fn big_integers_128_bit_failures() {
+        let result: Result< i128, _ > = js! { return 1; }.try_into();
+        assert_eq!( result.unwrap_err().to_string(), "expected bigint, got number" );
+        let result: Result< u128, _ > = js! { return BigInt( -1 ); }.try_into();
+        assert_eq!( result, Err( ConversionError::OutOfRange ) );
+        let result: Result< i128, _ > = js! { return BigInt( "170141183460469231731687303715884105728" ); }.try_into();
+        assert_eq!( result, Err( ConversionError::OutOfRange ) );
+    }
+
     #[test]
     fn reference() {
         let date = js! { return new Date(); };
@@ -0,0 +1,94 @@
+use webcore::try_from::TryInto;
+use webcore::value::{Reference, Value};
+use webcore::serialization::JsSerialize;
+
+/// A type representing a JavaScript `Set`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set)
+// https://tc39.github.io/ecma262/#sec-set-objects
+#[derive(Clone, PartialEq, Eq, Debug, ReferenceType)]
+#[reference(instance_of = "Set")]
+pub struct JsSet( Reference );
+
+impl JsSet {
+    /// Creates a new empty `Set`.
+    pub fn new() -> Self {
+        js!( return new Set(); ).try_into().unwrap()
+    }
+
+    /// Returns the number of values in the set.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/size)
+    pub fn len( &self ) -> usize {
+        js!( return @{self}.size; ).try_into().unwrap()
+    }
+
+    /// Adds the given `value` to the set, unless it's already there.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/add)
+    pub fn add< V: JsSerialize >( &self, value: V ) {
+        js! { @(no_return)
+            @{self}.add( @{value} );
+        }
+    }
+
+    /// Returns whether the set contains the given `value`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/has)
+    pub fn has< V: JsSerialize >( &self, value: V ) -> bool {
+        js!( return @{self}.has( @{value} ); ).try_into().unwrap()
+    }
+
+    /// Removes the given `value` from the set; returns whether it was there.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/delete)
+    pub fn delete< V: JsSerialize >( &self, value: V ) -> bool {
+        js!( return @{self}.delete( @{value} ); ).try_into().unwrap()
+    }
+
+    /// Removes all of the values of the set.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/clear)
+    pub fn clear( &self ) {
+        js! { @(no_return)
+            @{self}.clear();
+        }
+    }
+
+    /// Returns the values of the set, in insertion order.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/values)
+    pub fn values( &self ) -> Vec< Value > {
+        js!( return Array.from( @{self}.values() ); ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webcore::try_from::TryFrom;
+
+    #[test]
+    fn set() {
+        let set = JsSet::new();
+        set.add( "a" );
+        set.add( 1 );
+        set.add( "a" );
+        assert_eq!( set.len(), 2 );
+        assert!( set.has( "a" ) );
+        assert!( !set.has( "1" ) );
+        assert_eq!( set.values(), vec![ Value::from( "a" ), Value::from( 1 ) ] );
+
+        assert!( set.delete( "a" ) );
+        assert!( !set.delete( "a" ) );
+        set.clear();
+        assert_eq!( set.len(), 0 );
+    }
+
+    #[test]
+    fn set_from_value() {
+        let set: JsSet = js!( return new Set( [ 1, 2, 2, 3 ] ); ).try_into().unwrap();
+        assert_eq!( set.len(), 3 );
+        assert!( JsSet::try_from( js!( return [ 1, 2 ]; ) ).is_err() );
+    }
+}
@@ -10,6 +10,32 @@ use std::fmt;
 /// either directly or indirectly. Breaking this rule will result
 /// in undefined behavior!
 ///
+/// # Element types
+///
+/// Every supported element type is mapped to the corresponding
+/// JavaScript typed array view:
+///
+/// | Rust  | JavaScript     |
+/// |-------|----------------|
+/// | `u8`  | `Uint8Array`   |
+/// | `i8`  | `Int8Array`    |
+/// | `u16` | `Uint16Array`  |
+/// | `i16` | `Int16Array`   |
+/// | `u32` | `Uint32Array`  |
+/// | `i32` | `Int32Array`   |
+/// | `f32` | `Float32Array` |
+/// | `f64` | `Float64Array` |
+///
+/// The view is created directly over the WebAssembly memory, so its
+/// `byteLength` is always `slice.len() * mem::size_of::< T >()`.
+/// JavaScript requires the offset of a multi-byte view to be a multiple
+/// of its element size; this always holds for a Rust slice, since its
+/// elements are naturally aligned, but it means that the slice **must not**
+/// be obtained by transmuting a misaligned byte slice.
+///
+/// Values of a `Float32Array` or a `Float64Array` are passed bit-for-bit,
+/// including NaNs and signed zeros.
+///
 /// # Examples
 ///
 /// ```rust
@@ -42,3 +68,101 @@ impl< 'a, T > UnsafeTypedArray< 'a, T > {
         UnsafeTypedArray( slice )
     }
 }
+
+/// A wrapper type for exposing mutable raw Rust slices as `TypedArray`s
+/// at zero cost without copying, so that they can be filled in by JavaScript.
+///
+/// This is the writable counterpart of [UnsafeTypedArray](struct.UnsafeTypedArray.html);
+/// any writes made to the `TypedArray` on the JavaScript side are visible
+/// in the original slice after the `js!` call returns.
+///
+/// Using this is **even more unsafe** than using `UnsafeTypedArray`! On top of
+/// the rules for `UnsafeTypedArray` you **must not** trigger any memory allocation
+/// while the `TypedArray` is being accessed; if the heap gets reallocated the
+/// `TypedArray` will be detached and the writes will be silently lost, or,
+/// even worse, will land in memory which doesn't belong to the slice anymore.
+///
+/// # Examples
+///
+/// ```rust
+/// let mut buffer = [0_u8; 16];
+/// {
+///     let array = unsafe { UnsafeTypedArrayMut::new( &mut buffer ) };
+///     js!( @(no_return)
+///         crypto.getRandomValues( @{array} );
+///     );
+/// }
+/// println!( "Random bytes: {:?}", buffer );
+/// ```
+pub struct UnsafeTypedArrayMut< 'a, T: 'a >( pub(crate) &'a mut [T] );
+
+impl< 'a, T > fmt::Debug for UnsafeTypedArrayMut< 'a, T > {
+    #[inline]
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> Result< (), fmt::Error > {
+        write!( formatter, "UnsafeTypedArrayMut" )
+    }
+}
+
+impl< 'a, T > UnsafeTypedArrayMut< 'a, T > {
+    /// Creates a new `UnsafeTypedArrayMut`.
+    ///
+    /// Even though this function is marked as `unsafe`
+    /// the unsafely only comes into play after you
+    /// pass it to the `js!` macro.
+    #[inline]
+    pub unsafe fn new( slice: &'a mut [T] ) -> Self {
+        UnsafeTypedArrayMut( slice )
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webcore::try_from::TryInto;
+
+    macro_rules! assert_view {
+        ($slice:expr, $view:expr) => {{
+            let slice = $slice;
+            let array = unsafe { UnsafeTypedArray::new( slice ) };
+            let result: Vec< ::webcore::value::Value > = js!(
+                var array = @{array};
+                return [
+                    Object.prototype.toString.call( array ),
+                    array.byteLength,
+                    array.byteOffset % array.BYTES_PER_ELEMENT,
+                    Array.prototype.slice.call( array )
+                ];
+            ).try_into().unwrap();
+
+            let mut result = result.into_iter();
+            let kind: String = result.next().unwrap().try_into().unwrap();
+            let byte_length: usize = result.next().unwrap().try_into().unwrap();
+            let misalignment: usize = result.next().unwrap().try_into().unwrap();
+            let values: Vec< f64 > = result.next().unwrap().try_into().unwrap();
+
+            assert_eq!( kind, concat!( "[object ", $view, "]" ) );
+            assert_eq!( byte_length, slice.len() * ::std::mem::size_of_val( &slice[ 0 ] ) );
+            assert_eq!( misalignment, 0 );
+            assert_eq!( values, slice.iter().map( |&value| value as f64 ).collect::< Vec< f64 > >() );
+        }}
+    }
+
+    #[test]
+    fn test_element_types() {
+        assert_view!( &[1_u8, 2, 255][..], "Uint8Array" );
+        assert_view!( &[-1_i8, 2, -128][..], "Int8Array" );
+        assert_view!( &[1_u16, 2, 65535][..], "Uint16Array" );
+        assert_view!( &[-1_i16, 2, -32768][..], "Int16Array" );
+        assert_view!( &[1_u32, 2, 4294967295][..], "Uint32Array" );
+        assert_view!( &[-1_i32, 2, -2147483648][..], "Int32Array" );
+        assert_view!( &[1.5_f32, -2.25, 1e30][..], "Float32Array" );
+        assert_view!( &[1.5_f64, -2.25, 1e300][..], "Float64Array" );
+    }
+
+    #[test]
+    fn test_f64_subslice() {
+        // An odd offset into an `f64` slice is still 8-byte aligned.
+        let values = [0.5_f64, 1.5, 2.5, 3.5, 4.5];
+        assert_view!( &values[ 1..4 ], "Float64Array" );
+    }
+}
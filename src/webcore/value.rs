@@ -3,15 +3,24 @@ use std::hash::Hash;
 use std::fmt;
 use std::error;
 use std::mem;
+use std::str;
+use std::time::Duration;
+use std::net::{IpAddr, SocketAddr};
 use webcore::void::Void;
 use webcore::try_from::{TryFrom, TryInto};
 use webcore::number::{self, Number};
-use webcore::object::Object;
+use webcore::object::{Object, unwrap_found};
 use webcore::array::Array;
 use webcore::serialization::JsSerialize;
 use webcore::reference_type::ReferenceType;
 use webcore::instance_of::InstanceOf;
 use webcore::symbol::Symbol;
+use webcore::big_int::BigInt;
+use webcore::intern::PropertyKey;
+use webcore::once::Once;
+use webcore::promise::Promise;
+use webcore::base64::Base64Alphabet;
+use webapi::dom_exception::DataCloneError;
 
 /// A unit type representing JavaScript's `undefined`.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
@@ -56,6 +65,54 @@ impl Reference {
             None
         }
     }
+
+    /// Converts a copy of this reference into the given type `T`; returns `None`
+    /// if the reference isn't of type `T`.
+    ///
+    /// This is the borrowing counterpart of [downcast](#method.downcast).
+    #[inline]
+    pub fn try_into_typed< T: ReferenceType >( &self ) -> Option< T > {
+        self.clone().downcast()
+    }
+
+    /// Registers a `callback` which will be called once the JavaScript object
+    /// this reference points to is garbage collected.
+    ///
+    /// Returns `false` without registering anything if the browser
+    /// doesn't support `FinalizationRegistry`.
+    ///
+    /// This is only meant for debugging, e.g. to confirm that an object was
+    /// actually freed after all of the references to it were dropped. The garbage
+    /// collector is free to collect the object at any point in time or to never
+    /// collect it at all, so the `callback` may never be called; it **must not**
+    /// be relied upon for correctness. Note that the object can't be collected
+    /// while any `Reference` to it is still alive on the Rust side.
+    ///
+    /// The `callback` is kept alive for as long as the object itself, together with
+    /// everything it captures, so it must not capture a `Reference` to the object,
+    /// since then the object would never be collected.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry)
+    // https://tc39.es/ecma262/#sec-finalization-registry-objects
+    pub fn on_finalize< F: FnOnce() + 'static >( &self, callback: F ) -> bool {
+        let is_supported: bool = js!( return typeof FinalizationRegistry === "function"; ).try_into().unwrap();
+        if !is_supported {
+            return false;
+        }
+
+        js! { @(no_return)
+            var registry = Module.STDWEB_PRIVATE.finalization_registry;
+            if( !registry ) {
+                registry = Module.STDWEB_PRIVATE.finalization_registry = new FinalizationRegistry( function( callback ) {
+                    callback();
+                });
+            }
+
+            registry.register( @{self}, @{Once( callback )} );
+        }
+
+        true
+    }
 }
 
 impl PartialEq for Reference {
@@ -145,7 +202,7 @@ impl_infallible_try_from! {
 /// let v2: Value = true.into();
 /// let v3: Value = vec![ 1, 2, 3 ].into();
 /// let v4: Value = Null.into();
-/// let v5: Value = 123_u64.try_into().unwrap();
+/// let v5: Value = 123_u64.into();
 ///
 /// let v1_r: String = v1.try_into().unwrap();
 /// let v2_r: bool = v2.try_into().unwrap();
@@ -161,11 +218,21 @@ pub enum Value {
     Bool( bool ),
     Number( Number ),
     Symbol( Symbol ),
+    BigInt( BigInt ),
     String( String ),
     Reference( Reference )
 }
 
 impl Value {
+    /// Creates a `BigInt` from the given integer, regardless of
+    /// whether it could be represented exactly as a number.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt)
+    #[inline]
+    pub fn big_int( value: i64 ) -> Self {
+        Value::BigInt( value.into() )
+    }
+
     /// Checks whenever the Value is of the Symbol variant.
     #[inline]
     pub fn is_symbol( &self ) -> bool {
@@ -176,6 +243,16 @@ impl Value {
         }
     }
 
+    /// Checks whenever the Value is of the BigInt variant.
+    #[inline]
+    pub fn is_big_int( &self ) -> bool {
+        if let Value::BigInt( _ ) = *self {
+            true
+        } else {
+            false
+        }
+    }
+
     /// Checks whenever the Value is of the Reference variant.
     #[inline]
     pub fn is_reference( &self ) -> bool {
@@ -206,6 +283,32 @@ impl Value {
         }
     }
 
+    /// Checks whenever the Value is a thenable, that is an object with a callable `then`
+    /// property, like a `Promise` or a Promise-like object from a library.
+    ///
+    /// Such a `Value` can be converted into a [`PromiseFuture`](struct.PromiseFuture.html).
+    // https://promisesaplus.com/#terminology
+    pub fn is_thenable( &self ) -> bool {
+        if let Value::Reference( ref reference ) = *self {
+            Promise::is_thenable( reference )
+        } else {
+            false
+        }
+    }
+
+    /// Decodes a string `Value` containing base64 data encoded with the given `alphabet`.
+    ///
+    /// Returns `None` if this isn't a string, or if it isn't valid base64.
+    /// The decoding is done in Rust, so unlike `atob` it works directly on bytes.
+    ///
+    /// This is the inverse of [base64::bytes_to_base64_value](base64/fn.bytes_to_base64_value.html).
+    pub fn to_base64_bytes( &self, alphabet: Base64Alphabet ) -> Option< Vec< u8 > > {
+        match *self {
+            Value::String( ref string ) => alphabet.decode( string ).ok(),
+            _ => None
+        }
+    }
+
     /// Gets a reference to the [Reference](struct.Reference.html) inside this `Value`.
     #[inline]
     pub fn as_reference( &self ) -> Option< &Reference > {
@@ -295,6 +398,468 @@ impl Value {
             _ => None
         }
     }
+
+    /// Calls the function inside this `Value` with the given `this` and
+    /// with the arguments taken from `args_array`, exactly like `fn.apply( this, argsArray )`
+    /// would in JavaScript.
+    ///
+    /// The `args_array` has to be either an `Array` or an `arguments` object,
+    /// otherwise an error is returned.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/apply)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-function.prototype.apply
+    pub fn apply( &self, this: &Value, args_array: &Value ) -> Result< Value, ConversionError > {
+        let is_function: bool = js!( return typeof @{self} === "function"; ).try_into().unwrap();
+        if !is_function {
+            return Err( ConversionError::Custom( format!( "apply called on a value which is not a function; actual type is {}", value_type_name( self ) ) ) );
+        }
+
+        let is_args_array: bool = js!(
+            var args = @{args_array};
+            return Array.isArray( args ) || Object.prototype.toString.call( args ) === "[object Arguments]";
+        ).try_into().unwrap();
+
+        if !is_args_array {
+            return Err( ConversionError::Custom( format!( "apply expects an array of arguments; actual type is {}", value_type_name( args_array ) ) ) );
+        }
+
+        Ok( js!( return @{self}.apply( @{this}, @{args_array} ); ) )
+    }
+
+    /// Follows the given `path` of property names through nested objects
+    /// and arrays, and returns the value at its end, or `None` if any
+    /// of the own properties along the way doesn't exist.
+    ///
+    /// Unlike [get_path](#method.get_path) this distinguishes a missing
+    /// property from one which is set to `undefined`, and the names are
+    /// taken verbatim, so they can contain dots or brackets.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let response = js!( return { data: { items: [ { name: "first" } ] } }; );
+    /// let name = response.try_get_path( &[ "data", "items", "0", "name" ] );
+    /// assert_eq!( name, Some( Value::String( "first".to_owned() ) ) );
+    /// ```
+    pub fn try_get_path( &self, path: &[&str] ) -> Option< Value > {
+        let value = js!(
+            var value = @{self};
+            var path = @{path};
+            for( var i = 0; i < path.length; ++i ) {
+                if( value === null || (typeof value !== "object" && typeof value !== "function") ) {
+                    return null;
+                }
+
+                if( !Object.prototype.hasOwnProperty.call( value, path[ i ] ) ) {
+                    return null;
+                }
+
+                value = value[ path[ i ] ];
+            }
+
+            return [ value ];
+        );
+
+        unwrap_found( value )
+    }
+
+    /// Returns the property with the given `key` of the object inside this `Value`.
+    ///
+    /// The `key` can be either a `&str` or an [InternedString](struct.InternedString.html).
+    /// Returns `Value::Undefined` if the property doesn't exist.
+    pub fn get< K: PropertyKey >( &self, key: K ) -> Value {
+        key.get_property( self )
+    }
+
+    /// Sets the property with the given `key` of the object inside this `Value`.
+    ///
+    /// The `key` can be either a `&str` or an [InternedString](struct.InternedString.html);
+    /// using interned keys avoids passing the same property names over and over
+    /// again when creating many similar objects.
+    pub fn set< K: PropertyKey, V: JsSerialize >( &self, key: K, value: V ) {
+        key.set_property( self, value )
+    }
+
+    /// Creates a deep copy of this `Value` using the structured clone algorithm,
+    /// exactly like `postMessage` would.
+    ///
+    /// This correctly handles `Map`s, `Set`s, `Date`s, typed arrays, `ArrayBuffer`s
+    /// and nested objects, and it can be used to check beforehand whenever a value
+    /// can be sent through `postMessage`. Functions (including Rust closures) and
+    /// DOM nodes can't be cloned; trying to clone them returns an error.
+    ///
+    /// The native `structuredClone` is used when it's available. Otherwise the value is
+    /// validated by posting it through a `MessageChannel`, which rejects anything
+    /// which can't be cloned, and is then copied by hand; unlike `structuredClone`
+    /// this fallback returns an error for `Error` objects.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/structuredClone)
+    // https://html.spec.whatwg.org/#dom-structuredclone
+    pub fn structured_clone( &self ) -> Result< Value, DataCloneError > {
+        js!(
+            var value = @{self};
+            var data_clone_error = function( message ) {
+                return new DOMException( message, "DataCloneError" );
+            };
+
+            try {
+                if( typeof structuredClone === "function" ) {
+                    return { ok: true, value: structuredClone( value ) };
+                }
+
+                if( typeof MessageChannel === "function" ) {
+                    var channel = new MessageChannel();
+                    try {
+                        channel.port1.postMessage( value );
+                    } finally {
+                        channel.port1.close();
+                    }
+                }
+
+                var copies = new Map();
+                var copy = function( value ) {
+                    if( typeof value === "function" || typeof value === "symbol" ) {
+                        throw data_clone_error( String( value ) + " could not be cloned." );
+                    }
+
+                    if( value === null || typeof value !== "object" ) {
+                        return value;
+                    }
+
+                    if( copies.has( value ) ) {
+                        return copies.get( value );
+                    }
+
+                    var output;
+                    if( Array.isArray( value ) ) {
+                        output = new Array( value.length );
+                        copies.set( value, output );
+                        for( var i = 0; i < value.length; ++i ) {
+                            output[ i ] = copy( value[ i ] );
+                        }
+                    } else if( value instanceof Map ) {
+                        output = new Map();
+                        copies.set( value, output );
+                        value.forEach( function( entry, key ) {
+                            output.set( copy( key ), copy( entry ) );
+                        });
+                    } else if( value instanceof Set ) {
+                        output = new Set();
+                        copies.set( value, output );
+                        value.forEach( function( entry ) {
+                            output.add( copy( entry ) );
+                        });
+                    } else if( value instanceof Date ) {
+                        output = new Date( value.getTime() );
+                    } else if( value instanceof RegExp ) {
+                        output = new RegExp( value.source, value.flags );
+                    } else if( value instanceof ArrayBuffer ) {
+                        output = value.slice( 0 );
+                    } else if( ArrayBuffer.isView( value ) ) {
+                        var buffer = copy( value.buffer );
+                        if( value instanceof DataView ) {
+                            output = new DataView( buffer, value.byteOffset, value.byteLength );
+                        } else {
+                            output = new value.constructor( buffer, value.byteOffset, value.length );
+                        }
+                    } else if( typeof Blob !== "undefined" && value instanceof Blob ) {
+                        output = value;
+                    } else if( Object.prototype.toString.call( value ) === "[object Object]" ) {
+                        output = {};
+                        copies.set( value, output );
+                        Object.keys( value ).forEach( function( key ) {
+                            output[ key ] = copy( value[ key ] );
+                        });
+                    } else {
+                        throw data_clone_error( Object.prototype.toString.call( value ) + " could not be cloned." );
+                    }
+
+                    copies.set( value, output );
+                    return output;
+                };
+
+                return { ok: true, value: copy( value ) };
+            } catch( error ) {
+                if( !(error instanceof DOMException) || error.name !== "DataCloneError" ) {
+                    error = data_clone_error( String( error && error.message ) );
+                }
+
+                return { ok: false, error: error };
+            }
+        ).try_into().unwrap()
+    }
+
+    /// Walks the given `path` of nested properties, starting from this `Value`,
+    /// and returns the value at the end of it.
+    ///
+    /// The `path` can use both dots and brackets, so `"data.items[0].id"`
+    /// and `"data.items.0.id"` are equivalent; numeric segments index arrays.
+    /// If any of the segments is missing `Value::Undefined` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let response = js!( return { data: { items: [ { id: 123 } ] } }; );
+    /// assert_eq!( response.get_path( "data.items[0].id" ), 123 );
+    /// assert_eq!( response.get_path( "data.missing.id" ), Value::Undefined );
+    /// ```
+    pub fn get_path( &self, path: &str ) -> Value {
+        let segments = parse_path( path );
+        if segments.is_empty() {
+            return self.clone();
+        }
+
+        js!(
+            var value = @{self};
+            var segments = @{segments};
+            for( var i = 0; i < segments.length; ++i ) {
+                if( value === null || (typeof value !== "object" && typeof value !== "function") ) {
+                    return undefined;
+                }
+
+                value = value[ segments[ i ] ];
+            }
+
+            return value;
+        )
+    }
+
+    /// Structurally compares this `Value` with `other`.
+    ///
+    /// Arrays are compared element by element, while plain objects are compared
+    /// as sets of key/value pairs, so the order in which their keys were inserted
+    /// doesn't matter. Any other references, e.g. to a `Date` or a DOM node, are
+    /// only equal if they point to the very same object.
+    ///
+    /// The comparison doesn't detect cycles, so it will not terminate
+    /// if both values contain a reference cycle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let left = js!( return { a: 1, b: [ 1, 2 ] }; );
+    /// let right = js!( return { b: [ 1, 2 ], a: 1 }; );
+    /// assert!( left.deep_eq( &right ) );
+    /// ```
+    pub fn deep_eq( &self, other: &Value ) -> bool {
+        deep_difference( self, other ).is_none()
+    }
+
+    /// Freezes the object this `Value` refers to, so that none of its properties can be
+    /// added, removed or changed anymore; does nothing if it's a primitive.
+    ///
+    /// The freezing is shallow: any objects referenced by the properties stay mutable.
+    /// Afterwards setting a property, e.g. with [Object::set_field](struct.Object.html#method.set_field),
+    /// silently does nothing, just like an assignment in non-strict JavaScript code.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/freeze)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-object.freeze
+    pub fn freeze( &self ) {
+        if let Value::Reference( ref reference ) = *self {
+            js! { @(no_return)
+                Object.freeze( @{reference} );
+            }
+        }
+    }
+
+    /// Checks whenever this `Value` is frozen; primitives are always considered to be frozen.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isFrozen)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-object.isfrozen
+    pub fn is_frozen( &self ) -> bool {
+        match *self {
+            Value::Reference( ref reference ) => js!( return Object.isFrozen( @{reference} ); ).try_into().unwrap(),
+            _ => true
+        }
+    }
+
+    /// Renders a JSON-like preview of this `Value` which is meant for debugging,
+    /// e.g. `{ "a": [ 1, "b" ], "c": [Function: handler] }`.
+    ///
+    /// Objects and arrays are only expanded up to `depth` levels deep, and at most
+    /// 100 of their elements are shown; whatever isn't shown is abbreviated as
+    /// `[Array]`, `[Object]` or `...`. Functions are rendered as `[Function: name]`,
+    /// objects created by other constructors are prefixed with the name of the
+    /// constructor, and circular references are rendered as `[Circular]`.
+    ///
+    /// Unlike this the `Debug` implementation only shows the variant and,
+    /// for references, an opaque id, since it doesn't call into JavaScript.
+    pub fn debug_string( &self, depth: usize ) -> String {
+        js!(
+            var MAX_ITEMS = 100;
+            var stack = [];
+            var render = function( value, depth ) {
+                var type = typeof value;
+                if( type === "string" ) {
+                    return JSON.stringify( value );
+                } else if( type === "bigint" ) {
+                    return value.toString() + "n";
+                } else if( type === "symbol" ) {
+                    return value.toString();
+                } else if( type === "function" ) {
+                    return "[Function: " + (value.name || "anonymous") + "]";
+                } else if( value === null || type !== "object" ) {
+                    return String( value );
+                }
+
+                if( stack.indexOf( value ) !== -1 ) {
+                    return "[Circular]";
+                }
+
+                var is_array = Array.isArray( value );
+                var constructor = Object.getPrototypeOf( value ) === null ? null : value.constructor;
+                var name = "";
+                if( !is_array && typeof constructor === "function" && constructor !== Object && constructor.name ) {
+                    name = constructor.name;
+                }
+
+                if( depth === 0 ) {
+                    return is_array ? "[Array]" : "[" + (name || "Object") + "]";
+                }
+
+                var keys = is_array ? null : Object.keys( value );
+                var length = is_array ? value.length : keys.length;
+                var items = [];
+                stack.push( value );
+                for( var i = 0; i < length && i < MAX_ITEMS; ++i ) {
+                    if( is_array ) {
+                        items.push( render( value[ i ], depth - 1 ) );
+                    } else {
+                        items.push( JSON.stringify( keys[ i ] ) + ": " + render( value[ keys[ i ] ], depth - 1 ) );
+                    }
+                }
+                stack.pop();
+
+                if( length > MAX_ITEMS ) {
+                    items.push( "..." );
+                }
+
+                if( is_array ) {
+                    return items.length === 0 ? "[]" : "[ " + items.join( ", " ) + " ]";
+                }
+
+                var body = items.length === 0 ? "{}" : "{ " + items.join( ", " ) + " }";
+                return name ? name + " " + body : body;
+            };
+
+            return render( @{self}, @{depth as u32} );
+        ).try_into().unwrap()
+    }
+}
+
+/// Returns the path at which `left` and `right` first differ according
+/// to [Value::deep_eq](enum.Value.html#method.deep_eq), together with
+/// the differing values, or `None` if they're structurally equal.
+#[doc(hidden)]
+pub fn deep_difference( left: &Value, right: &Value ) -> Option< (String, Value, Value) > {
+    let mut path = String::new();
+    difference_at( &mut path, left, right )
+}
+
+pub(crate) fn is_plain_object( reference: &Reference ) -> bool {
+    js!(
+        var prototype = Object.getPrototypeOf( @{reference} );
+        return prototype === Object.prototype || prototype === null;
+    ) == true
+}
+
+fn difference_at( path: &mut String, left: &Value, right: &Value ) -> Option< (String, Value, Value) > {
+    let mismatch = |path: &String| Some( (path.clone(), left.clone(), right.clone()) );
+    match (left, right) {
+        (&Value::Number( left_number ), &Value::Number( right_number )) => {
+            let left_number: f64 = left_number.try_into().unwrap();
+            let right_number: f64 = right_number.try_into().unwrap();
+            if left_number == right_number || (left_number.is_nan() && right_number.is_nan()) {
+                None
+            } else {
+                mismatch( path )
+            }
+        },
+        (&Value::Reference( ref left_reference ), &Value::Reference( ref right_reference )) => {
+            if left_reference == right_reference {
+                return None;
+            }
+
+            if let (Some( left_array ), Some( right_array )) = (left.as_array(), right.as_array()) {
+                let left_items: Vec< Value > = left_array.into();
+                let right_items: Vec< Value > = right_array.into();
+                if left_items.len() != right_items.len() {
+                    return mismatch( path );
+                }
+
+                let length = path.len();
+                for (index, (left_item, right_item)) in left_items.iter().zip( right_items.iter() ).enumerate() {
+                    path.push_str( &format!( "[{}]", index ) );
+                    let difference = difference_at( path, left_item, right_item );
+                    path.truncate( length );
+                    if difference.is_some() {
+                        return difference;
+                    }
+                }
+
+                None
+            } else if is_plain_object( left_reference ) && is_plain_object( right_reference ) {
+                let left_map: BTreeMap< String, Value > = left.as_object().unwrap().into();
+                let right_map: BTreeMap< String, Value > = right.as_object().unwrap().into();
+
+                let length = path.len();
+                let keys = left_map.keys().chain( right_map.keys().filter( |key| !left_map.contains_key( *key ) ) );
+                for key in keys {
+                    if !path.is_empty() {
+                        path.push( '.' );
+                    }
+                    path.push_str( key );
+
+                    let difference = match (left_map.get( key ), right_map.get( key )) {
+                        (Some( left_item ), Some( right_item )) => difference_at( path, left_item, right_item ),
+                        (left_item, right_item) => Some( (
+                            path.clone(),
+                            left_item.cloned().unwrap_or( Value::Undefined ),
+                            right_item.cloned().unwrap_or( Value::Undefined )
+                        ))
+                    };
+
+                    path.truncate( length );
+                    if difference.is_some() {
+                        return difference;
+                    }
+                }
+
+                None
+            } else {
+                mismatch( path )
+            }
+        },
+        (left, right) => {
+            if left == right {
+                None
+            } else {
+                mismatch( path )
+            }
+        }
+    }
+}
+
+fn parse_path( path: &str ) -> Vec< String > {
+    let mut segments = Vec::new();
+    let mut segment = String::new();
+    for character in path.chars() {
+        match character {
+            '.' | '[' | ']' => {
+                if !segment.is_empty() {
+                    segments.push( segment );
+                    segment = String::new();
+                }
+            },
+            character => segment.push( character )
+        }
+    }
+
+    if !segment.is_empty() {
+        segments.push( segment );
+    }
+
+    segments
 }
 
 impl AsRef< Value > for Value {
@@ -446,6 +1011,27 @@ impl< 'a, T > From< &'a mut Vec< T > > for Value where T: JsSerialize {
     }
 }
 
+impl< T, const N: usize > From< [T; N] > for Value where T: JsSerialize {
+    #[inline]
+    fn from( value: [T; N] ) -> Self {
+        value[..].into()
+    }
+}
+
+impl< 'a, T, const N: usize > From< &'a [T; N] > for Value where T: JsSerialize {
+    #[inline]
+    fn from( value: &'a [T; N] ) -> Self {
+        value[..].into()
+    }
+}
+
+impl< 'a, T, const N: usize > From< &'a mut [T; N] > for Value where T: JsSerialize {
+    #[inline]
+    fn from( value: &'a mut [T; N] ) -> Self {
+        value[..].into()
+    }
+}
+
 impl< 'a, T > From< &'a [T] > for Value where T: JsSerialize {
     #[inline]
     fn from( value: &'a [T] ) -> Self {
@@ -560,6 +1146,35 @@ macro_rules! impl_from_number {
 }
 
 impl_from_number!( i8 i16 i32 u8 u16 u32 f32 f64 );
+
+fn duration_to_milliseconds( duration: Duration ) -> f64 {
+    duration.as_secs() as f64 * 1000.0 + duration.subsec_nanos() as f64 / 1_000_000.0
+}
+
+/// Converts the `Duration` into a number of milliseconds, with any
+/// sub-millisecond precision preserved as the fractional part.
+impl From< Duration > for Value {
+    #[inline]
+    fn from( value: Duration ) -> Self {
+        Value::Number( duration_to_milliseconds( value ).into() )
+    }
+}
+
+impl< 'a > From< &'a Duration > for Value {
+    #[inline]
+    fn from( value: &'a Duration ) -> Self {
+        (*value).into()
+    }
+}
+
+impl< 'a > From< &'a mut Duration > for Value {
+    #[inline]
+    fn from( value: &'a mut Duration ) -> Self {
+        (*value).into()
+    }
+}
+
+impl_infallible_try_from!( Duration => Value; );
 impl_infallible_try_from! {
     Value => Value;
     Undefined => Value;
@@ -611,6 +1226,10 @@ impl_infallible_try_from! {
     impl< 'a, T > for &'a mut [T] => Array where (T: JsSerialize);
 }
 
+__impl_infallible_try_from!( (T, const N: usize) ([T; N]) (Value) (T: JsSerialize) );
+__impl_infallible_try_from!( ('a, T, const N: usize) (&'a [T; N]) (Value) (T: JsSerialize) );
+__impl_infallible_try_from!( ('a, T, const N: usize) (&'a mut [T; N]) (Value) (T: JsSerialize) );
+
 macro_rules! impl_try_from_number {
     ($($kind:ty)+) => {
         $(
@@ -626,7 +1245,62 @@ macro_rules! impl_try_from_number {
     };
 }
 
-impl_try_from_number!( i64 u64 usize );
+impl_try_from_number!( usize );
+
+// The magnitude of the integers which can be exactly represented by a JavaScript number.
+const MAX_SAFE_INTEGER: u64 = 9007199254740991;
+
+/// Converts the integer into a number if it can be represented exactly,
+/// otherwise into a `BigInt`.
+impl From< i64 > for Value {
+    #[inline]
+    fn from( value: i64 ) -> Self {
+        if value >= -(MAX_SAFE_INTEGER as i64) && value <= MAX_SAFE_INTEGER as i64 {
+            Value::Number( value.try_into().unwrap() )
+        } else {
+            Value::big_int( value )
+        }
+    }
+}
+
+/// Converts the integer into a number if it can be represented exactly,
+/// otherwise into a `BigInt`.
+impl From< u64 > for Value {
+    #[inline]
+    fn from( value: u64 ) -> Self {
+        if value <= MAX_SAFE_INTEGER {
+            Value::Number( value.try_into().unwrap() )
+        } else {
+            Value::BigInt( value.into() )
+        }
+    }
+}
+
+/// Always converts the integer into a `BigInt`, even if
+/// it could be represented exactly as a number.
+impl From< i128 > for Value {
+    #[inline]
+    fn from( value: i128 ) -> Self {
+        Value::BigInt( value.into() )
+    }
+}
+
+/// Always converts the integer into a `BigInt`, even if
+/// it could be represented exactly as a number.
+impl From< u128 > for Value {
+    #[inline]
+    fn from( value: u128 ) -> Self {
+        Value::BigInt( value.into() )
+    }
+}
+
+impl_infallible_try_from! {
+    i64 => Value;
+    u64 => Value;
+    i128 => Value;
+    u128 => Value;
+    BigInt => Value;
+}
 
 impl PartialEq< Undefined > for Value {
     #[inline]
@@ -698,6 +1372,16 @@ impl PartialEq< Symbol > for Value {
     }
 }
 
+impl PartialEq< BigInt > for Value {
+    #[inline]
+    fn eq( &self, right: &BigInt ) -> bool {
+        match *self {
+            Value::BigInt( ref left ) => *left == *right,
+            _ => false
+        }
+    }
+}
+
 impl< T: AsRef< Reference > > PartialEq< T > for Value {
     #[inline]
     fn eq( &self, right: &T ) -> bool {
@@ -798,7 +1482,28 @@ macro_rules! impl_partial_eq_to_number {
     };
 }
 
-impl_partial_eq_to_number!( i8 i16 i32 i64 u8 u16 u32 u64 usize f32 f64 );
+impl_partial_eq_to_number!( i8 i16 i32 u8 u16 u32 usize f32 f64 );
+
+macro_rules! impl_partial_eq_to_big_integer {
+    ($($kind:ty)+) => {
+        $(
+            impl PartialEq< $kind > for Value {
+                #[inline]
+                fn eq( &self, right: &$kind ) -> bool {
+                    match *self {
+                        Value::Number( left ) => left == *right,
+                        Value::BigInt( ref left ) => left.to_decimal_string() == right.to_string(),
+                        _ => false
+                    }
+                }
+            }
+
+            impl_partial_eq_boilerplate!( $kind );
+        )+
+    };
+}
+
+impl_partial_eq_to_big_integer!( i64 u64 );
 
 impl_partial_eq_boilerplate! {
     Undefined,
@@ -807,59 +1512,119 @@ impl_partial_eq_boilerplate! {
     str,
     String,
     Number,
-    Symbol
+    Symbol,
+    BigInt
 }
 
-/// A structure denoting a conversion error encountered when
-/// converting to or from a `Value`.
-#[doc(hidden)]
-#[derive(Clone, PartialEq, Eq, Debug)]
+/// An error which occurs when converting a `Value` into a Rust type.
+///
+/// Errors of nested conversions, e.g. of an element of a `Vec` or of a field
+/// of a map, are wrapped with the index or the key at which they occurred, so that
+/// the message contains the whole path, e.g. ``while converting field `items`: at index 3: expected number, got null``.
+/// The wrapped error is also available through `Error::source`.
+#[derive(Clone, PartialEq, Eq)]
 pub enum ConversionError {
-    TypeMismatch {
-        actual_type: &'static str
+    /// The value is of a different type than the one which was expected.
+    WrongType {
+        /// The name of the expected type.
+        expected: &'static str,
+        /// The JavaScript type of the value, as in `typeof`, except that `null` is reported as `"null"`.
+        actual: &'static str
     },
-    NumericConversionError( number::ConversionError ),
-    ValueConversionError( Box< ConversionError > ),
+    /// The number doesn't fit into the target type.
+    OutOfRange,
+    /// The number has a fractional part while an integer was expected.
+    NotAnInteger,
+    /// The array has a different number of elements than the one which was expected.
+    WrongLength {
+        /// The expected number of elements.
+        expected: usize,
+        /// The actual number of elements.
+        actual: usize
+    },
+    /// A required field is missing.
+    MissingField( String ),
+    /// The conversion of the element at the given index has failed.
+    AtIndex( usize, Box< ConversionError > ),
+    /// The conversion of the field with the given name has failed.
+    AtKey( String, Box< ConversionError > ),
+    /// The string couldn't be parsed into the target type.
+    Parse {
+        /// The name of the expected type.
+        expected: &'static str,
+        /// The string which couldn't be parsed.
+        input: String
+    },
+    /// Any other error.
     Custom( String )
 }
 
 fn value_type_name( value: &Value ) -> &'static str {
     match *value {
-        Value::Undefined => "Undefined",
-        Value::Null => "Null",
-        Value::Bool( _ ) => "Bool",
-        Value::Number( _ ) => "Number",
-        Value::Symbol( _ ) => "Symbol",
-        Value::String( _ ) => "String",
-        Value::Reference( _ ) => "Reference"
+        Value::Undefined => "undefined",
+        Value::Null => "null",
+        Value::Bool( _ ) => "boolean",
+        Value::Number( _ ) => "number",
+        Value::Symbol( _ ) => "symbol",
+        Value::BigInt( _ ) => "bigint",
+        Value::String( _ ) => "string",
+        Value::Reference( _ ) => "object"
     }
 }
 
 impl fmt::Display for ConversionError {
     fn fmt( &self, formatter: &mut fmt::Formatter ) -> Result< (), fmt::Error > {
         match *self {
-            ConversionError::TypeMismatch { actual_type } => write!( formatter, "type mismatch; actual type is {}", actual_type ),
-            ConversionError::NumericConversionError( ref inner ) => write!( formatter, "{}", inner ),
-            ConversionError::ValueConversionError( ref inner ) => write!( formatter, "value conversion error: {}", inner ),
+            ConversionError::WrongType { expected, actual } => write!( formatter, "expected {}, got {}", expected, actual ),
+            ConversionError::OutOfRange => write!( formatter, "number out of range" ),
+            ConversionError::NotAnInteger => write!( formatter, "number not an integer" ),
+            ConversionError::WrongLength { expected, actual } => write!( formatter, "wrong length; expected {} elements, got {}", expected, actual ),
+            ConversionError::MissingField( ref name ) => write!( formatter, "missing field `{}`", name ),
+            ConversionError::AtIndex( index, ref inner ) => write!( formatter, "at index {}: {}", index, inner ),
+            ConversionError::AtKey( ref key, ref inner ) => write!( formatter, "while converting field `{}`: {}", key, inner ),
+            ConversionError::Parse { expected, ref input } => write!( formatter, "failed to parse {:?} as {}", input, expected ),
             ConversionError::Custom( ref message ) => write!( formatter, "{}", message )
         }
     }
 }
 
-impl error::Error for ConversionError {
-    fn description( &self ) -> &str {
+// This is what ends up in the panic message of an `unwrap()`, so it's the same as `Display`.
+impl fmt::Debug for ConversionError {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> Result< (), fmt::Error > {
+        write!( formatter, "ConversionError({:?})", self.to_string() )
+    }
+}
+
+impl error::Error for ConversionError {
+    fn description( &self ) -> &str {
         match *self {
-            ConversionError::TypeMismatch { .. } => "type mismatch",
-            ConversionError::NumericConversionError( ref inner ) => inner.description(),
-            ConversionError::ValueConversionError( _ ) => "value conversion error",
+            ConversionError::WrongType { .. } => "wrong type",
+            ConversionError::OutOfRange => "number out of range",
+            ConversionError::NotAnInteger => "number not an integer",
+            ConversionError::WrongLength { .. } => "wrong length",
+            ConversionError::MissingField( _ ) => "missing field",
+            ConversionError::AtIndex( _, ref inner ) => inner.description(),
+            ConversionError::AtKey( _, ref inner ) => inner.description(),
+            ConversionError::Parse { .. } => "failed to parse a string",
             ConversionError::Custom( ref message ) => message
         }
     }
+
+    fn source( &self ) -> Option< &(dyn error::Error + 'static) > {
+        match *self {
+            ConversionError::AtIndex( _, ref inner ) => Some( &**inner ),
+            ConversionError::AtKey( _, ref inner ) => Some( &**inner ),
+            _ => None
+        }
+    }
 }
 
 impl From< number::ConversionError > for ConversionError {
     fn from( inner: number::ConversionError ) -> Self {
-        ConversionError::NumericConversionError( inner )
+        match inner {
+            number::ConversionError::OutOfRange => ConversionError::OutOfRange,
+            number::ConversionError::NotAnInteger => ConversionError::NotAnInteger
+        }
     }
 }
 
@@ -870,16 +1635,23 @@ impl From< Void > for ConversionError {
 }
 
 impl ConversionError {
+    #[doc(hidden)]
     #[inline]
-    pub(crate) fn type_mismatch( actual_value: &Value ) -> Self {
-        ConversionError::TypeMismatch {
-            actual_type: value_type_name( actual_value )
+    pub fn wrong_type( expected: &'static str, actual_value: &Value ) -> Self {
+        ConversionError::WrongType {
+            expected: expected,
+            actual: value_type_name( actual_value )
         }
     }
 
     #[inline]
-    pub(crate) fn value_conversion_error( inner: ConversionError ) -> Self {
-        ConversionError::ValueConversionError( Box::new( inner ) )
+    pub(crate) fn at_index( index: usize, inner: ConversionError ) -> Self {
+        ConversionError::AtIndex( index, Box::new( inner ) )
+    }
+
+    #[inline]
+    pub(crate) fn at_key( key: &str, inner: ConversionError ) -> Self {
+        ConversionError::AtKey( key.to_owned(), Box::new( inner ) )
     }
 }
 
@@ -890,7 +1662,7 @@ impl TryFrom< Value > for Undefined {
     fn try_from( value: Value ) -> Result< Self, Self::Error > {
         match value {
             Value::Undefined => Ok( Undefined ),
-            _ => Err( ConversionError::type_mismatch( &value ) )
+            _ => Err( ConversionError::wrong_type( "undefined", &value ) )
         }
     }
 }
@@ -902,7 +1674,7 @@ impl TryFrom< Value > for Null {
     fn try_from( value: Value ) -> Result< Self, Self::Error > {
         match value {
             Value::Null => Ok( Null ),
-            _ => Err( ConversionError::type_mismatch( &value ) )
+            _ => Err( ConversionError::wrong_type( "null", &value ) )
         }
     }
 }
@@ -914,7 +1686,7 @@ impl TryFrom< Value > for () {
     fn try_from( value: Value ) -> Result< Self, Self::Error > {
         match value {
             Value::Null | Value::Undefined => Ok( () ),
-            _ => Err( ConversionError::type_mismatch( &value ) )
+            _ => Err( ConversionError::wrong_type( "null or undefined", &value ) )
         }
     }
 }
@@ -926,7 +1698,7 @@ impl TryFrom< Value > for bool {
     fn try_from( value: Value ) -> Result< Self, Self::Error > {
         match value {
             Value::Bool( value ) => Ok( value ),
-            _ => Err( ConversionError::type_mismatch( &value ) )
+            _ => Err( ConversionError::wrong_type( "boolean", &value ) )
         }
     }
 }
@@ -944,7 +1716,7 @@ macro_rules! impl_try_into_number {
                             let result: Result< Self, _ > = value.try_into();
                             result.map_err( |error| error.into() )
                         },
-                        _ => Err( ConversionError::type_mismatch( &value ) )
+                        _ => Err( ConversionError::wrong_type( "number", &value ) )
                     }
                 }
             }
@@ -952,7 +1724,78 @@ macro_rules! impl_try_into_number {
     };
 }
 
-impl_try_into_number!( u8 u16 u32 u64 usize i8 i16 i32 i64 f64 );
+impl_try_into_number!( u8 u16 u32 usize i8 i16 i32 f64 );
+
+macro_rules! impl_try_into_big_integer {
+    ($($kind:ty)+) => {
+        $(
+            /// Succeeds for numbers which are integers within the range which can be
+            /// represented exactly by a JavaScript number, and for `BigInt`s which fit.
+            impl TryFrom< Value > for $kind {
+                type Error = ConversionError;
+
+                fn try_from( value: Value ) -> Result< Self, Self::Error > {
+                    match value {
+                        Value::Number( value ) => {
+                            let number: f64 = value.try_into().unwrap();
+                            if number.abs() > MAX_SAFE_INTEGER as f64 {
+                                return Err( ConversionError::OutOfRange );
+                            }
+
+                            let result: Result< Self, _ > = value.try_into();
+                            result.map_err( |error| error.into() )
+                        },
+                        Value::BigInt( ref value ) => {
+                            value.to_decimal_string().parse().map_err( |_| ConversionError::OutOfRange )
+                        },
+                        _ => Err( ConversionError::wrong_type( "number or bigint", &value ) )
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_try_into_big_integer!( u64 i64 );
+
+macro_rules! impl_try_into_128_bit_integer {
+    ($($kind:ty)+) => {
+        $(
+            /// Only succeeds for `BigInt`s which fit; numbers aren't accepted
+            /// since most 128-bit integers can't be represented by them.
+            impl TryFrom< Value > for $kind {
+                type Error = ConversionError;
+
+                fn try_from( value: Value ) -> Result< Self, Self::Error > {
+                    match value {
+                        Value::BigInt( ref value ) => {
+                            value.to_decimal_string().parse().map_err( |_| ConversionError::OutOfRange )
+                        },
+                        _ => Err( ConversionError::wrong_type( "bigint", &value ) )
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_try_into_128_bit_integer!( u128 i128 );
+
+/// Interprets the number as a number of milliseconds; fails if it's negative, `NaN` or infinite.
+impl TryFrom< Value > for Duration {
+    type Error = ConversionError;
+
+    fn try_from( value: Value ) -> Result< Self, Self::Error > {
+        let milliseconds: f64 = value.try_into()?;
+        if !(milliseconds >= 0.0) || !milliseconds.is_finite() || milliseconds / 1000.0 >= u64::max_value() as f64 {
+            return Err( ConversionError::OutOfRange );
+        }
+
+        let seconds = (milliseconds / 1000.0).trunc();
+        let nanoseconds = ((milliseconds - seconds * 1000.0) * 1_000_000.0).round();
+        Ok( Duration::from_secs( seconds as u64 ) + Duration::from_nanos( nanoseconds as u64 ) )
+    }
+}
 
 impl< E: Into< ConversionError >, V: TryFrom< Value, Error = E > > TryFrom< Value > for BTreeMap< String, V > {
     type Error = ConversionError;
@@ -964,7 +1807,7 @@ impl< E: Into< ConversionError >, V: TryFrom< Value, Error = E > > TryFrom< Valu
                 let object: Object = reference.try_into()?;
                 object.try_into()
             },
-            _ => Err( ConversionError::type_mismatch( &value ) )
+            _ => Err( ConversionError::wrong_type( "object", &value ) )
         }
     }
 }
@@ -979,7 +1822,7 @@ impl< E: Into< ConversionError >, V: TryFrom< Value, Error = E > > TryFrom< Valu
                 let object: Object = reference.try_into()?;
                 object.try_into()
             },
-            _ => Err( ConversionError::type_mismatch( &value ) )
+            _ => Err( ConversionError::wrong_type( "object", &value ) )
         }
     }
 }
@@ -994,7 +1837,83 @@ impl< E: Into< ConversionError >, T: TryFrom< Value, Error = E > > TryFrom< Valu
                 let array: Array = reference.try_into()?;
                 array.try_into()
             },
-            _ => Err( ConversionError::type_mismatch( &value ) )
+            _ => Err( ConversionError::wrong_type( "array", &value ) )
+        }
+    }
+}
+
+/// Converts a JavaScript array with exactly `N` elements into a fixed-size array.
+///
+/// Fails with `ConversionError::WrongLength` if the array has any other length.
+impl< E: Into< ConversionError >, T: TryFrom< Value, Error = E >, const N: usize > TryFrom< Value > for [T; N] {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from( value: Value ) -> Result< Self, Self::Error > {
+        match value {
+            Value::Reference( reference ) => {
+                let array: Array = reference.try_into()?;
+                let length = array.len();
+                if length != N {
+                    return Err( ConversionError::WrongLength { expected: N, actual: length } );
+                }
+
+                let vector: Vec< T > = array.try_into()?;
+                match <[T; N] as ::std::convert::TryFrom< Vec< T > >>::try_from( vector ) {
+                    Ok( array ) => Ok( array ),
+                    Err( _ ) => unreachable!()
+                }
+            },
+            _ => Err( ConversionError::wrong_type( "array", &value ) )
+        }
+    }
+}
+
+/// Converts a tagged object of the form `{ ok: true, value: ... }` or
+/// `{ ok: false, error: ... }` into a `Result`.
+///
+/// This gives a standard way of propagating errors from JavaScript into Rust:
+///
+/// ```rust
+/// let result: Result< String, String > = js!(
+///     try {
+///         return { ok: true, value: JSON.parse( @{input} ).name };
+///     } catch( error ) {
+///         return { ok: false, error: error.message };
+///     }
+/// ).try_into().unwrap();
+/// ```
+impl< T, E, TE, EE > TryFrom< Value > for Result< T, E >
+    where T: TryFrom< Value, Error = TE >,
+          E: TryFrom< Value, Error = EE >,
+          TE: Into< ConversionError >,
+          EE: Into< ConversionError >
+{
+    type Error = ConversionError;
+
+    fn try_from( value: Value ) -> Result< Self, Self::Error > {
+        let ok: Value = js!(
+            var value = @{&value};
+            if( typeof value !== "object" || value === null || typeof value.ok !== "boolean" ) {
+                return null;
+            }
+
+            return value.ok;
+        );
+
+        match ok {
+            Value::Bool( true ) => {
+                let inner = js!( return @{&value}.value; );
+                T::try_from( inner ).map( Ok ).map_err( |error| error.into() )
+            },
+            Value::Bool( false ) => {
+                let inner = js!( return @{&value}.error; );
+                E::try_from( inner ).map( Err ).map_err( |error| error.into() )
+            },
+            _ => match value {
+                Value::Reference( _ ) => Err( ConversionError::MissingField( "ok".to_owned() ) ),
+                _ => Err( ConversionError::wrong_type( "object", &value ) )
+            }
         }
     }
 }
@@ -1006,11 +1925,64 @@ impl TryFrom< Value > for String {
     fn try_from( value: Value ) -> Result< Self, Self::Error > {
         match value {
             Value::String( value ) => Ok( value ),
-            _ => Err( ConversionError::type_mismatch( &value ) )
+            _ => Err( ConversionError::wrong_type( "string", &value ) )
         }
     }
 }
 
+// A JavaScript string which is a single unpaired surrogate is converted
+// by the runtime into more than one U+FFFD, so it's rejected here too.
+fn string_to_char( string: &str ) -> Result< char, ConversionError > {
+    let mut chars = string.chars();
+    match (chars.next(), chars.next()) {
+        (Some( character ), None) => Ok( character ),
+        (None, _) => Err( ConversionError::Custom( "expected a single character; got an empty string".to_owned() ) ),
+        (Some( _ ), Some( _ )) => Err( ConversionError::Custom( format!( "expected a single character; got {:?}", string ) ) )
+    }
+}
+
+impl TryFrom< Value > for char {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from( value: Value ) -> Result< Self, Self::Error > {
+        match value {
+            Value::String( ref value ) => string_to_char( value ),
+            _ => Err( ConversionError::wrong_type( "string", &value ) )
+        }
+    }
+}
+
+fn parse_string< T: str::FromStr >( value: Value, expected: &'static str ) -> Result< T, ConversionError > {
+    match value {
+        Value::String( ref value ) => value.parse().map_err( |_| ConversionError::Parse {
+            expected: expected,
+            input: value.clone()
+        }),
+        _ => Err( ConversionError::wrong_type( "string", &value ) )
+    }
+}
+
+/// Parses an IPv4 or IPv6 address, e.g. `"127.0.0.1"` or `"::1"`.
+impl TryFrom< Value > for IpAddr {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from( value: Value ) -> Result< Self, Self::Error > {
+        parse_string( value, "IP address" )
+    }
+}
+
+/// Parses an IP address with a port, e.g. `"127.0.0.1:8080"` or `"[::1]:8080"`.
+impl TryFrom< Value > for SocketAddr {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from( value: Value ) -> Result< Self, Self::Error > {
+        parse_string( value, "socket address" )
+    }
+}
+
 impl TryFrom< Value > for Symbol {
     type Error = ConversionError;
 
@@ -1018,7 +1990,19 @@ impl TryFrom< Value > for Symbol {
     fn try_from( value: Value ) -> Result< Self, Self::Error > {
         match value {
             Value::Symbol( value ) => Ok( value ),
-            _ => Err( ConversionError::type_mismatch( &value ) )
+            _ => Err( ConversionError::wrong_type( "symbol", &value ) )
+        }
+    }
+}
+
+impl TryFrom< Value > for BigInt {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from( value: Value ) -> Result< Self, Self::Error > {
+        match value {
+            Value::BigInt( value ) => Ok( value ),
+            _ => Err( ConversionError::wrong_type( "bigint", &value ) )
         }
     }
 }
@@ -1030,7 +2014,7 @@ impl TryFrom< Value > for Reference {
     fn try_from( value: Value ) -> Result< Self, Self::Error > {
         match value {
             Value::Reference( value ) => Ok( value ),
-            _ => Err( ConversionError::type_mismatch( &value ) )
+            _ => Err( ConversionError::wrong_type( "object", &value ) )
         }
     }
 }
@@ -1042,7 +2026,7 @@ impl< 'a > TryFrom< &'a Value > for &'a str {
     fn try_from( value: &'a Value ) -> Result< Self, Self::Error > {
         match *value {
             Value::String( ref value ) => Ok( value ),
-            _ => Err( ConversionError::type_mismatch( &value ) )
+            _ => Err( ConversionError::wrong_type( "string", &value ) )
         }
     }
 }
@@ -1054,7 +2038,7 @@ impl< 'a > TryFrom< &'a Value > for &'a Symbol {
     fn try_from( value: &'a Value ) -> Result< Self, Self::Error > {
         match *value {
             Value::Symbol( ref value ) => Ok( value ),
-            _ => Err( ConversionError::type_mismatch( &value ) )
+            _ => Err( ConversionError::wrong_type( "symbol", &value ) )
         }
     }
 }
@@ -1066,7 +2050,7 @@ impl< 'a > TryFrom< &'a Value > for &'a Reference {
     fn try_from( value: &'a Value ) -> Result< Self, Self::Error > {
         match *value {
             Value::Reference( ref value ) => Ok( value ),
-            _ => Err( ConversionError::type_mismatch( &value ) )
+            _ => Err( ConversionError::wrong_type( "object", &value ) )
         }
     }
 }
@@ -1122,8 +2106,13 @@ impl_nullable_try_from_value! {
     impl< V > BTreeMap< String, V > where (V: TryFrom< Value, Error = ConversionError >);
     impl< V > HashMap< String, V > where (V: TryFrom< Value, Error = ConversionError >);
     impl< T > Vec< T > where (T: TryFrom< Value, Error = ConversionError >);
+    Duration;
     String;
+    char;
+    IpAddr;
+    SocketAddr;
     Symbol;
+    BigInt;
 }
 
 impl< 'a > TryFrom< &'a Value > for Option< &'a str > {
@@ -1186,6 +2175,34 @@ mod tests {
         assert!( value != "Bob" );
     }
 
+    #[test]
+    fn debug_string() {
+        let value = js!(
+            function Point() { this.x = 1; }
+            var object = { a: [ 1, "b", null, undefined ], c: function handler() {}, d: { e: { f: true } }, g: new Point() };
+            object.self = object;
+            return object;
+        );
+
+        assert_eq!(
+            value.debug_string( 2 ),
+            r#"{ "a": [ 1, "b", null, undefined ], "c": [Function: handler], "d": { "e": [Object] }, "g": Point { "x": 1 }, "self": [Circular] }"#
+        );
+        assert_eq!( value.debug_string( 0 ), "[Object]" );
+        assert_eq!( js!( return [ [], {} ]; ).debug_string( 1 ), "[ [Array], [Object] ]" );
+        assert_eq!( js!( return new Array( 101 ).fill( 0 ); ).debug_string( 1 ).matches( ", " ).count(), 100 );
+        assert_eq!( Value::String( "a \"b\"".to_owned() ).debug_string( 1 ), r#""a \"b\"""# );
+    }
+
+    #[test]
+    fn is_thenable() {
+        assert!( js!( return Promise.resolve( 1 ); ).is_thenable() );
+        assert!( js!( return { then: function() {} }; ).is_thenable() );
+        assert!( !js!( return { then: 1 }; ).is_thenable() );
+        assert!( !js!( return {}; ).is_thenable() );
+        assert!( !Value::Number( 1.into() ).is_thenable() );
+    }
+
     #[test]
     fn reference_equality() {
         let value = js! { return new Date() };
@@ -1334,6 +2351,126 @@ mod tests {
         assert!(!is_known_reference(refid));
     }
 
+    #[test]
+    fn try_get_path() {
+        let value = js!( return { data: { items: [ { name: "first" } ], empty: null } }; );
+        assert_eq!( value.try_get_path( &[ "data", "items", "0", "name" ] ), Some( Value::String( "first".to_owned() ) ) );
+        assert_eq!( value.try_get_path( &[ "data", "empty" ] ), Some( Value::Null ) );
+        assert_eq!( value.try_get_path( &[ "data", "empty", "name" ] ), None );
+        assert_eq!( value.try_get_path( &[ "data", "items", "1" ] ), None );
+        assert_eq!( value.try_get_path( &[ "data", "items", "length" ] ), Some( Value::Number( 1.into() ) ) );
+        assert_eq!( value.try_get_path( &[] ), Some( value.clone() ) );
+    }
+
+    #[test]
+    fn fixed_size_array_conversion() {
+        let color: Value = [255_u8, 128, 0, 255].into();
+        let length: usize = js!( return @{&color}.length; ).try_into().unwrap();
+        assert_eq!( length, 4 );
+
+        let color: [u8; 4] = color.try_into().unwrap();
+        assert_eq!( color, [255, 128, 0, 255] );
+
+        let uuid: [u8; 16] = js!( return Array.from( { length: 16 }, function( _, index ) { return index; } ); ).try_into().unwrap();
+        assert_eq!( uuid[ 15 ], 15 );
+
+        let names: [String; 2] = js!( return [ "a", "b" ]; ).try_into().unwrap();
+        assert_eq!( names, [ "a".to_owned(), "b".to_owned() ] );
+
+        let empty: [u8; 0] = js!( return []; ).try_into().unwrap();
+        assert_eq!( empty.len(), 0 );
+
+        let result: Result< [u8; 4], _ > = js!( return [ 1, 2, 3 ]; ).try_into();
+        assert_eq!( result, Err( super::ConversionError::WrongLength { expected: 4, actual: 3 } ) );
+
+        let result: Result< [u8; 2], _ > = js!( return [ 1, "2" ]; ).try_into();
+        assert!( result.is_err() );
+    }
+
+    #[test]
+    fn freeze() {
+        use webcore::object::Object;
+
+        let value = js!( return { a: 1, nested: { b: 2 } }; );
+        assert!( !value.is_frozen() );
+
+        value.freeze();
+        assert!( value.is_frozen() );
+
+        let object: Object = value.clone().try_into().unwrap();
+        object.set_field( "a", 2 );
+        object.set_field( "c", 3 );
+        assert_eq!( value.get_path( "a" ), 1 );
+        assert_eq!( value.get_path( "c" ), Value::Undefined );
+
+        let nested = value.get_path( "nested" );
+        assert!( !nested.is_frozen() );
+
+        let primitive = Value::Number( 1.into() );
+        primitive.freeze();
+        assert!( primitive.is_frozen() );
+        assert_eq!( primitive, 1 );
+    }
+
+    #[test]
+    fn collection_conversion_errors() {
+        use std::collections::BTreeMap;
+
+        let result: Result< Vec< i32 >, _ > = js!( return [ 1, "2", 3 ]; ).try_into();
+        let error = result.unwrap_err();
+        assert_eq!( format!( "{}", error ), "at index 1: expected number, got string" );
+
+        let result: Result< BTreeMap< String, Vec< i32 > >, _ > = js!( return { a: [ 1 ], b: [ 2, null ] }; ).try_into();
+        let error = result.unwrap_err();
+        assert_eq!( format!( "{}", error ), "while converting field `b`: at index 1: expected number, got null" );
+
+        let source = ::std::error::Error::source( &error ).unwrap();
+        assert_eq!( source.to_string(), "at index 1: expected number, got null" );
+        assert_eq!( format!( "{:?}", error ), format!( "ConversionError({:?})", error.to_string() ) );
+
+        let result: Result< u8, _ > = js!( return 256; ).try_into();
+        assert_eq!( result, Err( super::ConversionError::OutOfRange ) );
+
+        let result: Result< Result< i32, String >, _ > = js!( return {}; ).try_into();
+        assert_eq!( result, Err( super::ConversionError::MissingField( "ok".to_owned() ) ) );
+
+        let value: Option< Vec< String > > = js!( return null; ).try_into().unwrap();
+        assert_eq!( value, None );
+
+        let value: Option< Vec< String > > = js!( return [ "a" ]; ).try_into().unwrap();
+        assert_eq!( value, Some( vec![ "a".to_owned() ] ) );
+    }
+
+    #[test]
+    fn duration_conversion() {
+        use std::time::Duration;
+
+        let value: Value = Duration::from_secs( 30 ).into();
+        assert_eq!( value, 30000 );
+        let value: Value = Duration::new( 1, 500_250_000 ).into();
+        assert_eq!( value, 1500.25 );
+
+        let milliseconds: f64 = js!( return @{Duration::from_micros( 1500 )}; ).try_into().unwrap();
+        assert_eq!( milliseconds, 1.5 );
+
+        let duration: Duration = js!( return 2500.5; ).try_into().unwrap();
+        assert_eq!( duration, Duration::new( 2, 500_500_000 ) );
+
+        let duration: Result< Duration, _ > = js!( return -1; ).try_into();
+        assert!( duration.is_err() );
+        let duration: Result< Duration, _ > = js!( return NaN; ).try_into();
+        assert!( duration.is_err() );
+        let duration: Result< Duration, _ > = js!( return "1000"; ).try_into();
+        assert!( duration.is_err() );
+    }
+
+    #[test]
+    fn reference_on_finalize() {
+        let supported: bool = js! { return typeof FinalizationRegistry === "function"; }.try_into().unwrap();
+        let obj = js! { return new Object(); };
+        assert_eq!(obj.as_reference().unwrap().on_finalize(|| {}), supported);
+    }
+
     #[test]
     fn reference_refcount_clone() {
         let obj = js! { return new Object(); };
@@ -1351,4 +2488,337 @@ mod tests {
         drop(obj2);
         assert!(!is_known_reference(refid));
     }
+
+    #[test]
+    fn apply() {
+        let function = js! { return function( a, b ) { return this.base + a + b; }; };
+        let this = js! { return { base: 100 }; };
+        let args = js! { return [ 10, 1 ]; };
+        assert_eq!( function.apply( &this, &args ).unwrap(), 111 );
+
+        let arguments = js! { return (function() { return arguments; })( 20, 2 ); };
+        assert_eq!( function.apply( &this, &arguments ).unwrap(), 122 );
+    }
+
+    #[test]
+    fn apply_with_non_array_args() {
+        let function = js! { return function() {}; };
+        assert!( function.apply( &Value::Undefined, &Value::Bool( true ) ).is_err() );
+        assert!( function.apply( &Value::Undefined, &js! { return {}; } ).is_err() );
+    }
+
+    #[test]
+    fn apply_on_non_function() {
+        let value = Value::String( "foo".to_owned() );
+        let args = js! { return []; };
+        assert!( value.apply( &Value::Undefined, &args ).is_err() );
+    }
+
+    #[test]
+    fn parse_path() {
+        assert_eq!( super::parse_path( "" ), Vec::< String >::new() );
+        assert_eq!( super::parse_path( "data" ), vec![ "data" ] );
+        assert_eq!( super::parse_path( "data.items.0.id" ), vec![ "data", "items", "0", "id" ] );
+        assert_eq!( super::parse_path( "data.items[0].id" ), vec![ "data", "items", "0", "id" ] );
+        assert_eq!( super::parse_path( "[1][2]" ), vec![ "1", "2" ] );
+    }
+
+    #[test]
+    fn deep_eq() {
+        let left = js!( return { a: 1, b: { c: [ 1, 2, "three" ], d: null } }; );
+        let right = js!( return { b: { d: null, c: [ 1, 2, "three" ] }, a: 1.0 }; );
+        assert!( left.deep_eq( &right ) );
+        assert!( right.deep_eq( &left ) );
+
+        let reordered = js!( return [ 2, 1 ]; );
+        assert!( !reordered.deep_eq( &js!( return [ 1, 2 ]; ) ) );
+
+        let date = js!( return new Date( 0 ); );
+        assert!( date.deep_eq( &date ) );
+        assert!( !date.deep_eq( &js!( return new Date( 0 ); ) ) );
+    }
+
+    #[test]
+    fn deep_difference() {
+        let left = js!( return { a: 1, b: { c: [ 1, 2, 3 ] } }; );
+        let right = js!( return { b: { c: [ 1, 5, 3 ] }, a: 1 }; );
+        assert_eq!( super::deep_difference( &left, &right ), Some( ("b.c[1]".to_owned(), Value::from( 2 ), Value::from( 5 )) ) );
+
+        let missing = js!( return { a: 1 }; );
+        let extra = js!( return { a: 1, b: true }; );
+        assert_eq!( super::deep_difference( &missing, &extra ), Some( ("b".to_owned(), Value::Undefined, Value::Bool( true )) ) );
+
+        assert_eq!( super::deep_difference( &Value::from( 1 ), &Value::from( "1" ) ), Some( ("".to_owned(), Value::from( 1 ), Value::from( "1" )) ) );
+    }
+
+    #[test]
+    fn assert_value_eq() {
+        assert_value_eq!( js!( return { x: [ { y: 1 } ], z: "z" }; ), js!( return { z: "z", x: [ { y: 1 } ] }; ) );
+    }
+
+    #[test]
+    #[should_panic(expected = "differs at `x[0].y`")]
+    fn assert_value_eq_failure() {
+        assert_value_eq!( js!( return { x: [ { y: 1 } ] }; ), js!( return { x: [ { y: 2 } ] }; ) );
+    }
+
+    #[test]
+    fn get_path() {
+        let value = js! { return { data: { items: [ { id: 1 }, { id: 2, tags: [ "a" ] } ] } }; };
+        assert_eq!( value.get_path( "data.items.0.id" ), 1 );
+        assert_eq!( value.get_path( "data.items[1].id" ), 2 );
+        assert_eq!( value.get_path( "data.items[1].tags[0]" ), "a" );
+        assert_eq!( value.get_path( "data.items.length" ), 2 );
+        assert_eq!( value.get_path( "" ), value );
+        assert_eq!( value.get_path( "data.missing.id" ), Value::Undefined );
+        assert_eq!( value.get_path( "data.items[5].id" ), Value::Undefined );
+        assert_eq!( value.get_path( "data.items.0.id.foo" ), Value::Undefined );
+        assert_eq!( Value::Null.get_path( "foo" ), Value::Undefined );
+    }
+
+    #[test]
+    fn result_conversion() {
+        let result: Result< i32, String > = js!( return { ok: true, value: 123 }; ).try_into().unwrap();
+        assert_eq!( result, Ok( 123 ) );
+
+        let result: Result< i32, String > = js!( return { ok: false, error: "failed" }; ).try_into().unwrap();
+        assert_eq!( result, Err( "failed".to_owned() ) );
+
+        let result: Result< (), Value > = js!( return { ok: true }; ).try_into().unwrap();
+        assert_eq!( result, Ok( () ) );
+    }
+
+    #[test]
+    fn result_conversion_failures() {
+        let result: Result< Result< i32, String >, _ > = js!( return 123; ).try_into();
+        assert!( result.is_err() );
+
+        let result: Result< Result< i32, String >, _ > = js!( return { value: 123 }; ).try_into();
+        assert!( result.is_err() );
+
+        let result: Result< Result< i32, String >, _ > = js!( return { ok: true, value: "foo" }; ).try_into();
+        assert!( result.is_err() );
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn structured_clone() {
+        let value = js! {
+            return {
+                nested: { deeper: { array: new Uint8Array( [ 1, 2, 3 ] ) } },
+                map: new Map( [ [ "key", 1 ] ] ),
+                set: new Set( [ 1, 2 ] ),
+                date: new Date( 0 )
+            };
+        };
+
+        let clone = value.structured_clone().unwrap();
+        assert_ne!( clone, value );
+
+        let is_deep_copy: bool = js!(
+            var value = @{&value};
+            var clone = @{&clone};
+            var array = clone.nested.deeper.array;
+            return array !== value.nested.deeper.array &&
+                array instanceof Uint8Array &&
+                array.length === 3 && array[ 2 ] === 3 &&
+                clone.map instanceof Map && clone.map.get( "key" ) === 1 &&
+                clone.set instanceof Set && clone.set.has( 2 ) &&
+                clone.date instanceof Date && clone.date.getTime() === 0;
+        ).try_into().unwrap();
+        assert!( is_deep_copy );
+
+        assert_eq!( Value::Number( 1.into() ).structured_clone().unwrap(), 1 );
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn structured_clone_fallback() {
+        js! { @(no_return)
+            self.__stdweb_structured_clone = self.structuredClone;
+            self.structuredClone = undefined;
+        }
+
+        let value = js! {
+            var value = { array: new Float64Array( [ 0.5 ] ), map: new Map() };
+            value.map.set( "self", value );
+            return value;
+        };
+
+        let clone = value.structured_clone();
+        let closure = js! { return @{|| {}}; };
+        let closure_clone = closure.structured_clone();
+        js! { @(no_return)
+            self.structuredClone = self.__stdweb_structured_clone;
+            delete self.__stdweb_structured_clone;
+            @{closure}.drop();
+        }
+
+        let clone = clone.unwrap();
+        let is_deep_copy: bool = js!(
+            var value = @{&value};
+            var clone = @{&clone};
+            return clone !== value &&
+                clone.map.get( "self" ) === clone &&
+                clone.array instanceof Float64Array &&
+                clone.array !== value.array &&
+                clone.array[ 0 ] === 0.5;
+        ).try_into().unwrap();
+        assert!( is_deep_copy );
+        assert!( closure_clone.is_err() );
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn structured_clone_of_closure() {
+        let closure = js! { return @{|| {}}; };
+        assert!( closure.structured_clone().is_err() );
+        js! { @(no_return) @{closure}.drop(); }
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn on_finalize() {
+        let is_supported: bool = js!( return typeof FinalizationRegistry === "function"; ).try_into().unwrap();
+        let reference: Reference = js!( return {}; ).try_into().unwrap();
+        assert_eq!( reference.on_finalize( || {} ), is_supported );
+    }
+
+    #[cfg(feature = "web_test")]
+    #[test]
+    fn on_finalize_calls_the_callback() {
+        use std::rc::Rc;
+        use std::cell::Cell;
+
+        // A registry which lets us trigger the finalization by hand.
+        js! { @(no_return)
+            self.__stdweb_finalization_registry = self.FinalizationRegistry;
+            self.__stdweb_cached_registry = Module.STDWEB_PRIVATE.finalization_registry;
+            delete Module.STDWEB_PRIVATE.finalization_registry;
+            self.FinalizationRegistry = function( cleanup ) {
+                this.register = function( target, held_value ) {
+                    self.__stdweb_finalize = function() {
+                        cleanup( held_value );
+                    };
+                };
+            };
+        }
+
+        let finalized = Rc::new( Cell::new( false ) );
+        let reference: Reference = js!( return {}; ).try_into().unwrap();
+        let is_registered = reference.on_finalize({
+            let finalized = finalized.clone();
+            move || finalized.set( true )
+        });
+
+        assert!( is_registered );
+        assert!( !finalized.get() );
+        js! { @(no_return)
+            self.__stdweb_finalize();
+            delete self.__stdweb_finalize;
+            self.FinalizationRegistry = self.__stdweb_finalization_registry;
+            Module.STDWEB_PRIVATE.finalization_registry = self.__stdweb_cached_registry;
+            delete self.__stdweb_finalization_registry;
+            delete self.__stdweb_cached_registry;
+        }
+        assert!( finalized.get() );
+    }
+
+    #[test]
+    fn char_conversion() {
+        let value: Value = 'a'.into();
+        assert_eq!( value, "a" );
+
+        let character: char = js!( return "a"; ).try_into().unwrap();
+        assert_eq!( character, 'a' );
+
+        let character: char = js!( return "é"; ).try_into().unwrap();
+        assert_eq!( character, '\u{e9}' );
+
+        let character: char = js!( return String.fromCodePoint( 0x1F600 ); ).try_into().unwrap();
+        assert_eq!( character, '\u{1F600}' );
+
+        let character: Option< char > = js!( return null; ).try_into().unwrap();
+        assert_eq!( character, None );
+
+        let value: Value = '\u{FFFD}'.into();
+        let character: char = js!( return @{value}; ).try_into().unwrap();
+        assert_eq!( character, '\u{FFFD}' );
+
+        let character: char = js!( return String.fromCharCode( 0xFFFD ); ).try_into().unwrap();
+        assert_eq!( character, '\u{FFFD}' );
+    }
+
+    #[test]
+    fn char_conversion_failures() {
+        let character: Result< char, _ > = js!( return ""; ).try_into();
+        assert!( character.is_err() );
+
+        let character: Result< char, _ > = js!( return "ab"; ).try_into();
+        assert!( character.is_err() );
+
+        let character: Result< char, _ > = js!( return String.fromCharCode( 0xD800 ); ).try_into();
+        assert!( character.is_err() );
+
+        let character: Result< char, _ > = js!( return String.fromCharCode( 0xDC00 ); ).try_into();
+        assert!( character.is_err() );
+
+        let character: Result< char, _ > = js!( return 1; ).try_into();
+        assert!( character.is_err() );
+    }
+
+    #[test]
+    fn string_with_unpaired_surrogates() {
+        let string: String = js!( return String.fromCharCode( 0xD800 ) + "a" + String.fromCharCode( 0xDC00 ); ).try_into().unwrap();
+        assert_eq!( string, "\u{FFFD}a\u{FFFD}" );
+
+        let string: String = js!( return String.fromCharCode( 0xD800 ); ).try_into().unwrap();
+        assert_eq!( string, "\u{FFFD}\u{FFFD}\u{FFFD}" );
+
+        let string: String = js!( return String.fromCharCode( 0xD83D, 0xDE00 ); ).try_into().unwrap();
+        assert_eq!( string, "\u{1F600}" );
+    }
+
+    #[test]
+    fn ip_address_conversion() {
+        use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+        let address: IpAddr = js!( return "192.168.0.1"; ).try_into().unwrap();
+        assert_eq!( address, IpAddr::V4( Ipv4Addr::new( 192, 168, 0, 1 ) ) );
+
+        let address: IpAddr = js!( return "::1"; ).try_into().unwrap();
+        assert_eq!( address, IpAddr::V6( Ipv6Addr::LOCALHOST ) );
+
+        let address: Option< IpAddr > = js!( return null; ).try_into().unwrap();
+        assert_eq!( address, None );
+
+        let address: Result< IpAddr, _ > = js!( return "256.0.0.1"; ).try_into();
+        assert_eq!( address, Err( super::ConversionError::Parse {
+            expected: "IP address",
+            input: "256.0.0.1".to_owned()
+        }));
+
+        let address: Result< IpAddr, _ > = js!( return 1; ).try_into();
+        assert!( address.is_err() );
+    }
+
+    #[test]
+    fn socket_address_conversion() {
+        use std::net::{SocketAddr, Ipv4Addr, Ipv6Addr};
+
+        let address: SocketAddr = js!( return "127.0.0.1:8080"; ).try_into().unwrap();
+        assert_eq!( address, SocketAddr::new( Ipv4Addr::new( 127, 0, 0, 1 ).into(), 8080 ) );
+
+        let address: SocketAddr = js!( return "[::1]:8080"; ).try_into().unwrap();
+        assert_eq!( address, SocketAddr::new( Ipv6Addr::LOCALHOST.into(), 8080 ) );
+
+        let address: Result< SocketAddr, _ > = js!( return "::1:8080"; ).try_into();
+        assert!( address.is_err() );
+
+        let address: Result< SocketAddr, _ > = js!( return "example.com:80"; ).try_into();
+        assert!( address.is_err() );
+
+        let address: Result< SocketAddr, _ > = js!( return "127.0.0.1"; ).try_into();
+        assert!( address.is_err() );
+    }
 }
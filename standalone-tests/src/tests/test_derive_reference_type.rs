@@ -4,10 +4,33 @@ use utils::*;
 #[reference(instance_of = "Error")]
 pub struct Error( ::stdweb::Reference );
 
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+pub struct XhrLike( ::stdweb::Reference );
+
+impl ::stdweb::InstanceOf for XhrLike {
+    fn instance_of( reference: &::stdweb::Reference ) -> bool {
+        use stdweb::unstable::TryInto;
+        js!(
+            var object = @{reference};
+            return typeof object.open === "function" && typeof object.send === "function";
+        ).try_into().unwrap()
+    }
+}
+
 pub fn run() {
     test( "custom_reference_type", || {
         use stdweb::unstable::TryInto;
         let value = js! { return new ReferenceError(); };
         let _: Error = value.try_into().unwrap();
     });
+
+    test( "custom_reference_type_with_manual_instance_of", || {
+        use stdweb::unstable::TryInto;
+        let value = js! { return { open: function() {}, send: function() {} }; };
+        let _: XhrLike = value.try_into().unwrap();
+
+        let value = js! { return { open: function() {} }; };
+        let result: Result< XhrLike, _ > = value.try_into();
+        assert!( result.is_err() );
+    });
 }
@@ -248,7 +248,7 @@ pub fn derive_reference_type( input: TokenStream ) -> TokenStream {
             #[inline]
             fn try_from( reference: ::stdweb::Reference ) -> Result< Self, Self::Error > {
                 reference.downcast()
-                    .ok_or_else( || ::stdweb::private::ConversionError::Custom( "reference is of a different type".into() ) )
+                    .ok_or_else( || ::stdweb::private::ConversionError::WrongType { expected: stringify!( #name ), actual: "object" } )
             }
         }
 
@@ -267,10 +267,13 @@ pub fn derive_reference_type( input: TokenStream ) -> TokenStream {
 
             #[inline]
             fn try_from( value: ::stdweb::Value ) -> Result< Self, Self::Error > {
-                use ::stdweb::unstable::TryInto;
-                let reference: ::stdweb::Reference = value.try_into()?;
+                let reference = match value {
+                    ::stdweb::Value::Reference( reference ) => reference,
+                    value => return Err( ::stdweb::private::ConversionError::wrong_type( stringify!( #name ), &value ) )
+                };
+
                 reference.downcast()
-                    .ok_or_else( || ::stdweb::private::ConversionError::Custom( "reference is of a different type".into() ) )
+                    .ok_or_else( || ::stdweb::private::ConversionError::WrongType { expected: stringify!( #name ), actual: "object" } )
             }
         }
 
@@ -282,7 +285,7 @@ pub fn derive_reference_type( input: TokenStream ) -> TokenStream {
                 use ::stdweb::unstable::TryInto;
                 let reference: &::stdweb::Reference =
                     value.as_reference()
-                    .ok_or_else( || ::stdweb::private::ConversionError::Custom( "not a reference".into() ) )?;
+                    .ok_or_else( || ::stdweb::private::ConversionError::wrong_type( stringify!( #name ), value ) )?;
 
                 reference.try_into()
             }